@@ -1,6 +1,6 @@
 use alloy_dyn_abi::{DynSolType, DynSolValue};
-use alloy_primitives::{hex, U256};
-use alloy_sol_types::{sol, sol_data, SolType};
+use alloy_primitives::{bytes::BytesMut, hex, Bytes, U256};
+use alloy_sol_types::{abi, sol, sol_data, Encodable, SolCall, SolType};
 use criterion::{
     criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
 };
@@ -101,6 +101,60 @@ fn sol_types_encode(c: &mut Criterion) {
     g.finish();
 }
 
+fn sol_types_encode_requests(c: &mut Criterion) {
+    let mut g = group(c, "sol-types/encode_requests");
+
+    // builds the calldata of 100 requests, each ending up as `Bytes`
+    g.bench_function("vec", |b| {
+        let input = encode_struct_input();
+        b.iter(|| {
+            (0..100)
+                .map(|_| {
+                    Bytes::from(
+                        exactInputSingleCall {
+                            params: input.clone(),
+                        }
+                        .abi_encode(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        });
+    });
+
+    g.bench_function("bytes", |b| {
+        let input = encode_struct_input();
+        b.iter(|| {
+            (0..100)
+                .map(|_| {
+                    exactInputSingleCall {
+                        params: input.clone(),
+                    }
+                    .abi_encode_bytes()
+                })
+                .collect::<Vec<_>>()
+        });
+    });
+
+    g.bench_function("bytes_mut", |b| {
+        let input = encode_struct_input();
+        let mut buf = BytesMut::with_capacity(100 * 260);
+        b.iter(|| {
+            (0..100)
+                .map(|_| {
+                    let call = exactInputSingleCall {
+                        params: input.clone(),
+                    };
+                    buf.extend_from_slice(&exactInputSingleCall::SELECTOR);
+                    abi::encode_into_bytes_mut(&call.tokenize(), &mut buf);
+                    Bytes::from(buf.split().freeze())
+                })
+                .collect::<Vec<_>>()
+        });
+    });
+
+    g.finish();
+}
+
 fn sol_types_decode(c: &mut Criterion) {
     let mut g = group(c, "sol-types/decode");
 
@@ -117,6 +171,109 @@ fn sol_types_decode(c: &mut Criterion) {
     g.finish();
 }
 
+fn sol_types_dispatch(c: &mut Criterion) {
+    use alloy_sol_types::SolInterface;
+
+    let mut g = group(c, "sol-types/dispatch");
+
+    // decodes a call to each of the 128 functions, with zeroes as arguments,
+    // in an order that is not sorted by selector
+    let selectors = table::LargeInterface::LargeInterfaceCalls::SELECTORS;
+    let calldata = (0..selectors.len())
+        .map(|i| {
+            let mut data = vec![0; 4 + 32 * 8];
+            data[..4].copy_from_slice(&selectors[i * 37 % selectors.len()]);
+            data
+        })
+        .collect::<Vec<_>>();
+
+    g.bench_function("linear/decode", |b| {
+        b.iter(|| {
+            for data in &calldata {
+                linear::LargeInterface::LargeInterfaceCalls::abi_decode(black_box(data), false)
+                    .unwrap();
+            }
+        });
+    });
+
+    g.bench_function("table/decode", |b| {
+        b.iter(|| {
+            for data in &calldata {
+                table::LargeInterface::LargeInterfaceCalls::abi_decode(black_box(data), false)
+                    .unwrap();
+            }
+        });
+    });
+
+    g.bench_function("linear/valid_selector", |b| {
+        b.iter(|| {
+            for data in &calldata {
+                let selector = black_box(data)[..4].try_into().unwrap();
+                assert!(linear::LargeInterface::LargeInterfaceCalls::valid_selector(
+                    selector
+                ));
+            }
+        });
+    });
+
+    g.bench_function("table/valid_selector", |b| {
+        b.iter(|| {
+            for data in &calldata {
+                let selector = black_box(data)[..4].try_into().unwrap();
+                assert!(table::LargeInterface::LargeInterfaceCalls::valid_selector(
+                    selector
+                ));
+            }
+        });
+    });
+
+    g.finish();
+}
+
+/// Compares the entry points instrumented by the `tracing` feature with the
+/// same encoding and decoding done through the uninstrumented `abi` functions.
+///
+/// Without the feature, the two should not differ. Run with
+/// `--features tracing` to measure the cost of the disabled spans.
+fn sol_types_tracing(c: &mut Criterion) {
+    let mut g = group(c, "sol-types/tracing");
+
+    let input = encode_struct_input();
+    let data = Input::abi_encode(&input);
+
+    g.bench_function("instrumented/encode", |b| {
+        b.iter(|| Input::abi_encode(black_box(&input)));
+    });
+
+    g.bench_function("bare/encode", |b| {
+        b.iter(|| abi::encode(&<Input as Encodable<Input>>::to_tokens(black_box(&input))));
+    });
+
+    g.bench_function("instrumented/decode", |b| {
+        b.iter(|| Input::abi_decode(black_box(&data), false).unwrap());
+    });
+
+    g.bench_function("bare/decode", |b| {
+        b.iter(|| {
+            let token = abi::decode::<<Input as SolType>::TokenType<'_>>(black_box(&data), false);
+            Input::detokenize(token.unwrap())
+        });
+    });
+
+    g.finish();
+}
+
+mod linear {
+    alloy_sol_types::sol!(
+        #![sol(phf = false)]
+        "../sol-types/tests/contracts/LargeInterface.sol"
+    );
+}
+
+mod table {
+    alloy_sol_types::sol!("../sol-types/tests/contracts/LargeInterface.sol");
+}
+
 sol! {
     /// UniswapV3's `SwapRouter::ExactInputSingleParams`:
     /// <https://github.com/Uniswap/v3-periphery/blob/6cce88e63e176af1ddb6cc56e029110289622317/contracts/interfaces/ISwapRouter.sol#L10C10-L19>
@@ -130,6 +287,8 @@ sol! {
         uint256 amountOutMinimum;
         uint160 sqrtPriceLimitX96;
     }
+
+    function exactInputSingle(Input params) external payable returns (uint256 amountOut);
 }
 
 fn encode_single_input() -> String {
@@ -214,6 +373,9 @@ criterion_group!(
     dyn_abi_encode,
     dyn_abi_decode,
     sol_types_encode,
+    sol_types_encode_requests,
     sol_types_decode,
+    sol_types_dispatch,
+    sol_types_tracing,
 );
 criterion_main!(benches);
@@ -649,6 +649,8 @@ mod tests {
             prop_assert_eq!(parsed.as_ref(), Ok(&ty), "types don't match {:?}", s);
         }
 
+        prop_assert_eq!(value.abi_encoded_size(), value.abi_encode().len());
+
         let data = value.abi_encode_params();
         match ty.abi_decode_params(&data) {
             // skip the check if the type contains a CustomStruct, since
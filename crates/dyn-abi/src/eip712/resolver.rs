@@ -1,10 +1,12 @@
 use crate::{
-    eip712::typed_data::Eip712Types, eip712_parser::EncodeType, resolve::ResolveSolType,
+    collections::{Map, Set},
+    eip712::typed_data::Eip712Types,
+    eip712_parser::EncodeType,
+    resolve::ResolveSolType,
     DynSolType, DynSolValue, Error, Result,
 };
 use alloc::{
     borrow::ToOwned,
-    collections::{BTreeMap, BTreeSet},
     string::{String, ToString},
     vec::Vec,
 };
@@ -209,8 +211,8 @@ impl TypeDef {
 
 #[derive(Debug, Default)]
 struct DfsContext<'a> {
-    visited: BTreeSet<&'a TypeDef>,
-    stack: BTreeSet<&'a str>,
+    visited: Set<&'a TypeDef>,
+    stack: Set<&'a str>,
 }
 
 /// A dependency graph built from the `Eip712Types` object. This is used to
@@ -221,9 +223,9 @@ pub struct Resolver {
     /// Nodes in the graph
     // NOTE: Non-duplication of names must be enforced. See note on impl of Ord
     // for TypeDef
-    nodes: BTreeMap<String, TypeDef>,
+    nodes: Map<String, TypeDef>,
     /// Edges from a type name to its dependencies.
-    edges: BTreeMap<String, Vec<String>>,
+    edges: Map<String, Vec<String>>,
 }
 
 impl Serialize for Resolver {
@@ -563,6 +565,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn encode_type_is_deterministic() {
+        // same types, declared in different orders
+        let jsons = [
+            r#"{
+                "Mail": [{ "name": "from", "type": "Person" }, { "name": "to", "type": "Person[]" }, { "name": "attachment", "type": "Attachment" }],
+                "Person": [{ "name": "name", "type": "string" }, { "name": "wallet", "type": "address" }],
+                "Attachment": [{ "name": "owner", "type": "Person" }, { "name": "data", "type": "bytes" }]
+            }"#,
+            r#"{
+                "Attachment": [{ "name": "owner", "type": "Person" }, { "name": "data", "type": "bytes" }],
+                "Person": [{ "name": "name", "type": "string" }, { "name": "wallet", "type": "address" }],
+                "Mail": [{ "name": "from", "type": "Person" }, { "name": "to", "type": "Person[]" }, { "name": "attachment", "type": "Attachment" }]
+            }"#,
+        ];
+        let expected = "Mail(Person from,Person[] to,Attachment attachment)\
+                        Attachment(Person owner,bytes data)\
+                        Person(string name,address wallet)";
+
+        for json in jsons {
+            let types: Eip712Types = serde_json::from_str(json).unwrap();
+            let first = Resolver::from(&types);
+            let second = Resolver::from(&types);
+            assert_eq!(first.encode_type("Mail").unwrap(), expected);
+            assert_eq!(second.encode_type("Mail").unwrap(), expected);
+            assert_eq!(
+                serde_json::to_string(&first).unwrap(),
+                serde_json::to_string(&second).unwrap()
+            );
+        }
+    }
+
     #[test]
     fn it_resolves_types() {
         let mut graph = Resolver::default();
@@ -1,9 +1,10 @@
 use crate::{
+    collections::Map,
     eip712::{PropertyDef, Resolver},
     DynSolType, DynSolValue, Result,
 };
-use alloc::{collections::BTreeMap, string::String, vec::Vec};
-use alloy_primitives::{keccak256, B256};
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::{keccak256, keccak256_slices, B256};
 use alloy_sol_type_parser::TypeSpecifier;
 use alloy_sol_types::{Eip712Domain, SolStruct};
 use derive_more::{Deref, DerefMut, From, Into, IntoIterator};
@@ -13,11 +14,11 @@ use serde::{Deserialize, Serialize};
 #[derive(
     Clone, Debug, Default, PartialEq, Eq, Serialize, Deref, DerefMut, From, Into, IntoIterator,
 )]
-pub struct Eip712Types(#[into_iterator(owned, ref, ref_mut)] BTreeMap<String, Vec<PropertyDef>>);
+pub struct Eip712Types(#[into_iterator(owned, ref, ref_mut)] Map<String, Vec<PropertyDef>>);
 
 impl<'de> Deserialize<'de> for Eip712Types {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let map: BTreeMap<String, Vec<PropertyDef>> = BTreeMap::deserialize(deserializer)?;
+        let map: Map<String, Vec<PropertyDef>> = Map::deserialize(deserializer)?;
 
         for key in map.keys() {
             // ensure that all types are valid specifiers
@@ -198,20 +199,19 @@ impl TypedData {
     /// This is the hash of the magic bytes 0x1901 concatenated with the domain
     /// separator and the `hashStruct` result.
     pub fn eip712_signing_hash(&self) -> Result<B256> {
-        let mut buf = [0u8; 66];
-        buf[0] = 0x19;
-        buf[1] = 0x01;
-        buf[2..34].copy_from_slice(self.domain.separator().as_slice());
+        let domain_separator = self.domain.separator();
 
         // compatibility with <https://github.com/MetaMask/eth-sig-util>
-        let len = if self.primary_type != "EIP712Domain" {
-            buf[34..].copy_from_slice(self.hash_struct()?.as_slice());
-            66
+        if self.primary_type != "EIP712Domain" {
+            let struct_hash = self.hash_struct()?;
+            Ok(keccak256_slices(&[
+                &[0x19, 0x01],
+                &domain_separator[..],
+                &struct_hash[..],
+            ]))
         } else {
-            34
-        };
-
-        Ok(keccak256(&buf[..len]))
+            Ok(keccak256_slices(&[&[0x19, 0x01], &domain_separator[..]]))
+        }
     }
 }
 
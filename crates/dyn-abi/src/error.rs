@@ -11,6 +11,7 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 ///
 /// <https://eips.ethereum.org/EIPS/eip-712#definition-of-encodetype>
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
     /// Unknown type referenced from another type.
     #[cfg(feature = "eip712")]
@@ -36,12 +37,23 @@ pub enum Error {
         /// The actual length.
         actual: usize,
     },
+    /// Length mismatch between the topics of a log and the indexed
+    /// parameters of its event, including the signature topic.
+    TopicLengthMismatch {
+        /// The expected number of topics.
+        expected: usize,
+        /// The actual number of topics.
+        actual: usize,
+    },
 
     /// [`hex`] error.
     Hex(hex::FromHexError),
     /// [`alloy_sol_type_parser`] error.
     TypeParser(TypeParserError),
     /// [`alloy_sol_types`] error.
+    ///
+    /// Decode errors do not carry an
+    /// [element path](alloy_sol_types::Error::Element).
     SolTypes(SolTypesError),
 }
 
@@ -94,6 +106,10 @@ impl fmt::Display for Error {
                 f,
                 "encode length mismatch, expected {expected} types, got {actual}",
             ),
+            &Self::TopicLengthMismatch { expected, actual } => write!(
+                f,
+                "invalid log topic list length, expected {expected} topics, got {actual}",
+            ),
 
             Self::Hex(e) => e.fmt(f),
             Self::TypeParser(e) => e.fmt(f),
@@ -138,3 +154,38 @@ impl Error {
         Self::CircularDependency(dep.into())
     }
 }
+
+/// A [`DynSolValue`](crate::DynSolValue) that does not conform to a
+/// [`DynSolType`](crate::DynSolType).
+///
+/// Returned by
+/// [`DynSolType::assert_matches`](crate::DynSolType::assert_matches).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MismatchError {
+    /// The path to the mismatched value, such as `$.orders[2].amount`.
+    ///
+    /// `$` is the root value, `[i]` indexes into arrays and tuples, and
+    /// `.name` selects a field of an EIP-712 struct.
+    pub path: String,
+    /// The expected type.
+    pub expected: String,
+    /// A description of the actual value.
+    pub found: String,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MismatchError {}
+
+impl fmt::Display for MismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            path,
+            expected,
+            found,
+        } = self;
+        write!(
+            f,
+            "type mismatch at {path}: expected {expected}, found {found}"
+        )
+    }
+}
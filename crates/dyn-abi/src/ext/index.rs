@@ -0,0 +1,292 @@
+use super::JsonAbiExt;
+use crate::{DynSolType, DynSolValue, Error, ResolveSolType, Result};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+use alloy_json_abi::{Event, Function, JsonAbi};
+use alloy_primitives::{Selector, B256};
+use alloy_sol_types::Error as SolTypesError;
+
+/// An index of the functions and events of a [`JsonAbi`] by their selectors
+/// and topics, for decoding calldata and logs without knowing in advance
+/// which item they belong to.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_dyn_abi::{AbiIndex, DynSolValue};
+/// use alloy_json_abi::JsonAbi;
+/// use alloy_primitives::{hex, U256};
+///
+/// let abi: JsonAbi = serde_json::from_str(
+///     r#"[{
+///         "type": "function",
+///         "name": "approve",
+///         "inputs": [{ "name": "", "type": "address" }, { "name": "", "type": "uint256" }],
+///         "outputs": [],
+///         "stateMutability": "nonpayable"
+///     }]"#,
+/// )
+/// .unwrap();
+/// let index = AbiIndex::new(&abi).unwrap();
+///
+/// let calldata = hex!(
+///     "095ea7b3"
+///     "0000000000000000000000001111111111111111111111111111111111111111"
+///     "000000000000000000000000000000000000000000000000000000000000002a"
+/// );
+/// let (function, values) = index.decode_input(&calldata).unwrap();
+/// assert_eq!(function.name, "approve");
+/// assert_eq!(values[1], DynSolValue::Uint(U256::from(42), 256));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AbiIndex<'a> {
+    functions: BTreeMap<Selector, &'a Function>,
+    events: BTreeMap<B256, &'a Event>,
+}
+
+impl<'a> AbiIndex<'a> {
+    /// Indexes the functions and non-anonymous events of the given ABI.
+    ///
+    /// Returns an error if two functions have the same selector, or if two
+    /// events have the same topic. See [`JsonAbi::function_selectors`] and
+    /// [`JsonAbi::event_topics`] for the individual maps.
+    pub fn new(abi: &'a JsonAbi) -> Result<Self> {
+        let functions = abi
+            .function_selectors()
+            .map_err(|collision| Error::custom(collision.to_string()))?;
+        let events = abi
+            .event_topics()
+            .map_err(|collision| Error::custom(collision.to_string()))?;
+        Ok(Self { functions, events })
+    }
+
+    /// Returns the function with the given selector, if any.
+    #[inline]
+    pub fn function(&self, selector: Selector) -> Option<&'a Function> {
+        self.functions.get(&selector).copied()
+    }
+
+    /// Returns the event with the given signature topic, if any.
+    #[inline]
+    pub fn event(&self, topic: B256) -> Option<&'a Event> {
+        self.events.get(&topic).copied()
+    }
+
+    /// Decodes calldata, which must start with a selector, into the function
+    /// it belongs to and its input values.
+    pub fn decode_input(&self, data: &[u8]) -> Result<(&'a Function, Vec<DynSolValue>)> {
+        let selector = data
+            .get(..4)
+            .map(Selector::from_slice)
+            .ok_or(SolTypesError::Overrun)?;
+        let function = self
+            .function(selector)
+            .ok_or(SolTypesError::UnknownSelector {
+                name: "AbiIndex",
+                selector,
+            })?;
+        let values = function.abi_decode_input(&data[4..])?;
+        Ok((function, values))
+    }
+
+    /// Decodes a log into the event it belongs to and its parameter values,
+    /// in declaration order.
+    ///
+    /// The event is looked up by the first topic. Indexed parameters of value
+    /// types are decoded from their topics; indexed parameters of other types
+    /// are only stored as a hash, which is returned as a `bytes32` value.
+    pub fn decode_event(
+        &self,
+        topics: &[B256],
+        data: &[u8],
+    ) -> Result<(&'a Event, Vec<DynSolValue>)> {
+        let event = topics.first().and_then(|&topic| self.event(topic)).ok_or(
+            SolTypesError::UnknownEvent {
+                name: "AbiIndex",
+                signature: topics.first().copied(),
+            },
+        )?;
+
+        let n_indexed = event.inputs.iter().filter(|param| param.indexed).count();
+        if topics.len() != n_indexed + 1 {
+            return Err(Error::TopicLengthMismatch {
+                expected: n_indexed + 1,
+                actual: topics.len(),
+            })
+        }
+
+        let body_types = event
+            .inputs
+            .iter()
+            .filter(|param| !param.indexed)
+            .map(ResolveSolType::resolve)
+            .collect::<Result<Vec<_>>>()?;
+        let body = DynSolType::Tuple(body_types).abi_decode_sequence(data)?;
+        let mut body = match body {
+            DynSolValue::Tuple(values) => values.into_iter(),
+            _ => unreachable!("decoded a tuple type into a non-tuple value"),
+        };
+
+        let mut topics = topics[1..].iter();
+        let mut values = Vec::with_capacity(event.inputs.len());
+        for param in &event.inputs {
+            let value = if param.indexed {
+                let topic = topics.next().unwrap();
+                match param.resolve()? {
+                    ty @ (DynSolType::Address
+                    | DynSolType::Function
+                    | DynSolType::Bool
+                    | DynSolType::Int(_)
+                    | DynSolType::Uint(_)
+                    | DynSolType::FixedBytes(_)) => ty.abi_decode(topic.as_slice())?,
+                    _ => DynSolValue::FixedBytes(*topic, 32),
+                }
+            } else {
+                body.next().unwrap()
+            };
+            values.push(value);
+        }
+        Ok((event, values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{b256, hex, Address, U256};
+
+    const ABI: &str = r#"[
+        {
+            "type": "function",
+            "name": "transfer",
+            "inputs": [
+                { "name": "to", "type": "address" },
+                { "name": "amount", "type": "uint256" }
+            ],
+            "outputs": [{ "name": "", "type": "bool" }],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "function",
+            "name": "transfer",
+            "inputs": [{ "name": "to", "type": "address" }],
+            "outputs": [],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "event",
+            "name": "Transfer",
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "memo", "type": "string", "indexed": true },
+                { "name": "amount", "type": "uint256", "indexed": false },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "note", "type": "string", "indexed": false }
+            ],
+            "anonymous": false
+        }
+    ]"#;
+
+    #[test]
+    fn decode_input() {
+        let abi: JsonAbi = serde_json::from_str(ABI).unwrap();
+        let index = AbiIndex::new(&abi).unwrap();
+
+        let calldata = hex!(
+            "a9059cbb"
+            "0000000000000000000000001111111111111111111111111111111111111111"
+            "000000000000000000000000000000000000000000000000000000000000002a"
+        );
+        let (function, values) = index.decode_input(&calldata).unwrap();
+        assert_eq!(function.signature(), "transfer(address,uint256)");
+        assert_eq!(
+            values,
+            [
+                DynSolValue::Address(Address::repeat_byte(0x11)),
+                DynSolValue::Uint(U256::from(42), 256)
+            ]
+        );
+
+        // the overload is dispatched by its own selector
+        let overload = abi.function("transfer").unwrap()[1].selector();
+        let mut calldata = overload.to_vec();
+        calldata.extend_from_slice(&calldata_word(0x22));
+        let (function, values) = index.decode_input(&calldata).unwrap();
+        assert_eq!(function.signature(), "transfer(address)");
+        assert_eq!(values, [DynSolValue::Address(Address::repeat_byte(0x22))]);
+
+        assert_eq!(
+            index.decode_input(&[0xa9]).unwrap_err(),
+            SolTypesError::Overrun.into()
+        );
+        assert_eq!(
+            index.decode_input(&hex!("deadbeef")).unwrap_err(),
+            SolTypesError::UnknownSelector {
+                name: "AbiIndex",
+                selector: hex!("deadbeef").into()
+            }
+            .into()
+        );
+    }
+
+    #[test]
+    fn decode_event() {
+        let abi: JsonAbi = serde_json::from_str(ABI).unwrap();
+        let index = AbiIndex::new(&abi).unwrap();
+
+        let signature = abi.event("Transfer").unwrap()[0].selector();
+        assert_eq!(index.event(signature).unwrap().name, "Transfer");
+        let memo_hash = b256!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8");
+        let topics = [
+            signature,
+            B256::from_slice(&calldata_word(0x11)),
+            memo_hash,
+            B256::from_slice(&calldata_word(0x22)),
+        ];
+        let data = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(42), 256),
+            DynSolValue::String("hi".into()),
+        ])
+        .abi_encode_params();
+
+        let (event, values) = index.decode_event(&topics, &data).unwrap();
+        assert_eq!(event.name, "Transfer");
+        assert_eq!(
+            values,
+            [
+                DynSolValue::Address(Address::repeat_byte(0x11)),
+                DynSolValue::FixedBytes(memo_hash, 32),
+                DynSolValue::Uint(U256::from(42), 256),
+                DynSolValue::Address(Address::repeat_byte(0x22)),
+                DynSolValue::String("hi".into()),
+            ]
+        );
+
+        assert_eq!(
+            index.decode_event(&topics[..3], &data).unwrap_err(),
+            Error::TopicLengthMismatch {
+                expected: 4,
+                actual: 3
+            }
+        );
+        assert_eq!(
+            index.decode_event(&[], &data).unwrap_err(),
+            SolTypesError::UnknownEvent {
+                name: "AbiIndex",
+                signature: None
+            }
+            .into()
+        );
+        assert_eq!(
+            index.decode_event(&[memo_hash], &data).unwrap_err(),
+            SolTypesError::UnknownEvent {
+                name: "AbiIndex",
+                signature: Some(memo_hash)
+            }
+            .into()
+        );
+    }
+
+    fn calldata_word(byte: u8) -> [u8; 32] {
+        Address::repeat_byte(byte).into_word().0
+    }
+}
@@ -1,5 +1,8 @@
 mod abi;
 pub use abi::{FunctionExt, JsonAbiExt};
 
+mod index;
+pub use index::AbiIndex;
+
 // TODO
 // mod event;
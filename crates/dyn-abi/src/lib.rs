@@ -32,10 +32,10 @@ extern crate alloc;
 mod arbitrary;
 
 mod error;
-pub use error::{Error, Result};
+pub use error::{Error, MismatchError, Result};
 
 mod ext;
-pub use ext::{FunctionExt, JsonAbiExt};
+pub use ext::{AbiIndex, FunctionExt, JsonAbiExt};
 
 mod ty;
 pub use ty::DynSolType;
@@ -54,6 +54,8 @@ pub mod eip712;
 #[cfg(feature = "eip712")]
 pub use eip712::{parser as eip712_parser, Eip712Types, PropertyDef, Resolver, TypeDef, TypedData};
 
+#[doc(no_inline)]
+pub use alloy_json_abi::collections;
 #[doc(no_inline)]
 pub use alloy_sol_type_parser as parser;
 #[doc(no_inline)]
@@ -133,6 +133,16 @@ impl<'a> DynToken<'a> {
         }
     }
 
+    /// The number of words this token occupies in the head.
+    fn head_words(&self) -> usize {
+        match self {
+            Self::FixedSeq(inner, _) if !self.is_dynamic() => {
+                inner.iter().map(Self::head_words).sum()
+            }
+            _ => 1,
+        }
+    }
+
     /// Decodes from a decoder, populating the structure with the decoded data.
     #[inline]
     pub(crate) fn decode_populate(&mut self, dec: &mut Decoder<'a>) -> Result<()> {
@@ -170,6 +180,13 @@ impl<'a> DynToken<'a> {
                 // This expect is safe because this is only invoked after
                 // `empty_dyn_token()` which always sets template
                 let t = template.take().expect("no template for dynamic sequence");
+
+                // Reject sizes whose heads cannot fit in the data before
+                // allocating the tokens for them
+                if size.saturating_mul(t.head_words()) > child.remaining() / 32 {
+                    return Err(alloy_sol_types::Error::Overrun.into())
+                }
+
                 let mut new_tokens = if size == 1 {
                     // re-use the box allocation
                     unsafe { Vec::from_raw_parts(Box::into_raw(t), 1, 1) }
@@ -1,7 +1,9 @@
-use crate::{resolve::ResolveSolType, DynSolValue, DynToken, Error, Result, SolType, Word};
-use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+use crate::{
+    resolve::ResolveSolType, DynSolValue, DynToken, Error, MismatchError, Result, SolType, Word,
+};
+use alloc::{borrow::Cow, boxed::Box, format, string::String, vec::Vec};
 use alloy_sol_type_parser::TypeSpecifier;
-use alloy_sol_types::{abi::Decoder, sol_data};
+use alloy_sol_types::{abi::Decoder, abi_trace_result, abi_trace_span, sol_data};
 use core::{fmt, num::NonZeroUsize, str::FromStr};
 
 #[cfg(feature = "eip712")]
@@ -17,6 +19,100 @@ macro_rules! as_tuple {
     };
 }
 
+/// Checks each value against the corresponding type, adding the segment
+/// returned by `segment` to the path of a mismatch.
+fn check_each<'a>(
+    types: impl Iterator<Item = &'a DynSolType>,
+    values: &'a [DynSolValue],
+    strict: bool,
+    segment: impl Fn(usize) -> PathSegment<'a>,
+) -> Result<(), Mismatch<'a>> {
+    for (i, (ty, value)) in types.zip(values).enumerate() {
+        ty.check(value, strict).map_err(|mut mismatch| {
+            mismatch.path.push(segment(i));
+            mismatch
+        })?;
+    }
+    Ok(())
+}
+
+/// A segment of the path to a mismatched value.
+enum PathSegment<'a> {
+    /// An array or tuple element.
+    Index(usize),
+    /// An EIP-712 struct field.
+    #[cfg_attr(not(feature = "eip712"), allow(dead_code))]
+    Field(&'a str),
+}
+
+/// Why a value does not match a type.
+enum MismatchKind {
+    /// The value has a different type.
+    Type,
+    /// The integer does not fit in its bit width.
+    OutOfRange,
+    /// The fixed bytes have non-zero bytes past their length.
+    DirtyBytes,
+}
+
+/// A mismatch found by [`DynSolType::check`]. The path is built in reverse
+/// while unwinding, so that successful checks do not allocate.
+struct Mismatch<'a> {
+    ty: &'a DynSolType,
+    value: &'a DynSolValue,
+    kind: MismatchKind,
+    path: Vec<PathSegment<'a>>,
+}
+
+impl<'a> Mismatch<'a> {
+    #[inline]
+    const fn new(ty: &'a DynSolType, value: &'a DynSolValue, kind: MismatchKind) -> Self {
+        Self {
+            ty,
+            value,
+            kind,
+            path: Vec::new(),
+        }
+    }
+
+    fn into_error(self) -> MismatchError {
+        use fmt::Write;
+
+        let mut path = String::from("$");
+        for segment in self.path.iter().rev() {
+            match segment {
+                PathSegment::Index(i) => write!(path, "[{i}]").unwrap(),
+                PathSegment::Field(name) => write!(path, ".{name}").unwrap(),
+            }
+        }
+
+        let found = match self.value.as_type() {
+            Some(ty) => ty.sol_type_name().into_owned(),
+            None => match self.value {
+                DynSolValue::Array(_) => "empty array".into(),
+                DynSolValue::FixedArray(_) => "empty fixed array".into(),
+                _ => "unknown type".into(),
+            },
+        };
+        let found = match (self.kind, self.value) {
+            (MismatchKind::OutOfRange, DynSolValue::Int(int, _)) => {
+                format!("out-of-range {found} value {int}")
+            }
+            (MismatchKind::OutOfRange, DynSolValue::Uint(uint, _)) => {
+                format!("out-of-range {found} value {uint}")
+            }
+            (MismatchKind::DirtyBytes, _) => format!("{found} with non-zero padding"),
+            _ => found,
+        };
+
+        MismatchError {
+            path,
+            expected: self.ty.sol_type_name().into_owned(),
+            found,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct StructProp {
     name: String,
@@ -225,52 +321,124 @@ impl DynSolType {
 
     /// Check that the given [`DynSolValue`] matches this type.
     ///
+    /// This is a structural check only: integer values are not checked against
+    /// their bit width, and neither are fixed bytes against their length. See
+    /// [`assert_matches`](Self::assert_matches) for a stricter version that
+    /// also reports where and why the value does not match.
+    ///
     /// Note: this will not check any names, but just the types; e.g for
     /// `CustomStruct`, when the "eip712" feature is enabled, this will only
     /// check equality between the lengths and types of the tuple.
+    #[inline]
     pub fn matches(&self, value: &DynSolValue) -> bool {
-        match self {
-            Self::Address => matches!(value, DynSolValue::Address(_)),
-            Self::Function => matches!(value, DynSolValue::Function(_)),
-            Self::Bytes => matches!(value, DynSolValue::Bytes(_)),
-            Self::Int(size) => matches!(value, DynSolValue::Int(_, s) if s == size),
-            Self::Uint(size) => matches!(value, DynSolValue::Uint(_, s) if s == size),
-            Self::Bool => matches!(value, DynSolValue::Bool(_)),
-            Self::Array(t) => {
-                matches!(value, DynSolValue::Array(v) if v.iter().all(|v| t.matches(v)))
+        self.check(value, false).is_ok()
+    }
+
+    /// Check that the given [`DynSolValue`] matches this type, returning the
+    /// path to the first mismatched value otherwise.
+    ///
+    /// Besides the checks of [`matches`](Self::matches), this checks that
+    /// integers fit in their bit width, and that fixed bytes have no non-zero
+    /// bytes past their length, since these would be encoded as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynSolType, DynSolValue};
+    /// use alloy_primitives::U256;
+    ///
+    /// let ty: DynSolType = "(address,uint8[])".parse()?;
+    /// let value = DynSolValue::Tuple(vec![
+    ///     DynSolValue::Address(Default::default()),
+    ///     DynSolValue::Array(vec![
+    ///         DynSolValue::Uint(U256::from(1), 8),
+    ///         DynSolValue::Uint(U256::from(256), 8),
+    ///     ]),
+    /// ]);
+    /// let err = ty.assert_matches(&value).unwrap_err();
+    /// assert_eq!(err.path, "$[1][1]");
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "type mismatch at $[1][1]: expected uint8, found out-of-range uint8 value 256"
+    /// );
+    /// # Ok::<_, alloy_dyn_abi::Error>(())
+    /// ```
+    pub fn assert_matches(&self, value: &DynSolValue) -> Result<(), MismatchError> {
+        self.check(value, true).map_err(Mismatch::into_error)
+    }
+
+    fn check<'a>(&'a self, value: &'a DynSolValue, strict: bool) -> Result<(), Mismatch<'a>> {
+        let ok = match (self, value) {
+            (Self::Address, DynSolValue::Address(_))
+            | (Self::Function, DynSolValue::Function(_))
+            | (Self::Bool, DynSolValue::Bool(_))
+            | (Self::Bytes, DynSolValue::Bytes(_))
+            | (Self::String, DynSolValue::String(_)) => true,
+            (Self::Int(size), DynSolValue::Int(int, s)) if s == size => {
+                return if !strict || *size >= 256 || int.bits() as usize <= *size {
+                    Ok(())
+                } else {
+                    Err(Mismatch::new(self, value, MismatchKind::OutOfRange))
+                }
             }
-            Self::String => matches!(value, DynSolValue::String(_)),
-            Self::FixedBytes(size) => matches!(value, DynSolValue::FixedBytes(_, s) if s == size),
-            Self::FixedArray(t, size) => matches!(
-                value,
-                DynSolValue::FixedArray(v) if v.len() == *size && v.iter().all(|v| t.matches(v))
-            ),
-            Self::Tuple(types) => {
-                matches!(value, as_tuple!(DynSolValue tuple) if types.iter().zip(tuple).all(|(t, v)| t.matches(v)))
+            (Self::Uint(size), DynSolValue::Uint(uint, s)) if s == size => {
+                return if !strict || uint.bit_len() <= *size {
+                    Ok(())
+                } else {
+                    Err(Mismatch::new(self, value, MismatchKind::OutOfRange))
+                }
             }
-            #[cfg(feature = "eip712")]
-            Self::CustomStruct {
-                name: _,
-                prop_names,
-                tuple,
-            } => {
-                if let DynSolValue::CustomStruct {
-                    name: _,
-                    prop_names: p,
-                    tuple: t,
-                } = value
-                {
-                    // check just types
-                    prop_names.len() == tuple.len()
-                        && prop_names.len() == p.len()
-                        && tuple.len() == t.len()
-                        && tuple.iter().zip(t).all(|(a, b)| a.matches(b))
-                } else if let DynSolValue::Tuple(v) = value {
-                    v.iter().zip(tuple).all(|(v, t)| t.matches(v))
+            (Self::FixedBytes(size), DynSolValue::FixedBytes(word, s)) if s == size => {
+                let padding = word.get(*size..).unwrap_or_default();
+                return if !strict || padding.iter().all(|&b| b == 0) {
+                    Ok(())
                 } else {
-                    false
+                    Err(Mismatch::new(self, value, MismatchKind::DirtyBytes))
                 }
             }
+            (Self::Array(ty), DynSolValue::Array(values)) => {
+                return check_each(
+                    core::iter::repeat(&**ty),
+                    values,
+                    strict,
+                    PathSegment::Index,
+                )
+            }
+            (Self::FixedArray(ty, size), DynSolValue::FixedArray(values))
+                if values.len() == *size =>
+            {
+                return check_each(
+                    core::iter::repeat(&**ty),
+                    values,
+                    strict,
+                    PathSegment::Index,
+                )
+            }
+            (Self::Tuple(types), as_tuple!(DynSolValue values)) if types.len() == values.len() => {
+                return check_each(types.iter(), values, strict, PathSegment::Index)
+            }
+            #[cfg(feature = "eip712")]
+            (
+                Self::CustomStruct {
+                    prop_names, tuple, ..
+                },
+                as_tuple!(DynSolValue values),
+            ) if prop_names.len() == tuple.len() && tuple.len() == values.len() => {
+                if let DynSolValue::CustomStruct { prop_names: p, .. } = value {
+                    if p.len() != prop_names.len() {
+                        return Err(Mismatch::new(self, value, MismatchKind::Type))
+                    }
+                }
+                return check_each(tuple.iter(), values, strict, |i| {
+                    PathSegment::Field(&prop_names[i])
+                })
+            }
+            _ => false,
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(Mismatch::new(self, value, MismatchKind::Type))
         }
     }
 
@@ -500,10 +668,11 @@ impl DynSolType {
     #[inline]
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn abi_decode(&self, data: &[u8]) -> Result<DynSolValue> {
-        self.abi_decode_inner(
+        abi_trace_span!("abi_decode", sol_type = %self.sol_type_name(), len = data.len());
+        abi_trace_result!(self.abi_decode_inner(
             &mut Decoder::new(data, false),
             DynToken::decode_single_populate,
-        )
+        ))
     }
 
     /// Decode a [`DynSolValue`] from a byte slice. Fails if the value does not
@@ -541,10 +710,15 @@ impl DynSolType {
     #[inline]
     #[cfg_attr(debug_assertions, track_caller)]
     pub fn abi_decode_sequence(&self, data: &[u8]) -> Result<DynSolValue> {
-        self.abi_decode_inner(
+        abi_trace_span!(
+            "abi_decode_sequence",
+            sol_type = %self.sol_type_name(),
+            len = data.len(),
+        );
+        abi_trace_result!(self.abi_decode_inner(
             &mut Decoder::new(data, false),
             DynToken::decode_sequence_populate,
-        )
+        ))
     }
 
     #[inline]
@@ -571,7 +745,7 @@ impl DynSolType {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{hex, Address};
+    use alloy_primitives::{hex, Address, B256, I256, U256};
 
     #[test]
     fn dynamically_encodes() {
@@ -596,11 +770,251 @@ mod tests {
             token,
             DynToken::FixedSeq(vec![DynToken::Word(word1), DynToken::Word(word2)].into(), 2)
         );
-        let mut enc = crate::Encoder::default();
+        let mut enc = crate::Encoder::new();
         DynSolValue::encode_seq_to(val.as_fixed_seq().unwrap(), &mut enc);
         assert_eq!(enc.finish(), vec![word1, word2]);
     }
 
+    #[test]
+    fn encode_packed_matches_sol_types() {
+        use alloy_sol_types::{sol_data, SolType};
+
+        let mut word = Word::ZERO;
+        word[0] = 0x42;
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Int(I256::MINUS_ONE, 16),
+            DynSolValue::Array(vec![
+                DynSolValue::Uint(U256::from(1), 16),
+                DynSolValue::Uint(U256::from(2), 16),
+            ]),
+            DynSolValue::FixedArray(vec![DynSolValue::FixedBytes(word, 1)]),
+        ]);
+
+        type MyTy = (
+            sol_data::Int<16>,
+            sol_data::Array<sol_data::Uint<16>>,
+            sol_data::FixedArray<sol_data::FixedBytes<1>, 1>,
+        );
+        let expected = MyTy::abi_encode_packed(&(-1, vec![1, 2], [word[..1].try_into().unwrap()]));
+        assert_eq!(expected.len(), 2 + 3 * 32);
+        assert_eq!(value.abi_encode_packed(), expected);
+
+        // static tuples and fixed arrays in arrays are padded as a whole
+        let value = DynSolValue::Array(vec![
+            DynSolValue::Tuple(vec![
+                DynSolValue::Uint(U256::from(1), 8),
+                DynSolValue::FixedArray(vec![DynSolValue::Bool(true); 2]),
+            ]);
+            2
+        ]);
+
+        type MyTy2 = sol_data::Array<(sol_data::Uint<8>, sol_data::FixedArray<sol_data::Bool, 2>)>;
+        let rust = vec![(1, [true; 2]); 2];
+        let expected = MyTy2::abi_encode_packed(&rust);
+        assert_eq!(expected.len(), 2 * 3 * 32);
+        assert_eq!(MyTy2::packed_encoded_size(&rust), expected.len());
+        assert_eq!(value.abi_encode_packed(), expected);
+    }
+
+    #[test]
+    fn encoded_size() {
+        use alloy_sol_types::{sol_data, SolType};
+
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(1), 256),
+            DynSolValue::Bytes(vec![0x42; 33]),
+            DynSolValue::Array(vec![Address::repeat_byte(0x11).into(); 3]),
+        ]);
+        // head: 3 words, bytes: 1 + 2 words, address[]: 1 + 3 words, plus the
+        // offset of the dynamic tuple itself
+        assert_eq!(value.abi_encoded_size(), (1 + 3 + 3 + 4) * 32);
+        assert_eq!(value.abi_encoded_size(), value.abi_encode().len());
+
+        type MyTy = (
+            sol_data::Uint<256>,
+            sol_data::Bytes,
+            sol_data::Array<sol_data::Address>,
+        );
+        let rust = (
+            U256::from(1),
+            vec![0x42; 33],
+            vec![Address::repeat_byte(0x11); 3],
+        );
+        assert_eq!(value.abi_encode(), MyTy::abi_encode(&rust));
+
+        for value in [
+            DynSolValue::Bool(true),
+            DynSolValue::String(String::new()),
+            DynSolValue::Array(vec![]),
+            DynSolValue::FixedArray(vec![DynSolValue::String("a".into()); 2]),
+            DynSolValue::Tuple(vec![]),
+            DynSolValue::Tuple(vec![DynSolValue::Tuple(vec![DynSolValue::Bytes(vec![])])]),
+        ] {
+            assert_eq!(
+                value.abi_encoded_size(),
+                value.abi_encode().len(),
+                "{value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn assert_matches() {
+        let uint = |x: u64, size| DynSolValue::Uint(U256::from(x), size);
+        let int = |x: i64, size| DynSolValue::Int(I256::try_from(x).unwrap(), size);
+        let check = |ty: &str, value: &DynSolValue| {
+            let ty: DynSolType = ty.parse().unwrap();
+            let res = ty.assert_matches(value);
+            if res.is_ok() {
+                assert!(ty.matches(value));
+            }
+            res.map_err(|e| (e.path, e.expected, e.found))
+        };
+        let err = |path: &str, expected: &str, found: &str| {
+            Err((path.to_string(), expected.to_string(), found.to_string()))
+        };
+
+        assert_eq!(check("uint8", &uint(255, 8)), Ok(()));
+        let found = "out-of-range uint8 value 256";
+        assert_eq!(check("uint8", &uint(256, 8)), err("$", "uint8", found));
+        assert!(DynSolType::Uint(8).matches(&uint(256, 8)));
+        assert_eq!(check("uint8", &uint(1, 16)), err("$", "uint8", "uint16"));
+        assert_eq!(check("uint256", &DynSolValue::Uint(U256::MAX, 256)), Ok(()));
+        assert_eq!(check("int8", &int(-128, 8)), Ok(()));
+        assert_eq!(check("int8", &int(127, 8)), Ok(()));
+        assert_eq!(
+            check("int8", &int(-129, 8)),
+            err("$", "int8", "out-of-range int8 value -129")
+        );
+        assert_eq!(
+            check("int8", &int(128, 8)),
+            err("$", "int8", "out-of-range int8 value 128")
+        );
+        assert_eq!(check("int256", &DynSolValue::Int(I256::MIN, 256)), Ok(()));
+
+        let mut word = B256::ZERO;
+        word[..4].copy_from_slice(&[0xff; 4]);
+        assert_eq!(check("bytes4", &DynSolValue::FixedBytes(word, 4)), Ok(()));
+        assert_eq!(
+            check("bytes3", &DynSolValue::FixedBytes(word, 3)),
+            err("$", "bytes3", "bytes3 with non-zero padding")
+        );
+        assert_eq!(
+            check(
+                "bytes32",
+                &DynSolValue::FixedBytes(B256::repeat_byte(1), 32)
+            ),
+            Ok(())
+        );
+
+        // shapes and lengths
+        assert_eq!(
+            check("(bool,bool)", &DynSolValue::Tuple(vec![true.into()])),
+            err("$", "(bool,bool)", "(bool,)")
+        );
+        assert_eq!(
+            check("bool[2]", &DynSolValue::FixedArray(vec![true.into(); 3])),
+            err("$", "bool[2]", "bool[3]")
+        );
+        assert_eq!(check("bool[]", &DynSolValue::Array(vec![])), Ok(()));
+        assert_eq!(
+            check("bool", &DynSolValue::Array(vec![])),
+            err("$", "bool", "empty array")
+        );
+        assert_eq!(check("string", &true.into()), err("$", "string", "bool"));
+
+        // nested paths
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Address(Address::ZERO),
+            DynSolValue::Array(vec![
+                DynSolValue::Tuple(vec![uint(1, 256), true.into()]),
+                DynSolValue::Tuple(vec![uint(2, 256), true.into()]),
+                DynSolValue::Tuple(vec![uint(3, 128), true.into()]),
+            ]),
+        ]);
+        assert_eq!(
+            check("(address,(uint256,bool)[])", &value),
+            err("$[1][2][0]", "uint256", "uint128")
+        );
+        assert_eq!(
+            check("(address,(uint128,bool)[])", &value),
+            err("$[1][0][0]", "uint128", "uint256")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "eip712")]
+    fn assert_matches_struct_path() {
+        let order = |amount| DynSolValue::CustomStruct {
+            name: "Order".into(),
+            prop_names: vec!["maker".into(), "amount".into()],
+            tuple: vec![Address::ZERO.into(), amount],
+        };
+        let order_ty = DynSolType::CustomStruct {
+            name: "Order".into(),
+            prop_names: vec!["maker".into(), "amount".into()],
+            tuple: vec![DynSolType::Address, DynSolType::Uint(256)],
+        };
+        let book_ty = DynSolType::CustomStruct {
+            name: "Book".into(),
+            prop_names: vec!["orders".into()],
+            tuple: vec![DynSolType::Array(Box::new(order_ty))],
+        };
+
+        let amount = |x: u64| DynSolValue::Uint(U256::from(x), 256);
+        let mut orders = vec![order(amount(1)), order(amount(2)), order(amount(3))];
+        let book = |orders| DynSolValue::CustomStruct {
+            name: "Book".into(),
+            prop_names: vec!["orders".into()],
+            tuple: vec![DynSolValue::Array(orders)],
+        };
+        assert_eq!(book_ty.assert_matches(&book(orders.clone())), Ok(()));
+
+        orders[2] = order(DynSolValue::Int(I256::ONE, 256));
+        let err = book_ty.assert_matches(&book(orders.clone())).unwrap_err();
+        assert_eq!(err.path, "$.orders[2].amount");
+        assert_eq!(err.expected, "uint256");
+        assert_eq!(err.found, "int256");
+        assert_eq!(
+            err.to_string(),
+            "type mismatch at $.orders[2].amount: expected uint256, found int256"
+        );
+
+        // plain tuples are accepted in place of structs
+        let tuple = DynSolValue::Tuple(vec![DynSolValue::Array(orders)]);
+        assert_eq!(
+            book_ty.assert_matches(&tuple).unwrap_err().path,
+            "$.orders[2].amount"
+        );
+    }
+
+    #[test]
+    fn decode_array_length_overrun() {
+        // `bytes[][]` with a single 2^32 - 1 length array
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "00000000000000000000000000000000000000000000000000000000ffffffff"
+        );
+        let ty: DynSolType = "bytes[][]".parse().unwrap();
+        assert!(ty.abi_decode(&data).is_err());
+        assert!(
+            <sol_data::Array<sol_data::Array<sol_data::Bytes>>>::abi_decode(&data, true).is_err()
+        );
+
+        // empty tuples take no space
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "0000000000000000000000000000000000000000000000000000000000000003"
+        );
+        let ty: DynSolType = "()[]".parse().unwrap();
+        assert_eq!(
+            ty.abi_decode(&data).unwrap(),
+            DynSolValue::Array(vec![DynSolValue::Tuple(vec![]); 3])
+        );
+    }
+
     // also tests the type name parser
     macro_rules! encoder_tests {
         ($($name:ident($ty:literal, $encoded:literal)),* $(,)?) => {$(
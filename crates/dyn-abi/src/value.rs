@@ -1,7 +1,11 @@
 use crate::{DynSolType, DynToken, Word};
 use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
 use alloy_primitives::{Address, Function, I256, U256};
-use alloy_sol_types::{abi::Encoder, utils::words_for_len};
+use alloy_sol_types::{
+    abi::{Encoder, OutBuf},
+    abi_trace_span,
+    utils::words_for_len,
+};
 
 #[cfg(feature = "eip712")]
 macro_rules! as_fixed_seq {
@@ -595,9 +599,19 @@ impl DynSolValue {
         self.head_words() + self.tail_words()
     }
 
+    /// Returns the length of this value's ABI encoding in bytes, as returned by
+    /// [`abi_encode`](Self::abi_encode).
+    ///
+    /// This is computed without encoding the value, like
+    /// [`SolType::abi_encoded_size`](alloy_sol_types::SolType::abi_encoded_size).
+    #[inline]
+    pub fn abi_encoded_size(&self) -> usize {
+        self.total_words() * 32
+    }
+
     /// Append this data to the head of an in-progress blob via the encoder.
     #[inline]
-    pub fn head_append(&self, enc: &mut Encoder) {
+    pub fn head_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
         match self {
             Self::Address(_)
             | Self::Function(_)
@@ -622,7 +636,7 @@ impl DynSolValue {
 
     /// Append this data to the tail of an in-progress blob via the encoder.
     #[inline]
-    pub fn tail_append(&self, enc: &mut Encoder) {
+    pub fn tail_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
         match self {
             Self::Address(_)
             | Self::Function(_)
@@ -656,20 +670,31 @@ impl DynSolValue {
             Self::String(s) => buf.extend_from_slice(s.as_bytes()),
             Self::Bytes(bytes) => buf.extend_from_slice(bytes),
             Self::FixedBytes(word, size) => buf.extend_from_slice(&word[..*size]),
+            // `size` is in bits
             Self::Int(num, size) => {
-                let mut bytes = num.to_be_bytes::<32>();
-                let start = 32 - *size;
-                if num.is_negative() {
-                    bytes[start] |= 0x80;
-                } else {
-                    bytes[start] &= 0x7f;
-                }
-                buf.extend_from_slice(&bytes[start..]);
+                buf.extend_from_slice(&num.to_be_bytes::<32>()[(32 - *size / 8)..]);
             }
             Self::Uint(num, size) => {
-                buf.extend_from_slice(&num.to_be_bytes::<32>()[(32 - *size)..]);
+                buf.extend_from_slice(&num.to_be_bytes::<32>()[(32 - *size / 8)..]);
             }
-            as_fixed_seq!(inner) | Self::Array(inner) => {
+            Self::FixedArray(inner) | Self::Array(inner) => {
+                // array elements are padded to their standard ABI encoding,
+                // like in `alloy-sol-types`; dynamic ones are packed in place
+                for val in inner {
+                    if val.is_dynamic() {
+                        val.abi_encode_packed_to(buf);
+                    } else {
+                        val.head_append(&mut Encoder::with_buf(&mut *buf));
+                    }
+                }
+            }
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { tuple: inner, .. } => {
+                for val in inner {
+                    val.abi_encode_packed_to(buf);
+                }
+            }
+            Self::Tuple(inner) => {
                 for val in inner {
                     val.abi_encode_packed_to(buf);
                 }
@@ -711,7 +736,7 @@ impl DynSolValue {
     }
 
     /// Encode this data as a sequence into the given encoder.
-    pub(crate) fn encode_seq_to(contents: &[Self], enc: &mut Encoder) {
+    pub(crate) fn encode_seq_to<B: OutBuf>(contents: &[Self], enc: &mut Encoder<B>) {
         let head_words = contents.iter().map(Self::head_words).sum::<usize>();
         enc.push_offset(head_words as u32);
 
@@ -731,6 +756,7 @@ impl DynSolValue {
     /// sequence.
     #[inline]
     pub fn abi_encode(&self) -> Vec<u8> {
+        abi_trace_span!("abi_encode", sol_type = ?self.sol_type_name());
         Self::encode_seq(core::slice::from_ref(self))
     }
 
@@ -764,6 +790,7 @@ impl DynSolValue {
     /// value is not a fixed sequence, return `None`.
     #[inline]
     pub fn abi_encode_sequence(&self) -> Option<Vec<u8>> {
+        abi_trace_span!("abi_encode_sequence", sol_type = ?self.sol_type_name());
         self.as_fixed_seq().map(Self::encode_seq)
     }
 }
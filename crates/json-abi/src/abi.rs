@@ -1,11 +1,9 @@
-use crate::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
-use alloc::{
-    collections::{btree_map, btree_map::Values},
-    string::String,
-    vec::Vec,
+use crate::{
+    collections::{btree_map, btree_map::Values, Map},
+    AbiItem, Constructor, Error, Event, Fallback, Function, Receive,
 };
-use alloy_primitives::Bytes;
-use btree_map::BTreeMap;
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::{Bytes, Selector, B256};
 use core::{fmt, iter, iter::Flatten};
 use serde::{
     de::{MapAccess, SeqAccess, Visitor},
@@ -25,11 +23,11 @@ pub struct JsonAbi {
     /// The receive function.
     pub receive: Option<Receive>,
     /// The functions, indexed by the function name.
-    pub functions: BTreeMap<String, Vec<Function>>,
+    pub functions: Map<String, Vec<Function>>,
     /// The events, indexed by the event name.
-    pub events: BTreeMap<String, Vec<Event>>,
+    pub events: Map<String, Vec<Event>>,
     /// The errors, indexed by the error name.
-    pub errors: BTreeMap<String, Vec<Error>>,
+    pub errors: Map<String, Vec<Error>>,
 }
 
 impl JsonAbi {
@@ -196,8 +194,97 @@ impl JsonAbi {
     pub fn errors(&self) -> Flatten<Values<'_, String, Vec<Error>>> {
         self.errors.values().flatten()
     }
+
+    /// Builds a map from selector to function, for dispatching calldata.
+    ///
+    /// Returns an error if two functions have the same selector, which can
+    /// only happen with an actual hash collision or a duplicate function.
+    pub fn function_selectors(
+        &self,
+    ) -> Result<Map<Selector, &Function>, SelectorCollision<'_, Function, Selector>> {
+        index_by(self.functions(), Function::selector)
+    }
+
+    /// Builds a map from topic 0 to event, for dispatching logs.
+    ///
+    /// Anonymous events are skipped, as their logs have no signature topic.
+    ///
+    /// Returns an error if two events have the same topic, which can only
+    /// happen with a duplicate event.
+    pub fn event_topics(&self) -> Result<Map<B256, &Event>, SelectorCollision<'_, Event, B256>> {
+        index_by(
+            self.events().filter(|event| !event.anonymous),
+            Event::selector,
+        )
+    }
+}
+
+fn index_by<'a, T, S: Copy + Ord>(
+    items: impl Iterator<Item = &'a T>,
+    selector: impl Fn(&T) -> S,
+) -> Result<Map<S, &'a T>, SelectorCollision<'a, T, S>> {
+    let mut map = Map::new();
+    for item in items {
+        match map.entry(selector(item)) {
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(item);
+            }
+            btree_map::Entry::Occupied(entry) => {
+                return Err(SelectorCollision {
+                    selector: *entry.key(),
+                    first: entry.get(),
+                    second: item,
+                })
+            }
+        }
+    }
+    Ok(map)
+}
+
+/// Two ABI items with the same selector or topic.
+///
+/// This is returned by [`JsonAbi::function_selectors`] and
+/// [`JsonAbi::event_topics`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SelectorCollision<'a, T, S> {
+    /// The shared selector or topic.
+    pub selector: S,
+    /// The item that was indexed first.
+    pub first: &'a T,
+    /// The item that collided with it.
+    pub second: &'a T,
+}
+
+impl fmt::Display for SelectorCollision<'_, Function, Selector> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "functions `{}` and `{}` have the same selector {}",
+            self.first.signature(),
+            self.second.signature(),
+            self.selector
+        )
+    }
+}
+
+impl fmt::Display for SelectorCollision<'_, Event, B256> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "events `{}` and `{}` have the same topic {}",
+            self.first.signature(),
+            self.second.signature(),
+            self.selector
+        )
+    }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for SelectorCollision<'_, Function, Selector> {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SelectorCollision<'_, Event, B256> {}
+
 macro_rules! next_item {
     ($self:ident; $($ident:ident.$f:ident()),* $(,)?) => {$(
         if let Some(next) = $self.$ident.$f() {
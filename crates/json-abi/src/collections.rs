@@ -0,0 +1,18 @@
+//! Collection types used in the data structures of this crate and of
+//! `alloy-dyn-abi`, which re-exports this module.
+//!
+//! These are ordered, so that iteration order, and any output derived from it
+//! such as serialized JSON, [`JsonAbi::to_sol`] interfaces and EIP-712
+//! `encodeType` strings, is deterministic. They are also available in
+//! `no_std` environments.
+//!
+//! [`JsonAbi::to_sol`]: crate::JsonAbi::to_sol
+
+#[doc(no_inline)]
+pub use alloc::collections::{btree_map, btree_set, BTreeMap, BTreeSet};
+
+/// The map type used in the ABI data structures.
+pub type Map<K, V> = BTreeMap<K, V>;
+
+/// The set type used in the ABI data structures.
+pub type Set<T> = BTreeSet<T>;
@@ -31,8 +31,10 @@ extern crate alloc;
 
 use serde::{Deserialize, Serialize};
 
+pub mod collections;
+
 mod abi;
-pub use abi::{ContractObject, IntoItems, Items, JsonAbi};
+pub use abi::{ContractObject, IntoItems, Items, JsonAbi, SelectorCollision};
 
 mod item;
 pub use item::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
@@ -1,8 +1,9 @@
 use crate::{
+    collections::Set,
     item::{Error, Event, Fallback, Function, Receive},
     EventParam, InternalType, JsonAbi, Param, StateMutability,
 };
-use alloc::{collections::BTreeSet, string::String, vec::Vec};
+use alloc::{string::String, vec::Vec};
 use core::cmp::Ordering;
 
 const INDENT: &str = "    ";
@@ -45,13 +46,13 @@ impl ToSol for JsonAbi {
 }
 
 /// Recursively collects internal structs, enums, and udvts from an ABI's items.
-struct InternalTypes<'a>(BTreeSet<It<'a>>);
+struct InternalTypes<'a>(Set<It<'a>>);
 
 impl<'a> InternalTypes<'a> {
     #[allow(clippy::missing_const_for_fn)]
     #[inline]
     fn new() -> Self {
-        Self(BTreeSet::new())
+        Self(Set::new())
     }
 
     fn visit_abi(&mut self, abi: &'a JsonAbi) {
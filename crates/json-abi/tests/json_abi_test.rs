@@ -4,6 +4,7 @@ use alloy_json_abi::{
     Constructor, Error, Event, EventParam, Fallback, Function, JsonAbi, Param, Receive,
     StateMutability,
 };
+use alloy_primitives::{b256, fixed_bytes, hex};
 use std::collections::BTreeMap;
 
 #[test]
@@ -601,3 +602,154 @@ fn fallback() {
 
     assert_ser_de!(JsonAbi, deserialized);
 }
+
+#[test]
+fn selector_index() {
+    let json = r#"[
+        {
+            "type": "function",
+            "name": "transfer",
+            "inputs": [
+                { "name": "to", "type": "address" },
+                { "name": "amount", "type": "uint256" }
+            ],
+            "outputs": [],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "function",
+            "name": "transfer",
+            "inputs": [
+                {
+                    "name": "transfer",
+                    "type": "tuple",
+                    "components": [
+                        { "name": "to", "type": "address" },
+                        { "name": "amount", "type": "uint256" }
+                    ]
+                }
+            ],
+            "outputs": [],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "event",
+            "name": "Transfer",
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "amount", "type": "uint256", "indexed": false }
+            ],
+            "anonymous": false
+        },
+        {
+            "type": "event",
+            "name": "Anonymous",
+            "inputs": [],
+            "anonymous": true
+        }
+    ]"#;
+    let abi: JsonAbi = serde_json::from_str(json).unwrap();
+
+    // overloads have distinct selectors, with tuples flattened in signatures
+    let functions = abi.function_selectors().unwrap();
+    assert_eq!(functions.len(), 2);
+    assert_eq!(
+        functions[&fixed_bytes!("a9059cbb")].signature(),
+        "transfer(address,uint256)"
+    );
+    let tuple = abi.function("transfer").unwrap()[1].selector();
+    assert_eq!(functions[&tuple].signature(), "transfer((address,uint256))");
+
+    let events = abi.event_topics().unwrap();
+    assert_eq!(events.len(), 1);
+    let topic = b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+    assert_eq!(events[&topic].name, "Transfer");
+}
+
+#[test]
+fn selector_index_collisions() {
+    // a real 4-byte selector collision: both hash to `0x42966c68`
+    let mut abi: JsonAbi = serde_json::from_str(
+        r#"[
+            {
+                "type": "function",
+                "name": "burn",
+                "inputs": [{ "name": "", "type": "uint256" }],
+                "outputs": [],
+                "stateMutability": "nonpayable"
+            },
+            {
+                "type": "function",
+                "name": "collate_propagate_storage",
+                "inputs": [{ "name": "", "type": "bytes16" }],
+                "outputs": [],
+                "stateMutability": "nonpayable"
+            },
+            {
+                "type": "event",
+                "name": "Burn",
+                "inputs": [],
+                "anonymous": false
+            }
+        ]"#,
+    )
+    .unwrap();
+
+    let collision = abi.function_selectors().unwrap_err();
+    assert_eq!(collision.selector, hex!("42966c68"));
+    assert_eq!(collision.first.name, "burn");
+    assert_eq!(collision.second.name, "collate_propagate_storage");
+    assert_eq!(
+        collision.to_string(),
+        "functions `burn(uint256)` and `collate_propagate_storage(bytes16)` have the same \
+         selector 0x42966c68"
+    );
+
+    assert!(abi.event_topics().is_ok());
+    let burn = abi.events["Burn"][0].clone();
+    abi.events.get_mut("Burn").unwrap().push(burn);
+    let collision = abi.event_topics().unwrap_err();
+    assert_eq!(collision.first, collision.second);
+    assert!(collision
+        .to_string()
+        .starts_with("events `Burn()` and `Burn()` have the same topic"));
+}
+
+#[test]
+fn output_is_deterministic() {
+    let items = [
+        r#"{ "type": "function", "name": "transfer", "inputs": [{ "name": "to", "type": "address" }, { "name": "amount", "type": "uint256" }], "outputs": [], "stateMutability": "nonpayable" }"#,
+        r#"{ "type": "function", "name": "approve", "inputs": [{ "name": "spender", "type": "address" }, { "name": "amount", "type": "uint256" }], "outputs": [], "stateMutability": "nonpayable" }"#,
+        r#"{ "type": "event", "name": "Transfer", "inputs": [{ "name": "from", "type": "address", "indexed": true }], "anonymous": false }"#,
+        r#"{ "type": "event", "name": "Approval", "inputs": [{ "name": "owner", "type": "address", "indexed": true }], "anonymous": false }"#,
+        r#"{ "type": "error", "name": "Unauthorized", "inputs": [] }"#,
+        r#"{ "type": "error", "name": "Expired", "inputs": [{ "name": "deadline", "type": "uint256" }] }"#,
+    ];
+    let parse = |order: &[usize]| -> JsonAbi {
+        let json = order
+            .iter()
+            .map(|&i| items[i])
+            .collect::<Vec<_>>()
+            .join(",");
+        serde_json::from_str(&format!("[{json}]")).unwrap()
+    };
+
+    let abi = parse(&[0, 1, 2, 3, 4, 5]);
+    let reversed = parse(&[5, 4, 3, 2, 1, 0]);
+    let shuffled = parse(&[3, 0, 5, 1, 4, 2]);
+    assert_eq!(abi, reversed);
+    assert_eq!(abi, shuffled);
+
+    // items are visited in name order, regardless of the input order
+    let names: Vec<_> = abi.functions().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, ["approve", "transfer"]);
+
+    for other in [&reversed, &shuffled] {
+        assert_eq!(
+            serde_json::to_string(&abi).unwrap(),
+            serde_json::to_string(other).unwrap()
+        );
+        assert_eq!(abi.to_sol("Token"), other.to_sol("Token"));
+    }
+}
@@ -0,0 +1,61 @@
+use alloy_primitives::Address;
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+use std::time::Duration;
+
+const ADDRESSES: &[&str] = &[
+    "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+    "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+    "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+    "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+];
+
+// parse, re-encode the checksum and compare the strings
+fn validate_by_reencoding(s: &str, chain_id: Option<u64>) -> bool {
+    let address: Address = s.parse().unwrap();
+    s == address.to_checksum_raw(&mut [0; 42], chain_id)
+}
+
+fn checksum(c: &mut Criterion) {
+    let mut g = group(c, "checksum");
+
+    g.bench_function("reencode", |b| {
+        b.iter(|| {
+            for s in ADDRESSES {
+                assert!(validate_by_reencoding(black_box(s), None));
+            }
+        });
+    });
+    g.bench_function("validate", |b| {
+        b.iter(|| {
+            for s in ADDRESSES {
+                Address::validate_checksum(black_box(s), None).unwrap();
+            }
+        });
+    });
+    g.bench_function("parse_checksummed", |b| {
+        b.iter(|| {
+            for s in ADDRESSES {
+                Address::parse_checksummed(black_box(s), None).unwrap();
+            }
+        });
+    });
+    g.bench_function("parse_checksummed_batch", |b| {
+        b.iter(|| Address::parse_checksummed_batch(black_box(ADDRESSES), None));
+    });
+
+    g.finish();
+}
+
+fn group<'a>(c: &'a mut Criterion, group_name: &str) -> BenchmarkGroup<'a, WallTime> {
+    let mut g = c.benchmark_group(group_name);
+    g.noise_threshold(0.03)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(3))
+        .sample_size(200);
+    g
+}
+
+criterion_group!(benches, checksum);
+criterion_main!(benches);
@@ -0,0 +1,82 @@
+use alloy_primitives::{fixed_bytes, keccak256, B256};
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BatchSize, BenchmarkGroup,
+    Criterion,
+};
+use std::time::Duration;
+
+const N: usize = 10_000_000;
+
+fn words() -> Vec<B256> {
+    (0..N as u64).map(|i| keccak256(i.to_be_bytes())).collect()
+}
+
+// `sort_unstable_b256` should beat `slice::sort_unstable` on random hashes
+fn sort(c: &mut Criterion) {
+    let mut g = group(c, "sort_b256");
+    let words = words();
+
+    g.bench_function("sort_unstable", |b| {
+        b.iter_batched_ref(
+            || words.clone(),
+            |words| words.sort_unstable(),
+            BatchSize::LargeInput,
+        );
+    });
+    g.bench_function("sort_unstable_b256", |b| {
+        b.iter_batched_ref(
+            || words.clone(),
+            |words| fixed_bytes::sort_unstable_b256(words),
+            BatchSize::LargeInput,
+        );
+    });
+
+    let mut sorted = words.clone();
+    fixed_bytes::sort_unstable_b256(&mut sorted);
+    g.bench_function("dedup", |b| {
+        b.iter_batched_ref(
+            || sorted.clone(),
+            |words| words.dedup(),
+            BatchSize::LargeInput,
+        );
+    });
+    g.bench_function("dedup_sorted", |b| {
+        b.iter_batched_ref(
+            || sorted.clone(),
+            |words| fixed_bytes::dedup_sorted(words),
+            BatchSize::LargeInput,
+        );
+    });
+
+    let needles = &words[..1000];
+    g.bench_function("binary_search", |b| {
+        b.iter(|| {
+            needles
+                .iter()
+                .filter(|w| sorted.binary_search(black_box(w)).is_ok())
+                .count()
+        });
+    });
+    g.bench_function("contains_sorted", |b| {
+        b.iter(|| {
+            needles
+                .iter()
+                .filter(|w| fixed_bytes::contains_sorted(&sorted, black_box(w)))
+                .count()
+        });
+    });
+
+    g.finish();
+}
+
+fn group<'a>(c: &'a mut Criterion, group_name: &str) -> BenchmarkGroup<'a, WallTime> {
+    let mut g = c.benchmark_group(group_name);
+    g.noise_threshold(0.03)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(30))
+        .sample_size(10);
+    g
+}
+
+criterion_group!(benches, sort);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+use alloy_primitives::B256;
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+use std::{collections::HashMap, time::Duration};
+
+const N: usize = 10_000;
+
+fn keys() -> Vec<B256> {
+    (0..N as u64)
+        .map(|i| alloy_primitives::keccak256(i.to_be_bytes()))
+        .collect()
+}
+
+// `B256` keys should be as fast as the raw `[u8; 32]` arrays they wrap
+fn hash(c: &mut Criterion) {
+    let mut g = group(c, "hash");
+    let keys = keys();
+    let arrays: Vec<[u8; 32]> = keys.iter().map(|k| k.0).collect();
+
+    g.bench_function("insert_b256", |b| {
+        b.iter(|| {
+            let mut map = HashMap::with_capacity(N);
+            for (i, key) in keys.iter().enumerate() {
+                map.insert(*key, i);
+            }
+            black_box(map)
+        });
+    });
+    g.bench_function("insert_array", |b| {
+        b.iter(|| {
+            let mut map = HashMap::with_capacity(N);
+            for (i, key) in arrays.iter().enumerate() {
+                map.insert(*key, i);
+            }
+            black_box(map)
+        });
+    });
+
+    let map: HashMap<B256, usize> = keys.iter().copied().zip(0..).collect();
+    let array_map: HashMap<[u8; 32], usize> = arrays.iter().copied().zip(0..).collect();
+    g.bench_function("get_b256", |b| {
+        b.iter(|| keys.iter().map(|key| map[black_box(key)]).sum::<usize>());
+    });
+    g.bench_function("get_array", |b| {
+        b.iter(|| {
+            arrays
+                .iter()
+                .map(|key| array_map[black_box(key)])
+                .sum::<usize>()
+        });
+    });
+
+    g.finish();
+}
+
+fn group<'a>(c: &'a mut Criterion, group_name: &str) -> BenchmarkGroup<'a, WallTime> {
+    let mut g = c.benchmark_group(group_name);
+    g.noise_threshold(0.03)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(3))
+        .sample_size(200);
+    g
+}
+
+criterion_group!(benches, hash);
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+use alloy_primitives::{keccak256, keccak256_slices};
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+use std::time::Duration;
+
+const SIZES: &[usize] = &[32, 85, 256, 4096];
+
+fn tiny_keccak256(bytes: &[u8]) -> [u8; 32] {
+    use tiny_keccak::{Hasher, Keccak};
+
+    let mut hasher = Keccak::v256();
+    hasher.update(bytes);
+    let mut output = [0; 32];
+    hasher.finalize(&mut output);
+    output
+}
+
+#[cfg(feature = "asm-keccak")]
+fn asm_keccak256(bytes: &[u8]) -> [u8; 32] {
+    keccak_asm::Keccak256::digest(bytes).into()
+}
+
+fn keccak(c: &mut Criterion) {
+    let mut g = group(c, "keccak256");
+
+    for &size in SIZES {
+        let input = vec![0xa5; size];
+        g.bench_function(format!("tiny-keccak/{size}"), |b| {
+            b.iter(|| tiny_keccak256(black_box(&input)));
+        });
+        #[cfg(feature = "asm-keccak")]
+        g.bench_function(format!("keccak-asm/{size}"), |b| {
+            b.iter(|| asm_keccak256(black_box(&input)));
+        });
+        g.bench_function(format!("keccak256/{size}"), |b| {
+            b.iter(|| keccak256(black_box(&input)));
+        });
+    }
+
+    g.finish();
+}
+
+fn keccak_slices(c: &mut Criterion) {
+    let mut g = group(c, "keccak256_slices");
+
+    // the EIP-712 signing hash preimage: `0x1901 ++ domainSeparator ++ hashStruct`
+    let domain_separator = [0x11; 32];
+    let struct_hash = [0x22; 32];
+    g.bench_function("eip712/concat", |b| {
+        b.iter(|| {
+            let mut buf = [0u8; 2 + 32 + 32];
+            buf[0] = 0x19;
+            buf[1] = 0x01;
+            buf[2..34].copy_from_slice(black_box(&domain_separator));
+            buf[34..66].copy_from_slice(black_box(&struct_hash));
+            keccak256(buf)
+        });
+    });
+    g.bench_function("eip712/slices", |b| {
+        b.iter(|| {
+            let (domain_separator, struct_hash) = black_box((&domain_separator, &struct_hash));
+            keccak256_slices(&[&[0x19, 0x01], domain_separator, struct_hash])
+        });
+    });
+
+    for &size in SIZES {
+        let a = vec![0xa5; size];
+        let b = vec![0x5a; size];
+        g.bench_function(format!("concat/2x{size}"), |bench| {
+            bench.iter(|| keccak256([black_box(&a[..]), black_box(&b[..])].concat()));
+        });
+        g.bench_function(format!("slices/2x{size}"), |bench| {
+            bench.iter(|| keccak256_slices(&[black_box(&a), black_box(&b)]));
+        });
+    }
+
+    g.finish();
+}
+
+fn group<'a>(c: &'a mut Criterion, group_name: &str) -> BenchmarkGroup<'a, WallTime> {
+    let mut g = c.benchmark_group(group_name);
+    g.noise_threshold(0.03)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(3))
+        .sample_size(200);
+    g
+}
+
+criterion_group!(benches, keccak, keccak_slices);
+criterion_main!(benches);
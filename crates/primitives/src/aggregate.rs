@@ -0,0 +1,240 @@
+//! Overflow-aware sums and products of [`Uint`] and [`Signed`] iterators.
+//!
+//! The [`Sum`](core::iter::Sum) and [`Product`](core::iter::Product)
+//! implementations do not agree on overflow: [`Uint`]'s (from `ruint`) wrap
+//! around, while [`Signed`]'s panic. The functions in this module make the
+//! overflow behavior explicit instead:
+//!
+//! ```
+//! use alloy_primitives::{aggregate, U256};
+//!
+//! let balances = [U256::from(1), U256::from(2), U256::MAX];
+//! assert_eq!(
+//!     aggregate::checked_sum(balances[..2].iter().copied()),
+//!     Some(U256::from(3))
+//! );
+//! assert_eq!(aggregate::checked_sum(balances), None);
+//! assert_eq!(aggregate::saturating_sum(balances), U256::MAX);
+//! ```
+//!
+//! All of them fold from left to right, so an overflow is detected on the
+//! first partial sum or product that does not fit, even if the final result
+//! would. For unsigned sums this makes no difference, but for example the
+//! checked sum of `[I256::MAX, I256::ONE, I256::MINUS_ONE]` is `None`, and
+//! its saturating sum is `I256::MAX - 1`.
+
+use crate::{Signed, Uint};
+
+/// Integer types supported by the functions in the [`aggregate`](self)
+/// module.
+///
+/// This trait is sealed and implemented for [`Uint`] and [`Signed`].
+pub trait Aggregate: Copy + sealed::Sealed {
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    #[doc(hidden)]
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    #[doc(hidden)]
+    fn saturating_add(self, rhs: Self) -> Self;
+    #[doc(hidden)]
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    #[doc(hidden)]
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<const BITS: usize, const LIMBS: usize> Sealed for super::Uint<BITS, LIMBS> {}
+    impl<const BITS: usize, const LIMBS: usize> Sealed for super::Signed<BITS, LIMBS> {}
+}
+
+impl<const BITS: usize, const LIMBS: usize> Aggregate for Uint<BITS, LIMBS> {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::from_limbs({
+        let mut limbs = [0; LIMBS];
+        if LIMBS > 0 {
+            limbs[0] = 1;
+        }
+        limbs
+    });
+
+    #[inline]
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+
+    #[inline]
+    fn saturating_add(self, rhs: Self) -> Self {
+        self.saturating_add(rhs)
+    }
+
+    #[inline]
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs)
+    }
+
+    #[inline]
+    fn saturating_mul(self, rhs: Self) -> Self {
+        self.saturating_mul(rhs)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Aggregate for Signed<BITS, LIMBS> {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    #[inline]
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+
+    #[inline]
+    fn saturating_add(self, rhs: Self) -> Self {
+        self.saturating_add(rhs)
+    }
+
+    #[inline]
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs)
+    }
+
+    #[inline]
+    fn saturating_mul(self, rhs: Self) -> Self {
+        self.saturating_mul(rhs)
+    }
+}
+
+/// Sums the items of the iterator, returning `None` on overflow.
+#[inline]
+pub fn checked_sum<T: Aggregate>(iter: impl IntoIterator<Item = T>) -> Option<T> {
+    iter.into_iter().try_fold(T::ZERO, T::checked_add)
+}
+
+/// Sums the items of the iterator, saturating at the numeric bounds instead
+/// of overflowing.
+#[inline]
+pub fn saturating_sum<T: Aggregate>(iter: impl IntoIterator<Item = T>) -> T {
+    iter.into_iter().fold(T::ZERO, T::saturating_add)
+}
+
+/// Multiplies the items of the iterator, returning `None` on overflow.
+#[inline]
+pub fn checked_product<T: Aggregate>(iter: impl IntoIterator<Item = T>) -> Option<T> {
+    iter.into_iter().try_fold(T::ONE, T::checked_mul)
+}
+
+/// Multiplies the items of the iterator, saturating at the numeric bounds
+/// instead of overflowing.
+#[inline]
+pub fn saturating_product<T: Aggregate>(iter: impl IntoIterator<Item = T>) -> T {
+    iter.into_iter().fold(T::ONE, T::saturating_mul)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{I256, U256};
+
+    #[test]
+    fn empty() {
+        assert_eq!(checked_sum::<U256>([]), Some(U256::ZERO));
+        assert_eq!(saturating_product::<U256>([]), U256::from(1));
+        assert_eq!(checked_product::<I256>([]), Some(I256::ONE));
+        assert_eq!(<Uint<0, 0> as Aggregate>::ONE, Uint::ZERO);
+    }
+
+    #[test]
+    fn unsigned_overflow_boundary() {
+        let max = U256::MAX;
+        assert_eq!(checked_sum([max - U256::from(1), U256::from(1)]), Some(max));
+        assert_eq!(checked_sum([max, U256::from(1)]), None);
+        assert_eq!(saturating_sum([max, U256::from(1), U256::from(5)]), max);
+
+        let half = U256::from(1) << 128;
+        let expected = max - (half - U256::from(1));
+        assert_eq!(
+            checked_product([half - U256::from(1), half]),
+            Some(expected)
+        );
+        assert_eq!(checked_product([half, half]), None);
+        assert_eq!(saturating_product([half, half]), max);
+        assert_eq!(checked_product([half, half, U256::ZERO]), None);
+    }
+
+    #[test]
+    fn signed_overflow_boundary() {
+        assert_eq!(checked_sum([I256::MAX, I256::ZERO]), Some(I256::MAX));
+        assert_eq!(checked_sum([I256::MAX, I256::ONE]), None);
+        assert_eq!(checked_sum([I256::MIN, I256::MINUS_ONE]), None);
+        assert_eq!(saturating_sum([I256::MIN, I256::MINUS_ONE]), I256::MIN);
+
+        // partial sums are checked from left to right
+        assert_eq!(checked_sum([I256::MAX, I256::ONE, I256::MINUS_ONE]), None);
+        assert_eq!(
+            checked_sum([I256::MAX, I256::MINUS_ONE, I256::ONE]),
+            Some(I256::MAX)
+        );
+        assert_eq!(
+            saturating_sum([I256::MAX, I256::ONE, I256::MINUS_ONE]),
+            I256::MAX - I256::ONE
+        );
+
+        assert_eq!(checked_product([I256::MIN, I256::ONE]), Some(I256::MIN));
+        assert_eq!(checked_product([I256::MIN, I256::MINUS_ONE]), None);
+        assert_eq!(saturating_product([I256::MIN, I256::MINUS_ONE]), I256::MAX);
+        assert_eq!(
+            saturating_product([I256::MAX, I256::MINUS_ONE, I256::MAX]),
+            I256::MIN
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "proptest")]
+    fn unsigned_reference() {
+        use crate::{U128, U512};
+        use proptest::{collection::vec, prelude::*};
+
+        proptest!(|(values in vec(any::<U256>(), 0..8))| {
+            let wide = values.iter().fold(U512::ZERO, |acc, x| acc + U512::from(*x));
+            let expected = (wide <= U512::from(U256::MAX)).then(|| U256::from(wide));
+            prop_assert_eq!(checked_sum(values.iter().copied()), expected);
+            prop_assert_eq!(saturating_sum(values.iter().copied()), expected.unwrap_or(U256::MAX));
+        });
+
+        proptest!(|(values in vec(any::<u128>(), 0..4))| {
+            let unsigned = values.iter().map(|x| U128::from(*x));
+
+            let expected = values.iter().try_fold(1u128, |acc, x| acc.checked_mul(*x));
+            prop_assert_eq!(checked_product(unsigned.clone()), expected.map(U128::from));
+            let expected = values.iter().fold(1u128, |acc, x| acc.saturating_mul(*x));
+            prop_assert_eq!(saturating_product(unsigned), U128::from(expected));
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "proptest")]
+    fn signed_reference() {
+        use crate::I128;
+        use proptest::{collection::vec, prelude::*};
+
+        proptest!(|(values in vec(any::<i128>(), 0..8))| {
+            let signed = values.iter().map(|x| I128::try_from(*x).unwrap());
+
+            let expected = values.iter().try_fold(0i128, |acc, x| acc.checked_add(*x));
+            let expected = expected.map(|x| I128::try_from(x).unwrap());
+            prop_assert_eq!(checked_sum(signed.clone()), expected);
+            let expected = values.iter().fold(0i128, |acc, x| acc.saturating_add(*x));
+            prop_assert_eq!(saturating_sum(signed.clone()), I128::try_from(expected).unwrap());
+
+            let expected = values.iter().try_fold(1i128, |acc, x| acc.checked_mul(*x));
+            let expected = expected.map(|x| I128::try_from(x).unwrap());
+            prop_assert_eq!(checked_product(signed.clone()), expected);
+            let expected = values.iter().fold(1i128, |acc, x| acc.saturating_mul(*x));
+            prop_assert_eq!(saturating_product(signed), I128::try_from(expected).unwrap());
+        });
+    }
+}
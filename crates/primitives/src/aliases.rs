@@ -1,9 +1,86 @@
 //! Type aliases for common primitive types.
+//!
+//! The unsigned integer aliases are re-exported from [`ruint`], so their
+//! byte constructors are available directly on the alias, without naming
+//! [`Uint`](ruint::Uint):
+//!
+//! ```
+//! use alloy_primitives::{Address, U160, U256};
+//!
+//! let address = Address::repeat_byte(0x11);
+//! let value = U256::from_be_slice(address.as_slice());
+//! assert_eq!(value, address.into_u256());
+//! assert_eq!(U160::try_from_be_slice(&[0xff; 21]), None);
+//! assert_eq!(U256::from_le_slice(&[1, 0]), U256::from(1));
+//! ```
+//!
+//! # Conversions
+//!
+//! The unsigned integer aliases convert between each other, and to and from
+//! the primitive integers, with the inherent methods of [`Uint`](ruint::Uint):
+//! - `from` and `to` panic if the value does not fit, so they are best used for
+//!   widening;
+//! - `saturating_from` and `saturating_to` clamp the value to the target's
+//!   maximum;
+//! - `wrapping_from` and `wrapping_to` truncate the value.
+//!
+//! Standard [`TryFrom`] and [`From`] implementations between two `Uint` sizes
+//! would conflict with the blanket `impl<T> From<T> for T`, so the fallible
+//! conversions are provided by the [`UintTryFrom`] and [`UintTryTo`] traits
+//! instead, which are re-exported here.
+//!
+//! ```
+//! use alloy_primitives::{
+//!     aliases::{UintTryFrom, UintTryTo},
+//!     U160, U256, U64,
+//! };
+//!
+//! // widening never fails
+//! let slot = U256::from(U160::MAX);
+//!
+//! // narrowing fails if the value does not fit
+//! assert_eq!(U160::uint_try_from(slot), Ok(U160::MAX));
+//! assert!(U160::uint_try_from(slot + U256::from(1)).is_err());
+//! assert!(UintTryTo::<U64>::uint_try_to(&slot).is_err());
+//!
+//! // to primitive integers
+//! assert_eq!(U256::from(42).to::<u64>(), 42);
+//! assert_eq!(slot.saturating_to::<u64>(), u64::MAX);
+//! assert_eq!(slot.saturating_to::<U64>(), U64::MAX);
+//! assert_eq!(U64::saturating_from(slot), U64::MAX);
+//! ```
+//!
+//! # Parsing
+//!
+//! [`FromStr`](core::str::FromStr) for the unsigned integer aliases detects
+//! the `0x`, `0o` and `0b` prefixes and ignores every `_`. The [`ParseUint`]
+//! trait additionally validates the placement of the separators, and returns
+//! a descriptive [`ParseUintError`]:
+//!
+//! ```
+//! use alloy_primitives::{aliases::ParseUint, U256};
+//!
+//! assert_eq!(U256::parse("1_000_000_000"), Ok(U256::from(1_000_000_000)));
+//! assert_eq!(U256::parse("0xDE_AD"), Ok(U256::from(0xdead)));
+//! assert!(U256::parse("1__2").is_err());
+//! ```
+//!
+//! # Extension traits
+//!
+//! The [`Uint`] aliases are defined in [`ruint`], so this crate
+//! cannot add inherent methods to them. Additional methods are provided by
+//! extension traits instead: [`ParseUint`], [`LossyF64`],
+//! [`MulDiv`](crate::MulDiv) and [`IntoAddress`](crate::IntoAddress).
 
-use crate::{FixedBytes, Signed};
+use crate::{FixedBytes, Signed, Uint};
+use core::fmt;
 
-pub use ruint::aliases::{
-    U0, U1, U1024, U128, U16, U160, U192, U2048, U256, U32, U320, U384, U4096, U448, U512, U64, U8,
+pub use ruint::{
+    aliases::{
+        U0, U1, U1024, U128, U16, U160, U192, U2048, U256, U32, U320, U384, U4096, U448, U512, U64,
+        U8,
+    },
+    FromUintError, ToUintError, UintTryFrom, UintTryTo,
 };
 
 macro_rules! int_aliases {
@@ -86,3 +163,407 @@ pub type StorageValue = U256;
 /// Solidity contract functions are addressed using the first four bytes of the
 /// Keccak-256 hash of their signature.
 pub type Selector = FixedBytes<4>;
+
+/// Error returned by [`ParseUint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseUintError {
+    /// There are no digits after the radix prefix.
+    Empty,
+    /// A character is not a digit in the radix.
+    InvalidDigit {
+        /// The invalid character.
+        digit: char,
+        /// The radix of the number.
+        radix: u32,
+    },
+    /// A `_` separator is at the start or end of the digits, or next to
+    /// another separator.
+    InvalidSeparator {
+        /// The byte index of the separator in the input string.
+        index: usize,
+    },
+    /// The number does not fit in the integer type.
+    Overflow {
+        /// The number of bits of the integer type.
+        bits: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseUintError {}
+
+impl fmt::Display for ParseUintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("no digits to parse"),
+            Self::InvalidDigit { digit, radix } => {
+                write!(f, "invalid digit {digit:?} for radix {radix}")
+            }
+            Self::InvalidSeparator { index } => {
+                write!(f, "misplaced `_` separator at index {index}")
+            }
+            Self::Overflow { bits } => write!(f, "number too large to fit in {bits} bits"),
+        }
+    }
+}
+
+/// Parsing of unsigned integers with radix prefixes and `_` separators.
+///
+/// This is an [extension trait](crate::aliases#extension-traits) for all
+/// [`Uint`]s.
+///
+/// The radix is detected from a `0x`, `0o` or `0b` prefix (in either case),
+/// and defaults to 10. Digits may be grouped with single `_` separators,
+/// which cannot lead, trail or follow each other.
+///
+/// ```
+/// use alloy_primitives::{
+///     aliases::{ParseUint, ParseUintError},
+///     U256, U8,
+/// };
+///
+/// assert_eq!(U256::parse("0b1010_1010"), Ok(U256::from(0xaa)));
+/// assert_eq!(U256::parse("0o7_7"), Ok(U256::from(0o77)));
+/// assert_eq!(
+///     U256::parse("_1"),
+///     Err(ParseUintError::InvalidSeparator { index: 0 })
+/// );
+/// assert_eq!(U256::parse("0x"), Err(ParseUintError::Empty));
+/// assert_eq!(U8::parse("256"), Err(ParseUintError::Overflow { bits: 8 }));
+///
+/// // strict mode behaves exactly like `FromStr`
+/// assert_eq!(U256::parse_with("1__2", true), Ok(U256::from(12)));
+/// assert_eq!(U256::parse_with("0x", true), Ok(U256::ZERO));
+/// assert_eq!("0x".parse::<U256>(), Ok(U256::ZERO));
+/// ```
+pub trait ParseUint: Sized {
+    /// Parses the string, validating the placement of `_` separators.
+    ///
+    /// This is the same as [`parse_with(s, false)`](ParseUint::parse_with).
+    fn parse(s: &str) -> Result<Self, ParseUintError> {
+        Self::parse_with(s, false)
+    }
+
+    /// Parses the string.
+    ///
+    /// If `strict` is `true`, this accepts and rejects exactly the same
+    /// strings as [`FromStr`](core::str::FromStr): every `_` is ignored, and
+    /// empty digits parse as zero. Otherwise, the separators are validated
+    /// and empty digits are rejected.
+    fn parse_with(s: &str, strict: bool) -> Result<Self, ParseUintError>;
+}
+
+impl<const BITS: usize, const LIMBS: usize> ParseUint for Uint<BITS, LIMBS> {
+    fn parse_with(s: &str, strict: bool) -> Result<Self, ParseUintError> {
+        let (digits, radix) = match s.get(..2) {
+            Some("0x" | "0X") => (&s[2..], 16),
+            Some("0o" | "0O") => (&s[2..], 8),
+            Some("0b" | "0B") => (&s[2..], 2),
+            _ => (s, 10),
+        };
+
+        if !strict {
+            if digits.is_empty() {
+                return Err(ParseUintError::Empty);
+            }
+            let offset = s.len() - digits.len();
+            let bytes = digits.as_bytes();
+            for (i, &b) in bytes.iter().enumerate() {
+                if b == b'_' {
+                    if i == 0 || i == bytes.len() - 1 || bytes[i - 1] == b'_' {
+                        return Err(ParseUintError::InvalidSeparator { index: offset + i });
+                    }
+                } else if !(b as char).is_digit(radix) {
+                    let digit = digits[i..].chars().next().unwrap();
+                    return Err(ParseUintError::InvalidDigit { digit, radix });
+                }
+            }
+        }
+
+        Self::from_str_radix(digits, radix as u64).map_err(|e| match e {
+            ruint::ParseError::InvalidDigit(digit) => ParseUintError::InvalidDigit { digit, radix },
+            ruint::ParseError::BaseConvertError(_) => ParseUintError::Overflow { bits: BITS },
+            // the radix is always one of 2, 8, 10 or 16
+            ruint::ParseError::InvalidRadix(radix) => unreachable!("invalid radix {radix}"),
+        })
+    }
+}
+
+/// Lossy conversions between unsigned integers and [`f64`].
+///
+/// This is an [extension trait](crate::aliases#extension-traits) for all
+/// [`Uint`]s. The [`Signed`] integers have inherent methods with the same
+/// names.
+///
+/// ```
+/// use alloy_primitives::{aliases::LossyF64, U256};
+///
+/// let wei = U256::from(1_500_000_000_000_000_000u64);
+/// assert_eq!(wei.to_f64_lossy() / 1e18, 1.5);
+/// assert_eq!(U256::from_f64_lossy(1.5e18), wei);
+/// assert_eq!(U256::from_f64_lossy(-1.0), U256::ZERO);
+/// assert_eq!(U256::from_f64_lossy(f64::INFINITY), U256::MAX);
+/// ```
+pub trait LossyF64: Sized {
+    /// Converts the integer to the nearest [`f64`], rounding ties to even.
+    ///
+    /// This is exact up to 2<sup>53</sup>. Integers of 2<sup>1024</sup> or
+    /// more, which only fit in types of more than 1024 bits, convert to
+    /// [`f64::INFINITY`].
+    fn to_f64_lossy(&self) -> f64;
+
+    /// Converts an [`f64`] to the integer, like an `as` cast between
+    /// primitive types.
+    ///
+    /// The fractional part is truncated, rounding towards zero. Values that
+    /// are too large for the integer type, including infinity, saturate to
+    /// the maximum value; negative values saturate to zero, and `NaN`
+    /// converts to zero.
+    fn from_f64_lossy(value: f64) -> Self;
+}
+
+impl<const BITS: usize, const LIMBS: usize> LossyF64 for Uint<BITS, LIMBS> {
+    #[inline]
+    fn to_f64_lossy(&self) -> f64 {
+        // `ruint` rounds the leading 53 bits of the limbs to nearest, ties to even
+        f64::from(self)
+    }
+
+    fn from_f64_lossy(value: f64) -> Self {
+        if value.is_nan() || value < 1.0 {
+            return Self::ZERO;
+        }
+        if value == f64::INFINITY {
+            return Self::MAX;
+        }
+
+        // `value` is a normal number: `significand * 2^exponent`
+        let bits = value.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as isize - 1075;
+        let significand = (bits & ((1 << 52) - 1)) | (1 << 52);
+        let (significand, shift) = if exponent < 0 {
+            (significand >> -exponent, 0)
+        } else {
+            (significand, exponent as usize)
+        };
+
+        let bit_len = 64 - significand.leading_zeros() as usize + shift;
+        if bit_len > BITS {
+            return Self::MAX;
+        }
+        let mut limbs = [0; LIMBS];
+        limbs[0] = significand;
+        Self::from_limbs(limbs) << shift
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn uint_conversions() {
+        // U64 <-> U256
+        let small = U64::from(0x1234_5678_u64);
+        assert_eq!(U64::uint_try_from(U256::from(small)), Ok(small));
+        assert_eq!(U256::from(small).to::<u64>(), 0x1234_5678);
+        let large = U256::from(u64::MAX) + U256::from(1);
+        assert_eq!(
+            U64::uint_try_from(large),
+            Err(ToUintError::ValueTooLarge(64, U64::ZERO))
+        );
+        assert_eq!(large.saturating_to::<U64>(), U64::MAX);
+        assert_eq!(large.saturating_to::<u64>(), u64::MAX);
+        assert_eq!(U64::saturating_from(large), U64::MAX);
+
+        // U160 <-> U256
+        assert_eq!(U160::uint_try_from(U256::from(U160::MAX)), Ok(U160::MAX));
+        assert_eq!(
+            U256::from(U160::MAX),
+            (U256::from(1) << 160) - U256::from(1)
+        );
+        assert!(U160::uint_try_from(U256::MAX).is_err());
+        assert_eq!(
+            UintTryTo::<U160>::uint_try_to(&U256::MAX),
+            Err(FromUintError::Overflow(160, U160::MAX, U160::MAX))
+        );
+        assert_eq!(U256::MAX.saturating_to::<U160>(), U160::MAX);
+        assert_eq!(U256::MAX.wrapping_to::<U160>(), U160::MAX);
+
+        // U256 <-> U512
+        assert_eq!(U256::uint_try_from(U512::from(U256::MAX)), Ok(U256::MAX));
+        assert_eq!(U512::from(U256::MAX).to::<U256>(), U256::MAX);
+        let overflow = U512::from(U256::MAX) + U512::from(1);
+        assert!(U256::uint_try_from(overflow).is_err());
+        assert_eq!(overflow.saturating_to::<U256>(), U256::MAX);
+        assert_eq!(overflow.wrapping_to::<U256>(), U256::ZERO);
+    }
+
+    #[test]
+    fn parse_uint() {
+        let ok = |s: &str, n: u64| {
+            assert_eq!(U256::parse(s), Ok(U256::from(n)), "{s}");
+            assert_eq!(U256::parse_with(s, true), Ok(U256::from(n)), "{s}");
+            assert_eq!(s.parse::<U256>(), Ok(U256::from(n)), "{s}");
+        };
+        ok("0", 0);
+        ok("1_000_000_000", 1_000_000_000);
+        ok("0xDE_AD", 0xdead);
+        ok("0Xde_ad", 0xdead);
+        ok("0o1_7", 0o17);
+        ok("0B1_0", 2);
+        ok("007", 7);
+
+        let err = |s: &str, e: ParseUintError| {
+            assert_eq!(U256::parse(s), Err(e), "{s}");
+        };
+        err("", ParseUintError::Empty);
+        err("0x", ParseUintError::Empty);
+        err("_1", ParseUintError::InvalidSeparator { index: 0 });
+        err("0x_1", ParseUintError::InvalidSeparator { index: 2 });
+        err("1__2", ParseUintError::InvalidSeparator { index: 2 });
+        err("1_", ParseUintError::InvalidSeparator { index: 1 });
+        err("_", ParseUintError::InvalidSeparator { index: 0 });
+        err(
+            "12a",
+            ParseUintError::InvalidDigit {
+                digit: 'a',
+                radix: 10,
+            },
+        );
+        err(
+            "0b102",
+            ParseUintError::InvalidDigit {
+                digit: '2',
+                radix: 2,
+            },
+        );
+        err(
+            "0o8",
+            ParseUintError::InvalidDigit {
+                digit: '8',
+                radix: 8,
+            },
+        );
+        err(
+            "0xg",
+            ParseUintError::InvalidDigit {
+                digit: 'g',
+                radix: 16,
+            },
+        );
+        err(
+            "1é",
+            ParseUintError::InvalidDigit {
+                digit: 'é',
+                radix: 10,
+            },
+        );
+        err(
+            "-1",
+            ParseUintError::InvalidDigit {
+                digit: '-',
+                radix: 10,
+            },
+        );
+        err(
+            " 1",
+            ParseUintError::InvalidDigit {
+                digit: ' ',
+                radix: 10,
+            },
+        );
+
+        let max = U256::MAX.to_string();
+        assert_eq!(U256::parse(&max), Ok(U256::MAX));
+        assert_eq!(
+            U256::parse(&format!("{max}0")),
+            Err(ParseUintError::Overflow { bits: 256 })
+        );
+        assert_eq!(U8::parse("0xff"), Ok(U8::MAX));
+        assert_eq!(
+            U8::parse("0x1_00"),
+            Err(ParseUintError::Overflow { bits: 8 })
+        );
+        assert_eq!(U8::parse("0x0_0ff"), Ok(U8::MAX));
+
+        // strict mode accepts and rejects the same strings as `FromStr`
+        for s in [
+            "", "0x", "_1", "1__2", "1_", "_", "12a", "0b102", "-1", "0x1_00", "256",
+        ] {
+            assert_eq!(
+                U8::parse_with(s, true).is_ok(),
+                s.parse::<U8>().is_ok(),
+                "{s:?}"
+            );
+        }
+        assert_eq!(U256::parse_with("1__2_", true), Ok(U256::from(12)));
+        assert_eq!(
+            U256::parse_with("12a", true),
+            Err(ParseUintError::InvalidDigit {
+                digit: 'a',
+                radix: 10
+            })
+        );
+    }
+
+    #[test]
+    fn lossy_f64() {
+        let two_53 = 9_007_199_254_740_992.0;
+        for (int, float) in [
+            (U256::ZERO, 0.0),
+            (U256::from(1), 1.0),
+            (U256::from(1u64 << 53), two_53),
+            (U256::from((1u64 << 53) - 1), two_53 - 1.0),
+            (U256::from(1) << 255, 5.78960446186581e76),
+        ] {
+            assert_eq!(int.to_f64_lossy(), float);
+            assert_eq!(U256::from_f64_lossy(float), int);
+        }
+
+        // rounds to nearest, ties to even
+        assert_eq!(U256::from((1u64 << 53) + 1).to_f64_lossy(), two_53);
+        assert_eq!(U256::from((1u64 << 53) + 2).to_f64_lossy(), two_53 + 2.0);
+        assert_eq!(U256::from((1u64 << 53) + 3).to_f64_lossy(), two_53 + 4.0);
+        assert_eq!(U256::MAX.to_f64_lossy(), 1.157920892373162e77);
+        assert_eq!(U256::MAX.to_f64_lossy(), 2f64.powi(256));
+        assert_eq!(U1024::MAX.to_f64_lossy(), f64::INFINITY);
+        assert_eq!(U512::MAX.to_f64_lossy(), 2f64.powi(512));
+
+        // truncates towards zero
+        assert_eq!(
+            U256::from_f64_lossy(two_53 + 1.5),
+            U256::from((1u64 << 53) + 2)
+        );
+        assert_eq!(U256::from_f64_lossy(2.5), U256::from(2));
+        assert_eq!(U256::from_f64_lossy(0.999), U256::ZERO);
+        assert_eq!(U256::from_f64_lossy(f64::MIN_POSITIVE), U256::ZERO);
+        assert_eq!(U256::from_f64_lossy(f64::from_bits(1)), U256::ZERO);
+        assert_eq!(U256::from_f64_lossy(1.0 + f64::EPSILON), U256::from(1));
+
+        // saturates
+        assert_eq!(U256::from_f64_lossy(2f64.powi(256)), U256::MAX);
+        assert_eq!(U256::from_f64_lossy(2f64.powi(255)), U256::from(1) << 255);
+        assert_eq!(U256::from_f64_lossy(f64::MAX), U256::MAX);
+        assert_eq!(U256::from_f64_lossy(f64::INFINITY), U256::MAX);
+        assert_eq!(U256::from_f64_lossy(-1.0), U256::ZERO);
+        assert_eq!(U256::from_f64_lossy(f64::NEG_INFINITY), U256::ZERO);
+        assert_eq!(U256::from_f64_lossy(f64::NAN), U256::ZERO);
+        assert_eq!(U0::from_f64_lossy(1.0), U0::ZERO);
+        assert_eq!(U8::from_f64_lossy(255.9), U8::MAX);
+        assert_eq!(U8::from_f64_lossy(256.0), U8::MAX);
+
+        // agrees with the primitive casts
+        for x in [-1.0, 0.5, 255.5, 1e10, 1.8e19, 1e30, 3.4e38, 1e40] {
+            assert_eq!(U8::from_f64_lossy(x).to::<u8>(), x as u8, "{x}");
+            assert_eq!(U64::from_f64_lossy(x).to::<u64>(), x as u64, "{x}");
+            assert_eq!(U128::from_f64_lossy(x).to::<u128>(), x as u128, "{x}");
+            assert_eq!(
+                U128::from(x as u128).to_f64_lossy(),
+                x as u128 as f64,
+                "{x}"
+            );
+        }
+    }
+}
@@ -0,0 +1,327 @@
+//! Base64 encoding and decoding of [`Bytes`] and [`FixedBytes`].
+
+use crate::{Bytes, FixedBytes, InvalidLengthError};
+use ::base64::{
+    alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    DecodeError, Engine,
+};
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+const DECODE: GeneralPurposeConfig =
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+const STANDARD: GeneralPurpose = GeneralPurpose::new(&alphabet::STANDARD, DECODE);
+const URL_SAFE: GeneralPurpose = GeneralPurpose::new(&alphabet::URL_SAFE, DECODE);
+const URL_SAFE_NO_PAD: GeneralPurpose =
+    GeneralPurpose::new(&alphabet::URL_SAFE, DECODE.with_encode_padding(false));
+
+/// Error decoding base64 into bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Base64Error {
+    /// The input is not valid base64.
+    Decode(DecodeError),
+    /// The decoded bytes do not have the expected length.
+    InvalidLength(InvalidLengthError),
+}
+
+impl From<DecodeError> for Base64Error {
+    #[inline]
+    fn from(value: DecodeError) -> Self {
+        Self::Decode(value)
+    }
+}
+
+impl From<InvalidLengthError> for Base64Error {
+    #[inline]
+    fn from(value: InvalidLengthError) -> Self {
+        Self::InvalidLength(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Base64Error {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Decode(err) => Some(err),
+            Self::InvalidLength(err) => Some(err),
+        }
+    }
+}
+
+impl fmt::Display for Base64Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decode(err) => err.fmt(f),
+            Self::InvalidLength(err) => err.fmt(f),
+        }
+    }
+}
+
+/// Types that can be decoded from base64.
+///
+/// This is used by the [`serde::base64`](crate::serde::base64) module to
+/// deserialize fields.
+pub trait FromBase64: Sized {
+    /// Decodes base64 into `Self`.
+    ///
+    /// Both the standard and the URL-safe alphabets are accepted, with or
+    /// without padding.
+    fn from_base64(s: &str) -> Result<Self, Base64Error>;
+}
+
+impl FromBase64 for Vec<u8> {
+    #[inline]
+    fn from_base64(s: &str) -> Result<Self, Base64Error> {
+        let engine = if s.bytes().any(|b| b == b'-' || b == b'_') {
+            &URL_SAFE
+        } else {
+            &STANDARD
+        };
+        engine.decode(s).map_err(Into::into)
+    }
+}
+
+impl FromBase64 for Bytes {
+    #[inline]
+    fn from_base64(s: &str) -> Result<Self, Base64Error> {
+        Self::from_base64(s)
+    }
+}
+
+impl<const N: usize> FromBase64 for FixedBytes<N> {
+    #[inline]
+    fn from_base64(s: &str) -> Result<Self, Base64Error> {
+        Self::from_base64(s)
+    }
+}
+
+impl Bytes {
+    /// Decodes base64 into bytes.
+    ///
+    /// Both the standard and the URL-safe alphabets are accepted, with or
+    /// without padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{bytes, Bytes};
+    /// assert_eq!(Bytes::from_base64("3q2+7w==").unwrap(), bytes!("deadbeef"));
+    /// assert_eq!(Bytes::from_base64("3q2-7w").unwrap(), bytes!("deadbeef"));
+    /// assert!(Bytes::from_base64("3q2+7w=?").is_err());
+    /// ```
+    #[inline]
+    pub fn from_base64(s: &str) -> Result<Self, Base64Error> {
+        Vec::from_base64(s).map(Self::from)
+    }
+
+    /// Encodes the bytes as padded base64, using the standard alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::bytes;
+    /// assert_eq!(bytes!("deadbeef").to_base64(), "3q2+7w==");
+    /// ```
+    #[inline]
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self)
+    }
+
+    /// Encodes the bytes as unpadded base64, using the URL-safe alphabet, as
+    /// used in JWTs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::bytes;
+    /// assert_eq!(bytes!("deadbeef").to_base64_url(), "3q2-7w");
+    /// ```
+    #[inline]
+    pub fn to_base64_url(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self)
+    }
+}
+
+impl<const N: usize> FixedBytes<N> {
+    /// Decodes base64 into a fixed byte array, failing if the decoded bytes
+    /// are not exactly `N` bytes long.
+    ///
+    /// Both the standard and the URL-safe alphabets are accepted, with or
+    /// without padding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{fixed_bytes, FixedBytes};
+    /// assert_eq!(
+    ///     FixedBytes::<4>::from_base64("3q2+7w==").unwrap(),
+    ///     fixed_bytes!("deadbeef")
+    /// );
+    /// assert!(FixedBytes::<5>::from_base64("3q2+7w==").is_err());
+    /// ```
+    #[inline]
+    pub fn from_base64(s: &str) -> Result<Self, Base64Error> {
+        let bytes = Vec::from_base64(s)?;
+        Self::try_from(bytes.as_slice()).map_err(Into::into)
+    }
+
+    /// Encodes the bytes as padded base64, using the standard alphabet.
+    #[inline]
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self)
+    }
+
+    /// Encodes the bytes as unpadded base64, using the URL-safe alphabet, as
+    /// used in JWTs.
+    #[inline]
+    pub fn to_base64_url(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self)
+    }
+}
+
+/// Serialize and deserialize bytes as base64 strings, with
+/// `#[serde(with = "alloy_primitives::serde::base64")]`.
+///
+/// This is useful for fields that are base64 in JSON, but hex elsewhere. Values
+/// are serialized as padded base64 with the standard alphabet, and any
+/// alphabet or padding is accepted when deserializing.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{bytes, Bytes, B256};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Webhook {
+///     #[serde(with = "alloy_primitives::serde::base64")]
+///     payload: Bytes,
+///     #[serde(with = "alloy_primitives::serde::base64")]
+///     digest: B256,
+/// }
+///
+/// let json = r#"{"payload":"3q2+7w==","digest":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA"}"#;
+/// let webhook: Webhook = serde_json::from_str(json).unwrap();
+/// assert_eq!(webhook.payload, bytes!("deadbeef"));
+/// assert_eq!(webhook.digest, B256::ZERO);
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_base64 {
+    use super::FromBase64;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    /// Serializes the bytes as a base64 string.
+    pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: AsRef<[u8]>,
+    {
+        serializer.serialize_str(&super::Engine::encode(&super::STANDARD, value))
+    }
+
+    /// Deserializes a base64 string.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromBase64,
+    {
+        let s = <alloc::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        T::from_base64(&s).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hex, B256};
+
+    #[test]
+    fn roundtrip() {
+        let bytes = crate::bytes!("00ff10fbfe");
+        assert_eq!(bytes.to_base64(), "AP8Q+/4=");
+        assert_eq!(bytes.to_base64_url(), "AP8Q-_4");
+        for s in ["AP8Q+/4=", "AP8Q+/4", "AP8Q-_4=", "AP8Q-_4"] {
+            assert_eq!(Bytes::from_base64(s).unwrap(), bytes, "{s}");
+        }
+
+        assert_eq!(Bytes::from_base64("").unwrap(), Bytes::new());
+        assert_eq!(Bytes::new().to_base64(), "");
+
+        let hash = B256::repeat_byte(0xfb);
+        assert_eq!(B256::from_base64(&hash.to_base64()).unwrap(), hash);
+        assert_eq!(B256::from_base64(&hash.to_base64_url()).unwrap(), hash);
+    }
+
+    #[test]
+    fn errors() {
+        // mixed alphabets
+        assert!(matches!(
+            Bytes::from_base64("AP8Q-/4="),
+            Err(Base64Error::Decode(_))
+        ));
+        assert!(matches!(
+            Bytes::from_base64("AP8Q+/4=="),
+            Err(Base64Error::Decode(_))
+        ));
+        assert!(matches!(
+            Bytes::from_base64("A"),
+            Err(Base64Error::Decode(_))
+        ));
+
+        assert_eq!(
+            FixedBytes::<4>::from_base64("AP8Q+/4="),
+            Err(Base64Error::InvalidLength(InvalidLengthError {
+                expected: 4,
+                actual: 5
+            }))
+        );
+        assert_eq!(
+            FixedBytes::<6>::from_base64("AP8Q+/4")
+                .unwrap_err()
+                .to_string(),
+            "invalid length: expected 6 bytes, got 5"
+        );
+        assert_eq!(
+            FixedBytes::<5>::from_base64("AP8Q+/4"),
+            Ok(FixedBytes(hex!("00ff10fbfe")))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Payload {
+            #[serde(with = "serde_base64")]
+            data: Bytes,
+            #[serde(with = "serde_base64")]
+            key: FixedBytes<4>,
+            hex: FixedBytes<4>,
+        }
+
+        let payload = Payload {
+            data: crate::bytes!("00ff10fbfe"),
+            key: FixedBytes(hex!("deadbeef")),
+            hex: FixedBytes(hex!("deadbeef")),
+        };
+        let json = serde_json::to_string(&payload).unwrap();
+        assert_eq!(
+            json,
+            r#"{"data":"AP8Q+/4=","key":"3q2+7w==","hex":"0xdeadbeef"}"#
+        );
+        assert_eq!(serde_json::from_str::<Payload>(&json).unwrap(), payload);
+
+        let json = r#"{"data":"AP8Q-_4","key":"3q2-7w","hex":"0xdeadbeef"}"#;
+        assert_eq!(serde_json::from_str::<Payload>(json).unwrap(), payload);
+
+        let json = r#"{"data":"AP8Q-_4","key":"AP8Q-_4","hex":"0xdeadbeef"}"#;
+        let err = serde_json::from_str::<Payload>(json).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("invalid length: expected 4 bytes, got 5"),
+            "{err}"
+        );
+    }
+}
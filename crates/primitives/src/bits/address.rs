@@ -1,18 +1,33 @@
-use crate::{aliases::U160, utils::keccak256, wrap_fixed_bytes, FixedBytes};
-use alloc::{
-    borrow::Borrow,
-    string::{String, ToString},
+use crate::{
+    aliases::{U160, U256},
+    bits::fixed::write_truncated_hex,
+    utils::keccak256,
+    FixedBytes,
 };
+use alloc::{borrow::Borrow, string::String, vec::Vec};
 use core::{fmt, str};
 
 /// Error type for address checksum validation.
 #[derive(Debug, Copy, Clone)]
+#[non_exhaustive]
 pub enum AddressError {
     /// Error while decoding hex.
     Hex(hex::FromHexError),
 
     /// Invalid ERC-55 checksum.
     InvalidChecksum,
+
+    /// The upper 12 bytes of a word or integer were not zero.
+    DirtyUpperBytes,
+
+    /// Invalid ICAP country code, length or characters.
+    InvalidIcap,
+
+    /// Invalid ICAP (ISO 13616) checksum.
+    InvalidIcapChecksum,
+
+    /// Basic and indirect ICAP addresses are not supported.
+    UnsupportedIcap,
 }
 
 impl From<hex::FromHexError> for AddressError {
@@ -28,7 +43,11 @@ impl std::error::Error for AddressError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::Hex(err) => Some(err),
-            Self::InvalidChecksum => None,
+            Self::InvalidChecksum
+            | Self::DirtyUpperBytes
+            | Self::InvalidIcap
+            | Self::InvalidIcapChecksum
+            | Self::UnsupportedIcap => None,
         }
     }
 }
@@ -38,6 +57,67 @@ impl fmt::Display for AddressError {
         match self {
             Self::Hex(err) => err.fmt(f),
             Self::InvalidChecksum => f.write_str("Bad address checksum"),
+            Self::DirtyUpperBytes => f.write_str("non-zero upper bytes in address word"),
+            Self::InvalidIcap => f.write_str("Invalid ICAP address"),
+            Self::InvalidIcapChecksum => f.write_str("Bad ICAP address checksum"),
+            Self::UnsupportedIcap => {
+                f.write_str("Only direct ICAP addresses are supported, not basic or indirect")
+            }
+        }
+    }
+}
+
+/// The algorithm used to checksum an address string.
+///
+/// [EIP-1191] was only adopted by a few chains, such as RSK, and its checksums
+/// are rejected by tools that only know [EIP-55]. Prefer [`Eip55`] unless the
+/// target chain is known to use EIP-1191.
+///
+/// [`Eip55`]: ChecksumVariant::Eip55
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+/// [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ChecksumVariant<'a> {
+    /// The [EIP-55](https://eips.ethereum.org/EIPS/eip-55) checksum.
+    #[default]
+    Eip55,
+    /// The [EIP-1191](https://eips.ethereum.org/EIPS/eip-1191) checksum, which
+    /// also hashes the chain ID.
+    Eip1191 {
+        /// The [EIP-155](https://eips.ethereum.org/EIPS/eip-155) chain ID.
+        chain_id: u64,
+    },
+    /// Accepts the EIP-55 checksum, or the EIP-1191 checksum for any of the
+    /// given chain IDs, tried in order.
+    ///
+    /// This is only meaningful when parsing; addresses are encoded with
+    /// EIP-55.
+    Any {
+        /// The EIP-1191 chain IDs to try after EIP-55.
+        chain_ids: &'a [u64],
+    },
+}
+
+impl From<Option<u64>> for ChecksumVariant<'_> {
+    /// Converts the `chain_id` argument of the checksum methods on [`Address`]:
+    /// `None` is [EIP-55](ChecksumVariant::Eip55), and `Some` is
+    /// [EIP-1191](ChecksumVariant::Eip1191).
+    #[inline]
+    fn from(chain_id: Option<u64>) -> Self {
+        match chain_id {
+            Some(chain_id) => Self::Eip1191 { chain_id },
+            None => Self::Eip55,
+        }
+    }
+}
+
+impl ChecksumVariant<'_> {
+    /// Returns the chain ID used to encode a checksum with this variant.
+    #[inline]
+    const fn encoding_chain_id(self) -> Option<u64> {
+        match self {
+            Self::Eip1191 { chain_id } => Some(chain_id),
+            Self::Eip55 | Self::Any { .. } => None,
         }
     }
 }
@@ -82,7 +162,46 @@ wrap_fixed_bytes!(
     ///
     /// // Format the address without the checksum
     /// assert_eq!(format!("{address:?}"), "0xd8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// assert_eq!(format!("{address:#x}"), address.to_string_lowercase());
+    /// assert_eq!(format!("{address:X}"), "D8DA6BF26964AF9D7EED9E03E53415D37AA96045");
+    ///
+    /// // Parse an address without verifying the checksum, with either prefix
+    /// let uppercase = "0XD8DA6BF26964AF9D7EED9E03E53415D37AA96045";
+    /// assert_eq!(uppercase.parse::<Address>().unwrap(), expected);
     /// ```
+    ///
+    /// Building addresses in `const` contexts:
+    ///
+    /// ```
+    /// use alloy_primitives::{address, Address};
+    ///
+    /// const PRECOMPILES: [Address; 9] = [
+    ///     Address::with_last_byte(0x01), // ecrecover
+    ///     Address::with_last_byte(0x02), // sha256
+    ///     Address::with_last_byte(0x03), // ripemd160
+    ///     Address::with_last_byte(0x04), // identity
+    ///     Address::with_last_byte(0x05), // modexp
+    ///     Address::with_last_byte(0x06), // ecadd
+    ///     Address::with_last_byte(0x07), // ecmul
+    ///     Address::with_last_byte(0x08), // ecpairing
+    ///     Address::with_last_byte(0x09), // blake2f
+    /// ];
+    /// const BURN: Address = Address::repeat_byte(0xff);
+    ///
+    /// assert_eq!(PRECOMPILES[0], address!("0000000000000000000000000000000000000001"));
+    /// assert!(!PRECOMPILES.contains(&Address::ZERO));
+    /// assert_eq!(BURN, address!("ffffffffffffffffffffffffffffffffffffffff"));
+    /// ```
+    #[cfg_attr(
+        feature = "zerocopy",
+        derive(
+            zerocopy::FromBytes,
+            zerocopy::IntoBytes,
+            zerocopy::Immutable,
+            zerocopy::KnownLayout,
+            zerocopy::Unaligned
+        )
+    )]
     pub struct Address<20>;
 );
 
@@ -96,30 +215,142 @@ impl From<U160> for Address {
 impl From<Address> for U160 {
     #[inline]
     fn from(value: Address) -> Self {
-        Self::from_be_bytes(value.0 .0)
+        value.to_u160()
+    }
+}
+
+impl From<&Address> for U160 {
+    #[inline]
+    fn from(value: &Address) -> Self {
+        value.to_u160()
+    }
+}
+
+impl From<Address> for U256 {
+    /// Zero-extends the address. Note that the inherent, generic `U256::from`
+    /// takes precedence over this impl; use `.into()` or
+    /// [`Address::into_u256`] instead.
+    #[inline]
+    fn from(value: Address) -> Self {
+        value.into_u256()
+    }
+}
+
+impl TryFrom<U256> for Address {
+    type Error = AddressError;
+
+    /// Converts the lower 160 bits of the integer, failing if any of the upper
+    /// 96 bits are set.
+    #[inline]
+    fn try_from(value: U256) -> Result<Self, Self::Error> {
+        Self::try_from_word(FixedBytes(value.to_be_bytes()))
+    }
+}
+
+impl TryFrom<FixedBytes<32>> for Address {
+    type Error = AddressError;
+
+    /// See [`Address::try_from_word`].
+    #[inline]
+    fn try_from(word: FixedBytes<32>) -> Result<Self, Self::Error> {
+        Self::try_from_word(word)
     }
 }
 
+/// Checked conversion of an integer into an [`Address`].
+///
+/// This is an [extension trait](crate::aliases#extension-traits) for
+/// [`U256`].
+///
+/// ```
+/// use alloy_primitives::{Address, IntoAddress, U256};
+///
+/// let address = Address::repeat_byte(0x11);
+/// assert_eq!(address.into_u256().checked_into_address(), Some(address));
+/// assert_eq!((U256::from(1) << 160usize).checked_into_address(), None);
+/// ```
+pub trait IntoAddress {
+    /// Converts the integer into an address, returning `None` if it does not
+    /// fit in 160 bits.
+    fn checked_into_address(self) -> Option<Address>;
+}
+
+impl IntoAddress for U256 {
+    #[inline]
+    fn checked_into_address(self) -> Option<Address> {
+        Address::checked_from_word(FixedBytes(self.to_be_bytes()))
+    }
+}
+
+/// Formats the [EIP-55] checksummed address.
+///
+/// A precision keeps only that many digits at each end of the address, and the
+/// alternate flag is a shorthand for a precision of 4: `{:#}` and `{:.4}` both
+/// format as `0xd8dA…6045`. Use [`LowerHex`](fmt::LowerHex) or
+/// [`UpperHex`](fmt::UpperHex) to format the address without the checksum.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
 impl fmt::Display for Address {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut buf = [0; 42];
         let checksum = self.to_checksum_raw(&mut buf, None);
-        if f.alternate() {
-            // If the alternate flag is set, use middle-out compression
-            // "0x" + first 4 bytes + "…" + last 4 bytes
-            f.write_str(&checksum[..6])?;
-            f.write_str("…")?;
-            f.write_str(&checksum[38..])
-        } else {
-            f.write_str(checksum)
-        }
+        let digits = f.precision().or(if f.alternate() { Some(4) } else { None });
+        write_truncated_hex(f, checksum, true, digits)
     }
 }
 
 impl Address {
-    /// Creates an Ethereum address from an EVM word's upper 20 bytes
+    /// The `0x000000000000000000000000000000000000dEaD` burn address.
+    pub const DEAD: Self = address!("000000000000000000000000000000000000dEaD");
+
+    /// Commonly used burn addresses, which nobody holds the private key for:
+    /// - the zero address, [`Address::ZERO`];
+    /// - `0x000000000000000000000000000000000000dEaD`, [`Address::DEAD`];
+    /// - `0xdEAD000000000000000042069420694206942069`.
+    ///
+    /// See [`is_burn`](Address::is_burn).
+    pub const BURN_ADDRESSES: [Self; 3] = [
+        Self::ZERO,
+        Self::DEAD,
+        address!("dEAD000000000000000042069420694206942069"),
+    ];
+
+    /// Returns `true` if this is one of the [`BURN_ADDRESSES`].
+    ///
+    /// Use [`is_zero`](FixedBytes::is_zero) to only check for the zero
+    /// address.
+    ///
+    /// [`BURN_ADDRESSES`]: Address::BURN_ADDRESSES
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// assert!(Address::ZERO.is_burn());
+    /// assert!(address!("000000000000000000000000000000000000dEaD").is_burn());
+    /// assert!(!address!("d8da6bf26964af9d7eed9e03e53415d37aa96045").is_burn());
+    /// ```
+    #[inline]
+    pub const fn is_burn(&self) -> bool {
+        let mut i = 0;
+        while i < Self::BURN_ADDRESSES.len() {
+            if self.const_eq(&Self::BURN_ADDRESSES[i]) {
+                return true
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Creates an Ethereum address from an EVM word's lower 20 bytes
     /// (`word[12..]`).
     ///
+    /// The upper 12 bytes are ignored. Use [`checked_from_word`] or
+    /// [`try_from_word`] to reject words where they are not zero.
+    ///
+    /// [`checked_from_word`]: Address::checked_from_word
+    /// [`try_from_word`]: Address::try_from_word
+    ///
     /// # Examples
     ///
     /// ```
@@ -136,6 +367,52 @@ impl Address {
         Self(FixedBytes(word[12..].try_into().unwrap()))
     }
 
+    /// Creates an Ethereum address from an EVM word's lower 20 bytes, returning
+    /// `None` if any of the upper 12 bytes are not zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, b256, Address};
+    /// let word = b256!("000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// assert_eq!(
+    ///     Address::checked_from_word(word),
+    ///     Some(address!("d8da6bf26964af9d7eed9e03e53415d37aa96045"))
+    /// );
+    ///
+    /// let dirty = b256!("000000000000000000000001d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// assert_eq!(Address::checked_from_word(dirty), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_from_word(word: FixedBytes<32>) -> Option<Self> {
+        if word[..12].iter().all(|&b| b == 0) {
+            Some(Self::from_word(word))
+        } else {
+            None
+        }
+    }
+
+    /// Creates an Ethereum address from an EVM word's lower 20 bytes, failing
+    /// with [`AddressError::DirtyUpperBytes`] if any of the upper 12 bytes are
+    /// not zero.
+    ///
+    /// See [`checked_from_word`](Address::checked_from_word).
+    #[inline]
+    pub fn try_from_word(word: FixedBytes<32>) -> Result<Self, AddressError> {
+        Self::checked_from_word(word).ok_or(AddressError::DirtyUpperBytes)
+    }
+
+    /// Returns a `proptest` strategy for the precompile addresses
+    /// `0x01..=0x0a`.
+    ///
+    /// See the [`strategy`](crate::strategy) module for more strategies.
+    #[cfg(feature = "proptest")]
+    #[inline]
+    pub fn arbitrary_precompile() -> proptest::strategy::SBoxedStrategy<Self> {
+        crate::strategy::precompile_address()
+    }
+
     /// Left-pads the address to 32 bytes (EVM word size).
     ///
     /// # Examples
@@ -155,10 +432,153 @@ impl Address {
         FixedBytes(word)
     }
 
+    /// Converts the address into a [`U256`], zero-extending it.
+    ///
+    /// This is lossless; see [`IntoAddress::checked_into_address`] for the
+    /// inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address, U256};
+    /// assert_eq!(Address::with_last_byte(0x2a).into_u256(), U256::from(0x2a));
+    /// assert_eq!(
+    ///     address!("ffffffffffffffffffffffffffffffffffffffff").into_u256(),
+    ///     (U256::from(1) << 160usize) - U256::from(1),
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_u256(&self) -> U256 {
+        U256::from_be_bytes(self.into_word().0)
+    }
+
+    /// Converts the address into a [`U160`] without consuming it.
+    ///
+    /// Integer order matches the byte order of addresses, so this can be used
+    /// to sort addresses numerically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{Address, U160};
+    /// let address = Address::with_last_byte(0x2a);
+    /// assert_eq!(address.to_u160(), U160::from(0x2a));
+    /// assert_eq!(Address::from(address.to_u160()), address);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_u160(&self) -> U160 {
+        U160::from_be_bytes(self.0 .0)
+    }
+
+    /// Returns the leading `bytes` bytes of the address.
+    ///
+    /// This can be used as a map key to group addresses that share a prefix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is greater than 20.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// use std::collections::HashMap;
+    ///
+    /// let addresses = [
+    ///     address!("00000000219ab540356cBB839Cbe05303d7705Fa"),
+    ///     address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045"),
+    ///     address!("000000000000000000000000000000000000dEaD"),
+    /// ];
+    /// let mut clusters = HashMap::<&[u8], Vec<Address>>::new();
+    /// for address in &addresses {
+    ///     clusters
+    ///         .entry(address.prefix(2))
+    ///         .or_default()
+    ///         .push(*address);
+    /// }
+    /// assert_eq!(clusters[&[0, 0][..]], [addresses[0], addresses[2]]);
+    /// assert_eq!(clusters[&[0xd8, 0xda][..]], [addresses[1]]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn prefix(&self, bytes: usize) -> &[u8] {
+        assert!(
+            bytes <= 20,
+            "address prefix length {bytes} is greater than 20"
+        );
+        &self.0[..bytes]
+    }
+
+    /// Returns the number of leading zero bytes of the address.
+    ///
+    /// Zero bytes are cheaper in calldata, so this can be used to score
+    /// [`create2`](Self::create2) salts when mining a gas-efficient address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// assert_eq!(
+    ///     address!("00000000219ab540356cBB839Cbe05303d7705Fa").leading_zero_bytes(),
+    ///     4
+    /// );
+    /// assert_eq!(
+    ///     address!("000d6e7b3c3c0e5d4b1e8b4c7d3e0f7b8c5a2e90").leading_zero_bytes(),
+    ///     1
+    /// );
+    /// assert_eq!(Address::ZERO.leading_zero_bytes(), 20);
+    /// ```
+    #[inline]
+    pub const fn leading_zero_bytes(&self) -> usize {
+        let mut i = 0;
+        while i < 20 && self.0 .0[i] == 0 {
+            i += 1;
+        }
+        i
+    }
+
+    /// Returns the number of leading zero hex digits of the address.
+    ///
+    /// See [`leading_zero_bytes`](Self::leading_zero_bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// assert_eq!(
+    ///     address!("00000000219ab540356cBB839Cbe05303d7705Fa").leading_zero_nibbles(),
+    ///     8
+    /// );
+    /// assert_eq!(
+    ///     address!("000d6e7b3c3c0e5d4b1e8b4c7d3e0f7b8c5a2e90").leading_zero_nibbles(),
+    ///     3
+    /// );
+    /// assert_eq!(Address::ZERO.leading_zero_nibbles(), 40);
+    /// ```
+    #[inline]
+    pub const fn leading_zero_nibbles(&self) -> usize {
+        let bytes = self.leading_zero_bytes();
+        if bytes == 20 {
+            40
+        } else {
+            bytes * 2 + (self.0 .0[bytes] < 0x10) as usize
+        }
+    }
+
     /// Parse an Ethereum address, verifying its [EIP-55] checksum.
     ///
     /// You can optionally specify an [EIP-155 chain ID] to check the address
-    /// using [EIP-1191].
+    /// using [EIP-1191]. Most chains never adopted EIP-1191, so only pass a
+    /// chain ID if the chain is known to use it; see
+    /// [`parse_checksummed_with`](Self::parse_checksummed_with) to accept
+    /// either checksum.
+    ///
+    /// The `0x` prefix is required, but may also be written as `0X`. The case
+    /// of the hex digits must still match the mixed-case checksum exactly. Use
+    /// [`FromStr`](str::FromStr) to parse an address without verifying its
+    /// checksum.
     ///
     /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
     /// [EIP-155 chain ID]: https://eips.ethereum.org/EIPS/eip-155
@@ -182,21 +602,138 @@ impl Address {
         s: S,
         chain_id: Option<u64>,
     ) -> Result<Self, AddressError> {
-        fn parse_checksummed(s: &str, chain_id: Option<u64>) -> Result<Address, AddressError> {
-            // checksummed addresses always start with the "0x" prefix
-            if !s.starts_with("0x") {
-                return Err(AddressError::Hex(hex::FromHexError::InvalidStringLength))
-            }
+        Self::parse_checksummed_with(s, chain_id.into())
+    }
 
-            let address: Address = s.parse()?;
-            if s == address.to_checksum_raw(&mut [0; 42], chain_id) {
-                Ok(address)
-            } else {
-                Err(AddressError::InvalidChecksum)
+    /// Parses an Ethereum address string, verifying its checksum with the
+    /// given [`ChecksumVariant`].
+    ///
+    /// See [`parse_checksummed`](Self::parse_checksummed) for the accepted
+    /// formats.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the provided string is not a valid hex
+    /// address, or if it does not match any of the accepted checksums.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address, ChecksumVariant};
+    /// let expected = address!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+    /// let eip55 = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+    /// let rsk = "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD";
+    ///
+    /// assert!(Address::parse_checksummed_with(rsk, ChecksumVariant::Eip55).is_err());
+    /// let rsk_mainnet = ChecksumVariant::Eip1191 { chain_id: 30 };
+    /// assert_eq!(
+    ///     Address::parse_checksummed_with(rsk, rsk_mainnet).unwrap(),
+    ///     expected
+    /// );
+    ///
+    /// let any = ChecksumVariant::Any {
+    ///     chain_ids: &[30, 31],
+    /// };
+    /// assert_eq!(
+    ///     Address::parse_checksummed_with(eip55, any).unwrap(),
+    ///     expected
+    /// );
+    /// assert_eq!(Address::parse_checksummed_with(rsk, any).unwrap(), expected);
+    /// ```
+    pub fn parse_checksummed_with<S: AsRef<str>>(
+        s: S,
+        variant: ChecksumVariant<'_>,
+    ) -> Result<Self, AddressError> {
+        let s = s.as_ref();
+        match variant {
+            ChecksumVariant::Eip55 => ChecksumInput::new(None).parse(s),
+            ChecksumVariant::Eip1191 { chain_id } => ChecksumInput::new(Some(chain_id)).parse(s),
+            ChecksumVariant::Any { chain_ids } => {
+                let mut result = ChecksumInput::new(None).parse(s);
+                let mut chain_ids = chain_ids.iter();
+                while let (Err(AddressError::InvalidChecksum), Some(&chain_id)) =
+                    (&result, chain_ids.next())
+                {
+                    result = ChecksumInput::new(Some(chain_id)).parse(s);
+                }
+                result
             }
         }
+    }
 
-        parse_checksummed(s.as_ref(), chain_id)
+    /// Validates the [EIP-55] checksum of an Ethereum address string without
+    /// returning the parsed address.
+    ///
+    /// You can optionally specify an [EIP-155 chain ID] to check the address
+    /// using [EIP-1191].
+    ///
+    /// This accepts exactly the same inputs as
+    /// [`parse_checksummed`](Self::parse_checksummed). The case of each hex
+    /// digit is compared directly against the hash of the lowercase address,
+    /// so the checksummed string is never built.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    /// [EIP-155 chain ID]: https://eips.ethereum.org/EIPS/eip-155
+    /// [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the provided string is not a valid hex
+    /// address, or if it does not match the expected checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{Address, AddressError};
+    /// Address::validate_checksum("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045", None).unwrap();
+    /// Address::validate_checksum("0xD8Da6bf26964Af9d7EEd9e03e53415d37AA96045", Some(1)).unwrap();
+    /// assert!(matches!(
+    ///     Address::validate_checksum("0xd8da6bf26964af9d7eed9e03e53415d37aa96045", None),
+    ///     Err(AddressError::InvalidChecksum)
+    /// ));
+    /// ```
+    #[inline]
+    pub fn validate_checksum(s: &str, chain_id: Option<u64>) -> Result<(), AddressError> {
+        ChecksumInput::new(chain_id).parse(s).map(drop)
+    }
+
+    /// Parses many Ethereum addresses, verifying their [EIP-55] checksums.
+    ///
+    /// This is equivalent to calling
+    /// [`parse_checksummed`](Self::parse_checksummed) on each input, but the
+    /// hashing buffer and the [EIP-1191] chain ID prefix are only set up once.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    /// [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// let inputs = [
+    ///     "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+    ///     "0xd8da6bf26964af9d7eed9e03e53415d37aa96045",
+    /// ];
+    /// let results = Address::parse_checksummed_batch(inputs, None);
+    /// assert_eq!(
+    ///     results[0].unwrap(),
+    ///     address!("d8da6bf26964af9d7eed9e03e53415d37aa96045")
+    /// );
+    /// assert!(results[1].is_err());
+    /// ```
+    pub fn parse_checksummed_batch<I>(
+        inputs: I,
+        chain_id: Option<u64>,
+    ) -> Vec<Result<Self, AddressError>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut input = ChecksumInput::new(chain_id);
+        inputs
+            .into_iter()
+            .map(|s| input.parse(s.as_ref()))
+            .collect()
     }
 
     /// Encodes an Ethereum address to its [EIP-55] checksum.
@@ -278,7 +815,10 @@ impl Address {
     /// Encodes an Ethereum address to its [EIP-55] checksum.
     ///
     /// You can optionally specify an [EIP-155 chain ID] to encode the address
-    /// using [EIP-1191].
+    /// using [EIP-1191]. Most chains never adopted EIP-1191, and tools that do
+    /// not know it reject its checksums, so only pass a chain ID if the chain
+    /// is known to use it. This is a shorthand for
+    /// [`to_checksum_with`](Self::to_checksum_with).
     ///
     /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
     /// [EIP-155 chain ID]: https://eips.ethereum.org/EIPS/eip-155
@@ -299,33 +839,251 @@ impl Address {
     #[inline]
     #[must_use]
     pub fn to_checksum(&self, chain_id: Option<u64>) -> String {
-        self.to_checksum_raw(&mut [0u8; 42], chain_id).to_string()
+        self.to_checksum_with(chain_id.into())
+    }
+
+    /// Encodes an Ethereum address to its checksum with the given
+    /// [`ChecksumVariant`].
+    ///
+    /// [`ChecksumVariant::Any`] encodes the [EIP-55] checksum.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, ChecksumVariant};
+    /// let address = address!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+    ///
+    /// let checksummed = address.to_checksum_with(ChecksumVariant::Eip55);
+    /// assert_eq!(checksummed, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+    ///
+    /// let checksummed = address.to_checksum_with(ChecksumVariant::Eip1191 { chain_id: 30 });
+    /// assert_eq!(checksummed, "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_checksum_with(&self, variant: ChecksumVariant<'_>) -> String {
+        let mut out = String::with_capacity(42);
+        self.write_checksum(&mut out, variant.encoding_chain_id());
+        out
+    }
+
+    /// Formats the address as lowercase hex with the `0x` prefix, without
+    /// the checksum.
+    ///
+    /// This is the same as `format!("{self:#x}")`, and is useful where
+    /// addresses are compared case-sensitively, such as in some JSON-RPC
+    /// servers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::address;
+    /// let address = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+    /// assert_eq!(
+    ///     address.to_string_lowercase(),
+    ///     "0xd8da6bf26964af9d7eed9e03e53415d37aa96045"
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_string_lowercase(&self) -> String {
+        hex::encode_prefixed(self)
+    }
+
+    /// Encodes an Ethereum address to its [EIP-55] checksum into the given
+    /// string, clearing it first.
+    ///
+    /// This reuses the capacity of `out`, so it does not allocate when called
+    /// repeatedly with the same buffer. See [`to_checksum`](Self::to_checksum)
+    /// for more details.
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// let mut out = String::new();
+    ///
+    /// address.write_checksum(&mut out, None);
+    /// assert_eq!(out, "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+    ///
+    /// address.write_checksum(&mut out, Some(1));
+    /// assert_eq!(out, "0xD8Da6bf26964Af9d7EEd9e03e53415d37AA96045");
+    /// ```
+    #[inline]
+    pub fn write_checksum(&self, out: &mut String, chain_id: Option<u64>) {
+        out.clear();
+        out.push_str(self.to_checksum_raw(&mut [0u8; 42], chain_id));
+    }
+
+    /// Parses a direct [ICAP] address: the country code `XE`, two ISO 13616
+    /// check digits, and the address encoded in 30 base-36 digits.
+    ///
+    /// The basic (31 digits) and indirect (`XE..ETH...`) formats are rejected
+    /// with [`AddressError::UnsupportedIcap`].
+    ///
+    /// [ICAP]: https://github.com/ethereum/wiki/wiki/Inter-exchange-Client-Address-Protocol-(ICAP)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// let address = Address::from_icap("XE7338O073KYGTWWZN0F2WZ0R8PX5ZPPZS")?;
+    /// assert_eq!(
+    ///     address,
+    ///     address!("00c5496aee77c1ba1f0854206a26dda82a81d6d8")
+    /// );
+    /// # Ok::<(), alloy_primitives::AddressError>(())
+    /// ```
+    pub fn from_icap(s: &str) -> Result<Self, AddressError> {
+        let s = s.as_bytes();
+        if s.len() < 4 || !s[..2].eq_ignore_ascii_case(b"XE") {
+            return Err(AddressError::InvalidIcap)
+        }
+        if !s[2..4].iter().all(u8::is_ascii_digit) || !s.iter().all(u8::is_ascii_alphanumeric) {
+            return Err(AddressError::InvalidIcap)
+        }
+        let bban = &s[4..];
+        match bban.len() {
+            30 => {}
+            31 | 16 => return Err(AddressError::UnsupportedIcap),
+            _ => return Err(AddressError::InvalidIcap),
+        }
+
+        // ISO 13616: the BBAN followed by the country code and check digits
+        // must be 1 modulo 97
+        if icap_mod97(bban.iter().chain(&s[..4])) != 1 {
+            return Err(AddressError::InvalidIcapChecksum)
+        }
+
+        // 36^30 < 2^160, so this can not overflow
+        let base = U160::from(36);
+        let value = bban.iter().fold(U160::ZERO, |acc, &c| {
+            acc * base + U160::from(base36_digit(c))
+        });
+        Ok(value.into())
+    }
+
+    /// Encodes the address as a direct [ICAP] address, or returns `None` if it
+    /// is too large for the direct format, which only fits addresses below
+    /// `36^30` (`0x088f924eeceeda7fe92e1f5b1000000000000000`).
+    ///
+    /// See [`from_icap`](Self::from_icap) for more details.
+    ///
+    /// [ICAP]: https://github.com/ethereum/wiki/wiki/Inter-exchange-Client-Address-Protocol-(ICAP)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// let address = address!("00c5496aee77c1ba1f0854206a26dda82a81d6d8");
+    /// assert_eq!(
+    ///     address.to_icap().unwrap(),
+    ///     "XE7338O073KYGTWWZN0F2WZ0R8PX5ZPPZS"
+    /// );
+    ///
+    /// let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// assert_eq!(address.to_icap(), None);
+    /// ```
+    pub fn to_icap(&self) -> Option<String> {
+        const DIGITS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+        let mut value = U160::from_be_bytes(self.0 .0);
+        let base = U160::from(36);
+        let mut bban = [b'0'; 30];
+        for c in bban.iter_mut().rev() {
+            let (div, rem) = value.div_rem(base);
+            *c = DIGITS[rem.to::<usize>()];
+            value = div;
+        }
+        if value != U160::ZERO {
+            return None
+        }
+
+        let check = 98 - icap_mod97(bban.iter().chain(b"XE00"));
+        let mut out = String::with_capacity(34);
+        out.push_str("XE");
+        out.push(char::from(b'0' + check / 10));
+        out.push(char::from(b'0' + check % 10));
+        // SAFETY: the BBAN only contains ASCII digits and letters
+        out.push_str(unsafe { str::from_utf8_unchecked(&bban) });
+        Some(out)
+    }
+
+    /// Computes the `create` address for this address and nonce:
+    ///
+    /// `keccak256(rlp([sender, nonce]))[12:]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address};
+    /// let sender = address!("b20a608c624Ca5003905aA834De7156C68b2E1d0");
+    ///
+    /// let expected = address!("00000000219ab540356cBB839Cbe05303d7705Fa");
+    /// assert_eq!(sender.create(0), expected);
+    ///
+    /// let expected = address!("e33c6e89e69d085897f98e92b06ebd541d1daa99");
+    /// assert_eq!(sender.create(1), expected);
+    /// ```
+    #[cfg(feature = "rlp")]
+    #[inline]
+    #[must_use]
+    pub fn create(&self, nonce: u64) -> Self {
+        use alloy_rlp::{Encodable, EMPTY_LIST_CODE, EMPTY_STRING_CODE};
+
+        // max u64 encoded length is `1 + u64::BYTES`
+        const MAX_LEN: usize = 1 + (1 + 20) + 9;
+
+        let len = 22 + nonce.length();
+        debug_assert!(len <= MAX_LEN);
+
+        let mut out = [0u8; MAX_LEN];
+
+        // list header
+        // minus 1 to account for the list header itself
+        out[0] = EMPTY_LIST_CODE + len as u8 - 1;
+
+        // address header + address
+        out[1] = EMPTY_STRING_CODE + 20;
+        out[2..22].copy_from_slice(self.as_slice());
+
+        // nonce
+        nonce.encode(&mut &mut out[22..]);
+
+        let hash = keccak256(&out[..len]);
+        Self::from_word(hash)
     }
 
-    /// Computes the `create` address for this address and nonce:
+    /// Computes the `create` address for this address and a [`U256`] nonce.
     ///
-    /// `keccak256(rlp([sender, nonce]))[12:]`
+    /// This is the same as [`create`](Self::create), but accepts nonces that
+    /// do not fit in a `u64`, such as the ones used by some L2s and test
+    /// environments.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use alloy_primitives::{address, Address};
+    /// # use alloy_primitives::{address, Address, U256};
     /// let sender = address!("b20a608c624Ca5003905aA834De7156C68b2E1d0");
+    /// assert_eq!(sender.create_raw(U256::from(1)), sender.create(1));
     ///
-    /// let expected = address!("00000000219ab540356cBB839Cbe05303d7705Fa");
-    /// assert_eq!(sender.create(0), expected);
-    ///
-    /// let expected = address!("e33c6e89e69d085897f98e92b06ebd541d1daa99");
-    /// assert_eq!(sender.create(1), expected);
+    /// let big = U256::from(u64::MAX) + U256::from(1);
+    /// assert_ne!(sender.create_raw(big), sender.create(u64::MAX));
     /// ```
     #[cfg(feature = "rlp")]
     #[inline]
     #[must_use]
-    pub fn create(&self, nonce: u64) -> Self {
+    pub fn create_raw(&self, nonce: U256) -> Self {
         use alloy_rlp::{Encodable, EMPTY_LIST_CODE, EMPTY_STRING_CODE};
 
-        // max u64 encoded length is `1 + u64::BYTES`
-        const MAX_LEN: usize = 1 + (1 + 20) + 9;
+        // max U256 encoded length is `1 + U256::BYTES`, which keeps the list
+        // payload under 56 bytes so the header is always a single byte
+        const MAX_LEN: usize = 1 + (1 + 20) + 33;
 
         let len = 22 + nonce.length();
         debug_assert!(len <= MAX_LEN);
@@ -421,12 +1179,124 @@ impl Address {
         let hash = keccak256(bytes);
         Self::from_word(hash)
     }
+
+    /// The prefix of an [EIP-7702] delegation designator.
+    ///
+    /// [EIP-7702]: https://eips.ethereum.org/EIPS/eip-7702
+    pub const EIP7702_DELEGATION_PREFIX: [u8; 3] = [0xef, 0x01, 0x00];
+
+    /// Parses an [EIP-7702] delegation designator, returning the address that
+    /// the account's code is delegated to.
+    ///
+    /// A delegation designator is exactly 23 bytes long: the
+    /// [`EIP7702_DELEGATION_PREFIX`](Address::EIP7702_DELEGATION_PREFIX)
+    /// `0xef0100` followed by the delegated address. Returns `None` if `code`
+    /// is not a delegation designator.
+    ///
+    /// [EIP-7702]: https://eips.ethereum.org/EIPS/eip-7702
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, hex, Address};
+    /// let code = hex!("ef0100d8da6bf26964af9d7eed9e03e53415d37aa96045");
+    /// assert_eq!(
+    ///     Address::parse_7702_delegation(&code),
+    ///     Some(address!("d8da6bf26964af9d7eed9e03e53415d37aa96045"))
+    /// );
+    ///
+    /// assert_eq!(Address::parse_7702_delegation(&hex!("6080604052")), None);
+    /// ```
+    #[inline]
+    pub fn parse_7702_delegation(code: &[u8]) -> Option<Self> {
+        code.strip_prefix(&Self::EIP7702_DELEGATION_PREFIX)
+            .and_then(|address| Self::try_from(address).ok())
+    }
+}
+
+/// Returns the value of a base-36 ICAP digit.
+#[inline]
+const fn base36_digit(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        _ => c.to_ascii_uppercase() - b'A' + 10,
+    }
+}
+
+/// Computes the ISO 13616 remainder of the given alphanumeric characters
+/// modulo 97, where letters are expanded to the two-digit numbers `10..=35`.
+fn icap_mod97<'a>(chars: impl Iterator<Item = &'a u8>) -> u8 {
+    chars.fold(0u32, |rem, &c| {
+        let digit = base36_digit(c) as u32;
+        let shift = if digit < 10 { 10 } else { 100 };
+        (rem * shift + digit) % 97
+    }) as u8
+}
+
+/// The keccak256 input used to verify a checksummed address: the optional
+/// [EIP-1191] chain ID prefix followed by the lowercase hex address.
+///
+/// [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+struct ChecksumInput {
+    // A decimal `u64` string is at most 20 bytes long
+    storage: [u8; 20 + 2 + 40],
+    prefix_len: usize,
+}
+
+impl ChecksumInput {
+    fn new(chain_id: Option<u64>) -> Self {
+        let mut storage = [0u8; 20 + 2 + 40];
+        let prefix_len = match chain_id {
+            Some(chain_id) => {
+                let mut temp = itoa::Buffer::new();
+                let chain_id = temp.format(chain_id).as_bytes();
+                storage[..chain_id.len()].copy_from_slice(chain_id);
+                storage[chain_id.len()..chain_id.len() + 2].copy_from_slice(b"0x");
+                chain_id.len() + 2
+            }
+            None => 0,
+        };
+        Self {
+            storage,
+            prefix_len,
+        }
+    }
+
+    fn parse(&mut self, s: &str) -> Result<Address, AddressError> {
+        // checksummed addresses always start with the "0x" prefix
+        let hex = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) if hex.len() == 40 => hex.as_bytes(),
+            _ => return Err(AddressError::Hex(hex::FromHexError::InvalidStringLength)),
+        };
+
+        let mut address = Address::ZERO;
+        hex::decode_to_slice(hex, address.as_mut_slice())?;
+
+        let len = self.prefix_len + 40;
+        for (lower, c) in self.storage[self.prefix_len..len].iter_mut().zip(hex) {
+            *lower = c.to_ascii_lowercase();
+        }
+        let hash = keccak256(&self.storage[..len]);
+
+        for (i, c) in hex.iter().enumerate() {
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0xf
+            };
+            if c.is_ascii_alphabetic() && c.is_ascii_uppercase() != (nibble >= 8) {
+                return Err(AddressError::InvalidChecksum)
+            }
+        }
+        Ok(address)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::hex;
+    use core::mem;
 
     #[test]
     fn parse() {
@@ -446,6 +1316,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_uppercase_prefix() {
+        let expected = hex!("52908400098527886e0f7030069857d2e4169ee7");
+        let uppercase = "0X52908400098527886E0F7030069857D2E4169EE7";
+        assert_eq!(uppercase.parse::<Address>().unwrap(), expected);
+
+        // the prefix is case-insensitive, the checksum is not
+        assert_eq!(
+            Address::parse_checksummed(uppercase, None).unwrap(),
+            expected
+        );
+        assert!(matches!(
+            Address::parse_checksummed("0XD8DA6BF26964AF9D7EED9E03E53415D37AA96045", None),
+            Err(AddressError::InvalidChecksum)
+        ));
+    }
+
+    #[test]
+    fn from_word_dirty_bytes() {
+        let address = Address::from(hex!("d8da6bf26964af9d7eed9e03e53415d37aa96045"));
+        let word = address.into_word();
+        assert_eq!(Address::checked_from_word(word), Some(address));
+        assert_eq!(Address::try_from_word(word).unwrap(), address);
+        assert_eq!(Address::try_from(word).unwrap(), address);
+
+        let mut dirty = word;
+        dirty[0] = 1;
+        assert_eq!(Address::from_word(dirty), address);
+        assert_eq!(Address::checked_from_word(dirty), None);
+        assert!(matches!(
+            Address::try_from_word(dirty),
+            Err(AddressError::DirtyUpperBytes)
+        ));
+
+        let value = U256::from_be_bytes(word.0);
+        assert_eq!(Address::try_from(value).unwrap(), address);
+        assert_eq!(
+            Address::try_from(U256::from(U160::MAX)).unwrap(),
+            Address::repeat_byte(0xff)
+        );
+        assert!(matches!(
+            Address::try_from(U256::from(1) << 160),
+            Err(AddressError::DirtyUpperBytes)
+        ));
+    }
+
     // https://eips.ethereum.org/EIPS/eip-55
     #[test]
     fn checksum() {
@@ -530,6 +1446,346 @@ mod tests {
         }
     }
 
+    #[test]
+    fn checksum_variant() {
+        let rsk_mainnet = ChecksumVariant::Eip1191 { chain_id: 30 };
+        let rsk_testnet = ChecksumVariant::Eip1191 { chain_id: 31 };
+        // (EIP-55, RSK mainnet, RSK testnet), all disagreeing
+        let cases = [
+            (
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+                "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD",
+                "0x5aAeb6053F3e94c9b9A09F33669435E7EF1BEaEd",
+            ),
+            (
+                "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+                "0xFb6916095cA1Df60bb79ce92cE3EA74c37c5d359",
+                "0xFb6916095CA1dF60bb79CE92ce3Ea74C37c5D359",
+            ),
+        ];
+        for (eip55, mainnet, testnet) in cases {
+            let address: Address = eip55.parse().unwrap();
+            assert_eq!(address.to_checksum_with(ChecksumVariant::Eip55), eip55);
+            assert_eq!(address.to_checksum_with(rsk_mainnet), mainnet);
+            assert_eq!(address.to_checksum_with(rsk_testnet), testnet);
+            let any = ChecksumVariant::Any {
+                chain_ids: &[30, 31],
+            };
+            assert_eq!(address.to_checksum_with(any), eip55);
+
+            // the existing methods are equivalent
+            assert_eq!(address.to_checksum(None), eip55);
+            assert_eq!(address.to_checksum(Some(30)), mainnet);
+
+            for (s, variant) in [(eip55, ChecksumVariant::Eip55), (mainnet, rsk_mainnet)] {
+                assert_eq!(
+                    Address::parse_checksummed_with(s, variant).unwrap(),
+                    address
+                );
+            }
+            for (s, variant) in [
+                (mainnet, ChecksumVariant::Eip55),
+                (eip55, rsk_mainnet),
+                (testnet, rsk_mainnet),
+            ] {
+                assert!(matches!(
+                    Address::parse_checksummed_with(s, variant),
+                    Err(AddressError::InvalidChecksum)
+                ));
+            }
+
+            for s in [eip55, mainnet, testnet] {
+                assert_eq!(Address::parse_checksummed_with(s, any).unwrap(), address);
+            }
+            let eip55_only = ChecksumVariant::Any { chain_ids: &[] };
+            assert_eq!(
+                Address::parse_checksummed_with(eip55, eip55_only).unwrap(),
+                address
+            );
+            let mainnet_only = ChecksumVariant::Any { chain_ids: &[30] };
+            assert!(matches!(
+                Address::parse_checksummed_with(testnet, mainnet_only),
+                Err(AddressError::InvalidChecksum)
+            ));
+        }
+
+        assert!(matches!(
+            Address::parse_checksummed_with("0x5aAeb6", ChecksumVariant::Any { chain_ids: &[30] }),
+            Err(AddressError::Hex(_))
+        ));
+        assert_eq!(ChecksumVariant::from(None), ChecksumVariant::Eip55);
+        assert_eq!(ChecksumVariant::from(Some(30)), rsk_mainnet);
+    }
+
+    #[test]
+    fn validate_checksum_matches_to_checksum() {
+        // the previous implementation of `parse_checksummed`
+        fn validate_slow(s: &str, chain_id: Option<u64>) -> bool {
+            let Some(hex) = s.strip_prefix("0x") else {
+                return false
+            };
+            match hex.parse::<Address>() {
+                Ok(address) => hex == &address.to_checksum(chain_id)[2..],
+                Err(_) => false,
+            }
+        }
+
+        let addresses = [
+            "0x52908400098527886E0F7030069857D2E4169EE7",
+            "0xde709f2102306220921060314715629080e2fb77",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0x27b1FdB04752BBc536007A920D24ACB045561c26",
+            "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD",
+            "0x27B1FdB04752BbC536007a920D24acB045561C26",
+            "0xd1220a0CF47c7B9Be7A2E6Ba89f429762E7b9adB",
+        ];
+        for chain_id in [None, Some(30), Some(31), Some(u64::MAX)] {
+            for addr in addresses {
+                let expected = validate_slow(addr, chain_id);
+                assert_eq!(
+                    Address::validate_checksum(addr, chain_id).is_ok(),
+                    expected,
+                    "{addr} {chain_id:?}"
+                );
+
+                // flipping the case of any letter always invalidates the checksum
+                let mut flipped = String::from(addr);
+                let i = addr[2..].find(|c: char| c.is_ascii_alphabetic()).unwrap() + 2;
+                let c = addr.as_bytes()[i] ^ 0x20;
+                flipped.replace_range(i..=i, str::from_utf8(&[c]).unwrap());
+                if expected {
+                    assert!(matches!(
+                        Address::validate_checksum(&flipped, chain_id),
+                        Err(AddressError::InvalidChecksum)
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn validate_checksum_errors() {
+        let valid = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        assert!(Address::validate_checksum(valid, None).is_ok());
+        for invalid in [
+            &valid[2..],
+            &valid[..41],
+            "0x0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAedff",
+        ] {
+            assert!(matches!(
+                Address::validate_checksum(invalid, None),
+                Err(AddressError::Hex(hex::FromHexError::InvalidStringLength))
+            ));
+        }
+        assert!(matches!(
+            Address::validate_checksum("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeg", None),
+            Err(AddressError::Hex(hex::FromHexError::InvalidHexCharacter {
+                c: 'g',
+                index: 39
+            }))
+        ));
+    }
+
+    #[test]
+    fn parse_checksummed_batch() {
+        let inputs = [
+            "0x27b1FdB04752BBc536007A920D24ACB045561c26",
+            "0x27b1fdb04752bbc536007a920d24acb045561c26",
+            "0x3599689E6292B81B2D85451025146515070129Bb",
+            "not an address",
+        ];
+        let results = Address::parse_checksummed_batch(inputs, Some(30));
+        assert_eq!(results.len(), inputs.len());
+        for (input, result) in inputs.iter().zip(&results) {
+            let expected = Address::parse_checksummed(input, Some(30));
+            assert_eq!(result.ok(), expected.ok());
+        }
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(AddressError::InvalidChecksum)));
+        assert!(results[2].is_ok());
+        assert!(matches!(results[3], Err(AddressError::Hex(_))));
+    }
+
+    #[test]
+    fn write_checksum_reuses_buffer() {
+        let addresses = [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        ];
+        let mut out = String::with_capacity(64);
+        let ptr = out.as_ptr();
+        for addr in addresses {
+            let parsed: Address = addr.parse().unwrap();
+            parsed.write_checksum(&mut out, None);
+            assert_eq!(out, addr);
+            assert_eq!(out.as_ptr(), ptr);
+            assert_eq!(out.capacity(), 64);
+        }
+    }
+
+    #[test]
+    fn sentinels() {
+        assert!(Address::ZERO.is_zero());
+        assert!(Address::ZERO.is_burn());
+        assert!(Address::DEAD.is_burn());
+        assert!(!Address::DEAD.is_zero());
+        assert_eq!(
+            Address::DEAD,
+            Address::from(hex!("000000000000000000000000000000000000dead"))
+        );
+        for address in Address::BURN_ADDRESSES {
+            assert!(address.is_burn(), "{address}");
+        }
+
+        for i in 0..20 {
+            let mut address = Address::ZERO;
+            address[i] = 1;
+            assert!(!address.is_zero(), "{address}");
+            assert!(!address.is_burn(), "{address}");
+        }
+        assert!(!Address::repeat_byte(0xff).is_burn());
+        assert!(!Address::with_last_byte(0xad).is_burn());
+
+        const _: () = assert!(Address::DEAD.is_burn() && !Address::repeat_byte(1).is_burn());
+    }
+
+    #[test]
+    fn leading_zeros() {
+        let cases = [
+            (Address::ZERO, 20, 40),
+            (Address::with_last_byte(1), 19, 39),
+            (Address::with_last_byte(0x10), 19, 38),
+            (Address::repeat_byte(0x01), 0, 1),
+            (Address::repeat_byte(0xff), 0, 0),
+            (address!("000000000000000000000000000000000000dEaD"), 18, 36),
+            (address!("0000000000000000000000000000000000000001"), 19, 39),
+            (address!("00000000000000000000000000000000000f0000"), 17, 35),
+        ];
+        for (address, bytes, nibbles) in cases {
+            assert_eq!(address.leading_zero_bytes(), bytes, "{address}");
+            assert_eq!(address.leading_zero_nibbles(), nibbles, "{address}");
+        }
+
+        // scoring `create2` salts
+        let deployer = address!("4e59b44847b379578588920ca78fbf26c0b4956c");
+        let init_code_hash = FixedBytes::<32>::ZERO;
+        let best = (0u8..=255)
+            .map(|i| deployer.create2(FixedBytes::<32>::with_last_byte(i), init_code_hash))
+            .max_by_key(Address::leading_zero_nibbles)
+            .unwrap();
+        assert!(best.leading_zero_nibbles() >= 1, "{best}");
+    }
+
+    #[test]
+    fn prefix() {
+        let addresses = [
+            address!("00000000219ab540356cBB839Cbe05303d7705Fa"),
+            address!("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            address!("000000000000000000000000000000000000dEaD"),
+            address!("5a52E96BAcdaBb82fd05763E25335261B270Efcb"),
+            address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045"),
+        ];
+        let mut clusters = alloc::collections::BTreeMap::<&[u8], Vec<Address>>::new();
+        for address in &addresses {
+            clusters
+                .entry(address.prefix(1))
+                .or_default()
+                .push(*address);
+        }
+        assert_eq!(clusters.len(), 3);
+        assert_eq!(clusters[&[0x00][..]], [addresses[0], addresses[2]]);
+        assert_eq!(clusters[&[0x5a][..]], [addresses[1], addresses[3]]);
+        assert_eq!(clusters[&[0xd8][..]], [addresses[4]]);
+
+        assert!(addresses[0].prefix(0).is_empty());
+        assert_eq!(addresses[0].prefix(20), addresses[0].as_slice());
+    }
+
+    #[test]
+    #[should_panic]
+    fn prefix_out_of_bounds() {
+        let _ = Address::ZERO.prefix(21);
+    }
+
+    #[test]
+    fn icap() {
+        let cases = [
+            (Address::ZERO, "XE50000000000000000000000000000000"),
+            (
+                Address::with_last_byte(1),
+                "XE23000000000000000000000000000001",
+            ),
+            (
+                address!("00c5496aee77c1ba1f0854206a26dda82a81d6d8"),
+                "XE7338O073KYGTWWZN0F2WZ0R8PX5ZPPZS",
+            ),
+            (
+                address!("00000000219ab540356cBB839Cbe05303d7705Fa"),
+                "XE28000001ZMC4QTJE4SDALRUP5ZHW988Q",
+            ),
+            (
+                address!("088f924eeceeda7fe92e1f5b0fffffffffffffff"),
+                "XE43ZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZ",
+            ),
+        ];
+        for (address, icap) in cases {
+            assert_eq!(address.to_icap().as_deref(), Some(icap));
+            assert_eq!(Address::from_icap(icap).unwrap(), address);
+            assert_eq!(Address::from_icap(&icap.to_lowercase()).unwrap(), address);
+        }
+
+        assert_eq!(
+            address!("088f924eeceeda7fe92e1f5b1000000000000000").to_icap(),
+            None
+        );
+        assert_eq!(Address::repeat_byte(0xff).to_icap(), None);
+    }
+
+    #[test]
+    fn icap_errors() {
+        let cases = [
+            ("", AddressError::InvalidIcap),
+            (
+                "XE7338O073KYGTWWZN0F2WZ0R8PX5ZPPZ",
+                AddressError::InvalidIcap,
+            ),
+            (
+                "DE7338O073KYGTWWZN0F2WZ0R8PX5ZPPZS",
+                AddressError::InvalidIcap,
+            ),
+            (
+                "XEAA38O073KYGTWWZN0F2WZ0R8PX5ZPPZS",
+                AddressError::InvalidIcap,
+            ),
+            (
+                "XE7338O073KYGTWWZN0F2WZ0R8PX5ZPP-S",
+                AddressError::InvalidIcap,
+            ),
+            (
+                "XE7438O073KYGTWWZN0F2WZ0R8PX5ZPPZS",
+                AddressError::InvalidIcapChecksum,
+            ),
+            (
+                "XE7338O073KYGTWWZN0F2WZ0R8PX5ZPPZT",
+                AddressError::InvalidIcapChecksum,
+            ),
+            // basic
+            (
+                "XE65GB6LDNXYOFTX0NSV3FUWKOWIXAMJK36",
+                AddressError::UnsupportedIcap,
+            ),
+            // indirect
+            ("XE81ETHXREGGAVOFYORK", AddressError::UnsupportedIcap),
+        ];
+        for (icap, err) in cases {
+            let res = Address::from_icap(icap).unwrap_err();
+            assert_eq!(mem::discriminant(&res), mem::discriminant(&err), "{icap}");
+        }
+    }
+
     // https://ethereum.stackexchange.com/questions/760/how-is-the-address-of-an-ethereum-contract-computed
     #[test]
     #[cfg(feature = "rlp")]
@@ -552,7 +1808,95 @@ mod tests {
     }
 
     #[test]
-    #[cfg(all(feature = "rlp", feature = "arbitrary"))]
+    fn fmt() {
+        use alloc::string::ToString;
+
+        let address = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+
+        // `Display` is checksummed
+        assert_eq!(
+            format!("{address}"),
+            "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"
+        );
+        assert_eq!(format!("{address:#}"), "0xd8dA…6045");
+        assert_eq!(format!("{address:.4}"), "0xd8dA…6045");
+        assert_eq!(format!("{address:.2}"), "0xd8…45");
+        assert_eq!(format!("{address:#.6}"), "0xd8dA6B…A96045");
+        assert_eq!(format!("{address:.20}"), address.to_string());
+        assert_eq!(format!("{address:#.20}"), address.to_string());
+
+        // hex is not checksummed, and `#` adds the prefix
+        assert_eq!(
+            format!("{address:x}"),
+            "d8da6bf26964af9d7eed9e03e53415d37aa96045"
+        );
+        assert_eq!(format!("{address:#x}"), address.to_string_lowercase());
+        assert_eq!(
+            format!("{address:#X}"),
+            "0xD8DA6BF26964AF9D7EED9E03E53415D37AA96045"
+        );
+        assert_eq!(format!("{address:.4x}"), "d8da…6045");
+        assert_eq!(format!("{address:#.4x}"), "0xd8da…6045");
+        assert_eq!(format!("{address:#.4X}"), "0xD8DA…6045");
+        assert_eq!(format!("{address:?}"), address.to_string_lowercase());
+    }
+
+    #[test]
+    fn u256() {
+        let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        let value = address.into_u256();
+        assert_eq!(value, U256::from_be_slice(address.as_slice()));
+        assert_eq!(<U256 as From<Address>>::from(address), value);
+        assert_eq!(value.checked_into_address(), Some(address));
+
+        assert_eq!(U256::ZERO.checked_into_address(), Some(Address::ZERO));
+        let max = (U256::from(1) << 160usize) - U256::from(1);
+        assert_eq!(max.checked_into_address(), Some(Address::repeat_byte(0xff)));
+        assert_eq!((max + U256::from(1)).checked_into_address(), None);
+        assert_eq!(U256::MAX.checked_into_address(), None);
+    }
+
+    #[test]
+    fn u160() {
+        let address = address!("d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        let value = address.to_u160();
+        assert_eq!(value, U160::from_be_slice(address.as_slice()));
+        assert_eq!(<U160 as From<&Address>>::from(&address), value);
+        assert_eq!(<U160 as From<Address>>::from(address), value);
+        assert_eq!(Address::from(value), address);
+        assert_eq!(Address::from(&address.0 .0), address);
+        assert_eq!(Address::repeat_byte(0xff).to_u160(), U160::MAX);
+
+        let mut addresses = [Address::repeat_byte(0x11), Address::ZERO, address];
+        addresses.sort_by_key(Address::to_u160);
+        assert_eq!(
+            addresses,
+            [Address::ZERO, Address::repeat_byte(0x11), address]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "proptest")]
+    fn u256_roundtrip() {
+        use crate::strategy::{self, ByteDistribution};
+
+        proptest::proptest!(|(address in strategy::address(ByteDistribution::Edge))| {
+            proptest::prop_assert_eq!(address.into_u256().checked_into_address(), Some(address));
+        });
+
+        proptest::proptest!(|(value: U256)| {
+            let fits = value < U256::from(1) << 160usize;
+            proptest::prop_assert_eq!(value.checked_into_address().is_some(), fits);
+            proptest::prop_assert_eq!(Address::try_from(value).is_ok(), fits);
+        });
+        proptest::proptest!(|(low: Address, high in 1..=u64::MAX)| {
+            let value = low.into_u256() | U256::from(high) << 160usize;
+            proptest::prop_assert_eq!(value.checked_into_address(), None);
+        });
+    }
+
+    #[test]
+    #[cfg(all(feature = "rlp", feature = "proptest"))]
     fn create_correctness() {
         fn create_slow(address: &Address, nonce: u64) -> Address {
             use alloy_rlp::Encodable;
@@ -570,11 +1914,66 @@ mod tests {
             Address::from_word(keccak256(out))
         }
 
-        proptest::proptest!(|(address: Address, nonce: u64)| {
+        use crate::strategy::{self, ByteDistribution};
+
+        proptest::proptest!(|(address in strategy::address(ByteDistribution::Edge), nonce: u64)| {
             proptest::prop_assert_eq!(address.create(nonce), create_slow(&address, nonce));
         });
     }
 
+    #[test]
+    #[cfg(feature = "rlp")]
+    fn create_raw() {
+        let from = address!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+        for nonce in [0, 1, 0x7f, 0x80, 0xff, 0x100, u64::MAX] {
+            assert_eq!(
+                from.create_raw(U256::from(nonce)),
+                from.create(nonce),
+                "{nonce}"
+            );
+        }
+        for nonce in [
+            U256::from(u64::MAX) + U256::from(1),
+            U256::from(1) << 255usize,
+            U256::MAX,
+        ] {
+            assert_ne!(
+                from.create_raw(nonce),
+                from.create(nonce.wrapping_to::<u64>()),
+                "{nonce}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "rlp", feature = "proptest"))]
+    fn create_raw_correctness() {
+        fn create_slow(address: &Address, nonce: U256) -> Address {
+            use alloy_rlp::Encodable;
+
+            let mut out = vec![];
+
+            alloy_rlp::Header {
+                list: true,
+                payload_length: address.length() + nonce.length(),
+            }
+            .encode(&mut out);
+            address.encode(&mut out);
+            nonce.encode(&mut out);
+
+            Address::from_word(keccak256(out))
+        }
+
+        use crate::strategy::{self, ByteDistribution};
+
+        proptest::proptest!(|(address in strategy::address(ByteDistribution::Edge), nonce: u64)| {
+            proptest::prop_assert_eq!(address.create_raw(U256::from(nonce)), address.create(nonce));
+        });
+        proptest::proptest!(|(address in strategy::address(ByteDistribution::Edge), nonce: U256)| {
+            proptest::prop_assert_eq!(address.create_raw(nonce), create_slow(&address, nonce));
+        });
+    }
+
     // https://eips.ethereum.org/EIPS/eip-1014
     #[test]
     fn create2() {
@@ -637,4 +2036,30 @@ mod tests {
             assert_eq!(expected, from.create2_from_code(salt, init_code));
         }
     }
+
+    #[test]
+    fn parse_7702_delegation() {
+        let address = Address(FixedBytes(crate::hex!(
+            "d8da6bf26964af9d7eed9e03e53415d37aa96045"
+        )));
+        let code = crate::hex!("ef0100d8da6bf26964af9d7eed9e03e53415d37aa96045");
+        assert_eq!(Address::parse_7702_delegation(&code), Some(address));
+
+        // too short
+        assert_eq!(Address::parse_7702_delegation(&code[..22]), None);
+        assert_eq!(Address::parse_7702_delegation(&code[..3]), None);
+        assert_eq!(Address::parse_7702_delegation(&[]), None);
+
+        // too long
+        let mut long = code.to_vec();
+        long.push(0);
+        assert_eq!(Address::parse_7702_delegation(&long), None);
+
+        // wrong prefix
+        let mut other = code;
+        other[2] = 0x01;
+        assert_eq!(Address::parse_7702_delegation(&other), None);
+        assert_eq!(Address::parse_7702_delegation(&code[1..]), None);
+        assert_eq!(Address::parse_7702_delegation(address.as_slice()), None);
+    }
 }
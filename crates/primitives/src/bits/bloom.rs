@@ -2,7 +2,7 @@
 //!
 //! Adapted from <https://github.com/paritytech/parity-common/blob/2fb72eea96b6de4a085144ce239feb49da0cd39e/ethbloom/src/lib.rs>
 
-use crate::{keccak256, wrap_fixed_bytes, FixedBytes};
+use crate::{keccak256, FixedBytes};
 use core::borrow::Borrow;
 
 /// Number of bits to set per input in Ethereum bloom filter.
@@ -56,6 +56,16 @@ impl From<BloomInput<'_>> for Bloom {
 
 wrap_fixed_bytes!(
     /// Ethereum 256 byte bloom filter.
+    #[cfg_attr(
+        feature = "zerocopy",
+        derive(
+            zerocopy::FromBytes,
+            zerocopy::IntoBytes,
+            zerocopy::Immutable,
+            zerocopy::KnownLayout,
+            zerocopy::Unaligned
+        )
+    )]
     pub struct Bloom<256>;
 );
 
@@ -105,10 +115,40 @@ impl Bloom {
                 ptr += 1;
             }
             index &= MASK;
-            self.0[BLOOM_SIZE_BYTES - 1 - index / 8] |= 1 << (index % 8);
+            self.set_bit(index, true);
         }
     }
 
+    /// Accrues all the inputs into the bloom filter, hashing them in parallel
+    /// on the [`rayon`] thread pool.
+    ///
+    /// This is equivalent to calling [`accrue`](Self::accrue) on each input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{Address, Bloom, BloomInput};
+    /// let addresses: Vec<Address> = (0..100).map(Address::with_last_byte).collect();
+    /// let inputs: Vec<_> = addresses
+    ///     .iter()
+    ///     .map(|a| BloomInput::Raw(a.as_slice()))
+    ///     .collect();
+    /// let mut bloom = Bloom::default();
+    /// bloom.accrue_par(inputs);
+    /// assert!(bloom.contains_input(BloomInput::Raw(addresses[42].as_slice())));
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn accrue_par<'a, I>(&mut self, inputs: I)
+    where
+        I: rayon::iter::IntoParallelIterator<Item = BloomInput<'a>>,
+    {
+        use rayon::iter::ParallelIterator;
+        *self |= inputs
+            .into_par_iter()
+            .map(Self::from)
+            .reduce(Self::default, |a, b| a | b);
+    }
+
     /// Accrues the input into the bloom filter.
     pub fn accrue_bloom<B: Borrow<Self>>(&mut self, bloom: B) {
         let other = bloom.borrow();
@@ -121,7 +161,7 @@ impl Bloom {
         let h: &[u8; 32] = hash.as_ref();
         for i in [0, 2, 4] {
             let bit = (h[i + 1] as usize + ((h[i] as usize) << 8)) & 0x7FF;
-            self.0[BLOOM_SIZE_BYTES - 1 - bit / 8] |= 1 << (bit % 8);
+            self.set_bit(bit, true);
         }
     }
 }
@@ -139,6 +179,35 @@ const fn log2(x: usize) -> usize {
 mod tests {
     use super::*;
     use crate::hex;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn bits() {
+        let input = BloomInput::Raw(b"alloy");
+        let mut bloom = Bloom::default();
+        bloom.accrue(input);
+        assert!((1..=BLOOM_BITS_PER_ITEM as u32).contains(&bloom.count_ones()));
+
+        let mut rebuilt = Bloom::default();
+        for index in bloom.iter_ones() {
+            assert!(bloom.bit(index));
+            rebuilt.set_bit(index, true);
+        }
+        assert_eq!(rebuilt, bloom);
+        assert!(rebuilt.contains_input(input));
+
+        assert_eq!(
+            Bloom::with_last_byte(1).iter_ones().collect::<Vec<_>>(),
+            [0]
+        );
+        assert!(Bloom::repeat_byte(0x80).bit(2047));
+    }
+
+    #[test]
+    #[should_panic = "bit index out of bounds"]
+    fn bit_out_of_bounds() {
+        let _ = Bloom::ZERO.bit(2048);
+    }
 
     #[test]
     fn works() {
@@ -177,4 +246,36 @@ mod tests {
 
         assert_eq!(my_bloom, bloom);
     }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn accrue_par() {
+        use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+        let items: Vec<_> = (0..1000u32).map(|i| keccak256(i.to_be_bytes())).collect();
+        let mut inputs: Vec<_> = items
+            .iter()
+            .map(|item| BloomInput::Raw(&item[..20]))
+            .collect();
+        inputs.extend(items.iter().map(|item| BloomInput::Hash(*item)));
+
+        let mut sequential = Bloom::default();
+        for input in &inputs {
+            sequential.accrue(*input);
+        }
+
+        let mut parallel = Bloom::default();
+        parallel.accrue_par(inputs.par_iter().copied());
+        assert_eq!(parallel, sequential);
+
+        // accrues into the existing filter
+        let mut halves = Bloom::default();
+        halves.accrue_par(inputs[..1000].to_vec());
+        halves.accrue_par(inputs[1000..].to_vec());
+        assert_eq!(halves, sequential);
+
+        let mut empty = Bloom::repeat_byte(0x11);
+        empty.accrue_par(Vec::new());
+        assert_eq!(empty, Bloom::repeat_byte(0x11));
+    }
 }
@@ -0,0 +1,93 @@
+use super::FixedBytes;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+
+// Borsh `[u8; N]`: the bytes are serialized as-is, with no length prefix.
+impl<const N: usize> BorshSerialize for FixedBytes<N> {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for FixedBytes<N> {
+    #[inline]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = [0; N];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hex, Address, Bloom, B256, I256, U256, U64};
+
+    // Pinned encodings; changing any of these is a breaking change to the wire
+    // format.
+    #[test]
+    fn layout() {
+        let address = Address::from(hex!("dbf03b407c01e7cd3cbea99509d93f8dddc8c6fb"));
+        assert_eq!(borsh::to_vec(&address).unwrap(), address.as_slice());
+
+        let b256 = B256::from(hex!(
+            "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20"
+        ));
+        assert_eq!(borsh::to_vec(&b256).unwrap(), b256.as_slice());
+
+        assert_eq!(borsh::to_vec(&Bloom::ZERO).unwrap(), [0; 256]);
+
+        // `Uint`s are fixed-width little-endian
+        assert_eq!(
+            borsh::to_vec(&U64::from(1025)).unwrap(),
+            hex!("0104000000000000")
+        );
+        assert_eq!(
+            borsh::to_vec(&U256::from(0x0102_0304u64)).unwrap(),
+            hex!("0403020100000000000000000000000000000000000000000000000000000000")
+        );
+        assert_eq!(borsh::to_vec(&I256::MINUS_ONE).unwrap(), [0xff; 32]);
+        assert_eq!(
+            borsh::to_vec(&I256::MIN).unwrap(),
+            hex!("0000000000000000000000000000000000000000000000000000000000000080")
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let address = Address::repeat_byte(0x11);
+        let bloom = Bloom::repeat_byte(0xaa);
+        let value = U256::MAX >> 3;
+        let signed = I256::MIN + I256::ONE;
+        let encoded = borsh::to_vec(&(address, bloom, value, signed)).unwrap();
+        assert_eq!(encoded.len(), 20 + 256 + 32 + 32);
+        assert_eq!(
+            borsh::from_slice(&encoded).ok(),
+            Some((address, bloom, value, signed))
+        );
+
+        let list = vec![B256::repeat_byte(1), B256::repeat_byte(2)];
+        let encoded = borsh::to_vec(&list).unwrap();
+        assert_eq!(encoded[..4], 2u32.to_le_bytes());
+        assert_eq!(encoded.len(), 4 + 64);
+        assert_eq!(borsh::from_slice::<Vec<B256>>(&encoded).unwrap(), list);
+    }
+
+    #[test]
+    fn decode_rejects_wrong_length() {
+        assert!(B256::try_from_slice(&[0; 31]).is_err());
+        assert!(B256::try_from_slice(&[0; 33]).is_err());
+        assert!(Address::try_from_slice(&[0; 19]).is_err());
+        assert!(Address::try_from_slice(&[0; 21]).is_err());
+        assert!(U256::try_from_slice(&[0; 33]).is_err());
+        assert!(I256::try_from_slice(&[0; 16]).is_err());
+
+        // the reader is only advanced by the fixed width
+        let mut reader = &[0xaa; 24][..];
+        assert_eq!(
+            Address::deserialize_reader(&mut reader).unwrap(),
+            Address::repeat_byte(0xaa)
+        );
+        assert_eq!(reader.len(), 4);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{hex, B256};
+use core::fmt;
+
+/// Half of the order of the secp256k1 curve, `n / 2`.
+///
+/// Signatures with an `s` value above this are malleable, and cannot be
+/// represented in the [EIP-2098] compact form, which uses the highest bit of
+/// `s` to store the parity of `v`.
+///
+/// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
+pub const SECP256K1N_HALF: B256 = B256::new(hex!(
+    "7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0"
+));
+
+/// Error returned by [`pack_2098`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Eip2098Error {
+    /// The `s` value is greater than [`SECP256K1N_HALF`].
+    HighS,
+    /// The `v` value is not one of `0`, `1`, `27` or `28`.
+    InvalidParity(u8),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Eip2098Error {}
+
+impl fmt::Display for Eip2098Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HighS => f.write_str("signature `s` value is not in the lower half order"),
+            Self::InvalidParity(v) => write!(f, "invalid signature `v` value: {v}"),
+        }
+    }
+}
+
+/// Packs an ECDSA signature into its [EIP-2098] compact 64-byte form,
+/// `r || yParityAndS`.
+///
+/// `v` may be given either as the y-parity (`0` or `1`) or in its legacy form
+/// (`27` or `28`). The parity is stored in the highest bit of `s`, which is
+/// why `s` must not be greater than [`SECP256K1N_HALF`].
+///
+/// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{b256, pack_2098, unpack_2098};
+///
+/// let r = b256!("68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b90");
+/// let s = b256!("7e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064");
+///
+/// let compact = pack_2098(r, s, 28).unwrap();
+/// assert_eq!(compact[32], 0xfe);
+/// assert_eq!(unpack_2098(&compact), (r, s, 1));
+/// ```
+pub fn pack_2098(r: B256, s: B256, v: u8) -> Result<[u8; 64], Eip2098Error> {
+    let parity = match v {
+        0 | 27 => false,
+        1 | 28 => true,
+        v => return Err(Eip2098Error::InvalidParity(v)),
+    };
+    if s > SECP256K1N_HALF {
+        return Err(Eip2098Error::HighS)
+    }
+
+    let mut compact = [0; 64];
+    compact[..32].copy_from_slice(r.as_slice());
+    compact[32..].copy_from_slice(s.as_slice());
+    compact[32] |= (parity as u8) << 7;
+    Ok(compact)
+}
+
+/// Unpacks an [EIP-2098] compact signature into its `r`, `s` and y-parity
+/// (`0` or `1`) values.
+///
+/// This is the inverse of [`pack_2098`], and does not validate the values.
+///
+/// [EIP-2098]: https://eips.ethereum.org/EIPS/eip-2098
+pub fn unpack_2098(compact: &[u8; 64]) -> (B256, B256, u8) {
+    let r = B256::from_slice(&compact[..32]);
+    let mut s = B256::from_slice(&compact[32..]);
+    let parity = s[0] >> 7;
+    s[0] &= 0x7f;
+    (r, s, parity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // https://eips.ethereum.org/EIPS/eip-2098#test-cases
+    const R: B256 = b256!("68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b90");
+
+    #[test]
+    fn eip_test_cases() {
+        let s = b256!("7e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064");
+        let compact = pack_2098(R, s, 27).unwrap();
+        assert_eq!(
+            compact,
+            hex!(
+                "68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b90"
+                "7e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064"
+            )
+        );
+        assert_eq!(unpack_2098(&compact), (R, s, 0));
+
+        let r = b256!("9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76");
+        let s = b256!("139c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793");
+        let compact = pack_2098(r, s, 1).unwrap();
+        assert_eq!(
+            compact,
+            hex!(
+                "9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76"
+                "939c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793"
+            )
+        );
+        assert_eq!(pack_2098(r, s, 28), Ok(compact));
+        assert_eq!(unpack_2098(&compact), (r, s, 1));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(pack_2098(R, SECP256K1N_HALF, 1).map(|c| c[32]), Ok(0xff));
+
+        let mut high = SECP256K1N_HALF;
+        high[31] += 1;
+        assert_eq!(pack_2098(R, high, 0), Err(Eip2098Error::HighS));
+        assert_eq!(
+            pack_2098(R, B256::repeat_byte(0xff), 0),
+            Err(Eip2098Error::HighS)
+        );
+
+        for v in [2, 26, 29, 35, 255] {
+            assert_eq!(
+                pack_2098(R, B256::ZERO, v),
+                Err(Eip2098Error::InvalidParity(v))
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::aliases;
+use crate::{aliases, Uint};
 use core::{fmt, iter, ops, str};
 use derive_more::{Deref, DerefMut, From, Index, IndexMut, IntoIterator};
 
@@ -11,6 +11,12 @@ use derive_more::{Deref, DerefMut, From, Index, IndexMut, IntoIterator};
 /// Users looking to prevent type-confusion between byte arrays of different
 /// lengths should use the [`wrap_fixed_bytes!`](crate::wrap_fixed_bytes) macro
 /// to create a new fixed-length byte array type.
+///
+/// Like `[u8; N]`, it can be indexed with a `usize` or any range, which
+/// returns a `&u8` or a `&[u8]` respectively, and panics if out of bounds.
+///
+/// `Hash` is derived on purpose: it hashes like `[u8; N]` and `[u8]`, which is
+/// required by the `Borrow` impls, and already writes all the bytes at once.
 #[derive(
     Clone,
     Copy,
@@ -27,14 +33,52 @@ use derive_more::{Deref, DerefMut, From, Index, IndexMut, IntoIterator};
     IntoIterator,
 )]
 #[cfg_attr(
-    feature = "arbitrary",
-    derive(derive_arbitrary::Arbitrary, proptest_derive::Arbitrary)
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
 )]
 #[repr(transparent)]
 pub struct FixedBytes<const N: usize>(#[into_iterator(owned, ref, ref_mut)] pub [u8; N]);
 
 crate::impl_fb_traits!(FixedBytes<N>, N, const);
 
+// Implemented manually because `derive_arbitrary` requires `std`.
+#[cfg(feature = "arbitrary")]
+impl<'a, const N: usize> arbitrary::Arbitrary<'a> for FixedBytes<N> {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        <[u8; N]>::arbitrary(u).map(Self)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; N]>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<const N: usize> proptest::arbitrary::Arbitrary for FixedBytes<N> {
+    type Parameters = proptest::arbitrary::ParamsFor<[u8; N]>;
+    type Strategy = proptest::arbitrary::Mapped<[u8; N], Self>;
+
+    #[inline]
+    fn arbitrary() -> Self::Strategy {
+        use proptest::strategy::Strategy;
+        proptest::arbitrary::any::<[u8; N]>().prop_map(Self)
+    }
+
+    #[inline]
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+        proptest::arbitrary::any_with::<[u8; N]>(args).prop_map(Self)
+    }
+}
+
 impl<const N: usize> Default for FixedBytes<N> {
     #[inline]
     fn default() -> Self {
@@ -56,21 +100,44 @@ impl<const N: usize> From<&mut [u8; N]> for FixedBytes<N> {
     }
 }
 
+/// The error returned when converting a slice of the wrong length into
+/// [`FixedBytes`] or one of its wrappers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidLengthError {
+    /// The required length in bytes.
+    pub expected: usize,
+    /// The length of the given slice.
+    pub actual: usize,
+}
+
+impl fmt::Display for InvalidLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid length: expected {} bytes, got {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidLengthError {}
+
 /// Tries to create a `FixedBytes<N>` by copying from a slice `&[u8]`. Succeeds
 /// if `slice.len() == N`.
 impl<const N: usize> TryFrom<&[u8]> for FixedBytes<N> {
-    type Error = core::array::TryFromSliceError;
+    type Error = InvalidLengthError;
 
     #[inline]
     fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
-        <&Self>::try_from(slice).map(|this| *this)
+        <&Self>::try_from(slice).copied()
     }
 }
 
 /// Tries to create a `FixedBytes<N>` by copying from a mutable slice `&mut
 /// [u8]`. Succeeds if `slice.len() == N`.
 impl<const N: usize> TryFrom<&mut [u8]> for FixedBytes<N> {
-    type Error = core::array::TryFromSliceError;
+    type Error = InvalidLengthError;
 
     #[inline]
     fn try_from(slice: &mut [u8]) -> Result<Self, Self::Error> {
@@ -81,24 +148,36 @@ impl<const N: usize> TryFrom<&mut [u8]> for FixedBytes<N> {
 /// Tries to create a ref `FixedBytes<N>` by copying from a slice `&[u8]`.
 /// Succeeds if `slice.len() == N`.
 impl<'a, const N: usize> TryFrom<&'a [u8]> for &'a FixedBytes<N> {
-    type Error = core::array::TryFromSliceError;
+    type Error = InvalidLengthError;
 
     #[inline]
     fn try_from(slice: &'a [u8]) -> Result<&'a FixedBytes<N>, Self::Error> {
-        // SAFETY: `FixedBytes<N>` is `repr(transparent)` for `[u8; N]`
-        <&[u8; N]>::try_from(slice).map(|array_ref| unsafe { core::mem::transmute(array_ref) })
+        let actual = slice.len();
+        <&[u8; N]>::try_from(slice)
+            // SAFETY: `FixedBytes<N>` is `repr(transparent)` for `[u8; N]`
+            .map(|array_ref| unsafe { core::mem::transmute(array_ref) })
+            .map_err(|_| InvalidLengthError {
+                expected: N,
+                actual,
+            })
     }
 }
 
 /// Tries to create a ref `FixedBytes<N>` by copying from a mutable slice `&mut
 /// [u8]`. Succeeds if `slice.len() == N`.
 impl<'a, const N: usize> TryFrom<&'a mut [u8]> for &'a mut FixedBytes<N> {
-    type Error = core::array::TryFromSliceError;
+    type Error = InvalidLengthError;
 
     #[inline]
     fn try_from(slice: &'a mut [u8]) -> Result<&'a mut FixedBytes<N>, Self::Error> {
-        // SAFETY: `FixedBytes<N>` is `repr(transparent)` for `[u8; N]`
-        <&mut [u8; N]>::try_from(slice).map(|array_ref| unsafe { core::mem::transmute(array_ref) })
+        let actual = slice.len();
+        <&mut [u8; N]>::try_from(slice)
+            // SAFETY: `FixedBytes<N>` is `repr(transparent)` for `[u8; N]`
+            .map(|array_ref| unsafe { core::mem::transmute(array_ref) })
+            .map_err(|_| InvalidLengthError {
+                expected: N,
+                actual,
+            })
     }
 }
 
@@ -229,17 +308,21 @@ impl<const N: usize> fmt::Display for FixedBytes<N> {
     }
 }
 
+/// The alternate flag adds the `0x` prefix, and a precision keeps only that
+/// many digits at each end, separated by `…`: `{:#.4x}` formats as
+/// `0x0123…cdef`.
 impl<const N: usize> fmt::LowerHex for FixedBytes<N> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.fmt_hex::<false>(f, f.alternate())
+        self.fmt_hex_truncated::<false>(f)
     }
 }
 
+/// See [`LowerHex`](fmt::LowerHex).
 impl<const N: usize> fmt::UpperHex for FixedBytes<N> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.fmt_hex::<true>(f, f.alternate())
+        self.fmt_hex_truncated::<true>(f)
     }
 }
 
@@ -297,17 +380,30 @@ impl<const N: usize> ops::BitXorAssign for FixedBytes<N> {
     }
 }
 
+/// Parses a hex string, with an optional `0x` or `0X` prefix.
+///
+/// The hex digits themselves are parsed case-insensitively.
 impl<const N: usize> str::FromStr for FixedBytes<N> {
     type Err = hex::FromHexError;
 
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = strip_hex_prefix(s);
         let mut buf = [0u8; N];
         hex::decode_to_slice(s, &mut buf)?;
         Ok(Self(buf))
     }
 }
 
+/// Strips a `0x` or `0X` prefix from the given string, if present.
+#[inline]
+pub(crate) fn strip_hex_prefix(s: &str) -> &str {
+    match s.as_bytes() {
+        [b'0', b'x' | b'X', ..] => &s[2..],
+        _ => s,
+    }
+}
+
 #[cfg(feature = "rand")]
 impl<const N: usize> rand::distributions::Distribution<FixedBytes<N>>
     for rand::distributions::Standard
@@ -329,6 +425,15 @@ impl<const N: usize> FixedBytes<N> {
     }
 
     /// Creates a new [`FixedBytes`] with the last byte set to `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{fixed_bytes, FixedBytes};
+    /// const ONE: FixedBytes<4> = FixedBytes::with_last_byte(1);
+    /// assert_eq!(ONE, fixed_bytes!("00000001"));
+    /// assert_eq!(FixedBytes::<0>::with_last_byte(1), FixedBytes::ZERO);
+    /// ```
     #[inline]
     pub const fn with_last_byte(x: u8) -> Self {
         let mut bytes = [0u8; N];
@@ -339,6 +444,14 @@ impl<const N: usize> FixedBytes<N> {
     }
 
     /// Creates a new [`FixedBytes`] where all bytes are set to `byte`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{fixed_bytes, FixedBytes};
+    /// const ONES: FixedBytes<4> = FixedBytes::repeat_byte(0xff);
+    /// assert_eq!(ONES, fixed_bytes!("ffffffff"));
+    /// ```
     #[inline]
     pub const fn repeat_byte(byte: u8) -> Self {
         Self([byte; N])
@@ -350,6 +463,15 @@ impl<const N: usize> FixedBytes<N> {
         N
     }
 
+    /// Returns a `proptest` strategy for byte arrays with at most 3 non-zero
+    /// bytes. See
+    /// [`ByteDistribution::Sparse`](crate::strategy::ByteDistribution::Sparse).
+    #[cfg(feature = "proptest")]
+    #[inline]
+    pub fn arbitrary_sparse() -> proptest::strategy::SBoxedStrategy<Self> {
+        crate::strategy::fixed_bytes(crate::strategy::ByteDistribution::Sparse)
+    }
+
     /// Creates a new [`FixedBytes`] with cryptographically random content.
     ///
     /// # Panics
@@ -481,9 +603,12 @@ impl<const N: usize> FixedBytes<N> {
     }
 
     /// Returns `true` if no bits are set.
+    ///
+    /// This ORs all the bytes together instead of comparing them one by one,
+    /// so it does not branch on the contents.
     #[inline]
     pub fn is_zero(&self) -> bool {
-        *self == Self::ZERO
+        self.0.iter().fold(0, |acc, &byte| acc | byte) == 0
     }
 
     /// Compile-time equality. NOT constant-time equality.
@@ -538,6 +663,158 @@ impl<const N: usize> FixedBytes<N> {
         ret
     }
 
+    /// Returns the value of the bit at the given index.
+    ///
+    /// Bits are numbered the same way as in the EVM logs bloom: the bytes are
+    /// read as a big-endian integer, and bit `i` is the bit with value `2^i`.
+    /// That is, bit 0 is the least significant bit of the last byte, and bit
+    /// `N * 8 - 1` is the most significant bit of the first byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= N * 8`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::fixed_bytes;
+    /// let bytes = fixed_bytes!("8001");
+    /// assert!(bytes.bit(0));
+    /// assert!(!bytes.bit(1));
+    /// assert!(bytes.bit(15));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub const fn bit(&self, index: usize) -> bool {
+        assert!(index < N * 8, "bit index out of bounds");
+        self.0[N - 1 - index / 8] >> (index % 8) & 1 != 0
+    }
+
+    /// Sets the bit at the given index to `value`.
+    ///
+    /// See [`bit`](Self::bit) for the bit order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= N * 8`.
+    #[inline]
+    #[track_caller]
+    pub fn set_bit(&mut self, index: usize, value: bool) {
+        assert!(index < N * 8, "bit index out of bounds");
+        let byte = &mut self.0[N - 1 - index / 8];
+        let mask = 1 << (index % 8);
+        if value {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+
+    /// Returns the number of set bits.
+    #[inline]
+    pub const fn count_ones(&self) -> u32 {
+        let mut count = 0;
+        let mut i = 0;
+        while i < N {
+            count += self.0[i].count_ones();
+            i += 1;
+        }
+        count
+    }
+
+    /// Returns an iterator over the indices of the set bits, in ascending
+    /// order.
+    ///
+    /// See [`bit`](Self::bit) for the bit order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::fixed_bytes;
+    /// let bytes = fixed_bytes!("8001");
+    /// assert_eq!(bytes.iter_ones().collect::<Vec<_>>(), [0, 15]);
+    /// ```
+    #[inline]
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().rev().enumerate().flat_map(|(i, &byte)| {
+            (0..8)
+                .filter(move |j| byte >> j & 1 != 0)
+                .map(move |j| i * 8 + j)
+        })
+    }
+
+    /// Returns a copy of `self` with the bytes in reverse order.
+    ///
+    /// This converts between the big-endian and little-endian byte orders of
+    /// the same number, like [`Uint::to_be_bytes`] and [`Uint::to_le_bytes`].
+    ///
+    /// [`Uint::to_be_bytes`]: crate::Uint::to_be_bytes
+    /// [`Uint::to_le_bytes`]: crate::Uint::to_le_bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::fixed_bytes;
+    /// assert_eq!(fixed_bytes!("010203").reversed(), fixed_bytes!("030201"));
+    /// ```
+    #[inline]
+    pub const fn reversed(&self) -> Self {
+        let mut ret = Self::ZERO;
+        let mut i = 0;
+        while i < N {
+            ret.0[i] = self.0[N - 1 - i];
+            i += 1;
+        }
+        ret
+    }
+
+    /// Reverses the order of the bytes in place.
+    ///
+    /// See [`reversed`](Self::reversed) for more details.
+    #[inline]
+    pub fn reverse_mut(&mut self) {
+        self.0.reverse()
+    }
+
+    /// Interprets the bytes as a big-endian unsigned integer.
+    ///
+    /// This is the generic form of the `From<FixedBytes<N>>` implementations
+    /// of the integer types, such as `U256::from(B256)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` is not exactly `N * 8`. `ruint` 1.20 and later reject
+    /// this at compile time instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{fixed_bytes, aliases::U32, FixedBytes};
+    /// let selector = fixed_bytes!("a9059cbb");
+    /// assert_eq!(selector.to_uint::<32, 1>(), U32::from(0xa9059cbbu32));
+    /// assert_eq!(FixedBytes::from_uint(U32::from(0xa9059cbbu32)), selector);
+    /// ```
+    #[inline]
+    pub const fn to_uint<const BITS: usize, const LIMBS: usize>(&self) -> Uint<BITS, LIMBS> {
+        Uint::from_be_bytes(self.0)
+    }
+
+    /// Creates a new [`FixedBytes`] from the big-endian bytes of an unsigned
+    /// integer.
+    ///
+    /// This is the inverse of [`to_uint`](Self::to_uint).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` is not exactly `N * 8`, like
+    /// [`to_uint`](Self::to_uint).
+    #[inline]
+    pub const fn from_uint<const BITS: usize, const LIMBS: usize>(
+        value: Uint<BITS, LIMBS>,
+    ) -> Self {
+        Self(value.to_be_bytes())
+    }
+
     fn fmt_hex<const UPPER: bool>(&self, f: &mut fmt::Formatter<'_>, prefix: bool) -> fmt::Result {
         let mut buf = hex::Buffer::<N, true>::new();
         let s = if UPPER {
@@ -548,11 +825,43 @@ impl<const N: usize> FixedBytes<N> {
         // SAFETY: The buffer is guaranteed to be at least 2 bytes in length.
         f.write_str(unsafe { s.get_unchecked((!prefix as usize) * 2..) })
     }
+
+    fn fmt_hex_truncated<const UPPER: bool>(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = hex::Buffer::<N, true>::new();
+        let s = if UPPER {
+            buf.format_upper(self)
+        } else {
+            buf.format(self)
+        };
+        let s = if f.alternate() { s } else { &s[2..] };
+        write_truncated_hex(f, s, f.alternate(), f.precision())
+    }
+}
+
+/// Writes the hex string `s`, keeping only the first and last `digits` digits
+/// if it is longer than that.
+pub(crate) fn write_truncated_hex(
+    f: &mut fmt::Formatter<'_>,
+    s: &str,
+    prefixed: bool,
+    digits: Option<usize>,
+) -> fmt::Result {
+    let (prefix, hex) = s.split_at(prefixed as usize * 2);
+    match digits {
+        Some(digits) if digits * 2 < hex.len() => {
+            f.write_str(prefix)?;
+            f.write_str(&hex[..digits])?;
+            f.write_str("…")?;
+            f.write_str(&hex[hex.len() - digits..])
+        }
+        _ => f.write_str(s),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
 
     macro_rules! test_fmt {
         ($($fmt:literal, $hex:literal => $expected:literal;)+) => {$(
@@ -573,6 +882,96 @@ mod tests {
         assert_eq!(ACTUAL, EXPECTED);
     }
 
+    #[test]
+    fn try_from_slice() {
+        use crate::{Address, B256};
+        use alloc::string::ToString;
+
+        let bytes = [0x11; 33];
+        assert_eq!(B256::try_from(&bytes[..32]), Ok(B256::repeat_byte(0x11)));
+        assert_eq!(<&B256>::try_from(&bytes[1..]), Ok(&B256::repeat_byte(0x11)));
+
+        let err = B256::try_from(&bytes[..]).unwrap_err();
+        assert_eq!(
+            err,
+            InvalidLengthError {
+                expected: 32,
+                actual: 33
+            }
+        );
+        assert_eq!(err.to_string(), "invalid length: expected 32 bytes, got 33");
+
+        assert_eq!(
+            Address::try_from(&bytes[..20]),
+            Ok(Address::repeat_byte(0x11))
+        );
+        assert_eq!(
+            Address::try_from(&bytes[..32]),
+            Err(InvalidLengthError {
+                expected: 20,
+                actual: 32
+            })
+        );
+        let mut bytes = bytes;
+        assert_eq!(
+            <&mut Address>::try_from(&mut bytes[..]).unwrap_err(),
+            InvalidLengthError {
+                expected: 20,
+                actual: 33
+            }
+        );
+        let address = <&mut Address>::try_from(&mut bytes[..20]).unwrap();
+        address[0] = 0;
+        assert_eq!(bytes[0], 0);
+    }
+
+    // Also run without `std` in CI.
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary() {
+        use crate::{Address, I256};
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data = [0x11; 64];
+        let mut u = Unstructured::new(&data);
+        assert_eq!(
+            FixedBytes::<4>::arbitrary(&mut u).unwrap(),
+            FixedBytes([0x11; 4])
+        );
+        assert_eq!(
+            Address::arbitrary(&mut u).unwrap(),
+            Address::repeat_byte(0x11)
+        );
+        assert!(I256::arbitrary(&mut u).is_ok());
+        assert_eq!(FixedBytes::<32>::size_hint(0), (32, Some(32)));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn random_with() {
+        use crate::Address;
+        use rand::rngs::mock::StepRng;
+
+        let mut rng = StepRng::new(1, 0);
+        let bytes = FixedBytes::<8>::random_with(&mut rng);
+        assert_eq!(bytes, fixed_bytes!("0100000000000000"));
+
+        let mut rng = StepRng::new(0x0101010101010101, 0);
+        assert_eq!(Address::random_with(&mut rng), Address::repeat_byte(1));
+
+        let a: Address = rand::Rng::gen(&mut rng);
+        assert_eq!(a, Address::repeat_byte(1));
+    }
+
+    #[test]
+    #[cfg(feature = "getrandom")]
+    fn random() {
+        use crate::Address;
+
+        assert_ne!(Address::random(), Address::random());
+        assert_ne!(FixedBytes::<32>::random(), FixedBytes::<32>::random());
+    }
+
     #[test]
     fn display() {
         test_fmt! {
@@ -596,6 +995,9 @@ mod tests {
         test_fmt! {
             "{:x}", "0123456789abcdef" => "0123456789abcdef";
             "{:#x}", "0123456789abcdef" => "0x0123456789abcdef";
+            "{:.4x}", "0123456789abcdef" => "0123…cdef";
+            "{:#.2x}", "0123456789abcdef" => "0x01…ef";
+            "{:#.8x}", "0123456789abcdef" => "0x0123456789abcdef";
         }
     }
 
@@ -604,6 +1006,169 @@ mod tests {
         test_fmt! {
             "{:X}", "0123456789abcdef" => "0123456789ABCDEF";
             "{:#X}", "0123456789abcdef" => "0x0123456789ABCDEF";
+            "{:#.4X}", "0123456789abcdef" => "0x0123…CDEF";
+        }
+    }
+
+    #[test]
+    fn bits() {
+        let mut bytes = FixedBytes::<4>::ZERO;
+        assert_eq!(bytes.count_ones(), 0);
+        assert_eq!(bytes.iter_ones().next(), None);
+
+        bytes.set_bit(0, true);
+        bytes.set_bit(9, true);
+        bytes.set_bit(31, true);
+        assert_eq!(bytes, FixedBytes(crate::hex!("80000201")));
+        assert!(bytes.bit(0) && bytes.bit(9) && bytes.bit(31));
+        assert!(!bytes.bit(1) && !bytes.bit(8) && !bytes.bit(30));
+        assert_eq!(bytes.count_ones(), 3);
+        assert_eq!(bytes.iter_ones().collect::<Vec<_>>(), [0, 9, 31]);
+
+        bytes.set_bit(9, false);
+        bytes.set_bit(10, false);
+        assert_eq!(bytes, FixedBytes(crate::hex!("80000001")));
+
+        let all = FixedBytes::<32>::repeat_byte(0xff);
+        assert_eq!(all.count_ones(), 256);
+        assert!(all.iter_ones().eq(0..256));
+
+        const _: () = assert!(FixedBytes::<2>(crate::hex!("0100")).bit(8));
+    }
+
+    #[test]
+    #[should_panic = "bit index out of bounds"]
+    fn bit_out_of_bounds() {
+        let _ = FixedBytes::<4>::ZERO.bit(32);
+    }
+
+    #[test]
+    #[should_panic = "bit index out of bounds"]
+    fn set_bit_out_of_bounds() {
+        let mut bytes = FixedBytes::<4>::ZERO;
+        bytes.set_bit(32, true);
+    }
+
+    #[test]
+    fn reverse() {
+        let bytes = FixedBytes(crate::hex!("0102030405"));
+        assert_eq!(bytes.reversed(), FixedBytes(crate::hex!("0504030201")));
+        assert_eq!(bytes.reversed().reversed(), bytes);
+        assert_eq!(FixedBytes::<0>::ZERO.reversed(), FixedBytes::ZERO);
+
+        let mut reversed = bytes;
+        reversed.reverse_mut();
+        assert_eq!(reversed, bytes.reversed());
+
+        let value = crate::U256::from(0x0102_0304_0506_0708_u64);
+        let be = FixedBytes::<32>::from(value.to_be_bytes::<32>());
+        assert_eq!(be.reversed().0, value.to_le_bytes::<32>());
+
+        let address = crate::Address::with_last_byte(1);
+        assert_eq!(address.reversed()[0], 1);
+        assert_eq!(address.reversed().reversed(), address);
+
+        const REVERSED: FixedBytes<2> = FixedBytes(crate::hex!("0100")).reversed();
+        assert_eq!(REVERSED, FixedBytes(crate::hex!("0001")));
+    }
+
+    #[test]
+    fn uint() {
+        use crate::aliases::{U256, U32};
+
+        let bytes = FixedBytes(crate::hex!("a9059cbb"));
+        assert_eq!(bytes.to_uint::<32, 1>(), U32::from(0xa9059cbb_u32));
+        assert_eq!(FixedBytes::from_uint(U32::from(0xa9059cbb_u32)), bytes);
+        assert_eq!(u32::from(bytes), 0xa9059cbb);
+
+        let word = FixedBytes::<32>::with_last_byte(1);
+        assert_eq!(word.to_uint::<256, 4>(), U256::from(1));
+        assert_eq!(FixedBytes::<32>::from_uint(U256::from(1)), word);
+        assert_eq!(
+            FixedBytes::<32>::from_uint(U256::MAX),
+            FixedBytes::repeat_byte(0xff)
+        );
+
+        const ONE: FixedBytes<2> = FixedBytes::from_uint(crate::aliases::U16::from_limbs([1]));
+        assert_eq!(ONE, FixedBytes(crate::hex!("0001")));
+    }
+
+    #[test]
+    #[cfg(feature = "proptest")]
+    fn uint_roundtrip() {
+        use crate::aliases::{B256, B32, U256, U32};
+
+        proptest::proptest!(|(bytes: B256, value: U256)| {
+            proptest::prop_assert_eq!(B256::from_uint(bytes.to_uint::<256, 4>()), bytes);
+            proptest::prop_assert_eq!(B256::from_uint(value).to_uint::<256, 4>(), value);
+            proptest::prop_assert_eq!(<U256 as From<B256>>::from(bytes), bytes.to_uint());
+        });
+        proptest::proptest!(|(bytes: B32, value: u32)| {
+            proptest::prop_assert_eq!(B32::from_uint(bytes.to_uint::<32, 1>()), bytes);
+            proptest::prop_assert_eq!(B32::from(value).to_uint::<32, 1>(), U32::from(value));
+        });
+    }
+
+    #[test]
+    fn index() {
+        let mut bytes = FixedBytes(crate::hex!("0102030405060708"));
+        assert_eq!(bytes[0], 1);
+        assert_eq!(bytes[7], 8);
+        assert_eq!(bytes[2..4], [3, 4]);
+        assert_eq!(bytes[..2], [1, 2]);
+        assert_eq!(bytes[6..], [7, 8]);
+        assert_eq!(bytes[..], *bytes.as_slice());
+        assert_eq!(bytes[1..=2], [2, 3]);
+        assert_eq!(bytes[..=0], [1]);
+        assert!(bytes[8..].is_empty());
+
+        bytes[0] = 0xff;
+        bytes[6..].copy_from_slice(&[0xaa, 0xbb]);
+        assert_eq!(bytes, FixedBytes(crate::hex!("ff0203040506aabb")));
+
+        let address = crate::Address::repeat_byte(0x11);
+        assert_eq!(address[19], 0x11);
+        assert_eq!(address[..4], [0x11; 4]);
+    }
+
+    #[test]
+    #[should_panic = "index out of bounds"]
+    fn index_out_of_bounds() {
+        let bytes = FixedBytes::<4>::ZERO;
+        let i = 4;
+        let _ = bytes[i];
+    }
+
+    #[test]
+    #[should_panic = "range end index 5 out of range for slice of length 4"]
+    fn range_out_of_bounds() {
+        let bytes = FixedBytes::<4>::ZERO;
+        let end = 5;
+        let _ = &bytes[1..end];
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash() {
+        use std::{
+            collections::{hash_map::DefaultHasher, HashMap},
+            hash::{Hash, Hasher},
+        };
+
+        fn hash(value: impl Hash) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
         }
+
+        // `Borrow<[u8]>` and `Borrow<[u8; N]>` require identical hashes
+        let bytes = FixedBytes::<32>(core::array::from_fn(|i| i as u8));
+        assert_eq!(hash(bytes), hash(bytes.0));
+        assert_eq!(hash(bytes), hash(bytes.as_slice()));
+        assert_eq!(hash(crate::Address::repeat_byte(0x11)), hash([0x11u8; 20]));
+
+        let map = HashMap::from([(bytes, 1)]);
+        assert_eq!(map.get(bytes.as_slice()), Some(&1));
+        assert_eq!(map.get(&bytes.0), Some(&1));
     }
 }
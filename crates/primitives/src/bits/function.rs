@@ -6,6 +6,16 @@ wrap_fixed_bytes! {
     ///
     /// An address (20 bytes), followed by a function selector (4 bytes).
     /// Encoded identical to `bytes24`.
+    #[cfg_attr(
+        feature = "zerocopy",
+        derive(
+            zerocopy::FromBytes,
+            zerocopy::IntoBytes,
+            zerocopy::Immutable,
+            zerocopy::KnownLayout,
+            zerocopy::Unaligned
+        )
+    )]
     pub struct Function<24>;
 }
 
@@ -65,7 +65,10 @@ macro_rules! wrap_fixed_bytes {
             )*
         )]
         #[repr(transparent)]
-        $vis struct $name(#[into_iterator(owned, ref, ref_mut)] pub $crate::FixedBytes<$n>);
+        // `$vis` rather than `pub`: the field can't be more visible than `$name`
+        // anyway, and the marker types that the `zerocopy` derives generate
+        // from it then don't trigger `missing_*_implementations` here.
+        $vis struct $name(#[into_iterator(owned, ref, ref_mut)] $vis $crate::FixedBytes<$n>);
 
         impl $crate::private::From<[u8; $n]> for $name {
             #[inline]
@@ -96,7 +99,7 @@ macro_rules! wrap_fixed_bytes {
         }
 
         impl $crate::private::TryFrom<&[u8]> for $name {
-            type Error = $crate::private::core::array::TryFromSliceError;
+            type Error = $crate::InvalidLengthError;
 
             #[inline]
             fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
@@ -105,7 +108,7 @@ macro_rules! wrap_fixed_bytes {
         }
 
         impl $crate::private::TryFrom<&mut [u8]> for $name {
-            type Error = $crate::private::core::array::TryFromSliceError;
+            type Error = $crate::InvalidLengthError;
 
             #[inline]
             fn try_from(slice: &mut [u8]) -> Result<Self, Self::Error> {
@@ -114,28 +117,28 @@ macro_rules! wrap_fixed_bytes {
         }
 
         impl<'a> $crate::private::TryFrom<&'a [u8]> for &'a $name {
-            type Error = $crate::private::core::array::TryFromSliceError;
+            type Error = $crate::InvalidLengthError;
 
             #[inline]
             #[allow(unsafe_code)]
             fn try_from(slice: &'a [u8]) -> Result<&'a $name, Self::Error> {
                 // SAFETY: `$name` is `repr(transparent)` for `FixedBytes<$n>`
                 // and consequently `[u8; $n]`
-                <&[u8; $n] as $crate::private::TryFrom<&[u8]>>::try_from(slice)
-                    .map(|array_ref| unsafe { $crate::private::core::mem::transmute(array_ref) })
+                <&$crate::FixedBytes<$n> as $crate::private::TryFrom<&[u8]>>::try_from(slice)
+                    .map(|fixed_ref| unsafe { $crate::private::core::mem::transmute(fixed_ref) })
             }
         }
 
         impl<'a> $crate::private::TryFrom<&'a mut [u8]> for &'a mut $name {
-            type Error = $crate::private::core::array::TryFromSliceError;
+            type Error = $crate::InvalidLengthError;
 
             #[inline]
             #[allow(unsafe_code)]
             fn try_from(slice: &'a mut [u8]) -> Result<&'a mut $name, Self::Error> {
                 // SAFETY: `$name` is `repr(transparent)` for `FixedBytes<$n>`
                 // and consequently `[u8; $n]`
-                <&mut [u8; $n] as $crate::private::TryFrom<&mut [u8]>>::try_from(slice)
-                    .map(|array_ref| unsafe { $crate::private::core::mem::transmute(array_ref) })
+                <&mut $crate::FixedBytes<$n> as $crate::private::TryFrom<&mut [u8]>>::try_from(slice)
+                    .map(|fixed_ref| unsafe { $crate::private::core::mem::transmute(fixed_ref) })
             }
         }
 
@@ -174,9 +177,13 @@ macro_rules! wrap_fixed_bytes {
         }
 
         $crate::impl_fb_traits!($name, $n);
+        $crate::impl_borsh!($name, $n);
+        $crate::impl_postgres!($name, $n);
         $crate::impl_rlp!($name, $n);
         $crate::impl_serde!($name);
+        $crate::impl_ssz!($name, $n);
         $crate::impl_arbitrary!($name, $n);
+        $crate::impl_proptest!($name, $n);
         $crate::impl_rand!($name);
 
         impl $name {
@@ -255,6 +262,57 @@ macro_rules! wrap_fixed_bytes {
             pub const fn bit_xor(self, rhs: Self) -> Self {
                 Self(self.0.bit_xor(rhs.0))
             }
+
+            /// Returns the value of the bit at the given index.
+            ///
+            /// See [`FixedBytes::bit`] for the bit order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the index is out of bounds.
+            #[inline]
+            #[track_caller]
+            pub const fn bit(&self, index: usize) -> bool {
+                self.0.bit(index)
+            }
+
+            /// Sets the bit at the given index to `value`.
+            ///
+            /// See [`FixedBytes::bit`] for the bit order.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the index is out of bounds.
+            #[inline]
+            #[track_caller]
+            pub fn set_bit(&mut self, index: usize, value: bool) {
+                self.0.set_bit(index, value)
+            }
+
+            /// Returns the number of set bits.
+            #[inline]
+            pub const fn count_ones(&self) -> u32 {
+                self.0.count_ones()
+            }
+
+            /// Returns an iterator over the indices of the set bits, in
+            /// ascending order.
+            #[inline]
+            pub fn iter_ones(&self) -> impl $crate::private::Iterator<Item = usize> + '_ {
+                self.0.iter_ones()
+            }
+
+            /// Returns a copy of `self` with the bytes in reverse order.
+            #[inline]
+            pub const fn reversed(&self) -> Self {
+                Self(self.0.reversed())
+            }
+
+            /// Reverses the order of the bytes in place.
+            #[inline]
+            pub fn reverse_mut(&mut self) {
+                self.0.reverse_mut()
+            }
         }
     };
 }
@@ -488,6 +546,72 @@ macro_rules! impl_rand {
     ($($t:tt)*) => {};
 }
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "postgres")]
+macro_rules! impl_postgres {
+    ($t:ty, $n:literal) => {
+        impl $crate::private::postgres_types::ToSql for $t {
+            #[inline]
+            fn to_sql(
+                &self,
+                ty: &$crate::private::postgres_types::Type,
+                out: &mut $crate::bytes::BytesMut,
+            ) -> Result<
+                $crate::private::postgres_types::IsNull,
+                $crate::private::Box<dyn $crate::private::StdError + Sync + Send>,
+            > {
+                $crate::private::postgres_types::ToSql::to_sql(&self.0, ty, out)
+            }
+
+            #[inline]
+            fn accepts(ty: &$crate::private::postgres_types::Type) -> bool {
+                <$crate::FixedBytes<$n> as $crate::private::postgres_types::ToSql>::accepts(ty)
+            }
+
+            #[inline]
+            fn to_sql_checked(
+                &self,
+                ty: &$crate::private::postgres_types::Type,
+                out: &mut $crate::bytes::BytesMut,
+            ) -> Result<
+                $crate::private::postgres_types::IsNull,
+                $crate::private::Box<dyn $crate::private::StdError + Sync + Send>,
+            > {
+                $crate::private::postgres_types::ToSql::to_sql_checked(&self.0, ty, out)
+            }
+        }
+
+        impl<'a> $crate::private::postgres_types::FromSql<'a> for $t {
+            #[inline]
+            fn from_sql(
+                ty: &$crate::private::postgres_types::Type,
+                raw: &'a [u8],
+            ) -> Result<Self, $crate::private::Box<dyn $crate::private::StdError + Sync + Send>>
+            {
+                <$crate::FixedBytes<$n> as $crate::private::postgres_types::FromSql<'a>>::from_sql(
+                    ty, raw,
+                )
+                .map(Self)
+            }
+
+            #[inline]
+            fn accepts(ty: &$crate::private::postgres_types::Type) -> bool {
+                <$crate::FixedBytes<$n> as $crate::private::postgres_types::FromSql<'a>>::accepts(
+                    ty,
+                )
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "postgres"))]
+macro_rules! impl_postgres {
+    ($t:ty, $n:literal) => {};
+}
+
 #[doc(hidden)]
 #[macro_export]
 #[cfg(feature = "rlp")]
@@ -555,6 +679,92 @@ macro_rules! impl_serde {
     ($t:ty) => {};
 }
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "borsh")]
+macro_rules! impl_borsh {
+    ($t:ty, $n:literal) => {
+        impl $crate::private::borsh::BorshSerialize for $t {
+            #[inline]
+            fn serialize<W: $crate::private::borsh::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::private::borsh::io::Result<()> {
+                $crate::private::borsh::BorshSerialize::serialize(&self.0, writer)
+            }
+        }
+
+        impl $crate::private::borsh::BorshDeserialize for $t {
+            #[inline]
+            fn deserialize_reader<R: $crate::private::borsh::io::Read>(
+                reader: &mut R,
+            ) -> $crate::private::borsh::io::Result<Self> {
+                $crate::private::borsh::BorshDeserialize::deserialize_reader(reader).map(Self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "borsh"))]
+macro_rules! impl_borsh {
+    ($t:ty, $n:literal) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "ssz")]
+macro_rules! impl_ssz {
+    ($t:ty, $n:literal) => {
+        impl $crate::private::ssz::Encode for $t {
+            #[inline]
+            fn is_ssz_fixed_len() -> bool {
+                true
+            }
+
+            #[inline]
+            fn ssz_fixed_len() -> usize {
+                $n
+            }
+
+            #[inline]
+            fn ssz_bytes_len(&self) -> usize {
+                $n
+            }
+
+            #[inline]
+            fn ssz_append(&self, buf: &mut $crate::private::Vec<u8>) {
+                $crate::private::ssz::Encode::ssz_append(&self.0, buf)
+            }
+        }
+
+        impl $crate::private::ssz::Decode for $t {
+            #[inline]
+            fn is_ssz_fixed_len() -> bool {
+                true
+            }
+
+            #[inline]
+            fn ssz_fixed_len() -> usize {
+                $n
+            }
+
+            #[inline]
+            fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, $crate::private::ssz::DecodeError> {
+                $crate::private::ssz::Decode::from_ssz_bytes(bytes).map(Self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "ssz"))]
+macro_rules! impl_ssz {
+    ($t:ty, $n:literal) => {};
+}
+
 #[doc(hidden)]
 #[macro_export]
 #[cfg(feature = "arbitrary")]
@@ -576,7 +786,21 @@ macro_rules! impl_arbitrary {
                 <$crate::FixedBytes<$n> as $crate::private::arbitrary::Arbitrary>::size_hint(depth)
             }
         }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "arbitrary"))]
+macro_rules! impl_arbitrary {
+    ($t:ty, $n:literal) => {};
+}
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "proptest")]
+macro_rules! impl_proptest {
+    ($t:ty, $n:literal) => {
         impl $crate::private::proptest::arbitrary::Arbitrary for $t {
             type Parameters = <$crate::FixedBytes<$n> as $crate::private::proptest::arbitrary::Arbitrary>::Parameters;
             type Strategy = $crate::private::proptest::strategy::Map<
@@ -603,8 +827,8 @@ macro_rules! impl_arbitrary {
 
 #[doc(hidden)]
 #[macro_export]
-#[cfg(not(feature = "arbitrary"))]
-macro_rules! impl_arbitrary {
+#[cfg(not(feature = "proptest"))]
+macro_rules! impl_proptest {
     ($t:ty, $n:literal) => {};
 }
 
@@ -617,9 +841,9 @@ macro_rules! fixed_bytes_macros {
         ///
         /// If the input is empty, a zero-initialized array is returned.
         ///
-        /// Note that the strings cannot be prefixed with `0x`.
-        ///
-        /// See [`hex!`](crate::hex!) for more information.
+        /// Each string may be prefixed with `0x`, and may contain `_` and
+        /// whitespace separators. The length of the data is checked at compile
+        /// time.
         ///
         /// # Examples
         ///
@@ -630,7 +854,7 @@ macro_rules! fixed_bytes_macros {
         #[doc = concat!("assert_eq!(ZERO, ", stringify!($ty), "::ZERO);")]
         ///
         /// # stringify!(
-        #[doc = concat!("let byte_array: ", stringify!($ty), " = ", stringify!($name), "!(\"…\");")]
+        #[doc = concat!("let byte_array: ", stringify!($ty), " = ", stringify!($name), "!(\"0x…\");")]
         /// # );
         /// ```
         $(#[$attr])*
@@ -641,7 +865,7 @@ macro_rules! fixed_bytes_macros {
             };
 
             ($d ($d s:literal)+) => {
-                $crate::$ty::new($crate::hex!($d ($d s)+))
+                $crate::$ty::new($crate::__hex!($d ($d s)+))
             };
         }
     )*};
@@ -668,9 +892,20 @@ fixed_bytes_macros! { $
 ///
 /// If the input is empty, an empty instance is returned.
 ///
-/// Note that the strings cannot be prefixed with `0x`.
+/// Each string may be prefixed with `0x`, and may contain `_` and whitespace
+/// separators.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{bytes, Bytes};
 ///
-/// See [`hex!`](crate::hex!) for more information.
+/// static MY_BYTES: Bytes = bytes!("0x0123_4567" "89abcdef");
+/// assert_eq!(
+///     MY_BYTES,
+///     Bytes::from(&[0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef])
+/// );
+/// ```
 #[macro_export]
 macro_rules! bytes {
     () => {
@@ -679,11 +914,112 @@ macro_rules! bytes {
 
     ($($s:literal)+) => {{
         // force const eval
-        const STATIC_BYTES: &'static [u8] = &$crate::hex!($($s)+);
+        const STATIC_BYTES: &'static [u8] = &$crate::__hex!($($s)+);
         $crate::Bytes::from_static(STATIC_BYTES)
     }};
 }
 
+/// Decodes a sequence of hex string literals into a byte array at compile
+/// time. Used by the byte literal macros.
+///
+/// Unlike [`hex!`](crate::hex!), each string may be prefixed with `0x`, and
+/// may contain `_` separators.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __hex {
+    ($($s:literal)+) => {{
+        const STRINGS: &[&str] = &[$($s),+];
+        const LEN: usize = $crate::private::hex_len(STRINGS);
+        const BYTES: [u8; LEN] = $crate::private::hex_decode::<LEN>(STRINGS);
+        BYTES
+    }};
+}
+
+/// Returns the start of the hex digits in the given string, skipping the
+/// leading whitespace and the optional `0x` prefix.
+const fn hex_start(s: &[u8]) -> usize {
+    let mut i = 0;
+    while i < s.len() && s[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    if i + 1 < s.len() && s[i] == b'0' && (s[i + 1] == b'x' || s[i + 1] == b'X') {
+        i += 2;
+    }
+    i
+}
+
+/// Returns the value of the given hex digit, or `None` if the byte is a
+/// separator.
+///
+/// # Panics
+///
+/// Panics if the byte is neither a hex digit nor a separator.
+const fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        b'_' | b' ' | b'\t' | b'\n' | b'\r' => None,
+        _ => panic!("invalid character in hex literal"),
+    }
+}
+
+/// Returns the number of bytes encoded in the given hex strings.
+///
+/// # Panics
+///
+/// Panics if the strings contain invalid characters or an odd number of hex
+/// digits.
+#[doc(hidden)]
+pub const fn hex_len(strings: &[&str]) -> usize {
+    let mut digits = 0;
+    let mut i = 0;
+    while i < strings.len() {
+        let s = strings[i].as_bytes();
+        let mut j = hex_start(s);
+        while j < s.len() {
+            if hex_digit(s[j]).is_some() {
+                digits += 1;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    if digits % 2 != 0 {
+        panic!("hex literal has an odd number of digits");
+    }
+    digits / 2
+}
+
+/// Decodes the given hex strings into an array of [`hex_len`] bytes.
+#[doc(hidden)]
+pub const fn hex_decode<const N: usize>(strings: &[&str]) -> [u8; N] {
+    let mut out = [0u8; N];
+    let mut digits = 0;
+    let mut i = 0;
+    while i < strings.len() {
+        let s = strings[i].as_bytes();
+        let mut j = hex_start(s);
+        while j < s.len() {
+            if let Some(digit) = hex_digit(s[j]) {
+                if digits % 2 == 0 {
+                    out[digits / 2] = digit << 4;
+                } else {
+                    out[digits / 2] |= digit;
+                }
+                digits += 1;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(
+        digits == N * 2,
+        "hex literal length does not match the output length"
+    );
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{hex, Address, Bytes, FixedBytes};
@@ -703,6 +1039,22 @@ mod tests {
         static B: Bytes = bytes!("112233");
         assert_eq!(B[..], [0x11, 0x22, 0x33]);
 
+        // `0x` prefixes and separators, as copied from block explorers
+        const A4: Address = address!("0x0102030405060708090a0b0c0d0e0f1011121314");
+        const A5: Address = address!("0x01020304_05060708_090A0B0C_0D0E0F10_11121314");
+        const A6: Address = address!("0x0102030405" "0x060708090a" "0b0c0d0e0f1011121314");
+        assert_eq!(A1, A4);
+        assert_eq!(A1, A5);
+        assert_eq!(A1, A6);
+        const H: crate::B256 =
+            b256!("0xd78ad95fa46c994b6551d0da85fc275fe613ce37657fb8d5e3d130840159d822");
+        assert_eq!(
+            H[..],
+            hex!("d78ad95fa46c994b6551d0da85fc275fe613ce37657fb8d5e3d130840159d822")
+        );
+        static C: Bytes = bytes!("0X11_22" " 0x33 ");
+        assert_eq!(C, B);
+
         static EMPTY_BYTES1: Bytes = bytes!();
         static EMPTY_BYTES2: Bytes = bytes!("");
         assert_eq!(EMPTY_BYTES1, EMPTY_BYTES2);
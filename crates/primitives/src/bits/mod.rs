@@ -1,20 +1,37 @@
 #[macro_use]
 mod macros;
+#[doc(hidden)]
+pub use macros::{hex_decode, hex_len};
 
 mod address;
-pub use address::{Address, AddressError};
+pub use address::{Address, AddressError, ChecksumVariant, IntoAddress};
 
 mod bloom;
 pub use bloom::{Bloom, BloomInput, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES};
 
+mod eip2098;
+pub use eip2098::{pack_2098, unpack_2098, Eip2098Error, SECP256K1N_HALF};
+
 mod fixed;
-pub use fixed::FixedBytes;
+pub use fixed::{FixedBytes, InvalidLengthError};
 
 mod function;
 pub use function::Function;
 
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "postgres")]
+mod postgres;
+
 #[cfg(feature = "rlp")]
 mod rlp;
 
 #[cfg(feature = "serde")]
 mod serde;
+
+#[cfg(feature = "ssz")]
+mod ssz;
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
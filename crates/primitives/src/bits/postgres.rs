@@ -0,0 +1,92 @@
+//! Postgres support for fixed byte arrays.
+//!
+//! [`FixedBytes`] and the types created with
+//! [`wrap_fixed_bytes!`](crate::wrap_fixed_bytes) are stored as `BYTEA`.
+//!
+//! The [`Uint`](crate::Uint) aliases, such as [`U256`](crate::U256), use the
+//! `ruint` implementations instead. These are meant to be stored as `NUMERIC`,
+//! but can also be read from and written to a big-endian `BYTEA`.
+
+use super::FixedBytes;
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+use std::error::Error;
+
+type BoxedError = Box<dyn Error + Sync + Send>;
+
+impl<const N: usize> ToSql for FixedBytes<N> {
+    #[inline]
+    fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxedError> {
+        out.extend_from_slice(&self.0);
+        Ok(IsNull::No)
+    }
+
+    accepts!(BYTEA);
+
+    to_sql_checked!();
+}
+
+impl<'a, const N: usize> FromSql<'a> for FixedBytes<N> {
+    #[inline]
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, BoxedError> {
+        Self::try_from(raw).map_err(|_| {
+            format!(
+                "invalid BYTEA length for FixedBytes<{N}>: expected {N} bytes, got {}",
+                raw.len()
+            )
+            .into()
+        })
+    }
+
+    accepts!(BYTEA);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hex, Address, B256, U256};
+
+    fn to_sql<T: ToSql>(value: &T, ty: &Type) -> Result<BytesMut, BoxedError> {
+        let mut out = BytesMut::new();
+        value.to_sql_checked(ty, &mut out)?;
+        Ok(out)
+    }
+
+    #[test]
+    fn fixed_bytes_bytea() {
+        let b256 = B256::repeat_byte(0x11);
+        let out = to_sql(&b256, &Type::BYTEA).unwrap();
+        assert_eq!(out[..], b256[..]);
+        assert_eq!(B256::from_sql(&Type::BYTEA, &out).unwrap(), b256);
+
+        let address = Address::from(hex!("dbf03b407c01e7cd3cbea99509d93f8dddc8c6fb"));
+        let out = to_sql(&address, &Type::BYTEA).unwrap();
+        assert_eq!(out[..], address[..]);
+        assert_eq!(Address::from_sql(&Type::BYTEA, &out).unwrap(), address);
+    }
+
+    #[test]
+    fn wrong_type() {
+        assert!(!<Address as ToSql>::accepts(&Type::TEXT));
+        assert!(!<B256 as FromSql<'_>>::accepts(&Type::NUMERIC));
+        assert!(to_sql(&B256::ZERO, &Type::TEXT).is_err());
+    }
+
+    #[test]
+    fn wrong_length() {
+        let err = Address::from_sql(&Type::BYTEA, &[0; 32]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid BYTEA length for FixedBytes<20>: expected 20 bytes, got 32"
+        );
+        assert!(B256::from_sql(&Type::BYTEA, &[]).is_err());
+    }
+
+    #[test]
+    fn u256_bytea() {
+        let value = U256::from(0x0102_0304u64);
+        let out = to_sql(&value, &Type::BYTEA).unwrap();
+        assert_eq!(out[..], value.to_be_bytes::<32>());
+        assert_eq!(U256::from_sql(&Type::BYTEA, &out).unwrap(), value);
+    }
+}
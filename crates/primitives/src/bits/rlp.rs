@@ -33,3 +33,92 @@ fixed_bytes_max_encoded_len!(0, 1, 2, 4, 8, 16, 20, 32, 64, 128, 256, 512, 1024)
 unsafe impl<const N: usize> MaxEncodedLenAssoc for FixedBytes<N> {
     const LEN: usize = N + length_of_length(N);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hex, Address, Bloom, B256};
+
+    // encodings as produced by go-ethereum's `rlp.EncodeToBytes`
+    #[test]
+    fn known_vectors() {
+        let b256 = B256::repeat_byte(0x11);
+        let encoded = alloy_rlp::encode(b256);
+        assert_eq!(encoded[0], 0xa0);
+        assert_eq!(encoded[1..], b256[..]);
+
+        let address = Address::from(hex!("dbf03b407c01e7cd3cbea99509d93f8dddc8c6fb"));
+        assert_eq!(
+            alloy_rlp::encode(address),
+            hex!("94dbf03b407c01e7cd3cbea99509d93f8dddc8c6fb")
+        );
+
+        let bloom = Bloom::repeat_byte(0xff);
+        let encoded = alloy_rlp::encode(bloom);
+        assert_eq!(encoded[..3], hex!("b90100"));
+        assert_eq!(encoded[3..], bloom[..]);
+
+        // single bytes below 0x80 are their own encoding
+        assert_eq!(alloy_rlp::encode(FixedBytes([0x7f])), [0x7f]);
+        assert_eq!(alloy_rlp::encode(FixedBytes([0x80])), [0x81, 0x80]);
+        assert_eq!(alloy_rlp::encode(FixedBytes([0x00])), [0x00]);
+    }
+
+    #[test]
+    fn decode_rejects_wrong_length() {
+        let b256 = B256::repeat_byte(0x11);
+        let encoded = alloy_rlp::encode(b256);
+        assert_eq!(B256::decode(&mut &encoded[..]).unwrap(), b256);
+
+        // leading-zero-stripped encoding of a 32-byte value
+        let short = alloy_rlp::encode(&b256[1..]);
+        assert_eq!(
+            B256::decode(&mut &short[..]),
+            Err(alloy_rlp::Error::UnexpectedLength)
+        );
+        assert_eq!(
+            Address::decode(&mut &encoded[..]),
+            Err(alloy_rlp::Error::UnexpectedLength)
+        );
+        assert_eq!(
+            Bloom::decode(&mut &encoded[..]),
+            Err(alloy_rlp::Error::UnexpectedLength)
+        );
+    }
+
+    #[test]
+    fn max_encoded_len() {
+        fn max_len<T: MaxEncodedLen<LEN>, const LEN: usize>(_: &T) -> usize {
+            LEN
+        }
+
+        let b256 = B256::repeat_byte(0x11);
+        assert_eq!(max_len(&b256), 33);
+        assert_eq!(<B256 as MaxEncodedLenAssoc>::LEN, 33);
+        assert_eq!(b256.length(), 33);
+
+        let bloom = Bloom::repeat_byte(0x22);
+        assert_eq!(<Bloom as MaxEncodedLenAssoc>::LEN, 259);
+        assert_eq!(bloom.length(), 259);
+    }
+
+    #[test]
+    #[cfg(feature = "proptest")]
+    fn roundtrip() {
+        fn roundtrip<T: Encodable + Decodable + PartialEq + core::fmt::Debug>(
+            value: T,
+        ) -> Result<(), proptest::test_runner::TestCaseError> {
+            let encoded = alloy_rlp::encode(&value);
+            proptest::prop_assert_eq!(encoded.len(), value.length());
+            proptest::prop_assert_eq!(T::decode(&mut &encoded[..]).unwrap(), value);
+            Ok(())
+        }
+
+        proptest::proptest!(|(b1: FixedBytes<1>, b256: B256, address: Address, bloom: Bloom)| {
+            roundtrip(b1)?;
+            roundtrip(b256)?;
+            roundtrip(address)?;
+            roundtrip(bloom)?;
+        });
+    }
+}
@@ -0,0 +1,178 @@
+use super::FixedBytes;
+use ssz::{Decode, DecodeError, Encode};
+
+// SSZ `ByteVector[N]`: the bytes are serialized as-is, with no length prefix.
+impl<const N: usize> Encode for FixedBytes<N> {
+    #[inline]
+    fn is_ssz_fixed_len() -> bool {
+        true
+    }
+
+    #[inline]
+    fn ssz_fixed_len() -> usize {
+        N
+    }
+
+    #[inline]
+    fn ssz_bytes_len(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    fn ssz_append(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0);
+    }
+}
+
+impl<const N: usize> Decode for FixedBytes<N> {
+    #[inline]
+    fn is_ssz_fixed_len() -> bool {
+        true
+    }
+
+    #[inline]
+    fn ssz_fixed_len() -> usize {
+        N
+    }
+
+    #[inline]
+    fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        Self::try_from(bytes).map_err(|_| DecodeError::InvalidByteLength {
+            len: bytes.len(),
+            expected: N,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hex, Address, Bloom, B256, U256, U64};
+
+    // `uint_256_*` cases from the consensus-spec-tests `ssz_generic/uints` suite
+    #[test]
+    fn u256_spec_vectors() {
+        let cases = [
+            (U256::ZERO, [0u8; 32]),
+            (U256::MAX, [0xff; 32]),
+            (
+                U256::MAX >> 8,
+                hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff00"),
+            ),
+        ];
+        for (value, encoded) in cases {
+            assert_eq!(value.as_ssz_bytes(), encoded);
+            assert_eq!(U256::from_ssz_bytes(&encoded).unwrap(), value);
+        }
+    }
+
+    // `ethereum_ssz` implements SSZ for the `ethereum-types` hashes and
+    // integers on its own, so encodings must agree with it
+    #[test]
+    fn ethereum_types_vectors() {
+        let words = [
+            B256::ZERO,
+            B256::repeat_byte(0xff),
+            B256::with_last_byte(1),
+            B256::new(hex!(
+                "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20"
+            )),
+            B256::new(hex!(
+                "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+            )),
+        ];
+        for word in words {
+            assert_ethereum_types_eq(word);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "proptest")]
+    fn ethereum_types_roundtrip() {
+        proptest::proptest!(|(word: B256)| assert_ethereum_types_eq(word));
+    }
+
+    fn assert_ethereum_types_eq(word: B256) {
+        let h256 = ethereum_types::H256(word.0);
+        assert_eq!(word.as_ssz_bytes(), h256.as_ssz_bytes());
+        assert_eq!(B256::from_ssz_bytes(&h256.as_ssz_bytes()).unwrap(), word);
+
+        let address = Address::from_word(word);
+        let h160 = ethereum_types::H160(address.0 .0);
+        assert_eq!(address.as_ssz_bytes(), h160.as_ssz_bytes());
+        assert_eq!(
+            Address::from_ssz_bytes(&h160.as_ssz_bytes()).unwrap(),
+            address
+        );
+
+        let value = U256::from_be_bytes(word.0);
+        let u256 = ethereum_types::U256::from_big_endian(word.as_slice());
+        assert_eq!(value.as_ssz_bytes(), u256.as_ssz_bytes());
+        assert_eq!(U256::from_ssz_bytes(&u256.as_ssz_bytes()).unwrap(), value);
+    }
+
+    #[test]
+    fn uint_little_endian() {
+        let value = U256::from(0x0102_0304u64);
+        let encoded = value.as_ssz_bytes();
+        assert_eq!(encoded.len(), 32);
+        assert_eq!(encoded[..4], hex!("04030201"));
+        assert!(encoded[4..].iter().all(|&b| b == 0));
+
+        // opposite byte order to the ABI encoding
+        let mut be = value.to_be_bytes::<32>();
+        be.reverse();
+        assert_eq!(encoded, be);
+
+        assert_eq!(U64::from(1025).as_ssz_bytes(), hex!("0104000000000000"));
+    }
+
+    #[test]
+    fn fixed_bytes_roundtrip() {
+        let b256 = B256::repeat_byte(0x11);
+        assert_eq!(<B256 as Encode>::ssz_fixed_len(), 32);
+        assert_eq!(b256.as_ssz_bytes(), b256.as_slice());
+        assert_eq!(B256::from_ssz_bytes(b256.as_slice()).unwrap(), b256);
+
+        let address = Address::from(hex!("dbf03b407c01e7cd3cbea99509d93f8dddc8c6fb"));
+        assert_eq!(<Address as Encode>::ssz_fixed_len(), 20);
+        assert_eq!(address.as_ssz_bytes(), address.as_slice());
+        assert_eq!(
+            Address::from_ssz_bytes(address.as_slice()).unwrap(),
+            address
+        );
+
+        let bloom = Bloom::repeat_byte(0xaa);
+        assert_eq!(<Bloom as Decode>::ssz_fixed_len(), 256);
+        assert_eq!(bloom.as_ssz_bytes(), bloom.as_slice());
+        assert_eq!(Bloom::from_ssz_bytes(bloom.as_slice()).unwrap(), bloom);
+    }
+
+    #[test]
+    fn fixed_bytes_in_list() {
+        let list = vec![B256::repeat_byte(1), B256::repeat_byte(2)];
+        let encoded = list.as_ssz_bytes();
+        assert_eq!(encoded.len(), 64);
+        assert_eq!(encoded[..32], [1; 32]);
+        assert_eq!(encoded[32..], [2; 32]);
+        assert_eq!(Vec::<B256>::from_ssz_bytes(&encoded).unwrap(), list);
+    }
+
+    #[test]
+    fn decode_rejects_wrong_length() {
+        assert_eq!(
+            B256::from_ssz_bytes(&[0; 31]),
+            Err(DecodeError::InvalidByteLength {
+                len: 31,
+                expected: 32
+            })
+        );
+        assert_eq!(
+            Address::from_ssz_bytes(&[0; 21]),
+            Err(DecodeError::InvalidByteLength {
+                len: 21,
+                expected: 20
+            })
+        );
+    }
+}
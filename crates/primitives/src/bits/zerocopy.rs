@@ -0,0 +1,85 @@
+//! [`zerocopy`] support.
+//!
+//! [`FixedBytes`], [`Address`], [`Bloom`] and [`Function`] implement
+//! [`FromBytes`], [`IntoBytes`], [`Immutable`], [`KnownLayout`] and
+//! [`Unaligned`], so that byte buffers can be reinterpreted as slices of these
+//! types without copying, and vice versa.
+//!
+//! [`FromBytes`]: zerocopy::FromBytes
+//! [`IntoBytes`]: zerocopy::IntoBytes
+//! [`Immutable`]: zerocopy::Immutable
+//! [`KnownLayout`]: zerocopy::KnownLayout
+//! [`Unaligned`]: zerocopy::Unaligned
+//!
+//! Types created with [`wrap_fixed_bytes!`](crate::wrap_fixed_bytes) outside of
+//! this crate don't implement them. Depend on `zerocopy` directly and pass its
+//! derives to the macro as attributes instead.
+//!
+//! [`Uint`](crate::Uint)s are not supported, as they are stored as
+//! native-endian `u64` limbs. Store them as [`B256`](crate::B256) instead, and
+//! convert with [`Uint::from_be_bytes`](crate::Uint::from_be_bytes).
+
+use super::{Address, Bloom, FixedBytes, Function};
+use core::mem::{align_of, size_of};
+
+// The derives rely on the `repr(transparent)` chain down to `[u8; N]`.
+const _: () = {
+    assert!(size_of::<FixedBytes<32>>() == 32 && align_of::<FixedBytes<32>>() == 1);
+    assert!(size_of::<Address>() == 20 && align_of::<Address>() == 1);
+    assert!(size_of::<Bloom>() == 256 && align_of::<Bloom>() == 1);
+    assert!(size_of::<Function>() == 24 && align_of::<Function>() == 1);
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hex, B256};
+    use zerocopy::{FromBytes, FromZeros, IntoBytes};
+
+    #[test]
+    fn cast_slice() {
+        let bytes = hex!(
+            "0000000000000000000000000000000000000001"
+            "d8da6bf26964af9d7eed9e03e53415d37aa96045"
+            "ffffffffffffffffffffffffffffffffffffffff"
+        );
+        let addresses = <[Address]>::ref_from_bytes(&bytes).unwrap();
+        assert_eq!(
+            addresses,
+            [
+                Address::with_last_byte(1),
+                Address::from(hex!("d8da6bf26964af9d7eed9e03e53415d37aa96045")),
+                Address::repeat_byte(0xff),
+            ]
+        );
+        assert_eq!(addresses.as_bytes(), bytes);
+
+        // Any offset works, but the length must be a multiple of the size.
+        assert!(<[Address]>::ref_from_bytes(&bytes[1..]).is_err());
+        assert!(<[Address]>::ref_from_bytes(&bytes[..59]).is_err());
+        assert_eq!(<[Address]>::ref_from_bytes(&bytes[1..41]).unwrap().len(), 2);
+        assert!(<[Address]>::ref_from_bytes(&[]).unwrap().is_empty());
+
+        let hashes = <[B256]>::ref_from_bytes(&bytes[..32]).unwrap();
+        assert_eq!(hashes, [B256::from_slice(&bytes[..32])]);
+    }
+
+    #[test]
+    fn cast_mut() {
+        let mut bytes = [0u8; 48];
+        let hashes = <[B256]>::mut_from_bytes(&mut bytes[..32]).unwrap();
+        hashes[0] = B256::repeat_byte(0x11);
+        assert_eq!(bytes[..32], [0x11; 32]);
+
+        let function = Function::mut_from_bytes(&mut bytes[8..32]).unwrap();
+        *function = Function::repeat_byte(0x22);
+        assert_eq!(bytes[..8], [0x11; 8]);
+        assert_eq!(bytes[8..32], [0x22; 24]);
+        assert_eq!(bytes[32..], [0; 16]);
+
+        let bloom = Bloom::read_from_bytes(&[0xaa; 256]).unwrap();
+        assert_eq!(bloom, Bloom::repeat_byte(0xaa));
+        assert_eq!(bloom.as_bytes(), [0xaa; 256]);
+        assert_eq!(Bloom::new_zeroed(), Bloom::ZERO);
+    }
+}
@@ -0,0 +1,50 @@
+use super::Bytes;
+use alloc::vec::Vec;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+
+// Borsh `Vec<u8>`: a `u32` little-endian length prefix, followed by the bytes.
+impl BorshSerialize for Bytes {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        <[u8]>::serialize(self, writer)
+    }
+}
+
+impl BorshDeserialize for Bytes {
+    #[inline]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Vec::<u8>::deserialize_reader(reader).map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        assert_eq!(borsh::to_vec(&Bytes::new()).unwrap(), [0; 4]);
+        assert_eq!(
+            borsh::to_vec(&crate::bytes!("deadbeef")).unwrap(),
+            crate::hex!("04000000deadbeef")
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let bytes = Bytes::from(vec![0x42; 300]);
+        let encoded = borsh::to_vec(&bytes).unwrap();
+        assert_eq!(encoded[..4], 300u32.to_le_bytes());
+        assert_eq!(Bytes::try_from_slice(&encoded).unwrap(), bytes);
+        assert_eq!(borsh::to_vec(&bytes.to_vec()).unwrap(), encoded);
+    }
+
+    #[test]
+    fn decode_rejects_wrong_length() {
+        // truncated
+        assert!(Bytes::try_from_slice(&crate::hex!("05000000deadbeef")).is_err());
+        assert!(Bytes::try_from_slice(&[4, 0, 0]).is_err());
+        // trailing data
+        assert!(Bytes::try_from_slice(&crate::hex!("03000000deadbeef")).is_err());
+    }
+}
@@ -5,6 +5,9 @@ use core::{
     ops::{Deref, DerefMut, RangeBounds},
 };
 
+#[cfg(feature = "borsh")]
+mod borsh;
+
 #[cfg(feature = "rlp")]
 mod rlp;
 
@@ -288,6 +291,20 @@ impl Bytes {
     }
 
     /// Returns a slice of self for the provided range.
+    ///
+    /// This does not copy: the returned `Bytes` shares the underlying buffer
+    /// and only increments its reference count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{bytes, Bytes};
+    ///
+    /// let b = bytes!("deadbeef");
+    /// let s = b.slice(1..3);
+    /// assert_eq!(s, bytes!("adbe"));
+    /// assert_eq!(s.as_ptr(), b[1..].as_ptr());
+    /// ```
     #[inline]
     pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
         Self(self.0.slice(range))
@@ -339,7 +356,7 @@ impl<'a> arbitrary::Arbitrary<'a> for Bytes {
     }
 }
 
-#[cfg(feature = "arbitrary")]
+#[cfg(feature = "proptest")]
 impl proptest::arbitrary::Arbitrary for Bytes {
     type Parameters = proptest::arbitrary::ParamsFor<Vec<u8>>;
     type Strategy = proptest::arbitrary::Mapped<Vec<u8>, Self>;
@@ -381,6 +398,30 @@ mod tests {
         assert_eq!(format!("{b}"), expected);
     }
 
+    #[test]
+    fn slice_shares_buffer() {
+        let b = Bytes::from(vec![1, 2, 3, 4, 5]);
+        let s = b.slice(1..4);
+        assert_eq!(s, [2, 3, 4][..]);
+        assert_eq!(s.as_ptr(), b[1..].as_ptr());
+
+        let s = s.slice(1..);
+        assert_eq!(s, [3, 4][..]);
+        assert_eq!(s.as_ptr(), b[2..].as_ptr());
+    }
+
+    #[test]
+    fn buf() {
+        use bytes::Buf;
+
+        let mut b = crate::bytes!("0102030405");
+        assert_eq!(b.remaining(), 5);
+        assert_eq!(b.get_u8(), 1);
+        assert_eq!(b.get_u16(), 0x0203);
+        assert_eq!(b.copy_to_bytes(2), bytes::Bytes::from_static(&[4, 5]));
+        assert!(!b.has_remaining());
+    }
+
     #[test]
     fn debug() {
         let b = Bytes::from_static(&[1, 35, 69, 103, 137, 171, 205, 239]);
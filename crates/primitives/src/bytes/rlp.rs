@@ -19,3 +19,33 @@ impl Decodable for Bytes {
         bytes::Bytes::decode(buf).map(Self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex;
+
+    #[test]
+    fn known_vectors() {
+        for (bytes, expected) in [
+            (Bytes::new(), &hex!("80")[..]),
+            (Bytes::from_static(&[0x00]), &hex!("00")[..]),
+            (Bytes::from_static(&[0x7f]), &hex!("7f")[..]),
+            (Bytes::from_static(&[0x80]), &hex!("8180")[..]),
+            (Bytes::from_static(b"dog"), &hex!("83646f67")[..]),
+        ] {
+            assert_eq!(alloy_rlp::encode(&bytes), expected);
+            assert_eq!(bytes.length(), expected.len());
+            assert_eq!(Bytes::decode(&mut &expected[..]).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "proptest")]
+    fn roundtrip() {
+        proptest::proptest!(|(bytes: Bytes)| {
+            let encoded = alloy_rlp::encode(&bytes);
+            proptest::prop_assert_eq!(Bytes::decode(&mut &encoded[..]).unwrap(), bytes);
+        });
+    }
+}
@@ -0,0 +1,250 @@
+//! Batch operations on slices of [`B256`] words.
+//!
+//! Sorting and searching large numbers of hashes or storage keys is memory
+//! bound, and comparing them as byte arrays does not vectorize well. The
+//! functions in this module compare words as four big-endian `u64` limbs
+//! instead, which orders them exactly like the derived [`Ord`], and sort them
+//! with an in-place radix sort on their leading bytes.
+//!
+//! ```
+//! use alloy_primitives::{fixed_bytes, keccak256, B256};
+//!
+//! let mut hashes: Vec<B256> = (0..1000u64).map(|i| keccak256(i.to_be_bytes())).collect();
+//! hashes.extend_from_within(..100);
+//!
+//! fixed_bytes::sort_unstable_b256(&mut hashes);
+//! assert!(hashes.windows(2).all(|w| w[0] <= w[1]));
+//!
+//! let len = fixed_bytes::dedup_sorted(&mut hashes);
+//! hashes.truncate(len);
+//! assert_eq!(hashes.len(), 1000);
+//! assert!(fixed_bytes::contains_sorted(
+//!     &hashes,
+//!     &keccak256(42u64.to_be_bytes())
+//! ));
+//! ```
+
+use crate::B256;
+use core::cmp::Ordering;
+
+/// Slices up to this length are sorted by comparison instead of radix sort.
+const SMALL_SORT_THRESHOLD: usize = 64;
+
+/// Returns the word as big-endian limbs, which compare like the bytes.
+#[inline(always)]
+fn be_limbs(word: &B256) -> [u64; 4] {
+    let mut limbs = [0; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(word.0.chunks_exact(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+/// Sorts the slice in ascending order, without preserving the order of equal
+/// elements.
+///
+/// The result is the same as [`slice::sort_unstable`], but this is
+/// considerably faster for large slices. It does not allocate.
+///
+/// This is a most-significant-byte-first radix sort ("American flag sort"):
+/// the elements are distributed in place into 256 buckets by their leading
+/// byte, and each bucket is then sorted by the next byte, until it is short
+/// enough to be sorted by comparing limbs.
+pub fn sort_unstable_b256(words: &mut [B256]) {
+    radix_sort(words, 0);
+}
+
+fn radix_sort(words: &mut [B256], mut depth: usize) {
+    let counts = loop {
+        if words.len() <= SMALL_SORT_THRESHOLD || depth == 32 {
+            words.sort_unstable_by_key(be_limbs);
+            return;
+        }
+
+        let mut counts = [0usize; 256];
+        for word in words.iter() {
+            counts[word[depth] as usize] += 1;
+        }
+
+        // all the words share this byte, so move on to the next one
+        if counts[words[0][depth] as usize] == words.len() {
+            depth += 1;
+        } else {
+            break counts;
+        }
+    };
+    let mut ends = [0usize; 256];
+    let mut end = 0;
+    for (bucket_end, count) in ends.iter_mut().zip(counts) {
+        end += count;
+        *bucket_end = end;
+    }
+    let mut heads = [0usize; 256];
+    heads[1..].copy_from_slice(&ends[..255]);
+
+    // Every swap moves one word into its final bucket.
+    for bucket in 0..256 {
+        while heads[bucket] < ends[bucket] {
+            let target = words[heads[bucket]][depth] as usize;
+            if target != bucket {
+                words.swap(heads[bucket], heads[target]);
+            }
+            heads[target] += 1;
+        }
+    }
+
+    let mut start = 0;
+    for end in ends {
+        if end - start > 1 {
+            radix_sort(&mut words[start..end], depth + 1);
+        }
+        start = end;
+    }
+}
+
+/// Returns `true` if the sorted slice contains the given word.
+///
+/// The slice must be sorted in ascending order, for example with
+/// [`sort_unstable_b256`]; otherwise the result is unspecified.
+#[inline]
+pub fn contains_sorted(words: &[B256], word: &B256) -> bool {
+    binary_search_sorted(words, word).is_ok()
+}
+
+/// Binary searches the sorted slice for the given word.
+///
+/// See [`slice::binary_search`] for the meaning of the result. The slice must
+/// be sorted in ascending order; otherwise the result is unspecified.
+pub fn binary_search_sorted(words: &[B256], word: &B256) -> Result<usize, usize> {
+    let key = be_limbs(word);
+    words.binary_search_by(|probe| be_limbs(probe).cmp(&key))
+}
+
+/// Moves the unique elements of the sorted slice to its front, and returns
+/// their number.
+///
+/// The order of the unique elements is preserved; the remaining elements are
+/// left in an unspecified order. Use [`Vec::truncate`] with the returned
+/// length to remove them from a vector.
+///
+/// The slice must be sorted, or at least have all of its equal elements
+/// adjacent to each other, like for [`Vec::dedup`].
+///
+/// [`Vec::truncate`]: alloc::vec::Vec::truncate
+/// [`Vec::dedup`]: alloc::vec::Vec::dedup
+pub fn dedup_sorted(words: &mut [B256]) -> usize {
+    // nothing needs to be moved until the first duplicate
+    let Some(first) = words.windows(2).position(|w| w[0] == w[1]) else {
+        return words.len();
+    };
+    let mut len = first + 1;
+    for i in first + 2..words.len() {
+        if words[i] != words[len - 1] {
+            words[len] = words[i];
+            len += 1;
+        }
+    }
+    len
+}
+
+/// Compares two words like the derived [`Ord`], as big-endian limbs.
+#[inline]
+pub fn cmp_b256(a: &B256, b: &B256) -> Ordering {
+    be_limbs(a).cmp(&be_limbs(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keccak256;
+    use alloc::vec::Vec;
+
+    fn hashes(n: u64) -> Vec<B256> {
+        (0..n).map(|i| keccak256(i.to_be_bytes())).collect()
+    }
+
+    fn assert_sorts(mut words: Vec<B256>) {
+        let mut expected = words.clone();
+        expected.sort_unstable();
+        sort_unstable_b256(&mut words);
+        assert_eq!(words, expected);
+    }
+
+    #[test]
+    fn sort() {
+        assert_sorts(Vec::new());
+        assert_sorts(vec![B256::ZERO]);
+        assert_sorts(hashes(10));
+        assert_sorts(hashes(10_000));
+
+        // duplicates
+        let mut words = hashes(1000);
+        words.extend_from_within(..);
+        words.extend_from_within(..500);
+        assert_sorts(words);
+
+        // all equal
+        assert_sorts(vec![B256::repeat_byte(0x42); 1000]);
+
+        // shared prefixes, like left-padded addresses and small integers
+        let padded = hashes(1000).into_iter().map(|h| {
+            let mut word = B256::ZERO;
+            word[12..].copy_from_slice(&h[12..]);
+            word
+        });
+        assert_sorts(padded.collect());
+        assert_sorts(
+            (0..1000u64)
+                .rev()
+                .map(|i| B256::from(crate::U256::from(i)))
+                .collect(),
+        );
+    }
+
+    #[test]
+    fn search_and_dedup() {
+        let mut words = hashes(1000);
+        words.extend_from_within(..);
+        sort_unstable_b256(&mut words);
+
+        for hash in hashes(1000) {
+            assert!(contains_sorted(&words, &hash));
+        }
+        assert!(!contains_sorted(&words, &keccak256(1000u64.to_be_bytes())));
+        assert!(!contains_sorted(&[], &B256::ZERO));
+
+        let len = dedup_sorted(&mut words);
+        assert_eq!(len, 1000);
+        let mut expected = hashes(1000);
+        expected.sort_unstable();
+        assert_eq!(words[..len], expected[..]);
+        assert_eq!(binary_search_sorted(&words[..len], &expected[10]), Ok(10));
+
+        assert_eq!(dedup_sorted(&mut []), 0);
+        assert_eq!(dedup_sorted(&mut [B256::ZERO; 3]), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "proptest")]
+    fn sort_agrees_with_ord() {
+        use crate::strategy::{fixed_bytes, ByteDistribution};
+        use proptest::collection::vec;
+
+        let words = |distribution| vec(fixed_bytes::<32>(distribution), 0..1000);
+        for distribution in [ByteDistribution::Uniform, ByteDistribution::Edge] {
+            proptest::proptest!(|(mut words in words(distribution), a: B256, b: B256)| {
+                proptest::prop_assert_eq!(cmp_b256(&a, &b), a.cmp(&b));
+
+                let mut expected = words.clone();
+                expected.sort_unstable();
+                sort_unstable_b256(&mut words);
+                proptest::prop_assert_eq!(&words, &expected);
+
+                expected.dedup();
+                let len = dedup_sorted(&mut words);
+                proptest::prop_assert_eq!(&words[..len], &expected[..]);
+                proptest::prop_assert!(contains_sorted(&words[..len], &a) == expected.contains(&a));
+            });
+        }
+    }
+}
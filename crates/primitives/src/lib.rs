@@ -19,36 +19,75 @@
 #[macro_use]
 extern crate alloc;
 
+// The code generated by `proptest-derive` refers to `::std`.
+#[cfg(all(feature = "proptest", not(feature = "std")))]
+extern crate std;
+
+// Overridden by `tiny-keccak`.
+#[cfg(all(feature = "asm-keccak", feature = "tiny-keccak"))]
+use keccak_asm as _;
+
+#[cfg(all(
+    feature = "native-keccak",
+    feature = "asm-keccak",
+    not(feature = "tiny-keccak")
+))]
+compile_error!("the `native-keccak` and `asm-keccak` features are mutually exclusive");
+
 // Used in Serde tests.
 #[cfg(test)]
-use {bincode as _, serde as _, serde_json as _};
+use {::serde as _, bincode as _, serde_json as _};
+
+pub mod aggregate;
 
 pub mod aliases;
 #[doc(no_inline)]
 pub use aliases::{
-    BlockHash, BlockNumber, ChainId, Selector, StorageKey, StorageValue, TxHash, TxIndex, TxNumber,
-    B128, B256, B512, B64, I128, I16, I160, I256, I32, I64, I8, U128, U16, U160, U256, U32, U512,
-    U64, U8,
+    BlockHash, BlockNumber, ChainId, LossyF64, ParseUint, Selector, StorageKey, StorageValue,
+    TxHash, TxIndex, TxNumber, UintTryFrom, UintTryTo, B128, B256, B512, B64, I128, I16, I160,
+    I256, I32, I64, I8, U128, U16, U160, U256, U32, U512, U64, U8,
 };
 
 mod bits;
 pub use bits::{
-    Address, AddressError, Bloom, BloomInput, FixedBytes, Function, BLOOM_BITS_PER_ITEM,
-    BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES,
+    pack_2098, unpack_2098, Address, AddressError, Bloom, BloomInput, ChecksumVariant,
+    Eip2098Error, FixedBytes, Function, IntoAddress, InvalidLengthError, BLOOM_BITS_PER_ITEM,
+    BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES, SECP256K1N_HALF,
 };
 
+pub mod fixed_bytes;
+
 #[path = "bytes/mod.rs"]
 mod bytes_;
 pub use self::bytes_::Bytes;
 
+#[cfg(feature = "base64")]
+#[path = "base64.rs"]
+mod base64_;
+#[cfg(feature = "base64")]
+pub use base64_::{Base64Error, FromBase64};
+
 #[cfg(feature = "getrandom")]
 mod impl_core;
 
+mod math;
+pub use math::MulDiv;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
 mod signed;
 pub use signed::{BigIntConversionError, ParseSignedError, Sign, Signed};
 
-mod utils;
-pub use utils::{eip191_hash_message, keccak256};
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+pub mod utils;
+#[cfg(feature = "ripemd")]
+pub use utils::ripemd160;
+#[cfg(feature = "sha2")]
+pub use utils::sha256;
+pub use utils::{eip191_hash_message, keccak256, keccak256_const, keccak256_slices, Keccak256};
 
 #[doc(no_inline)]
 pub use ::bytes;
@@ -65,6 +104,13 @@ pub use ruint::{self, Uint};
 #[doc(no_inline)]
 pub use tiny_keccak::{self, Hasher, Keccak};
 
+#[cfg(feature = "ripemd")]
+#[doc(no_inline)]
+pub use ripemd::{self, Ripemd160};
+#[cfg(feature = "sha2")]
+#[doc(no_inline)]
+pub use sha2::{self, Sha256};
+
 #[cfg(feature = "serde")]
 #[doc(no_inline)]
 pub use ::hex::serde as serde_hex;
@@ -96,18 +142,32 @@ pub mod private {
     };
     pub use derive_more;
 
+    pub use crate::bits::{hex_decode, hex_len};
+
     #[cfg(feature = "getrandom")]
     pub use getrandom;
 
     #[cfg(feature = "rand")]
     pub use rand;
 
+    #[cfg(feature = "borsh")]
+    pub use borsh;
+
+    #[cfg(feature = "postgres")]
+    pub use {alloc::boxed::Box, postgres_types, std::error::Error as StdError};
+
     #[cfg(feature = "rlp")]
     pub use alloy_rlp;
 
     #[cfg(feature = "serde")]
     pub use serde;
 
+    #[cfg(feature = "ssz")]
+    pub use {alloc::vec::Vec, ssz};
+
     #[cfg(feature = "arbitrary")]
-    pub use {arbitrary, derive_arbitrary, proptest, proptest_derive};
+    pub use {arbitrary, derive_arbitrary};
+
+    #[cfg(feature = "proptest")]
+    pub use {proptest, proptest_derive};
 }
@@ -0,0 +1,270 @@
+use crate::{aliases::U1024, U128, U256, U512, U64};
+use ruint::UintTryFrom;
+
+/// `a * b / c` with a double-width intermediate product.
+///
+/// This is an [extension trait](crate::aliases#extension-traits) for [`U64`],
+/// [`U128`], [`U256`] and [`U512`].
+///
+/// The product is computed at twice the bit width, so only the final
+/// quotient has to fit in `Self`:
+///
+/// ```
+/// use alloy_primitives::{MulDiv, U256};
+///
+/// let amount = U256::MAX;
+/// assert_eq!(amount.checked_mul(U256::from(3)), None);
+/// assert_eq!(
+///     amount.checked_mul_div(U256::from(3), U256::from(3)),
+///     Some(amount)
+/// );
+/// assert_eq!(amount.checked_mul_div(U256::from(4), U256::from(3)), None);
+/// assert_eq!(
+///     U256::from(10).mul_div(U256::from(3), U256::from(4)),
+///     U256::from(7)
+/// );
+/// assert_eq!(
+///     U256::from(10).mul_div_rounding_up(U256::from(3), U256::from(4)),
+///     U256::from(8)
+/// );
+/// ```
+///
+/// The `wad_*` methods operate on 18-decimal fixed-point numbers, where
+/// [`WAD`](MulDiv::WAD) represents `1.0`:
+///
+/// ```
+/// use alloy_primitives::{utils::parse_ether, MulDiv};
+///
+/// let price = parse_ether("1.5").unwrap();
+/// let amount = parse_ether("2").unwrap();
+/// assert_eq!(amount.wad_mul(price), parse_ether("3").unwrap());
+/// assert_eq!(
+///     amount.wad_div(price),
+///     parse_ether("1.333333333333333333").unwrap()
+/// );
+/// ```
+pub trait MulDiv: Sized {
+    /// `10^18`, the fixed-point representation of `1.0` in the `wad_*`
+    /// methods.
+    const WAD: Self;
+
+    /// Computes `self * b / c`, rounding down. Returns `None` if `c` is zero
+    /// or if the quotient does not fit in `Self`.
+    fn checked_mul_div(self, b: Self, c: Self) -> Option<Self>;
+
+    /// Computes `self * b / c`, rounding down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `c` is zero or if the quotient does not fit in `Self`.
+    #[inline]
+    #[track_caller]
+    fn mul_div(self, b: Self, c: Self) -> Self {
+        self.checked_mul_div(b, c)
+            .expect("mul_div: division by zero or overflow")
+    }
+
+    /// Computes `self * b / c`, rounding up. Returns `None` if `c` is zero or
+    /// if the quotient does not fit in `Self`.
+    fn checked_mul_div_rounding_up(self, b: Self, c: Self) -> Option<Self>;
+
+    /// Computes `self * b / c`, rounding up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `c` is zero or if the quotient does not fit in `Self`.
+    #[inline]
+    #[track_caller]
+    fn mul_div_rounding_up(self, b: Self, c: Self) -> Self {
+        self.checked_mul_div_rounding_up(b, c)
+            .expect("mul_div_rounding_up: division by zero or overflow")
+    }
+
+    /// Multiplies two fixed-point numbers: `self * b / WAD`, rounding down.
+    /// Returns `None` if the result does not fit in `Self`.
+    #[inline]
+    fn checked_wad_mul(self, b: Self) -> Option<Self> {
+        self.checked_mul_div(b, Self::WAD)
+    }
+
+    /// Multiplies two fixed-point numbers: `self * b / WAD`, rounding down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in `Self`.
+    #[inline]
+    #[track_caller]
+    fn wad_mul(self, b: Self) -> Self {
+        self.checked_wad_mul(b).expect("wad_mul: overflow")
+    }
+
+    /// Divides two fixed-point numbers: `self * WAD / b`, rounding down.
+    /// Returns `None` if `b` is zero or if the result does not fit in `Self`.
+    #[inline]
+    fn checked_wad_div(self, b: Self) -> Option<Self> {
+        self.checked_mul_div(Self::WAD, b)
+    }
+
+    /// Divides two fixed-point numbers: `self * WAD / b`, rounding down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` is zero or if the result does not fit in `Self`.
+    #[inline]
+    #[track_caller]
+    fn wad_div(self, b: Self) -> Self {
+        self.checked_wad_div(b)
+            .expect("wad_div: division by zero or overflow")
+    }
+}
+
+macro_rules! impl_mul_div {
+    ($($t:ty => $wide:ty),+ $(,)?) => {$(
+        impl MulDiv for $t {
+            const WAD: Self = {
+                let mut limbs = [0; Self::LIMBS];
+                limbs[0] = 1_000_000_000_000_000_000;
+                Self::from_limbs(limbs)
+            };
+
+            #[inline]
+            fn checked_mul_div(self, b: Self, c: Self) -> Option<Self> {
+                if c == Self::ZERO {
+                    return None
+                }
+                let product: $wide = self.widening_mul(b);
+                Self::uint_try_from(product / <$wide>::from(c)).ok()
+            }
+
+            #[inline]
+            fn checked_mul_div_rounding_up(self, b: Self, c: Self) -> Option<Self> {
+                if c == Self::ZERO {
+                    return None
+                }
+                let product: $wide = self.widening_mul(b);
+                let (quotient, remainder) = product.div_rem(<$wide>::from(c));
+                // cannot overflow, as the product is at most `(2^BITS - 1)^2`
+                let quotient = quotient + <$wide>::from(remainder != <$wide>::ZERO);
+                Self::uint_try_from(quotient).ok()
+            }
+        }
+    )+};
+}
+
+impl_mul_div!(U64 => U128, U128 => U256, U256 => U512, U512 => U1024);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn u256_boundaries() {
+        let max = U256::MAX;
+        let two = U256::from(2);
+        assert_eq!(max.checked_mul_div(max, max), Some(max));
+        assert_eq!(max.checked_mul_div(two, two), Some(max));
+        assert_eq!(max.checked_mul_div(two, max), Some(two));
+        assert_eq!(max.checked_mul_div(two, U256::from(1)), None);
+        assert_eq!(max.checked_mul_div(max, U256::ZERO), None);
+        assert_eq!(U256::ZERO.checked_mul_div(max, U256::ZERO), None);
+
+        // 2^255 * 3 / 2 = 3 * 2^254
+        let half = U256::from(1) << 255usize;
+        assert_eq!(half.mul_div(U256::from(3), two), U256::from(3) << 254usize);
+    }
+
+    // https://github.com/Uniswap/v3-core/blob/main/test/FullMath.spec.ts
+    #[test]
+    fn full_math_reference() {
+        let q128 = U256::from(1) << 128usize;
+        let max = U256::MAX;
+        let n = |x: u64| U256::from(x);
+
+        // mulDiv
+        assert_eq!(q128.checked_mul_div(q128, U256::ZERO), None);
+        assert_eq!(q128.checked_mul_div(q128, n(1)), None);
+        assert_eq!(max.checked_mul_div(max, max), Some(max));
+        let third = q128 / n(3);
+        assert_eq!(
+            q128.mul_div(n(50) * q128 / n(100), n(150) * q128 / n(100)),
+            third
+        );
+        assert_eq!(
+            q128.mul_div(n(35) * q128, n(8) * q128),
+            n(4375) * q128 / n(1000)
+        );
+        assert_eq!(q128.mul_div(n(1000) * q128, n(3000) * q128), third);
+
+        // mulDivRoundingUp
+        assert_eq!(q128.checked_mul_div_rounding_up(q128, U256::ZERO), None);
+        assert_eq!(q128.checked_mul_div_rounding_up(q128, n(1)), None);
+        assert_eq!(max.checked_mul_div_rounding_up(max, max), Some(max));
+        assert_eq!(max.checked_mul_div_rounding_up(max, max - n(1)), None);
+        let a = n(535006138814359);
+        let b = "432862656469423142931042426214547535783388063929571229938474969"
+            .parse::<U256>()
+            .unwrap();
+        assert_eq!(a.checked_mul_div(b, n(2)), Some(max));
+        assert_eq!(a.checked_mul_div_rounding_up(b, n(2)), None);
+        let third_up = third + n(1);
+        assert_eq!(
+            q128.mul_div_rounding_up(n(50) * q128 / n(100), n(150) * q128 / n(100)),
+            third_up
+        );
+        assert_eq!(
+            q128.mul_div_rounding_up(n(35) * q128, n(8) * q128),
+            n(4375) * q128 / n(1000)
+        );
+        assert_eq!(
+            q128.mul_div_rounding_up(n(1000) * q128, n(3000) * q128),
+            third_up
+        );
+    }
+
+    #[test]
+    fn wad() {
+        assert_eq!(U64::WAD, U64::from(10u64.pow(18)));
+        assert_eq!(U256::WAD, U256::from(10u64.pow(18)));
+        assert_eq!(U512::WAD, U512::from(10u64.pow(18)));
+
+        let wad = U256::WAD;
+        let n = |x: u64| U256::from(x);
+        assert_eq!(n(3).wad_mul(wad), n(3));
+        assert_eq!(n(3).wad_div(wad), n(3));
+        assert_eq!((wad / n(2)).wad_mul(wad / n(2)), wad / n(4));
+        assert_eq!(wad.wad_div(n(3) * wad), n(333_333_333_333_333_333));
+        assert_eq!(n(1).wad_mul(n(1)), U256::ZERO);
+
+        assert_eq!(U256::MAX.checked_wad_mul(wad), Some(U256::MAX));
+        assert_eq!(U256::MAX.checked_wad_mul(wad + n(1)), None);
+        assert_eq!(U256::MAX.checked_wad_div(wad), Some(U256::MAX));
+        assert_eq!(U256::MAX.checked_wad_div(wad - n(1)), None);
+        assert_eq!(wad.checked_wad_div(U256::ZERO), None);
+    }
+
+    #[test]
+    #[should_panic = "mul_div: division by zero or overflow"]
+    fn mul_div_panics() {
+        U256::MAX.mul_div(U256::from(2), U256::from(1));
+    }
+
+    #[test]
+    #[cfg(feature = "proptest")]
+    fn u64_reference() {
+        use proptest::prelude::*;
+
+        proptest!(|(a: u64, b: u64, c: u64)| {
+            let expected = (a as u128 * b as u128).checked_div(c as u128);
+            let expected = expected.and_then(|x| u64::try_from(x).ok()).map(U64::from);
+            let actual = U64::from(a).checked_mul_div(U64::from(b), U64::from(c));
+            prop_assert_eq!(actual, expected);
+
+            let product = a as u128 * b as u128;
+            let expected = product.checked_div(c as u128);
+            let expected = expected.map(|x| x + (product % c as u128 != 0) as u128);
+            let expected = expected.and_then(|x| u64::try_from(x).ok()).map(U64::from);
+            let actual = U64::from(a).checked_mul_div_rounding_up(U64::from(b), U64::from(c));
+            prop_assert_eq!(actual, expected);
+        });
+    }
+}
@@ -0,0 +1,795 @@
+//! Helpers for use with `#[serde(with = "...")]`.
+//!
+//! The Ethereum JSON-RPC API distinguishes between two hex encodings:
+//! - *quantities* are integers encoded as the shortest possible `0x`-prefixed
+//!   hex string: `"0x0"`, `"0x1"`, `"0x400"`. Leading zeros are not allowed,
+//!   and the empty string `"0x"` is not a valid quantity;
+//! - *data* are byte arrays encoded as `0x`-prefixed hex strings with two hex
+//!   digits per byte: `"0x"`, `"0x00"`, `"0x0400"`.
+//!
+//! The [`quantity`] module implements the former for [`Quantity`] types (`u64`,
+//! `u128` and [`Uint`]), and the [`data`] module implements the latter for any
+//! byte array, such as [`Bytes`](crate::Bytes) and
+//! [`FixedBytes`](crate::FixedBytes).
+//!
+//! Some APIs use decimal strings for large numbers instead, such as
+//! `"10000000000000000"`. The [`decimal`] module serializes [`Quantity`] types
+//! in this form, and accepts decimal strings, hex quantities and JSON numbers
+//! when deserializing.
+//!
+//! Byte arrays that are base64 strings in JSON can use the [`base64`] module,
+//! with the `base64` feature.
+//!
+//! JSON numbers that do not fit in a `u128` are only supported with
+//! `serde_json`'s `arbitrary_precision` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{bytes, Bytes, U256};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Transaction {
+//!     #[serde(with = "alloy_primitives::serde::quantity")]
+//!     nonce: u64,
+//!     #[serde(with = "alloy_primitives::serde::quantity")]
+//!     value: U256,
+//!     #[serde(with = "alloy_primitives::serde::quantity::opt")]
+//!     gas_price: Option<u128>,
+//!     #[serde(with = "alloy_primitives::serde::data")]
+//!     input: Bytes,
+//! }
+//!
+//! let tx = Transaction {
+//!     nonce: 0,
+//!     value: U256::from(1024),
+//!     gas_price: None,
+//!     input: bytes!("0400"),
+//! };
+//! let json = r#"{"nonce":"0x0","value":"0x400","gas_price":null,"input":"0x0400"}"#;
+//! assert_eq!(serde_json::to_string(&tx).unwrap(), json);
+//! assert_eq!(serde_json::from_str::<Transaction>(json).unwrap(), tx);
+//!
+//! // Quantities may also be plain JSON numbers.
+//! let json = r#"{"nonce":0,"value":1024,"gas_price":null,"input":"0x0400"}"#;
+//! assert_eq!(serde_json::from_str::<Transaction>(json).unwrap(), tx);
+//!
+//! // But not hex strings with leading zeros.
+//! let json = r#"{"nonce":"0x00","value":"0x400","gas_price":null,"input":"0x0400"}"#;
+//! assert!(serde_json::from_str::<Transaction>(json).is_err());
+//! ```
+
+use crate::Uint;
+use alloc::string::String;
+use core::{fmt, marker::PhantomData};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Integer types supported by the [`quantity`] module.
+///
+/// This trait is sealed and implemented for `u64`, `u128` and [`Uint`].
+pub trait Quantity: fmt::LowerHex + fmt::Display + Sized + sealed::Sealed {
+    #[doc(hidden)]
+    fn from_hex_digits(digits: &str) -> Option<Self>;
+    #[doc(hidden)]
+    fn from_dec_digits(digits: &str) -> Option<Self>;
+    #[doc(hidden)]
+    fn from_u128(value: u128) -> Option<Self>;
+}
+
+#[cfg(feature = "base64")]
+pub use crate::base64_::serde_base64 as base64;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for u64 {}
+    impl Sealed for u128 {}
+    impl<const BITS: usize, const LIMBS: usize> Sealed for super::Uint<BITS, LIMBS> {}
+}
+
+impl Quantity for u64 {
+    #[inline]
+    fn from_hex_digits(digits: &str) -> Option<Self> {
+        Self::from_str_radix(digits, 16).ok()
+    }
+
+    #[inline]
+    fn from_dec_digits(digits: &str) -> Option<Self> {
+        Self::from_str_radix(digits, 10).ok()
+    }
+
+    #[inline]
+    fn from_u128(value: u128) -> Option<Self> {
+        value.try_into().ok()
+    }
+}
+
+impl Quantity for u128 {
+    #[inline]
+    fn from_hex_digits(digits: &str) -> Option<Self> {
+        Self::from_str_radix(digits, 16).ok()
+    }
+
+    #[inline]
+    fn from_dec_digits(digits: &str) -> Option<Self> {
+        Self::from_str_radix(digits, 10).ok()
+    }
+
+    #[inline]
+    fn from_u128(value: u128) -> Option<Self> {
+        Some(value)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Quantity for Uint<BITS, LIMBS> {
+    #[inline]
+    fn from_hex_digits(digits: &str) -> Option<Self> {
+        Self::from_str_radix(digits, 16).ok()
+    }
+
+    #[inline]
+    fn from_dec_digits(digits: &str) -> Option<Self> {
+        Self::from_str_radix(digits, 10).ok()
+    }
+
+    #[inline]
+    fn from_u128(value: u128) -> Option<Self> {
+        value.try_into().ok()
+    }
+}
+
+/// Parses a `0x`-prefixed hex quantity.
+///
+/// In strict mode, leading zeros and the empty quantity `"0x"` are rejected.
+/// Otherwise, both are accepted, and `"0x"` is parsed as zero.
+fn parse_quantity<T: Quantity>(s: &str, strict: bool) -> Result<T, &'static str> {
+    let digits = s.strip_prefix("0x").ok_or("missing 0x prefix")?;
+    if digits.is_empty() {
+        return if strict {
+            Err("empty hex string")
+        } else {
+            T::from_u128(0).ok_or("overflow")
+        }
+    }
+    if strict && digits.len() > 1 && digits.starts_with('0') {
+        return Err("leading zeros are not allowed")
+    }
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err("invalid hex digit")
+    }
+    T::from_hex_digits(digits).ok_or("number too large to fit in target type")
+}
+
+/// Parses a decimal number. Leading zeros are accepted.
+fn parse_decimal<T: Quantity>(s: &str) -> Result<T, &'static str> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("invalid decimal number")
+    }
+    T::from_dec_digits(s).ok_or("number too large to fit in target type")
+}
+
+/// The key of the map that `serde_json` deserializes numbers as with its
+/// `arbitrary_precision` feature.
+const SERDE_JSON_NUMBER: &str = "$serde_json::private::Number";
+
+/// Serializes a reference to a quantity.
+struct QuantityRef<'a, T>(&'a T);
+
+impl<T: Quantity> Serialize for QuantityRef<'_, T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:#x}", self.0))
+    }
+}
+
+/// Serializes a reference to a quantity as a decimal string.
+struct DecimalRef<'a, T>(&'a T);
+
+impl<T: Quantity> Serialize for DecimalRef<'_, T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self.0)
+    }
+}
+
+/// Deserializes a quantity, either strictly or leniently, and optionally from
+/// a decimal string.
+struct QuantityDe<T, const STRICT: bool, const DECIMAL: bool = false>(T);
+
+impl<'de, T: Quantity, const STRICT: bool, const DECIMAL: bool> Deserialize<'de>
+    for QuantityDe<T, STRICT, DECIMAL>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct QuantityVisitor<T, const STRICT: bool, const DECIMAL: bool>(PhantomData<T>);
+
+        impl<'de, T: Quantity, const STRICT: bool, const DECIMAL: bool> de::Visitor<'de>
+            for QuantityVisitor<T, STRICT, DECIMAL>
+        {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if DECIMAL {
+                    formatter.write_str(
+                        "a decimal string, a 0x-prefixed hex quantity or a non-negative integer",
+                    )
+                } else {
+                    formatter.write_str("a 0x-prefixed hex quantity or a non-negative integer")
+                }
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                self.visit_u128(v as u128)
+            }
+
+            fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+                T::from_u128(v).ok_or_else(|| {
+                    de::Error::custom(format_args!(
+                        "invalid quantity {v}: number too large to fit in target type"
+                    ))
+                })
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                let res = if DECIMAL && !v.starts_with("0x") {
+                    parse_decimal(v)
+                } else {
+                    parse_quantity(v, STRICT)
+                };
+                res.map_err(|e| de::Error::custom(format_args!("invalid quantity {v:?}: {e}")))
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                if map.next_key::<String>()?.as_deref() != Some(SERDE_JSON_NUMBER) {
+                    return Err(de::Error::invalid_type(de::Unexpected::Map, &self))
+                }
+                let v = map.next_value::<String>()?;
+                parse_decimal(&v)
+                    .map_err(|e| de::Error::custom(format_args!("invalid quantity {v}: {e}")))
+            }
+        }
+
+        let visitor = QuantityVisitor::<T, STRICT, DECIMAL>(PhantomData);
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor).map(Self)
+        } else {
+            deserializer.deserialize_str(visitor).map(Self)
+        }
+    }
+}
+
+/// (De)serialize integers as JSON-RPC quantities.
+///
+/// Values are serialized as minimal `0x`-prefixed hex strings. Both hex
+/// strings and non-negative JSON numbers are accepted when deserializing, but
+/// hex strings with leading zeros and the empty hex string `"0x"` are rejected.
+/// See [`lenient`](quantity::lenient) to accept those as well.
+///
+/// See the [module-level documentation](crate::serde) for more details.
+pub mod quantity {
+    use super::{Quantity, QuantityDe, QuantityRef};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes a value as a hex quantity.
+    pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Quantity,
+    {
+        QuantityRef(value).serialize(serializer)
+    }
+
+    /// Deserializes a value from a hex quantity or a JSON number.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Quantity,
+    {
+        QuantityDe::<T, true>::deserialize(deserializer).map(|q| q.0)
+    }
+
+    /// (De)serialize optional integers as JSON-RPC quantities.
+    ///
+    /// `None` is serialized as `null`.
+    pub mod opt {
+        use super::{Quantity, QuantityDe, QuantityRef};
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        /// Serializes an optional value as a hex quantity.
+        pub fn serialize<S, T>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+            T: Quantity,
+        {
+            match value {
+                Some(value) => serializer.serialize_some(&QuantityRef(value)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        /// Deserializes an optional value from a hex quantity or a JSON number.
+        pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Quantity,
+        {
+            Option::<QuantityDe<T, true>>::deserialize(deserializer).map(|q| q.map(|q| q.0))
+        }
+    }
+
+    /// (De)serialize sequences of integers as JSON-RPC quantities.
+    pub mod vec {
+        use super::{Quantity, QuantityDe, QuantityRef};
+        use alloc::vec::Vec;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        /// Serializes a sequence of values as hex quantities.
+        pub fn serialize<S, T>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+            T: Quantity,
+        {
+            serializer.collect_seq(values.iter().map(QuantityRef))
+        }
+
+        /// Deserializes a sequence of values from hex quantities or JSON
+        /// numbers.
+        pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Quantity,
+        {
+            let values = Vec::<QuantityDe<T, true>>::deserialize(deserializer)?;
+            Ok(values.into_iter().map(|q| q.0).collect())
+        }
+    }
+
+    /// (De)serialize integers as JSON-RPC quantities, accepting non-canonical
+    /// hex strings.
+    ///
+    /// Like [`quantity`](super::quantity), but hex strings with leading zeros
+    /// are accepted when deserializing, and `"0x"` is parsed as zero.
+    /// Serialization is unchanged.
+    pub mod lenient {
+        use super::{Quantity, QuantityDe, QuantityRef};
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        /// Serializes a value as a hex quantity.
+        pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+            T: Quantity,
+        {
+            QuantityRef(value).serialize(serializer)
+        }
+
+        /// Deserializes a value from a possibly non-canonical hex quantity or a
+        /// JSON number.
+        pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Quantity,
+        {
+            QuantityDe::<T, false>::deserialize(deserializer).map(|q| q.0)
+        }
+    }
+}
+
+/// (De)serialize integers as decimal strings.
+///
+/// Values are serialized as decimal strings, such as `"10000000000000000"`.
+/// Decimal strings, hex quantities and non-negative JSON numbers are all
+/// accepted when deserializing. Leading zeros are accepted in both decimal
+/// strings and hex quantities.
+///
+/// See the [module-level documentation](crate::serde) for more details.
+pub mod decimal {
+    use super::{DecimalRef, Quantity, QuantityDe};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes a value as a decimal string.
+    pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Quantity,
+    {
+        DecimalRef(value).serialize(serializer)
+    }
+
+    /// Deserializes a value from a decimal string, a hex quantity or a JSON
+    /// number.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Quantity,
+    {
+        QuantityDe::<T, false, true>::deserialize(deserializer).map(|q| q.0)
+    }
+
+    /// (De)serialize optional integers as decimal strings.
+    ///
+    /// `None` is serialized as `null`.
+    pub mod opt {
+        use super::{DecimalRef, Quantity, QuantityDe};
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        /// Serializes an optional value as a decimal string.
+        pub fn serialize<S, T>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+            T: Quantity,
+        {
+            match value {
+                Some(value) => serializer.serialize_some(&DecimalRef(value)),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        /// Deserializes an optional value from a decimal string, a hex
+        /// quantity or a JSON number.
+        pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+        where
+            D: Deserializer<'de>,
+            T: Quantity,
+        {
+            Option::<QuantityDe<T, false, true>>::deserialize(deserializer).map(|q| q.map(|q| q.0))
+        }
+    }
+}
+
+/// (De)serialize byte arrays as JSON-RPC data.
+///
+/// Values are serialized as `0x`-prefixed hex strings with two digits per byte,
+/// regardless of whether the format is human-readable. When deserializing, the
+/// `0x` prefix is required, and the hex string must have an even length.
+///
+/// See the [module-level documentation](crate::serde) for more details.
+pub mod data {
+    use alloc::borrow::Cow;
+    use core::fmt;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    /// Serializes the bytes as a `0x`-prefixed hex string.
+    pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: AsRef<[u8]>,
+    {
+        serializer.serialize_str(&hex::encode_prefixed(value))
+    }
+
+    /// Deserializes a `0x`-prefixed hex string.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: hex::FromHex,
+        T::Error: fmt::Display,
+    {
+        let s = <Cow<'de, str>>::deserialize(deserializer)?;
+        if !s.starts_with("0x") {
+            return Err(de::Error::custom(format_args!(
+                "invalid data {s:?}: missing 0x prefix"
+            )))
+        }
+        T::from_hex(&*s).map_err(|e| de::Error::custom(format_args!("invalid data {s:?}: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bytes, FixedBytes, U256};
+    use alloc::{string::ToString, vec::Vec};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Quantities {
+        #[serde(with = "quantity")]
+        a: u64,
+        #[serde(with = "quantity")]
+        b: u128,
+        #[serde(with = "quantity")]
+        c: U256,
+        #[serde(with = "quantity::opt")]
+        d: Option<u64>,
+        #[serde(with = "quantity::vec")]
+        e: Vec<U256>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Strict {
+        #[serde(with = "quantity")]
+        value: U256,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Lenient {
+        #[serde(with = "quantity::lenient")]
+        value: U256,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Data {
+        #[serde(with = "data")]
+        bytes: Bytes,
+        #[serde(with = "data")]
+        fixed: FixedBytes<2>,
+    }
+
+    fn strict(s: &str) -> Result<U256, String> {
+        let json = format!(r#"{{"value":{s}}}"#);
+        serde_json::from_str::<Strict>(&json)
+            .map(|s| s.value)
+            .map_err(|e| e.to_string())
+    }
+
+    fn lenient(s: &str) -> Result<U256, String> {
+        let json = format!(r#"{{"value":{s}}}"#);
+        serde_json::from_str::<Lenient>(&json)
+            .map(|s| s.value)
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn quantity_roundtrip() {
+        let q = Quantities {
+            a: 0,
+            b: u128::MAX,
+            c: U256::MAX,
+            d: Some(1),
+            e: vec![U256::ZERO, U256::from(0x400)],
+        };
+        let json = serde_json::to_string(&q).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"a":"0x0","b":"0x{}","c":"0x{}","d":"0x1","e":["0x0","0x400"]}}"#,
+                "f".repeat(32),
+                "f".repeat(64)
+            )
+        );
+        assert_eq!(serde_json::from_str::<Quantities>(&json).unwrap(), q);
+
+        let q = Quantities {
+            d: None,
+            e: vec![],
+            ..q
+        };
+        let json = serde_json::to_string(&q).unwrap();
+        assert!(json.ends_with(r#""d":null,"e":[]}"#), "{json}");
+        assert_eq!(serde_json::from_str::<Quantities>(&json).unwrap(), q);
+
+        let json = r#"{"a":1,"b":"0xA","c":18446744073709551615,"d":2,"e":[3,"0x4"]}"#;
+        let q = Quantities {
+            a: 1,
+            b: 10,
+            c: U256::from(u64::MAX),
+            d: Some(2),
+            e: vec![U256::from(3), U256::from(4)],
+        };
+        assert_eq!(serde_json::from_str::<Quantities>(json).unwrap(), q);
+    }
+
+    #[test]
+    fn quantity_strict() {
+        assert_eq!(strict(r#""0x0""#), Ok(U256::ZERO));
+        assert_eq!(strict("0"), Ok(U256::ZERO));
+        assert_eq!(strict(r#""0x1""#), Ok(U256::from(1)));
+        assert_eq!(strict(r#""0xdEaD""#), Ok(U256::from(0xdead)));
+
+        for (s, err) in [
+            (r#""0x""#, "empty hex string"),
+            (r#""0x00""#, "leading zeros are not allowed"),
+            (r#""0x01""#, "leading zeros are not allowed"),
+            (r#""1""#, "missing 0x prefix"),
+            (r#""""#, "missing 0x prefix"),
+            (r#""0X1""#, "missing 0x prefix"),
+            (r#""0x+1""#, "invalid hex digit"),
+            (r#""0xg""#, "invalid hex digit"),
+        ] {
+            let e = strict(s).unwrap_err();
+            assert!(e.contains(err), "{s}: {e}");
+        }
+
+        let too_large = format!(r#""0x1{}""#, "0".repeat(64));
+        let e = strict(&too_large).unwrap_err();
+        assert!(e.contains("number too large to fit in target type"), "{e}");
+
+        for s in ["-1", "1.0", "true", "null"] {
+            assert!(strict(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn quantity_lenient() {
+        assert_eq!(lenient(r#""0x""#), Ok(U256::ZERO));
+        assert_eq!(lenient(r#""0x0""#), Ok(U256::ZERO));
+        assert_eq!(lenient(r#""0x00""#), Ok(U256::ZERO));
+        assert_eq!(lenient(r#""0x0001""#), Ok(U256::from(1)));
+        assert_eq!(lenient("1"), Ok(U256::from(1)));
+        assert!(lenient(r#""1""#).unwrap_err().contains("missing 0x prefix"));
+        assert!(lenient(r#""0xg""#)
+            .unwrap_err()
+            .contains("invalid hex digit"));
+
+        let padded = format!(r#""0x{}1""#, "0".repeat(64));
+        assert_eq!(lenient(&padded), Ok(U256::from(1)));
+
+        let json = serde_json::to_string(&Lenient {
+            value: U256::from(1),
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"value":"0x1"}"#);
+    }
+
+    #[test]
+    fn quantity_overflow() {
+        #[derive(Debug, Deserialize)]
+        struct Small {
+            #[serde(with = "quantity")]
+            _value: crate::U8,
+        }
+
+        assert!(serde_json::from_str::<Small>(r#"{"_value":"0xff"}"#).is_ok());
+        assert!(serde_json::from_str::<Small>(r#"{"_value":255}"#).is_ok());
+        for json in [r#"{"_value":"0x100"}"#, r#"{"_value":256}"#] {
+            let e = serde_json::from_str::<Small>(json).unwrap_err().to_string();
+            assert!(e.contains("number too large to fit in target type"), "{e}");
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Decimal {
+        #[serde(with = "decimal")]
+        value: U256,
+        #[serde(with = "decimal::opt")]
+        opt: Option<u64>,
+    }
+
+    fn decimal(s: &str) -> Result<U256, String> {
+        let json = format!(r#"{{"value":{s},"opt":null}}"#);
+        serde_json::from_str::<Decimal>(&json)
+            .map(|s| s.value)
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn decimal_output() {
+        let value = U256::from(10_000_000_000_000_000u64);
+        let json = serde_json::to_string(&Strict { value }).unwrap();
+        assert_eq!(json, r#"{"value":"0x2386f26fc10000"}"#);
+        let json = serde_json::to_string(&Decimal {
+            value,
+            opt: Some(1),
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"value":"10000000000000000","opt":"1"}"#);
+
+        let json = serde_json::to_string(&Decimal {
+            value: U256::MAX,
+            opt: None,
+        })
+        .unwrap();
+        let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        assert_eq!(json, format!(r#"{{"value":"{max}","opt":null}}"#));
+        assert_eq!(
+            serde_json::from_str::<Decimal>(&json).unwrap().value,
+            U256::MAX
+        );
+    }
+
+    #[test]
+    fn decimal_input() {
+        let value = U256::from(10_000_000_000_000_000u64);
+        assert_eq!(decimal(r#""10000000000000000""#), Ok(value));
+        assert_eq!(decimal(r#""0x2386f26fc10000""#), Ok(value));
+        assert_eq!(decimal(r#""0x002386f26fc10000""#), Ok(value));
+        assert_eq!(decimal("10000000000000000"), Ok(value));
+        assert_eq!(decimal(r#""007""#), Ok(U256::from(7)));
+
+        assert!(decimal(r#""""#)
+            .unwrap_err()
+            .contains("invalid decimal number"));
+        assert!(decimal(r#""-1""#)
+            .unwrap_err()
+            .contains("invalid decimal number"));
+        assert!(decimal(r#""1.5""#)
+            .unwrap_err()
+            .contains("invalid decimal number"));
+        assert!(decimal(r#""0xg""#)
+            .unwrap_err()
+            .contains("invalid hex digit"));
+        assert!(decimal("-1").is_err());
+        assert!(decimal("1.5").is_err());
+
+        let too_large = format!(r#""{}0""#, U256::MAX);
+        let e = decimal(&too_large).unwrap_err();
+        assert!(e.contains("number too large to fit in target type"), "{e}");
+
+        let json = r#"{"value":"1","opt":"0x2"}"#;
+        assert_eq!(
+            serde_json::from_str::<Decimal>(json).unwrap(),
+            Decimal {
+                value: U256::from(1),
+                opt: Some(2)
+            }
+        );
+    }
+
+    #[test]
+    fn default_u256_forms() {
+        // `Uint`'s own implementation accepts all three forms as well.
+        let value = U256::from(10_000_000_000_000_000u64);
+        for json in [
+            r#""0x2386f26fc10000""#,
+            r#""10000000000000000""#,
+            "10000000000000000",
+        ] {
+            assert_eq!(serde_json::from_str::<U256>(json).unwrap(), value, "{json}");
+        }
+    }
+
+    #[test]
+    fn arbitrary_precision_number() {
+        // `serde_json` with `arbitrary_precision` deserializes numbers as a
+        // single-entry map holding the number's decimal representation.
+        use serde::de::value::{Error, MapDeserializer};
+
+        fn de(key: &str, value: &str) -> Result<U256, Error> {
+            let map = MapDeserializer::new(core::iter::once((key, value)));
+            decimal::deserialize(map)
+        }
+
+        let max = U256::MAX.to_string();
+        assert_eq!(de(SERDE_JSON_NUMBER, &max), Ok(U256::MAX));
+        let above_f64 = U256::from((1u64 << 53) + 1);
+        assert_eq!(de(SERDE_JSON_NUMBER, "9007199254740993"), Ok(above_f64));
+        assert!(de(SERDE_JSON_NUMBER, "1.5").is_err());
+        assert!(de(SERDE_JSON_NUMBER, "-1").is_err());
+        assert!(de("value", "1").is_err());
+
+        let map = MapDeserializer::<_, Error>::new(core::iter::once((SERDE_JSON_NUMBER, "10")));
+        assert_eq!(quantity::deserialize(map), Ok(U256::from(10)));
+    }
+
+    #[test]
+    fn data() {
+        let data = Data {
+            bytes: Bytes::new(),
+            fixed: FixedBytes([0x04, 0x00]),
+        };
+        let json = serde_json::to_string(&data).unwrap();
+        assert_eq!(json, r#"{"bytes":"0x","fixed":"0x0400"}"#);
+        assert_eq!(serde_json::from_str::<Data>(&json).unwrap(), data);
+
+        let json = r#"{"bytes":"0x0","fixed":"0x0400"}"#;
+        let e = serde_json::from_str::<Data>(json).unwrap_err().to_string();
+        assert!(e.contains("invalid data \"0x0\""), "{e}");
+
+        let json = r#"{"bytes":"00","fixed":"0x0400"}"#;
+        let e = serde_json::from_str::<Data>(json).unwrap_err().to_string();
+        assert!(e.contains("missing 0x prefix"), "{e}");
+
+        let json = r#"{"bytes":"0x00","fixed":"0x040000"}"#;
+        assert!(serde_json::from_str::<Data>(json).is_err());
+    }
+
+    #[test]
+    fn non_human_readable() {
+        let q = Quantities {
+            a: 1,
+            b: 2,
+            c: U256::from(3),
+            d: None,
+            e: vec![U256::from(4)],
+        };
+        let bin = bincode::serialize(&q).unwrap();
+        assert_eq!(bincode::deserialize::<Quantities>(&bin).unwrap(), q);
+
+        let data = Data {
+            bytes: crate::bytes!("0102"),
+            fixed: FixedBytes([0x04, 0x00]),
+        };
+        let bin = bincode::serialize(&data).unwrap();
+        assert_eq!(bincode::deserialize::<Data>(&bin).unwrap(), data);
+    }
+}
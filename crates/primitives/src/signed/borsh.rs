@@ -0,0 +1,18 @@
+use super::Signed;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+use ruint::Uint;
+
+// Serialized as the two's complement `Uint`, i.e. fixed-width little-endian.
+impl<const BITS: usize, const LIMBS: usize> BorshSerialize for Signed<BITS, LIMBS> {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> BorshDeserialize for Signed<BITS, LIMBS> {
+    #[inline]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Uint::deserialize_reader(reader).map(Self::from_raw)
+    }
+}
@@ -1,4 +1,5 @@
 use super::{utils::twos_complement, BigIntConversionError, ParseSignedError, Sign, Signed};
+use crate::aliases::LossyF64;
 use alloc::string::String;
 use core::str::FromStr;
 use ruint::Uint;
@@ -232,3 +233,42 @@ impl_conversions! {
     u64  [low_u64  -> low_u64,   as_u64],   i64  [low_u64  -> low_i64,   as_i64];
     usize[low_u64  -> low_usize, as_usize], isize[low_u64  -> low_isize, as_isize];
 }
+
+impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
+    /// Converts the integer to the nearest [`f64`], rounding ties to even.
+    ///
+    /// This is exact up to ±2<sup>53</sup>, and symmetric around zero.
+    #[inline]
+    pub fn to_f64_lossy(self) -> f64 {
+        let (sign, abs) = self.into_sign_and_abs();
+        let abs = abs.to_f64_lossy();
+        if sign.is_negative() {
+            -abs
+        } else {
+            abs
+        }
+    }
+
+    /// Converts an [`f64`] to the integer, like an `as` cast between
+    /// primitive types.
+    ///
+    /// The fractional part is truncated, rounding towards zero. Values that
+    /// are out of range, including infinities, saturate to [`Signed::MIN`] or
+    /// [`Signed::MAX`], and `NaN` converts to zero.
+    pub fn from_f64_lossy(value: f64) -> Self {
+        let sign = if value.is_sign_negative() {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        // `f64::abs` is not available in `core` on our MSRV
+        let abs = Uint::from_f64_lossy(f64::from_bits(value.to_bits() & !(1 << 63)));
+        if abs == Uint::ZERO {
+            return Self::ZERO;
+        }
+        Self::checked_from_sign_and_abs(sign, abs).unwrap_or(match sign {
+            Sign::Positive => Self::MAX,
+            Sign::Negative => Self::MIN,
+        })
+    }
+}
@@ -72,12 +72,23 @@ use ruint::Uint;
 /// To prevent this, we strongly recommend always prefixing hex strings with
 /// `0x` AFTER the sign (if any).
 #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
-#[cfg_attr(
-    feature = "arbitrary",
-    derive(derive_arbitrary::Arbitrary, proptest_derive::Arbitrary)
-)]
+#[cfg_attr(feature = "proptest", derive(proptest_derive::Arbitrary))]
 pub struct Signed<const BITS: usize, const LIMBS: usize>(pub(crate) Uint<BITS, LIMBS>);
 
+// Implemented manually because `derive_arbitrary` requires `std`.
+#[cfg(feature = "arbitrary")]
+impl<'a, const BITS: usize, const LIMBS: usize> arbitrary::Arbitrary<'a> for Signed<BITS, LIMBS> {
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Uint::arbitrary(u).map(Self)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Uint::<BITS, LIMBS>::size_hint(depth)
+    }
+}
+
 // formatting
 impl<const BITS: usize, const LIMBS: usize> fmt::Debug for Signed<BITS, LIMBS> {
     #[inline]
@@ -340,6 +351,9 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
 
     /// Creates a `Signed` from a sign and an absolute value. Returns the value
     /// and a bool that is true if the conversion caused an overflow.
+    ///
+    /// A negative zero is normalized to (positive) zero, and does not
+    /// overflow.
     #[inline(always)]
     pub fn overflowing_from_sign_and_abs(sign: Sign, abs: Uint<BITS, LIMBS>) -> (Self, bool) {
         let value = Self(match sign {
@@ -347,7 +361,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
             Sign::Negative => twos_complement(abs),
         });
 
-        (value, value.sign() != sign)
+        (value, value.sign() != sign && !value.is_zero())
     }
 
     /// Creates a `Signed` from an absolute value and a negative flag. Returns
@@ -605,6 +619,69 @@ mod tests {
         run_test!(I256, U256);
     }
 
+    #[test]
+    fn f64_conversion() {
+        let two_53 = 9_007_199_254_740_992.0;
+        for (int, float) in [
+            (I256::ZERO, 0.0),
+            (I256::ONE, 1.0),
+            (I256::MINUS_ONE, -1.0),
+            (I256::try_from(1i64 << 53).unwrap(), two_53),
+            (I256::try_from(-(1i64 << 53)).unwrap(), -two_53),
+        ] {
+            assert_eq!(int.to_f64_lossy(), float);
+            assert_eq!(I256::from_f64_lossy(float), int);
+        }
+
+        // rounds to nearest, ties to even, symmetrically
+        let tie = I256::try_from((1i64 << 53) + 1).unwrap();
+        assert_eq!(tie.to_f64_lossy(), two_53);
+        assert_eq!((-tie).to_f64_lossy(), -two_53);
+        let tie = I256::try_from((1i64 << 53) + 3).unwrap();
+        assert_eq!(tie.to_f64_lossy(), two_53 + 4.0);
+        assert_eq!((-tie).to_f64_lossy(), -two_53 - 4.0);
+
+        // 2^255 and -2^255
+        assert_eq!(I256::MAX.to_f64_lossy(), 5.78960446186581e76);
+        assert_eq!(I256::MIN.to_f64_lossy(), -5.78960446186581e76);
+        assert_eq!(I256::from_f64_lossy(-5.78960446186581e76), I256::MIN);
+
+        // truncates towards zero
+        assert_eq!(I256::from_f64_lossy(1.9), I256::ONE);
+        assert_eq!(I256::from_f64_lossy(-1.9), I256::MINUS_ONE);
+        assert_eq!(I256::from_f64_lossy(-0.9), I256::ZERO);
+        assert_eq!(I256::from_f64_lossy(-0.0), I256::ZERO);
+        assert_eq!(I256::from_f64_lossy(-f64::MIN_POSITIVE), I256::ZERO);
+
+        // saturates
+        assert_eq!(I256::from_f64_lossy(5.78960446186581e76), I256::MAX);
+        assert_eq!(I256::from_f64_lossy(f64::MAX), I256::MAX);
+        assert_eq!(I256::from_f64_lossy(f64::MIN), I256::MIN);
+        assert_eq!(I256::from_f64_lossy(f64::INFINITY), I256::MAX);
+        assert_eq!(I256::from_f64_lossy(f64::NEG_INFINITY), I256::MIN);
+        assert_eq!(I256::from_f64_lossy(f64::NAN), I256::ZERO);
+        assert_eq!(I256::from_f64_lossy(-f64::NAN), I256::ZERO);
+        assert_eq!(I8::from_f64_lossy(-128.9), I8::MIN);
+        assert_eq!(I8::from_f64_lossy(-129.0), I8::MIN);
+        assert_eq!(I8::from_f64_lossy(127.9), I8::MAX);
+        assert_eq!(I8::from_f64_lossy(128.0), I8::MAX);
+
+        // agrees with the primitive casts
+        for x in [-1e20, -129.5, -3.5, -0.5, 0.5, 2.5, 126.99, 1e10, 1.5e19] {
+            assert_eq!(I8::from_f64_lossy(x).as_i8(), x as i8, "{x}");
+            assert_eq!(I64::from_f64_lossy(x).as_i64(), x as i64, "{x}");
+            assert_eq!(
+                i128::try_from(I128::from_f64_lossy(x)),
+                Ok(x as i128),
+                "{x}"
+            );
+            assert_eq!(
+                I128::try_from(x as i128).unwrap().to_f64_lossy(),
+                x as i128 as f64
+            );
+        }
+    }
+
     #[test]
     fn from_dec_str() {
         macro_rules! run_test {
@@ -715,6 +792,56 @@ mod tests {
         run_test!(I256, U256);
     }
 
+    #[test]
+    fn sign_and_abs() {
+        let abs = U256::from(42);
+        for sign in [Sign::Positive, Sign::Negative] {
+            let value = I256::checked_from_sign_and_abs(sign, abs).unwrap();
+            assert_eq!(value.into_sign_and_abs(), (sign, abs));
+        }
+
+        // `-0` is positive zero
+        assert_eq!(
+            I256::overflowing_from_sign_and_abs(Sign::Negative, U256::ZERO),
+            (I256::ZERO, false)
+        );
+        assert_eq!(I256::ZERO.into_sign_and_abs(), (Sign::Positive, U256::ZERO));
+        assert_eq!(I256::from_dec_str("-0"), Ok(I256::ZERO));
+        assert_eq!(I256::from_hex_str("-0x0"), Ok(I256::ZERO));
+        assert_eq!(I1::from_dec_str("-0"), Ok(I1::ZERO));
+
+        // a magnitude of `2^255` is only representable as `MIN`
+        let min_abs = U256::from(1) << 255;
+        assert_eq!(I256::MIN.into_sign_and_abs(), (Sign::Negative, min_abs));
+        assert_eq!(
+            I256::checked_from_sign_and_abs(Sign::Negative, min_abs),
+            Some(I256::MIN)
+        );
+        assert_eq!(
+            I256::overflowing_from_sign_and_abs(Sign::Positive, min_abs),
+            (I256::MIN, true)
+        );
+        assert_eq!(
+            I256::checked_from_sign_and_abs(Sign::Positive, min_abs),
+            None
+        );
+        assert_eq!(I256::from_dec_str(&I256::MIN.to_string()), Ok(I256::MIN));
+
+        assert_eq!(
+            I256::MAX.into_sign_and_abs(),
+            (Sign::Positive, min_abs - U256::from(1))
+        );
+        assert_eq!(
+            I256::overflowing_from_sign_and_abs(Sign::Negative, U256::MAX),
+            (I256::ONE, true)
+        );
+
+        assert!(Sign::Negative.is_negative() && !Sign::Negative.is_positive());
+        assert!(Sign::Positive.is_positive() && !Sign::Positive.is_negative());
+        assert_eq!(format!("{}{}", Sign::Negative, Sign::Positive), "-");
+        assert_eq!(format!("{:+}", Sign::Positive), "+");
+    }
+
     #[test]
     fn parse() {
         assert_eq!("0x0".parse::<I0>(), Ok(I0::default()));
@@ -18,6 +18,10 @@ mod ops;
 mod sign;
 pub use sign::Sign;
 
+/// Borsh support.
+#[cfg(feature = "borsh")]
+mod borsh;
+
 /// Serde support.
 #[cfg(feature = "serde")]
 mod serde;
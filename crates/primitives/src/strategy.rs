@@ -0,0 +1,187 @@
+//! [`proptest`] strategies with shaping parameters.
+//!
+//! Uniformly random bytes almost never hit the values that encoders and
+//! decoders special-case: zero words, leading zeros, all-ones words,
+//! precompile addresses. The strategies in this module bias the generated
+//! values towards those cases.
+//!
+//! The [`proptest::arbitrary::Arbitrary`] implementations of [`FixedBytes`]
+//! and the types created with [`wrap_fixed_bytes!`](crate::wrap_fixed_bytes)
+//! generate uniformly random bytes. Use [`fixed_bytes`] or [`address`] with a
+//! [`ByteDistribution`] to shape them:
+//!
+//! ```
+//! use alloy_primitives::{
+//!     strategy::{self, ByteDistribution},
+//!     Address,
+//! };
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(address in strategy::address(ByteDistribution::Edge))| {
+//!     prop_assert_eq!(Address::from_word(address.into_word()), address);
+//! });
+//! ```
+
+use crate::{Address, FixedBytes, Uint};
+use proptest::{
+    arbitrary::any,
+    collection::vec,
+    prop_oneof,
+    strategy::{Just, SBoxedStrategy, Strategy},
+};
+
+/// The distribution of the bytes generated by [`fixed_bytes`] and
+/// [`address`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ByteDistribution {
+    /// Every byte is uniformly random.
+    #[default]
+    Uniform,
+    /// Up to 3 random bytes are set, the rest are zero.
+    Sparse,
+    /// A single byte value in the last position, the rest are zero. This is
+    /// the shape of precompile addresses and of small right-aligned integers.
+    Small,
+    /// A mix of all zeros, all `0xff`, and the other distributions.
+    Edge,
+}
+
+/// Returns a strategy for [`FixedBytes`] using the given distribution.
+pub fn fixed_bytes<const N: usize>(
+    distribution: ByteDistribution,
+) -> SBoxedStrategy<FixedBytes<N>> {
+    // the other strategies need at least one byte to work with
+    if N == 0 {
+        return Just(FixedBytes::ZERO).sboxed();
+    }
+
+    match distribution {
+        ByteDistribution::Uniform => any::<[u8; N]>().prop_map(FixedBytes).sboxed(),
+        ByteDistribution::Sparse => vec((0..N, any::<u8>()), 0..=3)
+            .prop_map(|bytes| {
+                let mut out = FixedBytes::ZERO;
+                for (i, byte) in bytes {
+                    out[i] = byte;
+                }
+                out
+            })
+            .sboxed(),
+        ByteDistribution::Small => any::<u8>().prop_map(FixedBytes::with_last_byte).sboxed(),
+        ByteDistribution::Edge => prop_oneof![
+            1 => Just(FixedBytes::ZERO),
+            1 => Just(FixedBytes::repeat_byte(0xff)),
+            1 => fixed_bytes(ByteDistribution::Small),
+            2 => fixed_bytes(ByteDistribution::Sparse),
+            2 => fixed_bytes(ByteDistribution::Uniform),
+        ]
+        .sboxed(),
+    }
+}
+
+/// Returns a strategy for [`Address`] using the given distribution.
+pub fn address(distribution: ByteDistribution) -> SBoxedStrategy<Address> {
+    fixed_bytes(distribution).prop_map(Address).sboxed()
+}
+
+/// Returns a strategy for the precompile addresses `0x01..=0x0a`.
+pub fn precompile_address() -> SBoxedStrategy<Address> {
+    (1u8..=0x0a).prop_map(Address::with_last_byte).sboxed()
+}
+
+/// Returns a strategy for [`Uint`] biased towards `0`, `1`, `MAX`, powers of
+/// two and powers of two minus one.
+///
+/// `ruint`'s own `Arbitrary` implementation does not take any parameters, so
+/// use this with `in` instead of `any_with`:
+///
+/// ```
+/// use alloy_primitives::{strategy::uint_edge_cases, U256};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(x in uint_edge_cases::<256, 4>())| {
+///     prop_assert_eq!(U256::from_be_bytes(x.to_be_bytes::<32>()), x);
+/// });
+/// ```
+pub fn uint_edge_cases<const BITS: usize, const LIMBS: usize>() -> SBoxedStrategy<Uint<BITS, LIMBS>>
+{
+    if BITS == 0 {
+        return Just(Uint::ZERO).sboxed();
+    }
+
+    let mut one = Uint::ZERO;
+    one.set_bit(0, true);
+    prop_oneof![
+        1 => Just(Uint::ZERO),
+        1 => Just(one),
+        1 => Just(Uint::MAX),
+        2 => (0..BITS).prop_map(|i| {
+            let mut x = Uint::ZERO;
+            x.set_bit(i, true);
+            x
+        }),
+        2 => (1..=BITS).prop_map(|i| Uint::MAX >> (BITS - i)),
+        3 => any::<Uint<BITS, LIMBS>>(),
+    ]
+    .sboxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{B256, U256, U8};
+    use alloc::vec::Vec;
+    use proptest::{strategy::ValueTree, test_runner::TestRunner};
+
+    fn sample<T: core::fmt::Debug>(s: impl Strategy<Value = T>, n: usize) -> Vec<T> {
+        let mut runner = TestRunner::deterministic();
+        (0..n)
+            .map(|_| s.new_tree(&mut runner).unwrap().current())
+            .collect()
+    }
+
+    #[test]
+    fn distributions() {
+        for b in sample(fixed_bytes::<32>(ByteDistribution::Sparse), 100) {
+            assert!(b.iter().filter(|&&b| b != 0).count() <= 3);
+        }
+        for b in sample(fixed_bytes::<32>(ByteDistribution::Small), 100) {
+            assert!(b[..31].iter().all(|&b| b == 0));
+        }
+
+        let edge = sample(fixed_bytes::<32>(ByteDistribution::Edge), 200);
+        assert!(edge.contains(&B256::ZERO));
+        assert!(edge.contains(&B256::repeat_byte(0xff)));
+
+        for address in sample(address(ByteDistribution::Small), 100) {
+            assert!(address[..19].iter().all(|&b| b == 0));
+        }
+
+        assert_eq!(
+            sample(fixed_bytes::<0>(ByteDistribution::Edge), 10),
+            [FixedBytes::ZERO; 10]
+        );
+    }
+
+    #[test]
+    fn precompiles() {
+        for address in sample(precompile_address(), 100) {
+            assert!((1..=0x0a).contains(&address[19]));
+            assert!(address[..19].iter().all(|&b| b == 0));
+        }
+    }
+
+    #[test]
+    fn uint_edges() {
+        let values = sample(uint_edge_cases::<256, 4>(), 500);
+        assert!(values.contains(&U256::ZERO));
+        assert!(values.contains(&U256::from(1)));
+        assert!(values.contains(&U256::MAX));
+        assert!(values
+            .iter()
+            .any(|x| x.count_ones() == 1 && *x > U256::from(1)));
+
+        assert_eq!(sample(uint_edge_cases::<0, 0>(), 10), [Uint::ZERO; 10]);
+        assert!(sample(uint_edge_cases::<8, 1>(), 100).contains(&U8::MAX));
+        assert_eq!(sample(uint_edge_cases::<1, 1>(), 50).len(), 50);
+    }
+}
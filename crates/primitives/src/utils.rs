@@ -1,5 +1,14 @@
+//! Common Ethereum utilities.
+
 use crate::{bits::FixedBytes, B256};
-use alloc::{string::ToString, vec::Vec};
+use alloc::string::ToString;
+
+mod units;
+pub use units::{
+    checked_pow10, exp10, format_ether, format_units, format_units_signed, parse_amount,
+    parse_ether, parse_units, parse_units_signed, Unit, UnitsError,
+};
+
 /// The prefix used for hashing messages according to EIP-191.
 const EIP191_PREFIX: &str = "\x19Ethereum Signed Message:\n";
 
@@ -16,12 +25,7 @@ pub fn eip191_hash_message<T: AsRef<[u8]>>(message: T) -> B256 {
     let len = message.len();
     let len_string = len.to_string();
 
-    let mut eth_message = Vec::with_capacity(EIP191_PREFIX.len() + len_string.len() + len);
-    eth_message.extend_from_slice(EIP191_PREFIX.as_bytes());
-    eth_message.extend_from_slice(len_string.as_bytes());
-    eth_message.extend_from_slice(message);
-
-    keccak256(&eth_message)
+    keccak256_slices(&[EIP191_PREFIX.as_bytes(), len_string.as_bytes(), message])
 }
 
 /// Simple interface to the [`Keccak-256`] hash function.
@@ -57,6 +61,18 @@ pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> FixedBytes<32> {
                 unsafe { native_keccak256(bytes.as_ptr(), bytes.len(), output.as_mut_ptr()) };
                 output.into()
             }
+        } else if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak")))] {
+            /// Calls [`keccak-asm`] when the `asm-keccak` feature is enabled.
+            /// This is overridden when `tiny-keccak` is enabled.
+            ///
+            /// [`keccak-asm`]: https://docs.rs/keccak-asm/latest/keccak_asm/
+            fn keccak256(bytes: &[u8]) -> FixedBytes<32> {
+                use keccak_asm::{Digest, Keccak256};
+
+                let mut hasher = Keccak256::new();
+                hasher.update(bytes);
+                FixedBytes(hasher.finalize().into())
+            }
         } else {
             use tiny_keccak::{Hasher, Keccak};
 
@@ -77,6 +93,292 @@ pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> FixedBytes<32> {
     keccak256(bytes.as_ref())
 }
 
+/// Computes the [Keccak-256](keccak256) hash of the concatenation of the given
+/// slices, by feeding them to a [`Keccak256`] hasher one after the other.
+///
+/// ```
+/// use alloy_primitives::{keccak256, keccak256_slices};
+///
+/// let hash = keccak256_slices(&[b"\x19\x01", &[0x11; 32], &[0x22; 32]]);
+/// assert_eq!(
+///     hash,
+///     keccak256([&b"\x19\x01"[..], &[0x11; 32], &[0x22; 32]].concat())
+/// );
+/// ```
+pub fn keccak256_slices(slices: &[&[u8]]) -> FixedBytes<32> {
+    let mut hasher = Keccak256::new();
+    for slice in slices {
+        hasher.update(slice);
+    }
+    hasher.finalize()
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))] {
+        // the native hook only accepts a single buffer
+        type KeccakState = alloc::vec::Vec<u8>;
+    } else if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak")))] {
+        type KeccakState = keccak_asm::Keccak256;
+    } else {
+        type KeccakState = tiny_keccak::Keccak;
+    }
+}
+
+/// A streaming [Keccak-256](keccak256) hasher.
+///
+/// This uses the same implementation as [`keccak256`], and does not allocate,
+/// except with the `native-keccak` feature, whose host hook only accepts a
+/// single buffer.
+///
+/// ```
+/// use alloy_primitives::{keccak256, utils::Keccak256};
+///
+/// let mut hasher = Keccak256::new();
+/// hasher.update(b"hello ");
+/// hasher.update(b"world");
+/// assert_eq!(hasher.finalize(), keccak256(b"hello world"));
+/// ```
+#[derive(Clone)]
+pub struct Keccak256 {
+    state: KeccakState,
+}
+
+impl Default for Keccak256 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for Keccak256 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Keccak256").finish_non_exhaustive()
+    }
+}
+
+impl Keccak256 {
+    /// Creates a new hasher.
+    #[inline]
+    pub fn new() -> Self {
+        cfg_if::cfg_if! {
+            if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))] {
+                let state = KeccakState::new();
+            } else if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak")))] {
+                let state = <KeccakState as keccak_asm::Digest>::new();
+            } else {
+                let state = KeccakState::v256();
+            }
+        }
+        Self { state }
+    }
+
+    /// Absorbs the given bytes into the hasher.
+    #[inline]
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        let bytes = bytes.as_ref();
+        cfg_if::cfg_if! {
+            if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))] {
+                self.state.extend_from_slice(bytes);
+            } else if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak")))] {
+                keccak_asm::Digest::update(&mut self.state, bytes);
+            } else {
+                tiny_keccak::Hasher::update(&mut self.state, bytes);
+            }
+        }
+    }
+
+    /// Returns the hash of all the bytes absorbed so far.
+    #[inline]
+    pub fn finalize(self) -> B256 {
+        cfg_if::cfg_if! {
+            if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))] {
+                keccak256(self.state)
+            } else if #[cfg(all(feature = "asm-keccak", not(feature = "tiny-keccak")))] {
+                FixedBytes(keccak_asm::Digest::finalize(self.state).into())
+            } else {
+                let mut output = [0; 32];
+                tiny_keccak::Hasher::finalize(self.state, &mut output);
+                FixedBytes(output)
+            }
+        }
+    }
+}
+
+/// Computes the [Keccak-256](keccak256) hash of the given bytes in a `const`
+/// context.
+///
+/// This is a straightforward, portable implementation and is much slower than
+/// [`keccak256`], which should be preferred at runtime.
+///
+/// ```
+/// use alloy_primitives::{b256, keccak256_const, B256};
+///
+/// const TRANSFER: B256 = keccak256_const(b"Transfer(address,address,uint256)");
+/// assert_eq!(
+///     TRANSFER,
+///     b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+/// );
+/// ```
+pub const fn keccak256_const(bytes: &[u8]) -> FixedBytes<32> {
+    const RATE: usize = 136;
+
+    let mut state = [0u64; 25];
+    let mut offset = 0;
+    while bytes.len() - offset >= RATE {
+        state = keccak_absorb(state, bytes, offset);
+        offset += RATE;
+    }
+
+    // the last, padded block
+    let mut block = [0u8; RATE];
+    let mut i = 0;
+    while offset + i < bytes.len() {
+        block[i] = bytes[offset + i];
+        i += 1;
+    }
+    block[i] ^= 0x01;
+    block[RATE - 1] ^= 0x80;
+    state = keccak_absorb(state, &block, 0);
+
+    let mut output = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        output[i] = (state[i / 8] >> (8 * (i % 8))) as u8;
+        i += 1;
+    }
+    FixedBytes(output)
+}
+
+/// XORs the 136-byte block at `bytes[offset..]` into the state, and permutes
+/// it.
+const fn keccak_absorb(mut state: [u64; 25], bytes: &[u8], offset: usize) -> [u64; 25] {
+    let mut i = 0;
+    while i < 136 / 8 {
+        let j = offset + i * 8;
+        state[i] ^= u64::from_le_bytes([
+            bytes[j],
+            bytes[j + 1],
+            bytes[j + 2],
+            bytes[j + 3],
+            bytes[j + 4],
+            bytes[j + 5],
+            bytes[j + 6],
+            bytes[j + 7],
+        ]);
+        i += 1;
+    }
+    keccak_f(state)
+}
+
+/// The Keccak-f\[1600\] permutation.
+const fn keccak_f(mut a: [u64; 25]) -> [u64; 25] {
+    const RC: [u64; 24] = [
+        0x0000000000000001,
+        0x0000000000008082,
+        0x800000000000808a,
+        0x8000000080008000,
+        0x000000000000808b,
+        0x0000000080000001,
+        0x8000000080008081,
+        0x8000000000008009,
+        0x000000000000008a,
+        0x0000000000000088,
+        0x0000000080008009,
+        0x000000008000000a,
+        0x000000008000808b,
+        0x800000000000008b,
+        0x8000000000008089,
+        0x8000000000008003,
+        0x8000000000008002,
+        0x8000000000000080,
+        0x000000000000800a,
+        0x800000008000000a,
+        0x8000000080008081,
+        0x8000000000008080,
+        0x0000000080000001,
+        0x8000000080008008,
+    ];
+    const ROTC: [u32; 24] = [
+        1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+    ];
+    const PILN: [usize; 24] = [
+        10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+    ];
+
+    let mut round = 0;
+    while round < 24 {
+        // theta
+        let mut c = [0u64; 5];
+        let mut x = 0;
+        while x < 5 {
+            c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+            x += 1;
+        }
+        let mut x = 0;
+        while x < 5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            let mut y = 0;
+            while y < 25 {
+                a[y + x] ^= d;
+                y += 5;
+            }
+            x += 1;
+        }
+
+        // rho and pi
+        let mut last = a[1];
+        let mut i = 0;
+        while i < 24 {
+            let j = PILN[i];
+            let tmp = a[j];
+            a[j] = last.rotate_left(ROTC[i]);
+            last = tmp;
+            i += 1;
+        }
+
+        // chi
+        let mut y = 0;
+        while y < 25 {
+            let row = [a[y], a[y + 1], a[y + 2], a[y + 3], a[y + 4]];
+            let mut x = 0;
+            while x < 5 {
+                a[y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+                x += 1;
+            }
+            y += 5;
+        }
+
+        // iota
+        a[0] ^= RC[round];
+        round += 1;
+    }
+    a
+}
+
+/// Simple interface to the [`SHA-256`] hash function.
+///
+/// Use [`Sha256`](sha2::Sha256) to hash data incrementally.
+///
+/// [`SHA-256`]: https://en.wikipedia.org/wiki/SHA-2
+#[cfg(feature = "sha2")]
+pub fn sha256<T: AsRef<[u8]>>(bytes: T) -> B256 {
+    use sha2::Digest;
+
+    FixedBytes(sha2::Sha256::digest(bytes.as_ref()).into())
+}
+
+/// Simple interface to the [`RIPEMD-160`] hash function.
+///
+/// Use [`Ripemd160`](ripemd::Ripemd160) to hash data incrementally.
+///
+/// [`RIPEMD-160`]: https://en.wikipedia.org/wiki/RIPEMD
+#[cfg(feature = "ripemd")]
+pub fn ripemd160<T: AsRef<[u8]>>(bytes: T) -> FixedBytes<20> {
+    use ripemd::Digest;
+
+    FixedBytes(ripemd::Ripemd160::digest(bytes.as_ref()).into())
+}
+
 // test vector taken from:
 // https://web3js.readthedocs.io/en/v1.2.2/web3-eth-accounts.html#hashmessage
 #[test]
@@ -90,3 +392,105 @@ fn test_hash_message() {
             .unwrap()
     );
 }
+
+#[test]
+fn test_keccak256_backend() {
+    use alloc::vec::Vec;
+    use tiny_keccak::{Hasher, Keccak};
+
+    // whichever backend is enabled must agree with `tiny-keccak`
+    for len in [0, 1, 31, 32, 135, 136, 137, 1000] {
+        let input = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+        let mut hasher = Keccak::v256();
+        hasher.update(&input);
+        let mut expected = [0; 32];
+        hasher.finalize(&mut expected);
+        assert_eq!(keccak256(&input), expected, "{len}");
+    }
+}
+
+#[test]
+fn test_keccak256_slices() {
+    let input = (0..=255).collect::<alloc::vec::Vec<u8>>();
+    assert_eq!(keccak256_slices(&[]), keccak256(b""));
+    // split across the 136-byte rate boundary at every possible position
+    for i in 0..input.len() {
+        let (a, b) = input.split_at(i);
+        assert_eq!(keccak256_slices(&[a, b]), keccak256(&input), "{i}");
+        assert_eq!(
+            keccak256_slices(&[&[], a, &[], b]),
+            keccak256(&input),
+            "{i}"
+        );
+    }
+}
+
+#[test]
+fn test_keccak256_const() {
+    const EMPTY: B256 = keccak256_const(&[]);
+    assert_eq!(EMPTY, keccak256(b""));
+
+    for len in [0, 1, 31, 32, 135, 136, 137, 271, 272, 273, 1000] {
+        let input = (0..len).map(|i| i as u8).collect::<alloc::vec::Vec<_>>();
+        assert_eq!(keccak256_const(&input), keccak256(&input), "{len}");
+    }
+}
+
+// test vectors of the SHA-256 (0x02) and RIPEMD-160 (0x03) precompiles
+#[cfg(all(test, any(feature = "sha2", feature = "ripemd")))]
+const PRECOMPILE_INPUT: &[u8] = &hex_literal::hex!(
+    "38d18acb67d25c8bb9942764b62f18e17054f66a817bd4295423adf9ed98873e"
+    "000000000000000000000000000000000000000000000000000000000000001b"
+    "38d18acb67d25c8bb9942764b62f18e17054f66a817bd4295423adf9ed98873e"
+    "789d1dd423d25f0772d2748d60f7e4b81bb14d086eba8e8e8efb6dcff8a4ae02"
+);
+
+#[cfg(feature = "sha2")]
+#[test]
+fn test_sha256() {
+    use sha2::{Digest, Sha256};
+
+    assert_eq!(
+        sha256(b""),
+        FixedBytes(hex_literal::hex!(
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        ))
+    );
+    let expected = FixedBytes(hex_literal::hex!(
+        "811c7003375852fabd0d362e40e68607a12bdabae61a7d068fe5fdd1dbbf2a5d"
+    ));
+    assert_eq!(sha256(PRECOMPILE_INPUT), expected);
+
+    let mut hasher = Sha256::new();
+    for chunk in PRECOMPILE_INPUT.chunks(33) {
+        hasher.update(chunk);
+    }
+    assert_eq!(B256::from(<[u8; 32]>::from(hasher.finalize())), expected);
+}
+
+#[cfg(feature = "ripemd")]
+#[test]
+fn test_ripemd160() {
+    use ripemd::{Digest, Ripemd160};
+
+    assert_eq!(
+        ripemd160(b""),
+        FixedBytes(hex_literal::hex!(
+            "9c1185a5c5e9fc54612808977ee8f548b2258d31"
+        ))
+    );
+    // the precompile left-pads the output to 32 bytes
+    let expected = FixedBytes(hex_literal::hex!(
+        "9215b8d9882ff46f0dfde6684d78e831467f65e6"
+    ));
+    assert_eq!(ripemd160(PRECOMPILE_INPUT), expected);
+
+    let mut hasher = Ripemd160::new();
+    for chunk in PRECOMPILE_INPUT.chunks(33) {
+        hasher.update(chunk);
+    }
+    assert_eq!(
+        FixedBytes::<20>::from(<[u8; 20]>::from(hasher.finalize())),
+        expected
+    );
+}
@@ -0,0 +1,618 @@
+use crate::{Sign, I256, U256};
+use alloc::string::{String, ToString};
+use core::{convert::Infallible, fmt, str::FromStr};
+
+/// Ethereum units, as a number of decimals.
+///
+/// The named units are available as associated constants, such as
+/// [`Unit::GWEI`] and [`Unit::ETHER`], and can be parsed from their names.
+/// Arbitrary units of up to [`Unit::MAX`] decimals can be created with
+/// [`Unit::new`].
+///
+/// ```
+/// use alloy_primitives::utils::Unit;
+///
+/// assert_eq!("gwei".parse::<Unit>().unwrap(), Unit::GWEI);
+/// assert_eq!(Unit::new(6).unwrap(), Unit::MWEI);
+/// assert_eq!(Unit::ETHER.get(), 18);
+/// assert!(Unit::new(78).is_none());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Unit(u8);
+
+impl Unit {
+    /// Wei: 0 decimals.
+    pub const WEI: Self = Self(0);
+    /// Kwei: 3 decimals.
+    pub const KWEI: Self = Self(3);
+    /// Mwei: 6 decimals.
+    pub const MWEI: Self = Self(6);
+    /// Gwei: 9 decimals.
+    pub const GWEI: Self = Self(9);
+    /// Szabo: 12 decimals.
+    pub const SZABO: Self = Self(12);
+    /// Finney: 15 decimals.
+    pub const FINNEY: Self = Self(15);
+    /// Ether: 18 decimals.
+    pub const ETHER: Self = Self(18);
+
+    /// The largest unit: 77 decimals. `10^77` is the largest power of ten that
+    /// fits in a [`U256`].
+    pub const MAX: Self = Self(77);
+
+    /// The named units, and their names.
+    const NAMES: [(&'static str, Self); 7] = [
+        ("wei", Self::WEI),
+        ("kwei", Self::KWEI),
+        ("mwei", Self::MWEI),
+        ("gwei", Self::GWEI),
+        ("szabo", Self::SZABO),
+        ("finney", Self::FINNEY),
+        ("ether", Self::ETHER),
+    ];
+
+    /// Creates a unit with the given number of decimals, or returns `None` if
+    /// it is greater than [`Unit::MAX`].
+    #[inline]
+    pub const fn new(decimals: u8) -> Option<Self> {
+        if decimals <= Self::MAX.0 {
+            Some(Self(decimals))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of decimals of this unit.
+    #[inline]
+    pub const fn get(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the amount of wei in one of this unit, `10^decimals`.
+    #[inline]
+    pub const fn wei(self) -> U256 {
+        exp10(self.0)
+    }
+}
+
+/// The powers of ten that fit in a [`U256`], from `10^0` to `10^77`.
+const POW10: [U256; Unit::MAX.0 as usize + 1] = {
+    let mut table = [U256::from_limbs([1, 0, 0, 0]); Unit::MAX.0 as usize + 1];
+    let mut i = 1;
+    while i < table.len() {
+        table[i] = table[i - 1].wrapping_mul(U256::from_limbs([10, 0, 0, 0]));
+        i += 1;
+    }
+    table
+};
+
+/// Returns `10^n`, looked up in a table.
+///
+/// # Panics
+///
+/// Panics if `n` is greater than 77, as `10^78` does not fit in a [`U256`].
+/// See [`checked_pow10`] for a non-panicking version.
+///
+/// ```
+/// use alloy_primitives::{utils::exp10, U256};
+///
+/// const ONE_ETHER: U256 = exp10(18);
+/// assert_eq!(ONE_ETHER, U256::from(1_000_000_000_000_000_000u64));
+/// ```
+#[inline]
+pub const fn exp10(n: u8) -> U256 {
+    match checked_pow10(n) {
+        Some(value) => value,
+        None => panic!("10^n overflows U256 for n > 77"),
+    }
+}
+
+/// Returns `10^n`, or `None` if it does not fit in a [`U256`], that is, if `n`
+/// is greater than 77.
+///
+/// This is useful for scaling by a token's `decimals`, which is usually a
+/// `u8`.
+///
+/// ```
+/// use alloy_primitives::utils::checked_pow10;
+///
+/// assert_eq!(checked_pow10(6).unwrap().to::<u64>(), 1_000_000);
+/// assert_eq!(checked_pow10(78), None);
+/// ```
+#[inline]
+pub const fn checked_pow10(n: u8) -> Option<U256> {
+    if n <= Unit::MAX.0 {
+        Some(POW10[n as usize])
+    } else {
+        None
+    }
+}
+
+impl TryFrom<u8> for Unit {
+    type Error = UnitsError;
+
+    #[inline]
+    fn try_from(decimals: u8) -> Result<Self, Self::Error> {
+        Self::new(decimals).ok_or_else(|| UnitsError::InvalidUnit(decimals.to_string()))
+    }
+}
+
+impl TryFrom<&str> for Unit {
+    type Error = UnitsError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for Unit {
+    type Err = UnitsError;
+
+    /// Parses a unit from its name, case-insensitively, or from its number of
+    /// decimals.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(&(_, unit)) = Self::NAMES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+        {
+            return Ok(unit)
+        }
+        s.parse::<u8>()
+            .ok()
+            .and_then(Self::new)
+            .ok_or_else(|| UnitsError::InvalidUnit(s.to_string()))
+    }
+}
+
+/// The error type that is returned when parsing or formatting amounts in
+/// [`Unit`]s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnitsError {
+    /// The unit is neither a known unit name nor a number of decimals up to
+    /// [`Unit::MAX`].
+    InvalidUnit(String),
+    /// The amount is not a decimal number.
+    InvalidAmount,
+    /// The amount has non-zero digits past the number of decimals of the unit.
+    TooManyDecimals,
+    /// The amount does not fit in the integer type.
+    Overflow,
+}
+
+impl From<Infallible> for UnitsError {
+    #[inline]
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitsError {}
+
+impl fmt::Display for UnitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidUnit(unit) => write!(f, "invalid unit: {unit:?}"),
+            Self::InvalidAmount => f.write_str("invalid decimal amount"),
+            Self::TooManyDecimals => f.write_str("amount has too many decimals for the unit"),
+            Self::Overflow => f.write_str("amount does not fit in the integer size"),
+        }
+    }
+}
+
+/// Parses a decimal amount of `units` into wei.
+///
+/// `units` can be a [`Unit`], its name, or its number of decimals. The amount
+/// must not have non-zero digits past the number of decimals of the unit, as
+/// they would otherwise have to be truncated.
+///
+/// ```
+/// use alloy_primitives::{utils::parse_units, U256};
+///
+/// assert_eq!(parse_units("1.5", "gwei"), Ok(U256::from(1_500_000_000u64)));
+/// assert_eq!(parse_units("2", 6), Ok(U256::from(2_000_000u64)));
+/// assert!(parse_units("1.0000000001", "gwei").is_err());
+/// ```
+pub fn parse_units<K, E>(amount: &str, units: K) -> Result<U256, UnitsError>
+where
+    K: TryInto<Unit, Error = E>,
+    UnitsError: From<E>,
+{
+    let units = units.try_into()?;
+    let (int, frac) = amount.split_once('.').unwrap_or((amount, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if (int.is_empty() && frac.is_empty()) || !is_digits(int) || !is_digits(frac) {
+        return Err(UnitsError::InvalidAmount)
+    }
+
+    let decimals = units.get() as usize;
+    let (frac, rest) = frac.split_at(frac.len().min(decimals));
+    if rest.bytes().any(|b| b != b'0') {
+        return Err(UnitsError::TooManyDecimals)
+    }
+
+    let parse = |s: &str| {
+        if s.is_empty() {
+            Ok(U256::ZERO)
+        } else {
+            U256::from_str_radix(s, 10).map_err(|_| UnitsError::Overflow)
+        }
+    };
+    // cannot overflow, as `frac` has at most `Unit::MAX` digits
+    let frac = parse(frac)? * Unit::new((decimals - frac.len()) as u8).unwrap().wei();
+    parse(int)?
+        .checked_mul(units.wei())
+        .and_then(|int| int.checked_add(frac))
+        .ok_or(UnitsError::Overflow)
+}
+
+/// Parses a decimal amount of `units` into signed wei. The amount may start
+/// with a `-` sign.
+///
+/// See [`parse_units`] for more details.
+///
+/// ```
+/// use alloy_primitives::{utils::parse_units_signed, I256};
+///
+/// assert_eq!(
+///     parse_units_signed("-1.5", "gwei"),
+///     Ok(I256::try_from(-1_500_000_000i64).unwrap())
+/// );
+/// ```
+pub fn parse_units_signed<K, E>(amount: &str, units: K) -> Result<I256, UnitsError>
+where
+    K: TryInto<Unit, Error = E>,
+    UnitsError: From<E>,
+{
+    let (sign, abs) = match amount.strip_prefix('-') {
+        Some(abs) => (Sign::Negative, abs),
+        None => (Sign::Positive, amount),
+    };
+    let abs = parse_units(abs, units)?;
+    // `-0` is not a valid sign and absolute value pair
+    let sign = if abs.is_zero() { Sign::Positive } else { sign };
+    I256::checked_from_sign_and_abs(sign, abs).ok_or(UnitsError::Overflow)
+}
+
+/// Parses a decimal amount of ether into wei.
+///
+/// ```
+/// use alloy_primitives::{utils::parse_ether, U256};
+///
+/// assert_eq!(
+///     parse_ether("0.01"),
+///     Ok(U256::from(10_000_000_000_000_000u64))
+/// );
+/// ```
+#[inline]
+pub fn parse_ether(amount: &str) -> Result<U256, UnitsError> {
+    parse_units(amount, Unit::ETHER)
+}
+
+/// Parses a decimal amount followed by an optional unit into wei, such as
+/// `"1.5 ether"` or `"20gwei"`. Amounts without a unit are in wei.
+///
+/// The unit may be separated from the amount by whitespace. See
+/// [`parse_units`] for more details.
+///
+/// ```
+/// use alloy_primitives::{utils::parse_amount, U256};
+///
+/// assert_eq!(parse_amount("20 gwei"), Ok(U256::from(20_000_000_000u64)));
+/// assert_eq!(
+///     parse_amount("1.5ether"),
+///     Ok(U256::from(1_500_000_000_000_000_000u64))
+/// );
+/// assert_eq!(parse_amount("42"), Ok(U256::from(42)));
+/// ```
+pub fn parse_amount(s: &str) -> Result<U256, UnitsError> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| c != '.' && !c.is_ascii_digit())
+        .unwrap_or(s.len());
+    let (amount, unit) = s.split_at(split);
+    match unit.trim_start() {
+        "" => parse_units(amount, Unit::WEI),
+        // a unit given as a number of decimals would be ambiguous here
+        unit => match Unit::NAMES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+        {
+            Some(&(_, unit)) => parse_units(amount, unit),
+            None => Err(UnitsError::InvalidUnit(unit.to_string())),
+        },
+    }
+}
+
+/// Formats an amount of wei as a decimal amount of `units`.
+///
+/// `units` can be a [`Unit`], its name, or its number of decimals. The result
+/// is exact, and always has as many fractional digits as the unit has
+/// decimals.
+///
+/// ```
+/// use alloy_primitives::{utils::format_units, U256};
+///
+/// assert_eq!(
+///     format_units(U256::from(1_500_000_000u64), "gwei").unwrap(),
+///     "1.500000000"
+/// );
+/// assert_eq!(format_units(U256::from(42), 0).unwrap(), "42");
+/// ```
+pub fn format_units<K, E>(amount: U256, units: K) -> Result<String, UnitsError>
+where
+    K: TryInto<Unit, Error = E>,
+    UnitsError: From<E>,
+{
+    let units = units.try_into()?;
+    Ok(format_abs(amount, units))
+}
+
+/// Formats an amount of signed wei as a decimal amount of `units`.
+///
+/// See [`format_units`] for more details.
+pub fn format_units_signed<K, E>(amount: I256, units: K) -> Result<String, UnitsError>
+where
+    K: TryInto<Unit, Error = E>,
+    UnitsError: From<E>,
+{
+    let units = units.try_into()?;
+    let (sign, abs) = amount.into_sign_and_abs();
+    let abs = format_abs(abs, units);
+    Ok(if sign.is_negative() {
+        alloc::format!("-{abs}")
+    } else {
+        abs
+    })
+}
+
+/// Formats an amount of wei as a decimal amount of ether.
+///
+/// ```
+/// use alloy_primitives::{utils::format_ether, U256};
+///
+/// assert_eq!(
+///     format_ether(U256::from(10_000_000_000_000_000u64)),
+///     "0.010000000000000000"
+/// );
+/// ```
+#[inline]
+pub fn format_ether(amount: U256) -> String {
+    format_abs(amount, Unit::ETHER)
+}
+
+fn format_abs(amount: U256, units: Unit) -> String {
+    let decimals = units.get() as usize;
+    if decimals == 0 {
+        return amount.to_string()
+    }
+    let (int, frac) = amount.div_rem(units.wei());
+    alloc::format!("{int}.{:0>decimals$}", frac.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pow10() {
+        let ten = U256::from(10);
+        for n in 0..=77u8 {
+            assert_eq!(exp10(n), ten.pow(U256::from(n)), "10^{n}");
+            assert_eq!(checked_pow10(n), ten.checked_pow(U256::from(n)));
+            assert_eq!(exp10(n).to_string().len(), n as usize + 1);
+        }
+        assert_eq!(exp10(77).to_string(), alloc::format!("1{}", "0".repeat(77)));
+
+        // 10^78 is the first power of ten that overflows
+        assert_eq!(checked_pow10(78), None);
+        assert_eq!(checked_pow10(u8::MAX), None);
+        assert_eq!(ten.checked_pow(U256::from(78)), None);
+        assert!(ten.overflowing_pow(U256::from(78)).1);
+        assert!(exp10(77) > U256::MAX / ten);
+
+        const GWEI: U256 = exp10(9);
+        assert_eq!(GWEI, Unit::GWEI.wei());
+        assert_eq!(exp10(64).bit_len(), 213);
+    }
+
+    #[test]
+    #[should_panic = "10^n overflows U256"]
+    fn exp10_overflow() {
+        exp10(78);
+    }
+
+    #[test]
+    fn units() {
+        for (name, unit) in Unit::NAMES {
+            assert_eq!(name.parse::<Unit>().unwrap(), unit);
+            assert_eq!(name.to_uppercase().parse::<Unit>().unwrap(), unit);
+            assert_eq!(unit.get().to_string().parse::<Unit>().unwrap(), unit);
+        }
+        assert_eq!(
+            Unit::MAX.wei().to_string(),
+            alloc::format!("1{}", "0".repeat(77))
+        );
+        assert_eq!(Unit::try_from(77), Ok(Unit::MAX));
+        assert_eq!(
+            Unit::try_from(78),
+            Err(UnitsError::InvalidUnit("78".into()))
+        );
+        assert_eq!(
+            "78".parse::<Unit>(),
+            Err(UnitsError::InvalidUnit("78".into()))
+        );
+        assert_eq!(
+            "eth".parse::<Unit>(),
+            Err(UnitsError::InvalidUnit("eth".into()))
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let gwei = |n: u64| Ok(U256::from(n));
+        assert_eq!(parse_units("1", "gwei"), gwei(1_000_000_000));
+        assert_eq!(parse_units("1.5", "gwei"), gwei(1_500_000_000));
+        assert_eq!(parse_units(".5", "gwei"), gwei(500_000_000));
+        assert_eq!(parse_units("1.", "gwei"), gwei(1_000_000_000));
+        assert_eq!(parse_units("0.000000001", "gwei"), gwei(1));
+        assert_eq!(parse_units("1.000000001", Unit::GWEI), gwei(1_000_000_001));
+        assert_eq!(parse_units("007", 0), gwei(7));
+
+        // zero
+        assert_eq!(parse_units("0", "ether"), gwei(0));
+        assert_eq!(parse_units("0.0", "ether"), gwei(0));
+        assert_eq!(parse_units("0", 77), gwei(0));
+
+        // extra zeros are exact, other digits would be truncated
+        assert_eq!(parse_units("1.0000000000000", "gwei"), gwei(1_000_000_000));
+        assert_eq!(
+            parse_units("1.0000000001", "gwei"),
+            Err(UnitsError::TooManyDecimals)
+        );
+        assert_eq!(
+            parse_units("0.9999999999", "gwei"),
+            Err(UnitsError::TooManyDecimals)
+        );
+        assert_eq!(parse_units("1.5", "wei"), Err(UnitsError::TooManyDecimals));
+
+        for amount in ["", ".", "-1", "+1", "1e18", "1,5", " 1", "1.2.3", "0x1"] {
+            assert_eq!(
+                parse_units(amount, "ether"),
+                Err(UnitsError::InvalidAmount),
+                "{amount}"
+            );
+        }
+        assert_eq!(
+            parse_units("1", "eth"),
+            Err(UnitsError::InvalidUnit("eth".into()))
+        );
+    }
+
+    #[test]
+    fn parse_with_unit() {
+        let n = |n: u64| Ok(U256::from(n));
+        assert_eq!(parse_amount("1.5 ether"), n(1_500_000_000_000_000_000));
+        assert_eq!(parse_amount("20 gwei"), n(20_000_000_000));
+        assert_eq!(parse_amount(" .5\tGWEI "), n(500_000_000));
+        assert_eq!(parse_amount("1.50000gwei"), n(1_500_000_000));
+        assert_eq!(parse_amount("7 wei"), n(7));
+        assert_eq!(parse_amount("7"), n(7));
+
+        assert_eq!(parse_amount("1.5"), Err(UnitsError::TooManyDecimals));
+        assert_eq!(
+            parse_amount("1.0000000001 gwei"),
+            Err(UnitsError::TooManyDecimals)
+        );
+        assert_eq!(
+            parse_amount("1 eth"),
+            Err(UnitsError::InvalidUnit("eth".into()))
+        );
+        assert_eq!(
+            parse_amount("1 9"),
+            Err(UnitsError::InvalidUnit("9".into()))
+        );
+        assert_eq!(parse_amount("ether"), Err(UnitsError::InvalidAmount));
+        assert_eq!(parse_amount(""), Err(UnitsError::InvalidAmount));
+
+        let max = U256::MAX.to_string();
+        assert_eq!(parse_amount(&alloc::format!("{max} wei")), Ok(U256::MAX));
+        assert_eq!(
+            parse_amount(&alloc::format!("{max} kwei")),
+            Err(UnitsError::Overflow)
+        );
+    }
+
+    #[test]
+    fn parse_max() {
+        let max = U256::MAX.to_string();
+        assert_eq!(parse_units(&max, "wei"), Ok(U256::MAX));
+        assert_eq!(parse_units(&alloc::format!("{max}.0"), 0), Ok(U256::MAX));
+        let (int, frac) = max.split_at(max.len() - 18);
+        assert_eq!(parse_ether(&alloc::format!("{int}.{frac}")), Ok(U256::MAX));
+        let (int, frac) = max.split_at(1);
+        assert_eq!(
+            parse_units(&alloc::format!("{int}.{frac}"), Unit::MAX),
+            Ok(U256::MAX)
+        );
+
+        // `U256::MAX + 1`
+        let overflow = concat!(
+            "115792089237316195423570985008687907853",
+            "269984665640564039457584007913129639936"
+        );
+        assert_eq!(parse_units(overflow, 0), Err(UnitsError::Overflow));
+        assert_eq!(parse_units(&max, 1), Err(UnitsError::Overflow));
+        assert_eq!(parse_units("2", Unit::MAX), Err(UnitsError::Overflow));
+    }
+
+    #[test]
+    fn parse_signed() {
+        let n = |n: i64| Ok(I256::try_from(n).unwrap());
+        assert_eq!(parse_units_signed("-1.5", "gwei"), n(-1_500_000_000));
+        assert_eq!(parse_units_signed("1.5", "gwei"), n(1_500_000_000));
+        assert_eq!(parse_units_signed("-0", "gwei"), n(0));
+        assert_eq!(
+            parse_units_signed("--1", "gwei"),
+            Err(UnitsError::InvalidAmount)
+        );
+
+        let min = I256::MIN.to_string();
+        assert_eq!(parse_units_signed(&min, 0), Ok(I256::MIN));
+        assert_eq!(parse_units_signed(&min[1..], 0), Err(UnitsError::Overflow));
+        assert_eq!(parse_units_signed(&I256::MAX.to_string(), 0), Ok(I256::MAX));
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(format_units(U256::ZERO, "wei").unwrap(), "0");
+        assert_eq!(format_units(U256::ZERO, "gwei").unwrap(), "0.000000000");
+        assert_eq!(format_units(U256::from(1), "gwei").unwrap(), "0.000000001");
+        assert_eq!(
+            format_units(U256::from(1_500_000_000u64), 9).unwrap(),
+            "1.500000000"
+        );
+        assert_eq!(
+            format_ether(U256::from(10).pow(U256::from(18))),
+            "1.000000000000000000"
+        );
+        assert_eq!(
+            format_units(U256::from(1), "eth"),
+            Err(UnitsError::InvalidUnit("eth".into()))
+        );
+
+        let max = U256::MAX.to_string();
+        assert_eq!(format_units(U256::MAX, 0).unwrap(), max);
+        assert_eq!(
+            format_units(U256::MAX, Unit::MAX).unwrap(),
+            alloc::format!("1.{}", &max[1..])
+        );
+        let (int, frac) = max.split_at(max.len() - 18);
+        assert_eq!(format_ether(U256::MAX), alloc::format!("{int}.{frac}"));
+
+        let minus_one = I256::try_from(-1).unwrap();
+        assert_eq!(
+            format_units_signed(minus_one, "gwei").unwrap(),
+            "-0.000000001"
+        );
+        assert_eq!(
+            format_units_signed(I256::MIN, 0).unwrap(),
+            I256::MIN.to_string()
+        );
+        assert_eq!(
+            format_units_signed(I256::MAX, 0).unwrap(),
+            I256::MAX.to_string()
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        for unit in (0..=77).map(|d| Unit::new(d).unwrap()) {
+            for amount in [U256::ZERO, U256::from(1), unit.wei(), U256::MAX] {
+                let s = format_units(amount, unit).unwrap();
+                assert_eq!(parse_units(&s, unit), Ok(amount), "{s}");
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Checks that parsing fixed-size types from hex does not allocate.
+
+use alloy_primitives::{hex, Address, Bloom, FixedBytes, B256};
+
+#[path = "common/counting_alloc.rs"]
+mod counting_alloc;
+use counting_alloc::count_allocations;
+
+#[test]
+fn from_str_does_not_allocate() {
+    // borrowed slices of a larger buffer
+    let input = "0x000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045 \
+                 d8dA6BF26964aF9D7eEd9e03E53415D37aA96045 0xzz";
+    let mut parts = input.split(' ');
+    let (word, address, invalid) = (
+        parts.next().unwrap(),
+        parts.next().unwrap(),
+        parts.next().unwrap(),
+    );
+
+    // sanity check for the allocator
+    assert_eq!(count_allocations(|| word.to_string()).1.count, 1);
+
+    let (parsed, n) = count_allocations(|| word.parse::<FixedBytes<32>>());
+    assert_eq!(n.count, 0);
+    assert_eq!(
+        parsed.unwrap(),
+        B256::from(hex!(
+            "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045"
+        ))
+    );
+
+    let (parsed, n) = count_allocations(|| address.parse::<Address>());
+    assert_eq!(n.count, 0);
+    assert_eq!(
+        parsed.unwrap(),
+        Address::from(hex!("d8da6bf26964af9d7eed9e03e53415d37aa96045"))
+    );
+
+    let bloom = "ff".repeat(256);
+    let (parsed, n) = count_allocations(|| bloom.parse::<Bloom>());
+    assert_eq!(n.count, 0);
+    assert_eq!(parsed.unwrap(), Bloom::repeat_byte(0xff));
+
+    // errors do not allocate either
+    let (parsed, n) = count_allocations(|| invalid.parse::<B256>());
+    assert_eq!(n.count, 0);
+    assert!(parsed.is_err());
+}
@@ -0,0 +1,71 @@
+//! A global allocator that counts the allocations of the current thread.
+//!
+//! Shared by the allocation tests of several crates, which include it with
+//! `#[path = "..."] mod counting_alloc;`.
+
+#![allow(dead_code)]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// The allocations made by a closure.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Allocations {
+    /// The number of allocations, including reallocations.
+    pub count: usize,
+    /// The total number of bytes allocated.
+    pub bytes: usize,
+}
+
+// Counted per thread so that tests running in parallel do not interfere.
+thread_local! {
+    static ALLOCATIONS: Cell<Allocations> = const { Cell::new(Allocations { count: 0, bytes: 0 }) };
+}
+
+fn record(bytes: usize) {
+    let _ = ALLOCATIONS.try_with(|cell| {
+        let Allocations {
+            count,
+            bytes: total,
+        } = cell.get();
+        cell.set(Allocations {
+            count: count + 1,
+            bytes: total + bytes,
+        });
+    });
+}
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Runs `f`, returning its result and the allocations it made.
+pub fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, Allocations) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let r = f();
+    let after = ALLOCATIONS.with(Cell::get);
+    let allocations = Allocations {
+        count: after.count - before.count,
+        bytes: after.bytes - before.bytes,
+    };
+    (r, allocations)
+}
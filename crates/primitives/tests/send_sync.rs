@@ -0,0 +1,49 @@
+//! Checks that all public types can be shared and sent between threads.
+
+use alloy_primitives::*;
+use static_assertions::assert_impl_all;
+
+macro_rules! assert_send_sync {
+    ($($t:ty),+ $(,)?) => {$(
+        assert_impl_all!($t: Send, Sync);
+    )+};
+}
+
+assert_send_sync!(
+    Address,
+    AddressError,
+    B256,
+    BigIntConversionError,
+    Bloom,
+    BloomInput<'static>,
+    Bytes,
+    ChecksumVariant<'static>,
+    Eip2098Error,
+    FixedBytes<0>,
+    Function,
+    I256,
+    InvalidLengthError,
+    Keccak,
+    Keccak256,
+    ParseSignedError,
+    aliases::ParseUintError,
+    Selector,
+    Sign,
+    Signed<1, 1>,
+    U256,
+    Uint<0, 0>,
+    utils::Unit,
+    utils::UnitsError,
+);
+
+#[cfg(feature = "base64")]
+assert_send_sync!(Base64Error);
+
+#[cfg(feature = "proptest")]
+assert_send_sync!(strategy::ByteDistribution);
+
+#[cfg(feature = "ripemd")]
+assert_send_sync!(Ripemd160);
+
+#[cfg(feature = "sha2")]
+assert_send_sync!(Sha256);
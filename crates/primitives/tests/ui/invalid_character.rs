@@ -0,0 +1,7 @@
+use alloy_primitives::{fixed_bytes, FixedBytes};
+
+const A: FixedBytes<4> = fixed_bytes!("0x0102030g");
+
+const B: FixedBytes<4> = fixed_bytes!("0x01-02-03-04");
+
+fn main() {}
@@ -0,0 +1,7 @@
+use alloy_primitives::{address, bytes, Address, Bytes};
+
+const A: Address = address!("0x0102030405060708090a0b0c0d0e0f101112131");
+
+static B: Bytes = bytes!("0x123");
+
+fn main() {}
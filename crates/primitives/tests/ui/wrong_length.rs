@@ -0,0 +1,7 @@
+use alloy_primitives::{address, b256, Address, B256};
+
+const A: Address = address!("0x0102030405060708090a0b0c0d0e0f10111213");
+
+const B: B256 = b256!("0x01020304_05060708_090a0b0c_0d0e0f10_11121314_15161718_191a1b1c_1d1e1f20_21");
+
+fn main() {}
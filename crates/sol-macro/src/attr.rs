@@ -1,5 +1,9 @@
 use heck::{ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
-use syn::{punctuated::Punctuated, Attribute, Error, LitBool, LitStr, Path, Result, Token};
+use proc_macro2::Span;
+use syn::{
+    punctuated::Punctuated, Attribute, Error, Expr, ExprLit, Lit, LitBool, LitStr, Meta, Path,
+    Result, Token,
+};
 
 const DUPLICATE_ERROR: &str = "duplicate attribute";
 const UNKNOWN_ERROR: &str = "unknown `sol` attribute";
@@ -19,6 +23,105 @@ pub fn derives_mapped(attrs: &[Attribute]) -> impl Iterator<Item = Path> + '_ {
     })
 }
 
+/// Returns `true` if the attributes derive a trait with the given name,
+/// ignoring its path.
+pub fn derives_trait(attrs: &[Attribute], name: &str) -> bool {
+    derives_mapped(attrs).any(|path| {
+        path.segments
+            .last()
+            .map_or(false, |last| last.ident == name)
+    })
+}
+
+/// Returns the natspec tags in the given doc attributes as `(tag, content)`.
+///
+/// Lines that do not start with a tag are appended to the previous tag's
+/// content.
+fn natspec_tags(attrs: &[Attribute]) -> Vec<(String, String)> {
+    let mut tags: Vec<(String, String)> = Vec::new();
+    let strings = docs(attrs).filter_map(|attr| match &attr.meta {
+        Meta::NameValue(nv) => match &nv.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(s), ..
+            }) => Some(s.value()),
+            _ => None,
+        },
+        _ => None,
+    });
+    for doc in strings {
+        for line in doc.lines() {
+            // strip `/** ... */` block comment decorations
+            let line = line.trim().trim_start_matches('*').trim();
+            if let Some(rest) = line.strip_prefix('@') {
+                let (tag, content) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                tags.push((tag.to_string(), content.trim().to_string()));
+            } else if let Some((_, content)) = tags.last_mut() {
+                if !line.is_empty() {
+                    if !content.is_empty() {
+                        content.push(' ');
+                    }
+                    content.push_str(line);
+                }
+            }
+        }
+    }
+    tags
+}
+
+/// Splits the first word off of a natspec tag's content.
+fn split_name(content: &str) -> (&str, &str) {
+    let (name, rest) = content
+        .split_once(char::is_whitespace)
+        .unwrap_or((content, ""));
+    (name, rest.trim_start())
+}
+
+/// Returns the natspec `@param` description of each parameter, matched by
+/// name.
+pub fn param_docs<'a, I>(attrs: &[Attribute], names: I) -> Vec<Option<String>>
+where
+    I: IntoIterator<Item = Option<&'a str>>,
+{
+    let tags = natspec_tags(attrs);
+    names
+        .into_iter()
+        .map(|name| {
+            let name = name?;
+            tags.iter()
+                .filter(|(tag, _)| tag == "param")
+                .map(|(_, content)| split_name(content))
+                .find(|(n, _)| *n == name)
+                .map(|(_, doc)| doc.to_string())
+        })
+        .collect()
+}
+
+/// Returns the natspec `@return` description of each return value.
+///
+/// Like in Solidity, a description starting with the name of a return value
+/// is matched by name. Otherwise, the `n`th `@return` tag describes the `n`th
+/// return value.
+pub fn return_docs<'a, I>(attrs: &[Attribute], names: I) -> Vec<Option<String>>
+where
+    I: IntoIterator<Item = Option<&'a str>>,
+{
+    let names: Vec<_> = names.into_iter().collect();
+    let mut docs = vec![None; names.len()];
+    let tags = natspec_tags(attrs);
+    let returns = tags.iter().filter(|(tag, _)| tag == "return");
+    for (i, (_, content)) in returns.enumerate() {
+        let (name, rest) = split_name(content);
+        let (i, doc) = match names.iter().position(|n| *n == Some(name)) {
+            Some(i) => (i, rest),
+            None => (i, content.as_str()),
+        };
+        if let Some(slot @ None) = docs.get_mut(i) {
+            *slot = Some(doc.to_string());
+        }
+    }
+    docs
+}
+
 // When adding a new attribute:
 // 1. add a field to this struct,
 // 2. add a match arm in the `parse` function below,
@@ -32,6 +135,7 @@ pub fn derives_mapped(attrs: &[Attribute]) -> impl Iterator<Item = Path> + '_ {
 pub struct SolAttrs {
     pub all_derives: Option<bool>,
     pub extra_methods: Option<bool>,
+    pub phf: Option<bool>,
 
     // TODO: Implement
     pub rename: Option<LitStr>,
@@ -40,9 +144,53 @@ pub struct SolAttrs {
 
     pub bytecode: Option<LitStr>,
     pub deployed_bytecode: Option<LitStr>,
+
+    pub zero_is_none: Option<bool>,
+
+    pub return_struct: Option<LitStr>,
+
+    pub builder: Option<bool>,
+
+    pub serde: Option<bool>,
 }
 
 impl SolAttrs {
+    /// Returns an error if `return_struct` is set, for items other than
+    /// functions.
+    pub fn reject_return_struct(&self) -> Result<()> {
+        match &self.return_struct {
+            Some(lit) => Err(Error::new(
+                lit.span(),
+                "`return_struct` is only supported on functions",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an error spanned to `span` if `builder` is set, for items other
+    /// than structs and events.
+    pub fn reject_builder(&self, span: Span) -> Result<()> {
+        match self.builder {
+            Some(_) => Err(Error::new(
+                span,
+                "`builder` is only supported on structs and events",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns an error spanned to `span` if `serde` is set, for items other
+    /// than structs and enums.
+    pub fn reject_serde(&self, span: Span) -> Result<()> {
+        match self.serde {
+            Some(_) => Err(Error::new(
+                span,
+                "`serde` is only supported on structs and enums",
+            )),
+            None => Ok(()),
+        }
+    }
+
     pub fn parse(attrs: &[Attribute]) -> Result<(Self, Vec<Attribute>)> {
         let mut this = Self::default();
         let mut others = Vec::with_capacity(attrs.len());
@@ -100,15 +248,32 @@ impl SolAttrs {
                     Ok(LitStr::new(v, lit.span()))
                 };
 
+                // `path = "<ident>"`
+                let ident = || -> Result<LitStr> {
+                    let lit = lit()?;
+                    syn::parse_str::<syn::Ident>(&lit.value())
+                        .map_err(|_| Error::new(lit.span(), "expected identifier"))?;
+                    Ok(lit)
+                };
+
                 match_! {
                     all_derives => bool()?,
                     extra_methods => bool()?,
+                    phf => bool()?,
 
                     rename => lit()?,
                     rename_all => CasingStyle::from_lit(&lit()?)?,
 
                     bytecode => bytes()?,
                     deployed_bytecode => bytes()?,
+
+                    zero_is_none => bool()?,
+
+                    return_struct => ident()?,
+
+                    builder => bool()?,
+
+                    serde => bool()?,
                 };
                 Ok(())
             })?;
@@ -260,6 +425,10 @@ mod tests {
             #[sol(extra_methods)] => Ok(sol_attrs! { extra_methods: true }),
             #[sol(extra_methods = true)] => Ok(sol_attrs! { extra_methods: true }),
             #[sol(extra_methods = false)] => Ok(sol_attrs! { extra_methods: false }),
+
+            #[sol(phf)] => Ok(sol_attrs! { phf: true }),
+            #[sol(phf = false)] => Ok(sol_attrs! { phf: false }),
+            #[sol(phf)] #[sol(phf)] => Err(DUPLICATE_ERROR),
         }
 
         rename {
@@ -279,5 +448,95 @@ mod tests {
             #[sol(bytecode = "xyz")] => Err("expected hex literal"),
             #[sol(bytecode = "123")] => Err("expected even number of hex digits"),
         }
+
+        zero_is_none {
+            #[sol(zero_is_none)] => Ok(sol_attrs! { zero_is_none: true }),
+            #[sol(zero_is_none = false)] => Ok(sol_attrs! { zero_is_none: false }),
+            #[sol(zero_is_none)] #[sol(zero_is_none)] => Err(DUPLICATE_ERROR),
+        }
+
+        return_struct {
+            #[sol(return_struct = "Reserves")] => Ok(sol_attrs! { return_struct: parse_quote!("Reserves") }),
+            #[sol(return_struct = "")] => Err("expected identifier"),
+            #[sol(return_struct = "a b")] => Err("expected identifier"),
+            #[sol(return_struct)] => Err("expected `=`"),
+            #[sol(return_struct = "A")] #[sol(return_struct = "B")] => Err(DUPLICATE_ERROR),
+        }
+
+        builder {
+            #[sol(builder)] => Ok(sol_attrs! { builder: true }),
+            #[sol(builder = false)] => Ok(sol_attrs! { builder: false }),
+            #[sol(builder)] #[sol(builder)] => Err(DUPLICATE_ERROR),
+        }
+
+        serde {
+            #[sol(serde)] => Ok(sol_attrs! { serde: true }),
+            #[sol(serde = false)] => Ok(sol_attrs! { serde: false }),
+            #[sol(serde)] #[sol(serde)] => Err(DUPLICATE_ERROR),
+        }
+    }
+
+    fn doc_attrs(docs: &[&str]) -> Vec<Attribute> {
+        docs.iter().map(|doc| parse_quote!(#[doc = #doc])).collect()
+    }
+
+    #[test]
+    fn natspec_params() {
+        let attrs = doc_attrs(&[
+            " @notice Transfers tokens.",
+            " @param amount The amount",
+            "   to transfer.",
+            " @param to The recipient",
+            " @param unknown Not a parameter",
+        ]);
+        let docs = param_docs(&attrs, [Some("to"), Some("amount"), None, Some("memo")]);
+        assert_eq!(
+            docs,
+            [
+                Some("The recipient".to_string()),
+                Some("The amount to transfer.".to_string()),
+                None,
+                None,
+            ]
+        );
+
+        // `/** ... */` comments
+        let attrs = doc_attrs(&["*\n     * @param a First\n     * @param b Second\n     "]);
+        let docs = param_docs(&attrs, [Some("a"), Some("b")]);
+        assert_eq!(
+            docs,
+            [Some("First".to_string()), Some("Second".to_string())]
+        );
+    }
+
+    #[test]
+    fn natspec_returns() {
+        let attrs = doc_attrs(&[" @return The first", " @return The second"]);
+        let docs = return_docs(&attrs, [None, None, None]);
+        assert_eq!(
+            docs,
+            [
+                Some("The first".to_string()),
+                Some("The second".to_string()),
+                None
+            ]
+        );
+
+        let attrs = doc_attrs(&[" @return The first", " @return b The b"]);
+        let docs = return_docs(&attrs, [None, Some("b")]);
+        assert_eq!(
+            docs,
+            [Some("The first".to_string()), Some("The b".to_string())]
+        );
+
+        // named returns are also matched out of order
+        let attrs = doc_attrs(&[" @return b The b", " @return a The a"]);
+        let docs = return_docs(&attrs, [Some("a"), Some("b")]);
+        assert_eq!(docs, [Some("The a".to_string()), Some("The b".to_string())]);
+
+        assert_eq!(
+            return_docs(&doc_attrs(&[" @param a A"]), [Some("a")]),
+            [None]
+        );
     }
 }
@@ -0,0 +1,263 @@
+//! `#[derive(SolValue)]` expansion.
+
+use crate::{expand::expand_type, utils::combine_errors};
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    ext::IdentExt, spanned::Spanned, Data, DeriveInput, Error, Field, Fields, GenericArgument,
+    Index, PathArguments, Result, Type,
+};
+
+/// Expands `#[derive(SolValue)]` on a struct to implementations of `SolType`
+/// and `Encodable`, which ABI-encode it like a tuple of its fields:
+///
+/// ```ignore (pseudo-code)
+/// const _: () = {
+///     type UnderlyingSolTuple<'a> = (#(#field_sol_type,)*);
+///
+///     impl Encodable<Self> for #name {
+///         ...
+///     }
+///
+///     impl SolType for #name {
+///         type RustType = Self;
+///         type TokenType<'a> = <UnderlyingSolTuple<'a> as SolType>::TokenType<'a>;
+///         ...
+///     }
+/// };
+/// ```
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let DeriveInput {
+        ident: name,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let not_struct = "`SolValue` can only be derived for structs";
+    let fields = match data {
+        Data::Struct(data) => data.fields,
+        Data::Enum(data) => return Err(Error::new(data.enum_token.span, not_struct)),
+        Data::Union(data) => return Err(Error::new(data.union_token.span, not_struct)),
+    };
+    if !generics.params.is_empty() || generics.where_clause.is_some() {
+        return Err(Error::new_spanned(
+            generics,
+            "`SolValue` cannot be derived for generic structs",
+        ))
+    }
+
+    let mut errors = Vec::new();
+    let types: Vec<_> = fields
+        .iter()
+        .map(|field| {
+            field_sol_type(field).unwrap_or_else(|e| {
+                errors.push(e);
+                TokenStream::new()
+            })
+        })
+        .collect();
+    combine_errors(errors)?;
+
+    // spanned to the fields, so that type mismatches point at them
+    let mut tokenize = Vec::with_capacity(fields.len());
+    let mut detokenize = Vec::with_capacity(fields.len());
+    let mut encoded_size = Vec::with_capacity(fields.len());
+    let mut data_word = Vec::with_capacity(fields.len());
+    let mut packed_size = Vec::with_capacity(fields.len());
+    let mut packed = Vec::with_capacity(fields.len());
+    let mut pretty = Vec::with_capacity(fields.len());
+    for (i, ((field, ty), member)) in fields.iter().zip(&types).zip(fields.members()).enumerate() {
+        let span = field.ty.span();
+        let idx = Index::from(i);
+        let sol_type = quote_spanned!(span=> <#ty as ::alloy_sol_types::SolType>);
+        tokenize.push(quote_spanned! {span=>
+            ::alloy_sol_types::Encodable::<#ty>::to_tokens(&self.#member)
+        });
+        detokenize.push(quote_spanned!(span=> #member: #sol_type::detokenize(token.#idx)));
+        encoded_size.push(quote_spanned! {span=>
+            #sol_type::abi_encoded_size(&rust.#member) + 32 * #sol_type::DYNAMIC as usize
+        });
+        data_word.push(quote_spanned!(span=> #sol_type::eip712_data_word(&rust.#member).0));
+        packed_size.push(quote_spanned!(span=> #sol_type::packed_encoded_size(&rust.#member)));
+        packed.push(quote_spanned!(span=> #sol_type::abi_encode_packed_to(&rust.#member, out)));
+        let value = quote_spanned!(span=> &::alloy_sol_types::Pretty::<#ty>::new(&rust.#member));
+        pretty.push(match &field.ident {
+            Some(ident) => {
+                let ident_s = ident.unraw().to_string();
+                quote!(.field(#ident_s, #value))
+            }
+            None => quote!(.field(#value)),
+        });
+    }
+    let name_s = name.to_string();
+    let debug_builder = if matches!(fields, Fields::Named(_)) {
+        quote!(debug_struct)
+    } else {
+        quote!(debug_tuple)
+    };
+
+    Ok(quote! {
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        const _: () = {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (#(#types,)*);
+
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<Self> for #name {
+                fn to_tokens(&self) -> <Self as ::alloy_sol_types::SolType>::TokenType<'_> {
+                    (#(#tokenize,)*)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::alloy_sol_types::SolType for #name {
+                type RustType = Self;
+                type TokenType<'a> = <UnderlyingSolTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+
+                const ENCODED_SIZE: ::core::option::Option<usize> =
+                    <UnderlyingSolTuple<'static> as ::alloy_sol_types::SolType>::ENCODED_SIZE;
+
+                #[inline]
+                fn sol_type_name() -> ::alloy_sol_types::private::Cow<'static, str> {
+                    <UnderlyingSolTuple<'static> as ::alloy_sol_types::SolType>::sol_type_name()
+                }
+
+                #[inline]
+                fn abi_encoded_size(rust: &Self::RustType) -> usize {
+                    if let ::core::option::Option::Some(size) = Self::ENCODED_SIZE {
+                        return size
+                    }
+
+                    0usize #( + #encoded_size)*
+                }
+
+                #[inline]
+                fn valid_token(token: &Self::TokenType<'_>) -> bool {
+                    <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::valid_token(token)
+                }
+
+                #[inline]
+                fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
+                    Self { #(#detokenize,)* }
+                }
+
+                #[inline]
+                fn eip712_data_word(rust: &Self::RustType) -> ::alloy_sol_types::Word {
+                    let words: &[[u8; 32]] = &[#(#data_word),*];
+                    ::alloy_sol_types::private::keccak256(words.concat())
+                }
+
+                #[inline]
+                fn packed_encoded_size(rust: &Self::RustType) -> usize {
+                    0usize #( + #packed_size)*
+                }
+
+                #[inline]
+                fn abi_encode_packed_to(rust: &Self::RustType, out: &mut ::alloy_sol_types::private::Vec<u8>) {
+                    #(#packed;)*
+                }
+
+                fn fmt_pretty(rust: &Self::RustType, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.#debug_builder(#name_s) #(#pretty)* .finish()
+                }
+            }
+        };
+    })
+}
+
+/// Returns the `SolType` of a field: the Solidity type of its `#[sol(...)]`
+/// attribute if it has one, or the one inferred from its Rust type otherwise.
+fn field_sol_type(field: &Field) -> Result<TokenStream> {
+    let mut attrs = field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("sol"));
+    let Some(attr) = attrs.next() else {
+        return infer_sol_type(&field.ty)
+    };
+    if let Some(dup) = attrs.next() {
+        return Err(Error::new_spanned(dup, "duplicate `#[sol(...)]` attribute"))
+    }
+    attr.parse_args::<ast::Type>().map(|ty| expand_type(&ty))
+}
+
+/// Infers the `SolType` of a Rust type.
+///
+/// Types are matched by name only. Other type paths are assumed to implement
+/// `SolType` themselves, like `sol!` structs or other types that derive
+/// `SolValue`. Primitive integers and `Vec<u8>` are rejected, as they can
+/// represent several Solidity types.
+fn infer_sol_type(ty: &Type) -> Result<TokenStream> {
+    let sol_data = quote!(::alloy_sol_types::sol_data);
+    match ty {
+        Type::Group(group) => infer_sol_type(&group.elem),
+        Type::Paren(paren) => infer_sol_type(&paren.elem),
+        Type::Tuple(tuple) => {
+            let types = tuple
+                .elems
+                .iter()
+                .map(infer_sol_type)
+                .collect::<Result<Vec<_>>>()?;
+            Ok(quote!((#(#types,)*)))
+        }
+        Type::Array(array) => {
+            let ty = infer_sol_type(&array.elem)?;
+            let len = &array.len;
+            Ok(quote!(#sol_data::FixedArray<#ty, { #len }>))
+        }
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last().unwrap();
+            let args = match &segment.arguments {
+                PathArguments::None => None,
+                PathArguments::AngleBracketed(args) if args.args.len() == 1 => args.args.first(),
+                PathArguments::AngleBracketed(_) | PathArguments::Parenthesized(_) => {
+                    return Ok(ty.to_token_stream())
+                }
+            };
+            let name = segment.ident.to_string();
+            let sol_ty = match (name.as_str(), args) {
+                ("bool", None) => quote!(#sol_data::Bool),
+                ("Address", None) => quote!(#sol_data::Address),
+                ("String", None) => quote!(#sol_data::String),
+                ("Bytes", None) => quote!(#sol_data::PrimitiveBytes),
+                ("Function", None) => quote!(#sol_data::Function),
+                ("U256", None) => quote!(#sol_data::Uint<256>),
+                ("I256", None) => quote!(#sol_data::Int<256>),
+                ("B256", None) => quote!(#sol_data::FixedBytes<32>),
+                ("FixedBytes", Some(GenericArgument::Const(n))) => {
+                    quote!(#sol_data::FixedBytes<{ #n }>)
+                }
+                ("Vec", Some(GenericArgument::Type(elem))) => {
+                    if matches!(elem, Type::Path(p) if p.path.is_ident("u8")) {
+                        return Err(ambiguous(ty, "Vec<u8>", "bytes"))
+                    }
+                    let elem = infer_sol_type(elem)?;
+                    quote!(#sol_data::Array<#elem>)
+                }
+                ("usize", None) => return Err(ambiguous(ty, &name, "uint64")),
+                ("isize", None) => return Err(ambiguous(ty, &name, "int64")),
+                ("u8" | "u16" | "u32" | "u64" | "u128", None) => {
+                    return Err(ambiguous(ty, &name, &format!("uint{}", &name[1..])))
+                }
+                ("i8" | "i16" | "i32" | "i64" | "i128", None) => {
+                    return Err(ambiguous(ty, &name, &format!("int{}", &name[1..])))
+                }
+                _ => ty.to_token_stream(),
+            };
+            Ok(sol_ty)
+        }
+        _ => Err(Error::new_spanned(
+            ty,
+            "cannot infer the Solidity type of this field; specify it with `#[sol(...)]`",
+        )),
+    }
+}
+
+fn ambiguous(ty: &Type, name: &str, example: &str) -> Error {
+    let msg = format!(
+        "ambiguous Solidity type for `{name}`; \
+         specify it with `#[sol(...)]`, e.g. `#[sol({example})]`"
+    );
+    Error::new_spanned(ty, msg)
+}
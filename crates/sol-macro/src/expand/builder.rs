@@ -0,0 +1,124 @@
+//! `#[sol(builder)]` expansion.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// A field of the type that a builder constructs.
+pub(super) struct BuilderField {
+    /// The field name.
+    pub(super) name: Ident,
+    /// The Rust type of the field.
+    pub(super) ty: TokenStream,
+    /// Whether `build` fails if the field is not set, instead of using its
+    /// default value.
+    pub(super) required: bool,
+}
+
+/// Expands a builder for the struct `name` with the given fields. The builder
+/// also derives `Debug` if `debug` is true, which requires every field type to
+/// implement it:
+///
+/// ```ignore (pseudo-code)
+/// #[derive(Clone, Default)]
+/// pub struct #{name}Builder {
+///     #(#field_name: Option<#field_type>,)*
+/// }
+///
+/// impl #name {
+///     pub fn builder() -> #{name}Builder { ... }
+/// }
+///
+/// impl #{name}Builder {
+///     #(pub fn with_#field_name(mut self, #field_name: #field_type) -> Self { ... })*
+///
+///     pub fn build(self) -> Result<#name> { ... }
+/// }
+/// ```
+pub(super) fn expand(name: &Ident, fields: &[BuilderField], debug: bool) -> TokenStream {
+    let builder = format_ident!("{name}Builder");
+    let name_s = name.to_string();
+
+    let builder_doc = format!(
+        "A builder for [`{name_s}`], created with [`{name_s}::builder`].\n\n\
+         Fields that are not set are [`Default`]ed, except for indexed event \
+         parameters and fields whose type has no default value, which must be \
+         set before calling [`build`]({builder}::build)."
+    );
+    let constructor_doc = format!("Returns a builder for [`{name_s}`].");
+
+    let names: Vec<_> = fields.iter().map(|f| &f.name).collect();
+    let types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+
+    let setters = fields.iter().map(|BuilderField { name, ty, .. }| {
+        let field_s = name.to_string();
+        let field_s = field_s.trim_start_matches("r#");
+        let setter = format_ident!("with_{field_s}");
+        let doc = format!("Sets the `{field_s}` field.");
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            pub fn #setter(mut self, #name: #ty) -> Self {
+                self.#name = ::core::option::Option::Some(#name);
+                self
+            }
+        }
+    });
+
+    let values = fields.iter().map(|BuilderField { name, required, .. }| {
+        if *required {
+            let field_s = name.to_string();
+            let field_s = field_s.trim_start_matches("r#");
+            quote! {
+                self.#name.ok_or(::alloy_sol_types::Error::MissingField {
+                    name: #name_s,
+                    field: #field_s,
+                })?
+            }
+        } else {
+            quote!(self.#name.unwrap_or_default())
+        }
+    });
+
+    let derives = if debug {
+        quote!(Clone, Debug, Default)
+    } else {
+        quote!(Clone, Default)
+    };
+
+    quote! {
+        #[doc = #builder_doc]
+        #[allow(non_camel_case_types, non_snake_case)]
+        #[derive(#derives)]
+        pub struct #builder {
+            #(#names: ::core::option::Option<#types>,)*
+        }
+
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        const _: () = {
+            #[automatically_derived]
+            impl #name {
+                #[doc = #constructor_doc]
+                #[inline]
+                pub fn builder() -> #builder {
+                    ::core::default::Default::default()
+                }
+            }
+
+            #[automatically_derived]
+            impl #builder {
+                #(#setters)*
+
+                /// Builds the value, returning [`Error::MissingField`] if a
+                /// required field was not set.
+                ///
+                /// [`Error::MissingField`]: ::alloy_sol_types::Error::MissingField
+                #[inline]
+                pub fn build(self) -> ::alloy_sol_types::Result<#name> {
+                    ::core::result::Result::Ok(#name {
+                        #(#names: #values,)*
+                    })
+                }
+            }
+        };
+    }
+}
@@ -1,8 +1,11 @@
 //! [`ItemContract`] expansion.
 
 use super::{ty, ExpCtxt};
-use crate::{attr, utils::ExprArray};
-use ast::{Item, ItemContract, ItemError, ItemEvent, ItemFunction, SolIdent};
+use crate::{
+    attr,
+    utils::{self, ExprArray},
+};
+use ast::{Item, ItemContract, ItemError, ItemEvent, ItemFunction, SolIdent, Spanned};
 use heck::ToSnakeCase;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
@@ -16,9 +19,17 @@ use syn::{ext::IdentExt, parse_quote, Attribute, Result};
 ///         ...
 ///    }
 ///
+///     pub enum #{name}Returns {
+///         ...
+///    }
+///
 ///     pub enum #{name}Errors {
 ///         ...
 ///    }
+///
+///     pub enum #{name}Events {
+///         ...
+///    }
 /// }
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenStream> {
@@ -27,10 +38,14 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
     } = contract;
 
     let (sol_attrs, attrs) = attr::SolAttrs::parse(attrs)?;
+    sol_attrs.reject_return_struct()?;
+    sol_attrs.reject_builder(name.span())?;
+    sol_attrs.reject_serde(name.span())?;
     let extra_methods = sol_attrs
         .extra_methods
         .or(cx.attrs.extra_methods)
         .unwrap_or(false);
+    let phf = sol_attrs.phf.or(cx.attrs.phf);
 
     let bytecode = sol_attrs.bytecode.map(|lit| {
         let name = Ident::new("BYTECODE", lit.span());
@@ -60,25 +75,53 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
             Item::Event(event) => events.push(event),
             _ => {}
         }
-        if !d_attrs.is_empty() {
-            item_tokens.extend(quote!(#(#d_attrs)*));
+        match item {
+            // functions expand to both a call and a return struct, which both
+            // need the derives for the `Calls` and `Returns` enums
+            Item::Function(function) if !d_attrs.is_empty() => {
+                let mut function = function.clone();
+                function.attrs.extend(d_attrs.iter().cloned());
+                item_tokens.extend(cx.expand_item(&Item::Function(function))?);
+            }
+            _ => {
+                if !d_attrs.is_empty() {
+                    item_tokens.extend(quote!(#(#d_attrs)*));
+                }
+                item_tokens.extend(cx.expand_item(item)?);
+            }
         }
-        item_tokens.extend(cx.expand_item(item)?);
     }
 
-    let functions_enum = (!functions.is_empty()).then(|| {
-        let mut attrs = d_attrs.clone();
-        let doc_str = format!("Container for all the `{name}` function calls.");
-        attrs.push(parse_quote!(#[doc = #doc_str]));
-        CallLikeExpander::from_functions(cx, name, functions).expand(attrs, extra_methods)
-    });
-
-    let errors_enum = (!errors.is_empty()).then(|| {
-        let mut attrs = d_attrs.clone();
-        let doc_str = format!("Container for all the `{name}` custom errors.");
-        attrs.push(parse_quote!(#[doc = #doc_str]));
-        CallLikeExpander::from_errors(cx, name, errors).expand(attrs, extra_methods)
-    });
+    let functions_enum = (!functions.is_empty())
+        .then(|| -> Result<_> {
+            let mut attrs = d_attrs.clone();
+            let doc_str = format!("Container for all the `{name}` function calls.");
+            attrs.push(parse_quote!(#[doc = #doc_str]));
+
+            let mut returns_attrs = d_attrs.clone();
+            let doc_str =
+                format!("Container for the return values of all the `{name}` function calls.");
+            returns_attrs.push(parse_quote!(#[doc = #doc_str]));
+
+            let expander = CallLikeExpander::from_functions(cx, name, functions)?;
+            let returns_enum = expander.expand_returns(returns_attrs);
+            let calls_enum = expander.expand(attrs, extra_methods, phf);
+            Ok(quote! {
+                #calls_enum
+                #returns_enum
+            })
+        })
+        .transpose()?;
+
+    let errors_enum = (!errors.is_empty())
+        .then(|| -> Result<_> {
+            let mut attrs = d_attrs.clone();
+            let doc_str = format!("Container for all the `{name}` custom errors.");
+            attrs.push(parse_quote!(#[doc = #doc_str]));
+            let expander = CallLikeExpander::from_errors(cx, name, errors)?;
+            Ok(expander.expand(attrs, extra_methods, phf))
+        })
+        .transpose()?;
 
     let events_enum = (!events.is_empty()).then(|| {
         let mut attrs = d_attrs;
@@ -125,6 +168,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
 ///
 /// impl #name {
 ///     pub const SELECTORS: &'static [[u8; _]] = &[...];
+///     pub const fn selector_index(selector: [u8; 4]) -> Option<usize> { ... }
 /// }
 ///
 /// #if extra_methods
@@ -144,6 +188,8 @@ struct CallLikeExpander<'a> {
     cx: &'a ExpCtxt<'a>,
     name: Ident,
     variants: Vec<Ident>,
+    /// The indices of the variants, sorted by selector.
+    sorted: Vec<usize>,
     min_data_len: usize,
     trait_: Ident,
     data: CallLikeExpanderData,
@@ -153,6 +199,8 @@ enum CallLikeExpanderData {
     Function {
         selectors: Vec<ExprArray<u8, 4>>,
         types: Vec<Ident>,
+        returns_name: Ident,
+        return_types: Vec<Ident>,
     },
     Error {
         selectors: Vec<ExprArray<u8, 4>>,
@@ -167,39 +215,62 @@ impl<'a> CallLikeExpander<'a> {
         cx: &'a ExpCtxt<'a>,
         contract_name: &SolIdent,
         functions: Vec<&ItemFunction>,
-    ) -> Self {
+    ) -> Result<Self> {
         let variants: Vec<_> = functions
             .iter()
             .map(|f| cx.function_name_ident(f).0)
             .collect();
 
         let types: Vec<_> = variants.iter().map(|name| cx.raw_call_name(name)).collect();
+        let return_types = functions
+            .iter()
+            .map(|f| cx.return_name(f))
+            .collect::<Result<Vec<_>>>()?;
 
         let mut selectors: Vec<_> = functions.iter().map(|f| cx.function_selector(f)).collect();
+        let sorted = sort_order(&selectors);
+        check_selectors("function", &functions, &selectors, &sorted, |f| {
+            cx.function_signature(f)
+        })?;
         selectors.sort_unstable_by_key(|a| a.array);
 
-        Self {
+        Ok(Self {
             cx,
             name: format_ident!("{contract_name}Calls"),
             variants,
+            sorted,
             min_data_len: functions
                 .iter()
                 .map(|function| ty::params_base_data_size(cx, &function.arguments))
                 .min()
                 .unwrap(),
             trait_: Ident::new("SolCall", Span::call_site()),
-            data: CallLikeExpanderData::Function { selectors, types },
-        }
+            data: CallLikeExpanderData::Function {
+                selectors,
+                types,
+                returns_name: format_ident!("{contract_name}Returns"),
+                return_types,
+            },
+        })
     }
 
-    fn from_errors(cx: &'a ExpCtxt<'a>, contract_name: &SolIdent, errors: Vec<&ItemError>) -> Self {
+    fn from_errors(
+        cx: &'a ExpCtxt<'a>,
+        contract_name: &SolIdent,
+        errors: Vec<&ItemError>,
+    ) -> Result<Self> {
         let mut selectors: Vec<_> = errors.iter().map(|e| cx.error_selector(e)).collect();
+        let sorted = sort_order(&selectors);
+        check_selectors("error", &errors, &selectors, &sorted, |e| {
+            cx.error_signature(e)
+        })?;
         selectors.sort_unstable_by_key(|a| a.array);
 
-        Self {
+        Ok(Self {
             cx,
             name: format_ident!("{contract_name}Errors"),
             variants: errors.iter().map(|error| error.name.0.clone()).collect(),
+            sorted,
             min_data_len: errors
                 .iter()
                 .map(|error| ty::params_base_data_size(cx, &error.parameters))
@@ -207,17 +278,19 @@ impl<'a> CallLikeExpander<'a> {
                 .unwrap(),
             trait_: Ident::new("SolError", Span::call_site()),
             data: CallLikeExpanderData::Error { selectors },
-        }
+        })
     }
 
     fn from_events(cx: &'a ExpCtxt<'a>, contract_name: &SolIdent, events: Vec<&ItemEvent>) -> Self {
         let mut selectors: Vec<_> = events.iter().map(|e| cx.event_selector(e)).collect();
+        let sorted = sort_order(&selectors);
         selectors.sort_unstable_by_key(|a| a.array);
 
         Self {
             cx,
             name: format_ident!("{contract_name}Events"),
             variants: events.iter().map(|event| event.name.0.clone()).collect(),
+            sorted,
             min_data_len: events
                 .iter()
                 .map(|event| ty::params_base_data_size(cx, &event.params()))
@@ -237,7 +310,7 @@ impl<'a> CallLikeExpander<'a> {
         }
     }
 
-    fn expand(self, attrs: Vec<Attribute>, extra_methods: bool) -> TokenStream {
+    fn expand(self, attrs: Vec<Attribute>, extra_methods: bool, phf: Option<bool>) -> TokenStream {
         let Self {
             name,
             variants,
@@ -251,6 +324,45 @@ impl<'a> CallLikeExpander<'a> {
         let name_s = name.to_string();
         let count = variants.len();
         let def = self.generate_enum(attrs, extra_methods);
+
+        let (valid_selector, decode) = if phf.unwrap_or(count >= PHF_MIN) {
+            let sorted_variants = self.sorted.iter().map(|&i| &variants[i]);
+            let sorted_types = self.sorted.iter().map(|&i| &types[i]);
+            let valid_selector = quote!(Self::selector_index(selector).is_some());
+            let decode = quote! {
+                // in the same order as `SELECTORS`
+                static DECODE_SHIMS: &[fn(&[u8], bool) -> ::alloy_sol_types::Result<#name>] = &[#(
+                    |data: &[u8], validate: bool| {
+                        <#sorted_types as ::alloy_sol_types::#trait_>::abi_decode_raw(data, validate)
+                            .map(#name::#sorted_variants)
+                    },
+                )*];
+                match Self::selector_index(selector) {
+                    ::core::option::Option::Some(i) => DECODE_SHIMS[i](data, validate),
+                    ::core::option::Option::None => ::core::result::Result::Err(
+                        ::alloy_sol_types::Error::unknown_selector(Self::NAME, selector),
+                    ),
+                }
+            };
+            (valid_selector, decode)
+        } else {
+            let valid_selector = quote! {
+                ::core::matches!(selector, #(<#types as ::alloy_sol_types::#trait_>::SELECTOR)|*)
+            };
+            let decode = quote! {
+                match selector {
+                    #(<#types as ::alloy_sol_types::#trait_>::SELECTOR => {
+                        <#types as ::alloy_sol_types::#trait_>::abi_decode_raw(data, validate)
+                            .map(Self::#variants)
+                    })*
+                    s => ::core::result::Result::Err(::alloy_sol_types::Error::unknown_selector(
+                        Self::NAME,
+                        s,
+                    )),
+                }
+            };
+            (valid_selector, decode)
+        };
         quote! {
             #def
 
@@ -274,7 +386,7 @@ impl<'a> CallLikeExpander<'a> {
 
                 #[inline]
                 fn valid_selector(selector: [u8; 4]) -> bool {
-                    ::core::matches!(selector, #(<#types as ::alloy_sol_types::#trait_>::SELECTOR)|*)
+                    #valid_selector
                 }
 
                 #[inline]
@@ -283,16 +395,7 @@ impl<'a> CallLikeExpander<'a> {
                     data: &[u8],
                     validate: bool
                 )-> ::alloy_sol_types::Result<Self> {
-                    match selector {
-                        #(<#types as ::alloy_sol_types::#trait_>::SELECTOR => {
-                            <#types as ::alloy_sol_types::#trait_>::abi_decode_raw(data, validate)
-                                .map(Self::#variants)
-                        })*
-                        s => ::core::result::Result::Err(::alloy_sol_types::Error::unknown_selector(
-                            Self::NAME,
-                            s,
-                        )),
-                    }
+                    #decode
                 }
 
                 #[inline]
@@ -314,9 +417,90 @@ impl<'a> CallLikeExpander<'a> {
         }
     }
 
+    /// Expands the enum of the return values of the functions, and the
+    /// `SolCallInterface` implementation of the calls enum.
+    fn expand_returns(&self, mut attrs: Vec<Attribute>) -> TokenStream {
+        let CallLikeExpanderData::Function {
+            returns_name,
+            return_types,
+            ..
+        } = &self.data
+        else {
+            unreachable!("not a function expander")
+        };
+        let Self { name, variants, .. } = self;
+        let types = self.types();
+
+        self.cx.type_derives(
+            &mut attrs,
+            return_types.iter().cloned().map(ast::Type::custom),
+            false,
+        );
+        quote! {
+            #(#attrs)*
+            pub enum #returns_name {
+                #(#variants(#return_types),)*
+            }
+
+            #[automatically_derived]
+            impl ::alloy_sol_types::SolCallInterface for #name {
+                type Returns = #returns_name;
+
+                #[inline]
+                fn abi_decode_returns(
+                    &self,
+                    data: &[u8],
+                    validate: bool,
+                ) -> ::alloy_sol_types::Result<Self::Returns> {
+                    match self {#(
+                        Self::#variants(_) => {
+                            <#types as ::alloy_sol_types::SolCall>::abi_decode_returns(data, validate)
+                                .map(#returns_name::#variants)
+                        }
+                    )*}
+                }
+            }
+        }
+    }
+
     fn expand_event(self, attrs: Vec<Attribute>, extra_methods: bool) -> TokenStream {
-        // TODO: SolInterface for events
-        self.generate_enum(attrs, extra_methods)
+        let def = self.generate_enum(attrs, extra_methods);
+        let Self { name, variants, .. } = &self;
+        let types = self.types();
+        let name_s = name.to_string();
+        let count = variants.len();
+        quote! {
+            #def
+
+            #[automatically_derived]
+            impl ::alloy_sol_types::SolEventInterface for #name {
+                const NAME: &'static str = #name_s;
+                const COUNT: usize = #count;
+
+                fn decode_log(
+                    topics: &[::alloy_sol_types::Word],
+                    data: &[u8],
+                    validate: bool,
+                ) -> ::alloy_sol_types::Result<Self> {
+                    let signature = topics.first().copied();
+                    #(
+                        if !<#types as ::alloy_sol_types::SolEvent>::ANONYMOUS
+                            && signature == ::core::option::Option::Some(<#types as ::alloy_sol_types::SolEvent>::SIGNATURE_HASH)
+                        {
+                            return <#types as ::alloy_sol_types::SolEvent>::decode_log(
+                                topics.iter().copied(),
+                                data,
+                                validate,
+                            ).map(Self::#variants)
+                        }
+                    )*
+                    ::core::result::Result::Err(::alloy_sol_types::Error::UnknownEvent {
+                        name: Self::NAME,
+                        signature,
+                    })
+                }
+            }
+        }
     }
 
     fn generate_enum(&self, mut attrs: Vec<Attribute>, extra_methods: bool) -> TokenStream {
@@ -326,13 +510,48 @@ impl<'a> CallLikeExpander<'a> {
             data,
             ..
         } = self;
-        let (selectors, selector_type) = match data {
+        let (selectors, selector_type, selector_index) = match data {
             CallLikeExpanderData::Function { selectors, .. }
             | CallLikeExpanderData::Error { selectors } => {
-                (quote!(#(#selectors,)*), quote!([u8; 4]))
+                let selector_index = match selector_phf(selectors) {
+                    Some((displacements, slots)) => quote! {
+                        /// Returns the index of the given selector in
+                        /// [`SELECTORS`](Self::SELECTORS), if it is one of the
+                        /// selectors of this enum.
+                        ///
+                        /// This uses a perfect hash table generated at compile time.
+                        #[inline]
+                        pub const fn selector_index(selector: [u8; 4]) -> ::core::option::Option<usize> {
+                            const DISPLACEMENTS: &[u32] = &[#(#displacements),*];
+                            const SLOTS: &[u16] = &[#(#slots),*];
+                            ::alloy_sol_types::private::selector_index(
+                                Self::SELECTORS,
+                                DISPLACEMENTS,
+                                SLOTS,
+                                selector,
+                            )
+                        }
+                    },
+                    None => quote! {
+                        /// Returns the index of the given selector in
+                        /// [`SELECTORS`](Self::SELECTORS), if it is one of the
+                        /// selectors of this enum.
+                        ///
+                        /// This uses a binary search over the sorted selectors.
+                        #[inline]
+                        pub const fn selector_index(selector: [u8; 4]) -> ::core::option::Option<usize> {
+                            ::alloy_sol_types::private::selector_index_sorted(Self::SELECTORS, selector)
+                        }
+                    },
+                };
+                (
+                    quote!(#(#selectors,)*),
+                    quote!([u8; 4]),
+                    Some(selector_index),
+                )
             }
             CallLikeExpanderData::Event { selectors } => {
-                (quote!(#(#selectors,)*), quote!([u8; 32]))
+                (quote!(#(#selectors,)*), quote!([u8; 32]), None)
             }
         };
 
@@ -355,6 +574,8 @@ impl<'a> CallLikeExpander<'a> {
                 /// Note that the selectors might not be in the same order as the
                 /// variants, as they are sorted instead of ordered by definition.
                 pub const SELECTORS: &'static [#selector_type] = &[#selectors];
+
+                #selector_index
             }
         };
 
@@ -380,6 +601,115 @@ impl<'a> CallLikeExpander<'a> {
     }
 }
 
+/// The number of variants from which `Calls` and `Errors` enums are decoded
+/// with a perfect hash table instead of a `match`, unless overridden with
+/// `#[sol(phf)]`.
+const PHF_MIN: usize = 32;
+
+/// The maximum number of times the perfect hash table is grown before falling
+/// back to a binary search.
+const PHF_MAX_GROWTH: usize = 4;
+
+/// Generates the perfect hash table of the given sorted selectors, which is
+/// looked up with `alloy_sol_types::private::selector_index`.
+///
+/// Selectors are already uniformly distributed, so they are assigned to
+/// buckets by their high bits. Then, starting from the largest bucket, each
+/// bucket is given the first displacement that hashes all of its selectors to
+/// free slots. Returns the displacements of each bucket, and the index of the
+/// selector in each slot, or `u16::MAX` if the slot is empty.
+///
+/// The number of slots is doubled when a bucket can't be placed, up to
+/// [`PHF_MAX_GROWTH`] times. Returns `None` if that is not enough, in which
+/// case the selectors are binary searched instead.
+fn selector_phf(selectors: &[ExprArray<u8, 4>]) -> Option<(Vec<u32>, Vec<u16>)> {
+    fn high_bits(x: u32, len: usize) -> usize {
+        x.checked_shr(32 - len.trailing_zeros()).unwrap_or(0) as usize
+    }
+    let slot = |key: u32, d: u32, len| high_bits((key ^ d).wrapping_mul(0x9e37_79b9), len);
+
+    let keys: Vec<u32> = selectors
+        .iter()
+        .map(|s| u32::from_be_bytes(s.array))
+        .collect();
+    assert!(keys.len() < u16::MAX as usize, "too many selectors");
+    let n_buckets = (keys.len() / 2).next_power_of_two();
+    let mut n_slots = (keys.len() * 2).next_power_of_two();
+
+    let mut buckets = vec![Vec::new(); n_buckets];
+    for (i, &key) in keys.iter().enumerate() {
+        buckets[high_bits(key, n_buckets)].push(i);
+    }
+    let mut order: Vec<_> = (0..n_buckets).collect();
+    order.sort_by_key(|&b| std::cmp::Reverse(buckets[b].len()));
+
+    'outer: for _ in 0..=PHF_MAX_GROWTH {
+        let mut displacements = vec![0; n_buckets];
+        let mut slots = vec![u16::MAX; n_slots];
+        for &b in &order {
+            let bucket = &buckets[b];
+            let found = (0..1 << 16).find(|&d| {
+                let mut taken: Vec<usize> = Vec::with_capacity(bucket.len());
+                bucket.iter().all(|&i| {
+                    let s = slot(keys[i], d, n_slots);
+                    let free = slots[s] == u16::MAX && !taken.contains(&s);
+                    taken.push(s);
+                    free
+                })
+            });
+            let Some(d) = found else {
+                n_slots *= 2;
+                continue 'outer
+            };
+            displacements[b] = d;
+            for &i in bucket {
+                slots[slot(keys[i], d, n_slots)] = i as u16;
+            }
+        }
+        return Some((displacements, slots))
+    }
+    None
+}
+
+/// Returns an error for each pair of items with the same selector, as the
+/// selectors of an interface must be unique, like in Solidity.
+fn check_selectors<T: Spanned>(
+    kind: &str,
+    items: &[&T],
+    selectors: &[ExprArray<u8, 4>],
+    sorted: &[usize],
+    signature: impl Fn(&T) -> String,
+) -> Result<()> {
+    let errors = sorted.windows(2).filter_map(|w| {
+        let (a, b) = (w[0].min(w[1]), w[0].max(w[1]));
+        let selector = u32::from_be_bytes(selectors[a].array);
+        if selector != u32::from_be_bytes(selectors[b].array) {
+            return None
+        }
+
+        let msg = format!(
+            "{kind} `{}` has the same selector as `{}`: {selector:#010x}",
+            signature(items[b]),
+            signature(items[a]),
+        );
+        let mut err = syn::Error::new(items[b].span(), msg);
+
+        let msg = "other declaration is here";
+        let note = syn::Error::new(items[a].span(), msg);
+
+        err.combine(note);
+        Some(err)
+    });
+    utils::combine_errors(errors)
+}
+
+/// Returns the indices of the given selectors in sorted order.
+fn sort_order<const N: usize>(selectors: &[ExprArray<u8, N>]) -> Vec<usize> {
+    let mut order: Vec<_> = (0..selectors.len()).collect();
+    order.sort_unstable_by_key(|&i| selectors[i].array);
+    order
+}
+
 fn generate_variant_conversions(name: &Ident, variant: &Ident, ty: &Ident) -> TokenStream {
     quote! {
         #[automatically_derived]
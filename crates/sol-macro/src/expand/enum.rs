@@ -27,7 +27,9 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
         ..
     } = enumm;
 
-    let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    let (sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    sol_attrs.reject_return_struct()?;
+    sol_attrs.reject_builder(name.span())?;
     cx.derives(&mut attrs, [], false);
 
     let name_s = name.to_string();
@@ -45,12 +47,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
     let invalid_variant = has_invalid_variant.then(|| {
         let comma = (!variants.trailing_punct()).then(syn::token::Comma::default);
 
-        let has_serde = attr::derives_mapped(&attrs).any(|path| {
-            let Some(last) = path.segments.last() else {
-                return false
-            };
-            last.ident == "Serialize" || last.ident == "Deserialize"
-        });
+        let has_serde =
+            attr::derives_trait(&attrs, "Serialize") || attr::derives_trait(&attrs, "Deserialize");
         let serde_other = has_serde.then(|| quote!(#[serde(other)]));
 
         quote! {
@@ -72,6 +70,70 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
     let uint8 = quote!(::alloy_sol_types::sol_data::Uint<8>);
     let uint8_st = quote!(<#uint8 as ::alloy_sol_types::SolType>);
 
+    let serde_impl = sol_attrs.serde.or(cx.attrs.serde).unwrap_or(false).then(|| {
+        let serde = quote!(::alloy_sol_types::private::serde);
+        quote! {
+            #[automatically_derived]
+            impl ::alloy_sol_types::SolSerde for #name {
+                #[inline]
+                fn serialize<S: #serde::Serializer>(
+                    rust: &Self,
+                    serializer: S,
+                ) -> ::core::result::Result<S::Ok, S::Error> {
+                    <#uint8 as ::alloy_sol_types::SolSerde>::serialize(rust.as_u8(), serializer)
+                }
+
+                #[inline]
+                fn deserialize<'de, D: #serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> ::core::result::Result<Self, D::Error> {
+                    let value = <#uint8 as ::alloy_sol_types::SolSerde>::deserialize(deserializer)?;
+                    <Self as ::core::convert::TryFrom<u8>>::try_from(value)
+                        .map_err(<D::Error as #serde::de::Error>::custom)
+                }
+            }
+        }
+    });
+
+    let pretty_impl = {
+        let variant_names = variants.iter().map(|v| &v.ident);
+        let variant_strs = variants
+            .iter()
+            .map(|v| format!("{name_s}.{}", v.ident.as_string()));
+        let invalid_str = format!("{name_s}({})", u8::MAX);
+        quote! {
+            fn fmt_pretty(rust: &Self::RustType, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(match rust {
+                    #(Self::#variant_names => #variant_strs,)*
+                    #[allow(unreachable_patterns)]
+                    _ => #invalid_str,
+                })
+            }
+        }
+    };
+
+    let sol_literal_impl = {
+        let variant_names = variants.iter().map(|v| &v.ident);
+        let variant_literals = variants
+            .iter()
+            .map(|v| format!("{name_s}.{}", v.ident.as_string()));
+        // the only other possible value is the invalid variant
+        let invalid_literal = format!("{name_s}({})", u8::MAX);
+        // emitted through alloy-sol-types, which owns the `sol-literal` feature
+        quote! {
+            ::alloy_sol_types::__sol_literal_impl! {
+                #[inline]
+                fn to_sol_literal(rust: &Self::RustType) -> ::alloy_sol_types::private::String {
+                    match rust {
+                        #(Self::#variant_names => #variant_literals.into(),)*
+                        #[allow(unreachable_patterns)]
+                        _ => #invalid_literal.into(),
+                    }
+                }
+            }
+        }
+    };
+
     let tokens = quote! {
         #(#attrs)*
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
@@ -156,10 +218,19 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
                     #uint8_st::eip712_data_word(rust.as_u8())
                 }
 
+                #[inline]
+                fn packed_encoded_size(_rust: &Self::RustType) -> usize {
+                    1
+                }
+
                 #[inline]
                 fn abi_encode_packed_to(rust: &Self::RustType, out: &mut ::alloy_sol_types::private::Vec<u8>) {
                     out.push(*rust as u8);
                 }
+
+                #pretty_impl
+
+                #sol_literal_impl
             }
 
             #[automatically_derived]
@@ -175,6 +246,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
                     unsafe { ::core::mem::transmute::<&Self, &u8>(self) }
                 }
             }
+
+            #serde_impl
         };
     };
     Ok(tokens)
@@ -1,7 +1,10 @@
 //! [`ItemError`] expansion.
 
-use super::{expand_fields, expand_from_into_tuples, ty::expand_tokenize_func, ExpCtxt};
-use ast::ItemError;
+use super::{
+    expand_fields, expand_from_into_tuples, param_names, ty::expand_tokenize_func, ExpCtxt,
+};
+use crate::attr;
+use ast::{ItemError, Spanned};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Result;
@@ -26,7 +29,13 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
     } = error;
     cx.assert_resolved(params)?;
 
-    let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    let names = param_names(params);
+    let docs = attr::param_docs(attrs, names.iter().map(Option::as_deref));
+
+    let (sol_attrs, mut attrs) = attr::SolAttrs::parse(attrs)?;
+    sol_attrs.reject_return_struct()?;
+    sol_attrs.reject_builder(name.span())?;
+    sol_attrs.reject_serde(name.span())?;
     cx.derives(&mut attrs, params, true);
 
     let tokenize_impl = expand_tokenize_func(params.iter());
@@ -35,7 +44,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
     let selector = crate::utils::selector(&signature);
 
     let converts = expand_from_into_tuples(&name.0, params);
-    let fields = expand_fields(params);
+    let fields = expand_fields(params, &docs)?;
     let tokens = quote! {
         #(#attrs)*
         #[allow(non_camel_case_types, non_snake_case)]
@@ -1,6 +1,11 @@
 //! [`ItemEvent`] expansion.
 
-use super::{anon_name, expand_tuple_types, expand_type, ty, ExpCtxt};
+use super::{
+    anon_name,
+    builder::{self, BuilderField},
+    expand_doc, expand_tuple_types, expand_type, ty, ExpCtxt,
+};
+use crate::attr;
 use ast::{EventParameter, ItemEvent, SolIdent, Spanned};
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
@@ -21,7 +26,16 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     let ItemEvent { name, attrs, .. } = event;
     let params = event.params();
 
-    let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    let names = event
+        .parameters
+        .iter()
+        .map(|p| p.name.as_ref().map(|name| name.as_string()));
+    let names: Vec<_> = names.collect();
+    let docs = attr::param_docs(attrs, names.iter().map(Option::as_deref));
+
+    let (sol_attrs, mut attrs) = attr::SolAttrs::parse(attrs)?;
+    sol_attrs.reject_return_struct()?;
+    sol_attrs.reject_serde(name.span())?;
     cx.derives(&mut attrs, &params, true);
 
     cx.assert_resolved(&params)?;
@@ -89,11 +103,29 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         }
     });
 
-    let fields = event
-        .parameters
-        .iter()
-        .enumerate()
-        .map(|(i, p)| expand_event_topic_field(i, p, p.name.as_ref()));
+    let fields = event.parameters.iter().enumerate().map(|(i, p)| {
+        let doc = docs[i].as_deref().map(expand_doc);
+        let field = expand_event_topic_field(i, p, p.name.as_ref());
+        quote!(#doc pub #field)
+    });
+
+    let builder_impl = sol_attrs
+        .builder
+        .or(cx.attrs.builder)
+        .unwrap_or(false)
+        .then(|| {
+            let fields = event
+                .parameters
+                .iter()
+                .enumerate()
+                .map(|(i, p)| BuilderField {
+                    name: anon_name((i, p.name.as_ref())),
+                    ty: expand_event_topic_field_type(p),
+                    required: p.is_indexed() || !ty::implements_default(cx, &p.ty),
+                });
+            let debug = attr::derives_trait(&attrs, "Debug");
+            builder::expand(&name.0, &fields.collect::<Vec<_>>(), debug)
+        });
 
     let tokenize_body_impl = ty::expand_event_tokenize_func(event.parameters.iter());
 
@@ -107,7 +139,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         #(#attrs)*
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         pub struct #name {
-            #(pub #fields,)*
+            #(#fields,)*
         }
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
@@ -159,6 +191,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
                 }
             }
         };
+
+        #builder_impl
     };
     Ok(tokens)
 }
@@ -178,13 +212,17 @@ fn expand_event_topic_field(
     name: Option<&SolIdent>,
 ) -> TokenStream {
     let name = anon_name((i, name));
-    let ty = if param.indexed_as_hash() {
+    let ty = expand_event_topic_field_type(param);
+    quote!(#name: #ty)
+}
+
+fn expand_event_topic_field_type(param: &EventParameter) -> TokenStream {
+    if param.indexed_as_hash() {
         ty::expand_rust_type(&ast::Type::FixedBytes(
-            name.span(),
+            param.ty.span(),
             core::num::NonZeroU16::new(32).unwrap(),
         ))
     } else {
         ty::expand_rust_type(&param.ty)
-    };
-    quote!(#name: #ty)
+    }
 }
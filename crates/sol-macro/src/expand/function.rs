@@ -1,11 +1,13 @@
 //! [`ItemFunction`] expansion.
 
 use super::{
-    expand_fields, expand_from_into_tuples, expand_tuple_types, ty::expand_tokenize_func, ExpCtxt,
+    expand_fields, expand_from_into_tuples, expand_var_tuple_types, param_names,
+    ty::expand_tokenize_func, ExpCtxt,
 };
-use ast::ItemFunction;
+use crate::attr;
+use ast::{ItemFunction, Spanned};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::Result;
 
 /// Expands an [`ItemFunction`]:
@@ -25,6 +27,10 @@ use syn::Result;
 /// }
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenStream> {
+    if function.kind.is_constructor() {
+        return expand_constructor(cx, function)
+    }
+
     let ItemFunction {
         attrs,
         arguments,
@@ -33,7 +39,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
         ..
     } = function
     else {
-        // ignore functions without names (constructors, modifiers...)
+        // ignore functions without names (modifiers...)
         return Ok(quote!())
     };
     let returns = returns.as_ref().map(|r| &r.returns).unwrap_or_default();
@@ -43,7 +49,9 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
         cx.assert_resolved(returns)?;
     }
 
-    let (_sol_attrs, mut call_attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    let (sol_attrs, mut call_attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    sol_attrs.reject_builder(function.span())?;
+    sol_attrs.reject_serde(function.span())?;
     let mut return_attrs = call_attrs.clone();
     cx.derives(&mut call_attrs, arguments, true);
     if !returns.is_empty() {
@@ -51,13 +59,18 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     }
 
     let call_name = cx.call_name(function);
-    let return_name = cx.return_name(function);
+    let return_name = cx.return_name(function)?;
 
-    let call_fields = expand_fields(arguments);
-    let return_fields = expand_fields(returns);
+    let argument_names = param_names(arguments);
+    let argument_docs = attr::param_docs(attrs, argument_names.iter().map(Option::as_deref));
+    let return_names = param_names(returns);
+    let return_docs = attr::return_docs(attrs, return_names.iter().map(Option::as_deref));
 
-    let call_tuple = expand_tuple_types(arguments.types()).0;
-    let return_tuple = expand_tuple_types(returns.types()).0;
+    let call_fields = expand_fields(arguments, &argument_docs)?;
+    let return_fields = expand_fields(returns, &return_docs)?;
+
+    let call_tuple = expand_var_tuple_types(arguments).0;
+    let return_tuple = expand_var_tuple_types(returns).0;
 
     let converts = expand_from_into_tuples(&call_name, arguments);
     let return_converts = expand_from_into_tuples(&return_name, returns);
@@ -108,7 +121,74 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
                 }
 
                 fn abi_decode_returns(data: &[u8], validate: bool) -> ::alloy_sol_types::Result<Self::Return> {
-                    <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::abi_decode(data, validate).map(Into::into)
+                    <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::abi_decode_sequence(data, validate).map(Into::into)
+                }
+            }
+        };
+    };
+    Ok(tokens)
+}
+
+/// Expands a constructor [`ItemFunction`]:
+///
+/// ```ignore (pseudo-code)
+/// pub struct constructorCall {
+///     #(pub #argument_name: #argument_type,)*
+/// }
+///
+/// impl SolConstructor for constructorCall {
+///     ...
+/// }
+/// ```
+fn expand_constructor(cx: &ExpCtxt<'_>, constructor: &ItemFunction) -> Result<TokenStream> {
+    let ItemFunction {
+        attrs, arguments, ..
+    } = constructor;
+
+    // constructors used to be ignored, so skip the ones that reference types
+    // declared elsewhere instead of rejecting them
+    if !cx.is_resolved(arguments) {
+        return Ok(TokenStream::new())
+    }
+
+    let (sol_attrs, mut call_attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    sol_attrs.reject_return_struct()?;
+    sol_attrs.reject_builder(constructor.span())?;
+    sol_attrs.reject_serde(constructor.span())?;
+    cx.derives(&mut call_attrs, arguments, true);
+
+    let call_name = format_ident!("constructorCall");
+
+    let argument_names = param_names(arguments);
+    let argument_docs = attr::param_docs(attrs, argument_names.iter().map(Option::as_deref));
+    let call_fields = expand_fields(arguments, &argument_docs)?;
+    let call_tuple = expand_var_tuple_types(arguments).0;
+    let converts = expand_from_into_tuples(&call_name, arguments);
+    let tokenize_impl = expand_tokenize_func(arguments.iter());
+
+    let tokens = quote! {
+        #(#call_attrs)*
+        #[allow(non_camel_case_types, non_snake_case)]
+        #[derive(Clone)]
+        pub struct #call_name {
+            #(#call_fields),*
+        }
+
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        const _: () = {
+            { #converts }
+
+            #[automatically_derived]
+            impl ::alloy_sol_types::SolConstructor for #call_name {
+                type Arguments<'a> = #call_tuple;
+                type Token<'a> = <Self::Arguments<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+
+                fn new<'a>(tuple: <Self::Arguments<'a> as ::alloy_sol_types::SolType>::RustType) -> Self {
+                    tuple.into()
+                }
+
+                fn tokenize(&self) -> Self::Token<'_> {
+                    #tokenize_impl
                 }
             }
         };
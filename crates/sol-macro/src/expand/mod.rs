@@ -2,21 +2,25 @@
 
 use crate::{
     attr::{self, SolAttrs},
-    expand::ty::expand_rust_type,
     utils::{self, ExprArray},
 };
 use ast::{
-    File, Item, ItemError, ItemEvent, ItemFunction, Parameters, SolIdent, SolPath, Spanned, Type,
-    VariableDeclaration, Visit,
+    FieldList, File, Item, ItemError, ItemEvent, ItemFunction, Parameters, SolIdent, SolPath,
+    Spanned, Type, VariableDeclaration, Visit, VisitMut,
 };
 use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use quote::{format_ident, quote, TokenStreamExt};
-use std::{borrow::Borrow, collections::HashMap, fmt::Write};
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
 use syn::{parse_quote, Attribute, Error, Result};
 
 mod ty;
 pub use ty::expand_type;
 
+mod builder;
 mod contract;
 mod r#enum;
 mod error;
@@ -30,13 +34,53 @@ mod var_def;
 const RESOLVE_LIMIT: usize = 8;
 
 /// The [`sol!`][crate::sol!] expansion implementation.
-pub fn expand(ast: File) -> Result<TokenStream> {
+pub fn expand(mut ast: File) -> Result<TokenStream> {
+    let mut renamer = KeywordRenamer::default();
+    renamer.visit_file(&mut ast);
+    utils::combine_errors(renamer.errors)?;
     ExpCtxt::new(&ast).expand()
 }
 
+/// Renames identifiers that are Rust keywords which cannot be raw identifiers,
+/// such as a library function's `self` parameter, to `<name>_`.
+///
+/// Other Rust keywords are already parsed as raw identifiers by `syn-solidity`.
+/// Struct fields are not renamed, as their names are part of the EIP-712 type.
+#[derive(Default)]
+struct KeywordRenamer {
+    errors: Vec<Error>,
+}
+
+impl KeywordRenamer {
+    const KEYWORDS: [&'static str; 4] = ["self", "Self", "super", "crate"];
+}
+
+impl<'ast> VisitMut<'ast> for KeywordRenamer {
+    fn visit_ident(&mut self, ident: &'ast mut SolIdent) {
+        let s = ident.0.to_string();
+        if Self::KEYWORDS.contains(&s.as_str()) {
+            *ident = SolIdent::new_spanned(&format!("{s}_"), ident.span());
+        }
+    }
+
+    fn visit_field_list(&mut self, fields: &'ast mut FieldList) {
+        for field in fields.iter() {
+            if let Some(name) = &field.name {
+                if Self::KEYWORDS.contains(&name.0.to_string().as_str()) {
+                    let msg = format!("`{name}` cannot be used as a struct field name in Rust");
+                    self.errors.push(Error::new(name.span(), msg));
+                }
+            }
+        }
+        ast::visit_mut::visit_field_list(self, fields);
+    }
+}
+
 struct ExpCtxt<'ast> {
     all_items: Vec<&'ast Item>,
     custom_types: HashMap<SolIdent, Type>,
+    /// Structs that contain themselves through a dynamic array.
+    recursive_structs: HashSet<SolIdent>,
 
     /// `name => functions`
     functions: HashMap<String, Vec<&'ast ItemFunction>>,
@@ -53,6 +97,7 @@ impl<'ast> ExpCtxt<'ast> {
         Self {
             all_items: Vec::new(),
             custom_types: HashMap::new(),
+            recursive_structs: HashSet::new(),
             functions: HashMap::new(),
             function_overloads: HashMap::new(),
             attrs: SolAttrs::default(),
@@ -68,8 +113,10 @@ impl<'ast> ExpCtxt<'ast> {
         }
 
         self.visit_file(self.ast);
+        self.find_recursive_structs()?;
         if self.all_items.len() > 1 {
             self.resolve_custom_types()?;
+            self.assert_no_recursive_params()?;
             self.mk_overloads_map()?;
         }
 
@@ -105,6 +152,7 @@ impl<'ast> ExpCtxt<'ast> {
 impl ExpCtxt<'_> {
     fn parse_file_attributes(&mut self) -> Result<()> {
         let (attrs, others) = attr::SolAttrs::parse(&self.ast.attrs)?;
+        attrs.reject_return_struct()?;
         self.attrs = attrs;
 
         let errs = others
@@ -136,6 +184,7 @@ impl ExpCtxt<'_> {
         let map_ref: &mut HashMap<SolIdent, Type> =
             unsafe { &mut *(&mut self.custom_types as *mut _) };
         let map = &self.custom_types;
+        let recursive = &self.recursive_structs;
         for ty in map_ref.values_mut() {
             let mut i = 0;
             ty.visit_mut(|ty| {
@@ -146,6 +195,11 @@ impl ExpCtxt<'_> {
                 let Type::Custom(name) = &*ty else {
                     unreachable!()
                 };
+                // recursive structs are kept as custom types, as they would
+                // otherwise expand forever
+                if recursive.contains(name.last_tmp()) {
+                    return
+                }
                 let Some(resolved) = map.get(name.last_tmp()) else {
                     return
                 };
@@ -164,6 +218,119 @@ impl ExpCtxt<'_> {
         Ok(())
     }
 
+    /// Finds the structs that contain themselves, and stores them in
+    /// `recursive_structs`.
+    ///
+    /// A struct may only contain itself through a dynamic array, like
+    /// `struct Node { Node[] children; }`. Any other recursion would make its
+    /// size infinite, and is an error.
+    fn find_recursive_structs(&mut self) -> Result<()> {
+        // `struct => [(field struct, field type, whether it is in a dynamic array)]`
+        let mut graph = HashMap::<&SolIdent, Vec<(&SolIdent, &Type, bool)>>::new();
+        let structs = self.all_items.iter().filter_map(|item| match item {
+            Item::Struct(strukt) => Some(strukt),
+            _ => None,
+        });
+        for strukt in structs.clone() {
+            let edges = graph.entry(&strukt.name).or_default();
+            for ty in strukt.fields.types() {
+                self.struct_edges(ty, false, edges);
+            }
+        }
+
+        let mut errors = Vec::new();
+        let mut recursive = HashSet::new();
+        for strukt in structs {
+            let name = &strukt.name;
+            for &(field, ty, indirect) in &graph[name] {
+                if !indirect && reaches(&graph, field, name, true) {
+                    let msg = format!(
+                        "recursive struct definition; \
+                         `{name}` may only contain itself through a dynamic array"
+                    );
+                    errors.push(Error::new(ty.span(), msg));
+                }
+                if reaches(&graph, field, name, false) {
+                    recursive.insert(name.clone());
+                }
+            }
+        }
+        self.recursive_structs = recursive;
+        utils::combine_errors(errors)
+    }
+
+    /// Pushes the structs that are contained in `ty` to `edges`, together
+    /// with whether they are inside of a dynamic array.
+    fn struct_edges<'a>(
+        &'a self,
+        ty: &'a Type,
+        indirect: bool,
+        edges: &mut Vec<(&'a SolIdent, &'a Type, bool)>,
+    ) {
+        match ty {
+            Type::Array(array) => {
+                self.struct_edges(&array.ty, indirect || array.size.is_none(), edges)
+            }
+            Type::Tuple(tuple) => {
+                for ty in &tuple.types {
+                    self.struct_edges(ty, indirect, edges);
+                }
+            }
+            Type::Custom(name) => {
+                if let Some(Item::Struct(strukt)) = self.try_get_item(name) {
+                    edges.push((&strukt.name, ty, indirect));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns an error if a function, error, or event parameter contains a
+    /// recursive struct, as its signature would be infinitely long.
+    fn assert_no_recursive_params(&self) -> Result<()> {
+        let mut errors = Vec::new();
+        for &item in &self.all_items {
+            let types: Vec<&Type> = match item {
+                Item::Function(function) => function
+                    .arguments
+                    .types()
+                    .chain(function.returns.iter().flat_map(|r| r.returns.types()))
+                    .collect(),
+                Item::Error(error) => error.parameters.types().collect(),
+                Item::Event(event) => event.parameters.iter().map(|p| &p.ty).collect(),
+                _ => continue,
+            };
+            for ty in types {
+                if self.has_recursive_struct(ty) {
+                    let msg = "recursive types are not allowed in function, error, \
+                               or event parameters";
+                    errors.push(Error::new(ty.span(), msg));
+                }
+            }
+        }
+        utils::combine_errors(errors)
+    }
+
+    /// Returns whether the type contains a recursive struct.
+    fn has_recursive_struct(&self, ty: &Type) -> bool {
+        let mut found = false;
+        ty.visit(|ty| {
+            let Type::Custom(name) = ty else { return };
+            // paths into other scopes are not resolved
+            if name.len() > 1 {
+                return
+            }
+            let name = name.last();
+            found = found
+                || self.recursive_structs.contains(name)
+                || self
+                    .custom_types
+                    .get(name)
+                    .map_or(false, |ty| self.has_recursive_struct(ty));
+        });
+        found
+    }
+
     fn mk_overloads_map(&mut self) -> Result<()> {
         let all_orig_names: Vec<SolIdent> = self
             .functions
@@ -250,13 +417,22 @@ impl ExpCtxt<'_> {
     }
 
     fn try_get_item(&self, name: &SolPath) -> Option<&Item> {
-        let name = name.last_tmp();
+        // paths into other scopes are not resolved
+        if name.len() > 1 {
+            return None
+        }
+        let name = name.last();
         self.all_items
             .iter()
             .find(|item| item.name() == Some(name))
             .copied()
     }
 
+    /// Returns whether the struct contains itself through a dynamic array.
+    fn is_recursive_struct(&self, name: &SolIdent) -> bool {
+        self.recursive_structs.contains(name)
+    }
+
     fn custom_type(&self, name: &SolPath) -> &Type {
         match self.custom_types.get(name.last_tmp()) {
             Some(item) => item,
@@ -298,9 +474,14 @@ impl ExpCtxt<'_> {
         format_ident!("{function_name}Return")
     }
 
-    fn return_name(&self, function: &ItemFunction) -> Ident {
-        let function_name = self.function_name(function);
-        self.raw_return_name(function_name)
+    /// Returns the name of the function's return struct: the
+    /// `#[sol(return_struct = "...")]` attribute, or `{function_name}Return`.
+    fn return_name(&self, function: &ItemFunction) -> Result<Ident> {
+        let (attrs, _) = SolAttrs::parse(&function.attrs)?;
+        Ok(match attrs.return_struct {
+            Some(name) => Ident::new(&name.value(), name.span()),
+            None => self.raw_return_name(self.function_name(function)),
+        })
     }
 
     fn signature<'a, I: IntoIterator<Item = &'a VariableDeclaration>>(
@@ -400,6 +581,25 @@ impl ExpCtxt<'_> {
         attrs.push(parse_quote! { #[derive(#(#derives),*)] });
     }
 
+    /// Returns whether all the types in the parameters are resolved.
+    ///
+    /// Unlike [`assert_resolved`](Self::assert_resolved), this does not panic
+    /// on paths with more than one segment, which are never resolved.
+    fn is_resolved<'a, I>(&self, params: I) -> bool
+    where
+        I: IntoIterator<Item = &'a VariableDeclaration>,
+    {
+        let mut resolved = true;
+        for param in params {
+            param.ty.visit(|ty| {
+                if let Type::Custom(name) = ty {
+                    resolved &= name.len() == 1 && self.custom_types.contains_key(name.last());
+                }
+            });
+        }
+        resolved
+    }
+
     /// Returns an error if any of the types in the parameters are unresolved.
     ///
     /// Provides a better error message than an `unwrap` or `expect` when we
@@ -431,17 +631,73 @@ impl ExpCtxt<'_> {
 
 // helper functions
 
+/// Returns whether `to` can be reached from `from` in the struct graph built by
+/// [`ExpCtxt::find_recursive_structs`], optionally only through fields that are
+/// not in dynamic arrays.
+fn reaches(
+    graph: &HashMap<&SolIdent, Vec<(&SolIdent, &Type, bool)>>,
+    from: &SolIdent,
+    to: &SolIdent,
+    direct_only: bool,
+) -> bool {
+    let mut stack = vec![from];
+    let mut seen = HashSet::new();
+    while let Some(name) = stack.pop() {
+        if name == to {
+            return true
+        }
+        if !seen.insert(name) {
+            continue
+        }
+        if let Some(edges) = graph.get(name) {
+            let edges = edges
+                .iter()
+                .filter(|&&(_, _, indirect)| !(direct_only && indirect));
+            stack.extend(edges.map(|&(field, ..)| field));
+        }
+    }
+    false
+}
+
 /// Expands a list of parameters into a list of struct fields.
-fn expand_fields<P>(params: &Parameters<P>) -> impl Iterator<Item = TokenStream> + '_ {
-    params.iter().enumerate().map(|(i, var)| {
-        let name = anon_name((i, var.name.as_ref()));
-        let ty = expand_rust_type(&var.ty);
-        let attrs = &var.attrs;
-        quote! {
-            #(#attrs)*
-            pub #name: #ty
-        }
-    })
+///
+/// `docs` are the natspec descriptions of the parameters, by index. See
+/// [`attr::param_docs`].
+fn expand_fields<P>(params: &Parameters<P>, docs: &[Option<String>]) -> Result<Vec<TokenStream>> {
+    params
+        .iter()
+        .enumerate()
+        .map(|(i, var)| {
+            let (sol_attrs, attrs) = SolAttrs::parse(&var.attrs)?;
+            if sol_attrs.zero_is_none == Some(true) && !var.ty.is_one_word() {
+                let msg = "`zero_is_none` is only supported on single-word value types";
+                return Err(Error::new(var.ty.span(), msg))
+            }
+
+            let doc = docs.get(i).and_then(Option::as_deref).map(expand_doc);
+            let name = anon_name((i, var.name.as_ref()));
+            let ty = ty::expand_var_rust_type(var);
+            Ok(quote! {
+                #doc
+                #(#attrs)*
+                pub #name: #ty
+            })
+        })
+        .collect()
+}
+
+/// Expands a natspec description into a `#[doc]` attribute.
+fn expand_doc(doc: &str) -> TokenStream {
+    let doc = format!(" {doc}");
+    quote!(#[doc = #doc])
+}
+
+/// Returns the names of the parameters, for matching natspec tags.
+fn param_names<P>(params: &Parameters<P>) -> Vec<Option<String>> {
+    params
+        .iter()
+        .map(|var| var.name.as_ref().map(|name| name.as_string()))
+        .collect()
 }
 
 /// Generates an anonymous name from an integer. Used in [`anon_name`].
@@ -466,9 +722,9 @@ fn expand_from_into_tuples<P>(name: &Ident, fields: &Parameters<P>) -> TokenStre
     let idxs = (0..fields.len()).map(syn::Index::from);
 
     let names3 = names.clone();
-    let field_tys = fields.types().map(expand_type);
+    let field_tys = fields.iter().map(ty::expand_var_type);
 
-    let (sol_tuple, rust_tuple) = expand_tuple_types(fields.types());
+    let (sol_tuple, rust_tuple) = expand_var_tuple_types(fields.iter());
 
     quote! {
         #[doc(hidden)]
@@ -524,3 +780,154 @@ fn expand_tuple_types<'a, I: IntoIterator<Item = &'a Type>>(
         |stream| TokenStream::from(TokenTree::Group(Group::new(Delimiter::Parenthesis, stream)));
     (wrap_in_parens(sol), wrap_in_parens(rust))
 }
+
+/// Returns `(sol_tuple, rust_tuple)`, taking into account the parameters' field
+/// attributes.
+fn expand_var_tuple_types<'a, I: IntoIterator<Item = &'a VariableDeclaration>>(
+    vars: I,
+) -> (TokenStream, TokenStream) {
+    let (sol, rust): (Vec<_>, Vec<_>) = vars
+        .into_iter()
+        .map(|var| (ty::expand_var_type(var), ty::expand_var_rust_type(var)))
+        .unzip();
+    (quote!((#(#sol,)*)), quote!((#(#rust,)*)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+
+    /// Expands `src` and returns the docs of each field of the struct `name`.
+    fn field_docs(src: &str, name: &str) -> Vec<(String, Vec<String>)> {
+        let file = syn::parse_str::<File>(src).unwrap();
+        let tokens: Vec<_> = expand(file).unwrap().into_iter().collect();
+        let fields = tokens
+            .windows(3)
+            .find_map(|w| match w {
+                [TokenTree::Ident(kw), TokenTree::Ident(ident), TokenTree::Group(group)]
+                    if kw == "struct" && ident == name =>
+                {
+                    Some(syn::parse2::<syn::FieldsNamed>(group.to_token_stream()).unwrap())
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("struct {name} not found"));
+        fields
+            .named
+            .iter()
+            .map(|field| {
+                let docs = attr::docs(&field.attrs)
+                    .map(|attr| match &attr.meta {
+                        syn::Meta::NameValue(nv) => match &nv.value {
+                            syn::Expr::Lit(syn::ExprLit {
+                                lit: syn::Lit::Str(s),
+                                ..
+                            }) => s.value(),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    })
+                    .collect();
+                (field.ident.as_ref().unwrap().to_string(), docs)
+            })
+            .collect()
+    }
+
+    fn docs(expected: &[(&str, &[&str])]) -> Vec<(String, Vec<String>)> {
+        expected
+            .iter()
+            .map(|(name, docs)| {
+                (
+                    name.to_string(),
+                    docs.iter().map(|d| d.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn natspec_field_docs() {
+        let src = r#"
+            /// @notice Transfers tokens.
+            /// @param to The recipient.
+            /// @param amount The amount.
+            /// @return The first return value.
+            /// @return success Whether the transfer succeeded.
+            function transfer(address to, uint256 amount, bytes data)
+                returns (uint256, bool success);
+
+            /// @param from The sender.
+            /// @param value The value.
+            event Transfer(address indexed from, address indexed to, uint256 value);
+
+            /**
+             * @param needed The required balance.
+             */
+            error InsufficientBalance(uint256 available, uint256 needed);
+        "#;
+
+        assert_eq!(
+            field_docs(src, "transferCall"),
+            docs(&[
+                ("to", &[" The recipient."]),
+                ("amount", &[" The amount."]),
+                ("data", &[])
+            ])
+        );
+        assert_eq!(
+            field_docs(src, "transferReturn"),
+            docs(&[
+                ("_0", &[" The first return value."]),
+                ("success", &[" Whether the transfer succeeded."]),
+            ])
+        );
+        assert_eq!(
+            field_docs(src, "Transfer"),
+            docs(&[
+                ("from", &[" The sender."]),
+                ("to", &[]),
+                ("value", &[" The value."])
+            ])
+        );
+        assert_eq!(
+            field_docs(src, "InsufficientBalance"),
+            docs(&[("available", &[]), ("needed", &[" The required balance."])])
+        );
+    }
+
+    #[test]
+    fn recursive_structs() {
+        let expand = |src| expand(syn::parse_str::<File>(src).unwrap()).map_err(|e| e.to_string());
+        let recursive = "recursive struct definition; \
+                         `A` may only contain itself through a dynamic array";
+
+        // dynamic arrays, possibly nested in fixed arrays
+        assert!(expand("struct A { uint a; A[] children; }").is_ok());
+        assert!(expand("struct A { A[][2] a; }").is_ok());
+        assert!(expand("struct A { A[2][] a; }").is_ok());
+        assert!(expand("struct A { B[] b; } struct B { A a; }").is_ok());
+
+        assert_eq!(expand("struct A { A a; }").unwrap_err(), recursive);
+        assert_eq!(
+            expand("struct A { uint a; A[2] b; }").unwrap_err(),
+            recursive
+        );
+        assert_eq!(expand("struct A { (uint, A) a; }").unwrap_err(), recursive);
+        assert_eq!(
+            expand("struct A { B b; } struct B { A[1] a; }").unwrap_err(),
+            recursive
+        );
+
+        let err = expand("struct A { A[] a; } struct B { A a; } function f(B b);").unwrap_err();
+        assert_eq!(
+            err,
+            "recursive types are not allowed in function, error, or event parameters"
+        );
+        let err = expand("struct A { A[] a; } event E(A[] indexed a);").unwrap_err();
+        assert_eq!(
+            err,
+            "recursive types are not allowed in function, error, or event parameters"
+        );
+    }
+}
@@ -1,13 +1,17 @@
 //! [`ItemStruct`] expansion.
 
 use super::{
-    expand_fields, expand_from_into_tuples, expand_type, ty::expand_tokenize_func, ExpCtxt,
+    builder::{self, BuilderField},
+    expand_fields, expand_from_into_tuples, expand_type,
+    ty::{self, expand_tokenize_func, expand_var_type},
+    ExpCtxt,
 };
+use crate::attr;
 use ast::{Item, ItemStruct, Spanned, Type};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::num::NonZeroU16;
-use syn::Result;
+use syn::{Attribute, Result};
 
 /// Expands an [`ItemStruct`]:
 ///
@@ -33,18 +37,61 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
         ..
     } = s;
 
-    let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    let (sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    sol_attrs.reject_return_struct()?;
     cx.derives(&mut attrs, fields, true);
 
+    let builder_impl = sol_attrs
+        .builder
+        .or(cx.attrs.builder)
+        .unwrap_or(false)
+        .then(|| {
+            let fields = fields.iter().map(|f| BuilderField {
+                name: f.name.clone().unwrap().0,
+                ty: ty::expand_var_rust_type(f),
+                required: !ty::implements_default(cx, &f.ty),
+            });
+            let debug = attr::derives_trait(&attrs, "Debug");
+            builder::expand(&name.0, &fields.collect::<Vec<_>>(), debug)
+        });
+
     let (field_types, field_names): (Vec<_>, Vec<_>) = fields
         .iter()
-        .map(|f| (expand_type(&f.ty), f.name.as_ref().unwrap()))
+        .map(|f| (expand_var_type(f), f.name.as_ref().unwrap()))
         .unzip();
 
     let eip712_encode_type_fns = expand_encode_type_fns(cx, fields, name);
 
     let tokenize_impl = expand_tokenize_func(fields.iter());
 
+    // recursive structs wrap their token, as its type would otherwise contain
+    // itself
+    let recursive = cx.is_recursive_struct(name);
+    let (token_type, to_tokens_impl, token, token_ref) = if recursive {
+        (
+            quote!(::alloy_sol_types::abi::token::RecursiveToken<'a, Self>),
+            quote!(::alloy_sol_types::abi::token::RecursiveToken(#tokenize_impl)),
+            quote!(token.0),
+            quote!(&token.0),
+        )
+    } else {
+        (
+            quote!(<UnderlyingSolTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>),
+            tokenize_impl,
+            quote!(token),
+            quote!(token),
+        )
+    };
+    let recursive_impl = recursive.then(|| {
+        quote! {
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::alloy_sol_types::private::RecursiveStruct for #name {
+                type Fields = UnderlyingSolTuple<'static>;
+            }
+        }
+    });
+
     let encode_data_impl = match fields.len() {
         0 => unreachable!("struct with zero fields"),
         1 => {
@@ -59,10 +106,43 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
         },
     };
 
+    let serde_impls = sol_attrs
+        .serde
+        .or(cx.attrs.serde)
+        .unwrap_or(false)
+        .then(|| expand_serde_impls(&attrs, name, &field_types, &field_names));
+
+    let sol_literal_impl = {
+        let field_names_s = field_names.iter().map(|f| f.as_string());
+        // emitted through alloy-sol-types, which owns the `sol-literal` feature
+        quote! {
+            ::alloy_sol_types::__sol_literal_impl! {
+                #[inline]
+                fn to_sol_literal(rust: &Self::RustType) -> ::alloy_sol_types::private::String {
+                    ::alloy_sol_types::private::struct_literal(
+                        <Self as ::alloy_sol_types::SolStruct>::NAME,
+                        ::alloy_sol_types::private::Vec::from([#(
+                            (#field_names_s, <#field_types as ::alloy_sol_types::SolType>::to_sol_literal(&rust.#field_names)),
+                        )*]),
+                    )
+                }
+            }
+        }
+    };
+
+    let field_names_s = field_names.iter().map(|f| f.as_string());
+    let pretty_impl = quote! {
+        fn fmt_pretty(rust: &Self::RustType, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.debug_struct(<Self as ::alloy_sol_types::SolStruct>::NAME)
+                #(.field(#field_names_s, &::alloy_sol_types::Pretty::<#field_types>::new(&rust.#field_names)))*
+                .finish()
+        }
+    };
+
     let attrs = attrs.iter();
     let convert = expand_from_into_tuples(&name.0, fields);
     let name_s = name.to_string();
-    let fields = expand_fields(fields);
+    let fields = expand_fields(fields, &[])?;
 
     let tokens = quote! {
         #(#attrs)*
@@ -76,17 +156,22 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
         const _: () = {
             #convert
 
+            #recursive_impl
+
             #[automatically_derived]
             impl ::alloy_sol_types::Encodable<Self> for #name {
                 fn to_tokens(&self) -> <Self as ::alloy_sol_types::SolType>::TokenType<'_> {
-                    #tokenize_impl
+                    #to_tokens_impl
                 }
             }
 
             #[automatically_derived]
             impl ::alloy_sol_types::SolType for #name {
                 type RustType = Self;
-                type TokenType<'a> = <UnderlyingSolTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+                type TokenType<'a> = #token_type;
+
+                const ENCODED_SIZE: ::core::option::Option<usize> =
+                    <UnderlyingSolTuple<'static> as ::alloy_sol_types::SolType>::ENCODED_SIZE;
 
                 #[inline]
                 fn sol_type_name() -> ::alloy_sol_types::private::Cow<'static, str> {
@@ -104,12 +189,12 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
 
                 #[inline]
                 fn valid_token(token: &Self::TokenType<'_>) -> bool {
-                    <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::valid_token(token)
+                    <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::valid_token(#token_ref)
                 }
 
                 #[inline]
                 fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
-                    let tuple = <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::detokenize(token);
+                    let tuple = <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::detokenize(#token);
                     <Self as ::core::convert::From<UnderlyingRustTuple<'_>>>::from(tuple)
                 }
 
@@ -118,12 +203,23 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                     <Self as ::alloy_sol_types::SolStruct>::eip712_hash_struct(rust)
                 }
 
+                #[inline]
+                fn packed_encoded_size(rust: &Self::RustType) -> usize {
+                    0 #(
+                        + <#field_types as ::alloy_sol_types::SolType>::packed_encoded_size(&rust.#field_names)
+                    )*
+                }
+
                 #[inline]
                 fn abi_encode_packed_to(rust: &Self::RustType, out: &mut ::alloy_sol_types::private::Vec<u8>) {
-                    // TODO: Avoid cloning
-                    let tuple = <UnderlyingRustTuple<'_> as ::core::convert::From<Self>>::from(rust.clone());
-                    <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::abi_encode_packed_to(&tuple, out)
+                    #(
+                        <#field_types as ::alloy_sol_types::SolType>::abi_encode_packed_to(&rust.#field_names, out);
+                    )*
                 }
+
+                #pretty_impl
+
+                #sol_literal_impl
             }
 
             #[automatically_derived]
@@ -135,6 +231,13 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                 fn eip712_encode_data(&self) -> Vec<u8> {
                     #encode_data_impl
                 }
+
+                #[inline]
+                fn eip712_encode_data_to(&self, out: &mut impl FnMut(&::alloy_sol_types::Word)) {
+                    #(
+                        out(&<#field_types as ::alloy_sol_types::SolType>::eip712_data_word(&self.#field_names));
+                    )*
+                }
             }
 
             #[automatically_derived]
@@ -166,36 +269,112 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                     )
                 }
             }
+
+            #serde_impls
         };
+
+        #builder_impl
     };
     Ok(tokens)
 }
 
-fn expand_encode_type_fns(
-    cx: &ExpCtxt<'_>,
-    fields: &ast::Parameters<syn::token::Semi>,
+/// Expands the `SolSerde` implementation, and the `Serialize` and
+/// `Deserialize` implementations that are not already derived by the user.
+///
+/// The fields are (de)serialized through mirror structs whose fields wrap the
+/// struct fields with their Solidity types.
+fn expand_serde_impls(
+    attrs: &[Attribute],
     name: &ast::SolIdent,
+    field_types: &[TokenStream],
+    field_names: &[&ast::SolIdent],
 ) -> TokenStream {
-    // account for UDVTs and enums which do not implement SolStruct
-    let mut fields = fields.clone();
-    fields.visit_types_mut(|ty| {
-        let Type::Custom(name) = ty else { return };
-        match cx.try_get_item(name) {
-            // keep as custom
-            Some(Item::Struct(_)) | None => {}
-            // convert to underlying
-            Some(Item::Enum(_)) => *ty = Type::Uint(ty.span(), NonZeroU16::new(8)),
-            Some(Item::Udt(udt)) => *ty = udt.ty.clone(),
-            Some(item) => panic!("Invalid type in struct field: {item:?}"),
+    let name_s = name.to_string();
+    let serde = quote!(::alloy_sol_types::private::serde);
+
+    let serialize_impl = (!attr::derives_trait(attrs, "Serialize")).then(|| {
+        quote! {
+            #[derive(#serde::Serialize)]
+            #[serde(crate = "::alloy_sol_types::private::serde", rename = #name_s)]
+            struct __SolSerdeRef<'a> {
+                #(#field_names: ::alloy_sol_types::private::SerdeRef<'a, #field_types>,)*
+            }
+
+            #[automatically_derived]
+            impl #serde::Serialize for #name {
+                fn serialize<S: #serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> ::core::result::Result<S::Ok, S::Error> {
+                    let repr = __SolSerdeRef {
+                        #(#field_names: ::alloy_sol_types::private::SerdeRef(&self.#field_names),)*
+                    };
+                    #serde::Serialize::serialize(&repr, serializer)
+                }
+            }
+        }
+    });
+
+    let deserialize_impl = (!attr::derives_trait(attrs, "Deserialize")).then(|| {
+        quote! {
+            #[derive(#serde::Deserialize)]
+            #[serde(crate = "::alloy_sol_types::private::serde", rename = #name_s)]
+            struct __SolSerdeValue {
+                #(#field_names: ::alloy_sol_types::private::SerdeValue<#field_types>,)*
+            }
+
+            #[automatically_derived]
+            impl<'de> #serde::Deserialize<'de> for #name {
+                fn deserialize<D: #serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> ::core::result::Result<Self, D::Error> {
+                    let repr = <__SolSerdeValue as #serde::Deserialize>::deserialize(deserializer)?;
+                    ::core::result::Result::Ok(Self {
+                        #(#field_names: repr.#field_names.0,)*
+                    })
+                }
+            }
         }
     });
 
+    quote! {
+        #serialize_impl
+        #deserialize_impl
+
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolSerde for #name {
+            #[inline]
+            fn serialize<S: #serde::Serializer>(
+                rust: &Self,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                #serde::Serialize::serialize(rust, serializer)
+            }
+
+            #[inline]
+            fn deserialize<'de, D: #serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> ::core::result::Result<Self, D::Error> {
+                <Self as #serde::Deserialize<'de>>::deserialize(deserializer)
+            }
+        }
+    }
+}
+
+fn expand_encode_type_fns(
+    cx: &ExpCtxt<'_>,
+    fields: &ast::Parameters<syn::token::Semi>,
+    name: &ast::SolIdent,
+) -> TokenStream {
+    let fields = eip712_fields(cx, fields);
     let root = fields.eip712_signature(name.as_string());
 
     let custom = fields.iter().filter(|f| f.ty.has_custom());
     let n_custom = custom.clone().count();
 
-    let components_impl = if n_custom > 0 {
+    let components_impl = if cx.is_recursive_struct(name) {
+        expand_recursive_components(cx, &fields, name)
+    } else if n_custom > 0 {
         let bits = custom.map(|field| {
             // need to recurse to find the inner custom type
             let mut ty = None;
@@ -223,11 +402,18 @@ fn expand_encode_type_fns(
     };
 
     let encode_type_impl_opt = (n_custom == 0).then(|| {
+        // the encoded type is the root type, so its hash is known here
+        let type_hash = crate::utils::event_selector(&root);
         quote! {
             #[inline]
             fn eip712_encode_type() -> ::alloy_sol_types::private::Cow<'static, str> {
                 <Self as ::alloy_sol_types::SolStruct>::eip712_root_type()
             }
+
+            #[inline]
+            fn eip712_type_hash(&self) -> ::alloy_sol_types::private::B256 {
+                ::alloy_sol_types::private::B256::new(#type_hash)
+            }
         }
     });
 
@@ -244,3 +430,73 @@ fn expand_encode_type_fns(
         #encode_type_impl_opt
     }
 }
+
+/// Converts UDVTs and enums, which do not implement `SolStruct`, to their
+/// underlying types.
+fn eip712_fields(
+    cx: &ExpCtxt<'_>,
+    fields: &ast::Parameters<syn::token::Semi>,
+) -> ast::Parameters<syn::token::Semi> {
+    let mut fields = fields.clone();
+    fields.visit_types_mut(|ty| {
+        let Type::Custom(name) = ty else { return };
+        match cx.try_get_item(name) {
+            // keep as custom
+            Some(Item::Struct(_)) | None => {}
+            // convert to underlying
+            Some(Item::Enum(_)) => *ty = Type::Uint(ty.span(), NonZeroU16::new(8)),
+            Some(Item::Udt(udt)) => *ty = udt.ty.clone(),
+            Some(item) => panic!("Invalid type in struct field: {item:?}"),
+        }
+    });
+    fields
+}
+
+/// Expands the `eip712_components` body of a recursive struct.
+///
+/// The components of other structs are usually collected by calling their
+/// `eip712_components`, which would never return for a recursive struct. All
+/// the structs that it contains are instead collected here, each only once.
+/// Structs that are not defined in this macro invocation cannot contain this
+/// one, so their components are still collected at runtime.
+fn expand_recursive_components(
+    cx: &ExpCtxt<'_>,
+    fields: &ast::Parameters<syn::token::Semi>,
+    name: &ast::SolIdent,
+) -> TokenStream {
+    let mut structs = Vec::<&ItemStruct>::new();
+    let mut external = Vec::new();
+    let mut stack = vec![fields];
+    while let Some(fields) = stack.pop() {
+        for ty in fields.types() {
+            ty.visit(|ty| {
+                let Type::Custom(path) = ty else { return };
+                match cx.try_get_item(path) {
+                    Some(Item::Struct(strukt)) => {
+                        if !structs.iter().any(|s| s.name == strukt.name) {
+                            structs.push(strukt);
+                            stack.push(&strukt.fields);
+                        }
+                    }
+                    Some(_) => {}
+                    None => external.push(expand_type(ty)),
+                }
+            });
+        }
+    }
+
+    let roots = structs
+        .iter()
+        .filter(|s| s.name != *name)
+        .map(|s| eip712_fields(cx, &s.fields).eip712_signature(s.name.as_string()));
+    quote! {
+        let mut components = ::alloy_sol_types::private::Vec::from([#(
+            ::alloy_sol_types::private::Cow::Borrowed(#roots),
+        )*]);
+        #(
+            components.push(<#external as ::alloy_sol_types::SolStruct>::eip712_root_type());
+            components.extend(<#external as ::alloy_sol_types::SolStruct>::eip712_components());
+        )*
+        components
+    }
+}
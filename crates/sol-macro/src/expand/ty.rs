@@ -1,8 +1,13 @@
 //! [`Type`] expansion.
 
 use super::ExpCtxt;
-use crate::expand::generate_name;
-use ast::{EventParameter, Item, Parameters, Spanned, Type, TypeArray, VariableDeclaration};
+use crate::{
+    attr::{self, SolAttrs},
+    expand::generate_name,
+};
+use ast::{
+    EventParameter, Item, Parameters, SolIdent, Spanned, Type, TypeArray, VariableDeclaration,
+};
 use proc_macro2::{Ident, Literal, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use std::{fmt, num::NonZeroU16};
@@ -26,10 +31,41 @@ pub fn expand_rust_type(ty: &Type) -> TokenStream {
     tokens
 }
 
+/// Expands the type of a [`VariableDeclaration`] to its
+/// `alloy_sol_types::sol_data` equivalent, taking into account its field
+/// attributes.
+pub fn expand_var_type(var: &VariableDeclaration) -> TokenStream {
+    let ty = expand_type(&var.ty);
+    if is_zero_as_none(var) {
+        quote!(::alloy_sol_types::sol_data::ZeroAsNone<#ty>)
+    } else {
+        ty
+    }
+}
+
+/// Expands the type of a [`VariableDeclaration`] to its Rust type equivalent,
+/// taking into account its field attributes.
+pub fn expand_var_rust_type(var: &VariableDeclaration) -> TokenStream {
+    let ty = expand_rust_type(&var.ty);
+    if is_zero_as_none(var) {
+        quote!(::core::option::Option<#ty>)
+    } else {
+        ty
+    }
+}
+
+/// Returns whether the variable is annotated with `#[sol(zero_is_none)]`.
+///
+/// Attribute errors are reported when expanding the fields.
+fn is_zero_as_none(var: &VariableDeclaration) -> bool {
+    !var.attrs.is_empty()
+        && SolAttrs::parse(&var.attrs).map_or(false, |(attrs, _)| attrs.zero_is_none == Some(true))
+}
+
 /// Expands a [`VariableDeclaration`] into an invocation of its types tokenize
 /// method.
 fn expand_tokenize_statement(var: &VariableDeclaration, i: usize) -> TokenStream {
-    let ty = expand_type(&var.ty);
+    let ty = expand_var_type(var);
     let name = var.name.clone().unwrap_or_else(|| generate_name(i).into());
     quote! {
         <#ty as ::alloy_sol_types::SolType>::tokenize(&self.#name)
@@ -128,7 +164,8 @@ pub fn rec_expand_type(ty: &Type, tokens: &mut TokenStream) {
     tokens.extend(tts);
 }
 
-// IMPORTANT: Keep in sync with `sol-types/src/types/data_type.rs`
+// IMPORTANT: Keep in sync with `sol-types/src/types/data_type.rs`, including
+// `SOLIDITY_TYPE_MAP`
 /// The [`expand_rust_type`] recursive implementation.
 pub fn rec_expand_rust_type(ty: &Type, tokens: &mut TokenStream) {
     // Display sizes that match with the Rust type, otherwise we lose information
@@ -280,16 +317,32 @@ const MAX_SUPPORTED_TUPLE_LEN: usize = 12;
 
 /// Returns whether the given type can derive the [`Default`] trait.
 pub(super) fn can_derive_default(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
+    rec_can_derive_default(cx, ty, &mut Vec::new())
+}
+
+/// The [`can_derive_default`] recursive implementation.
+///
+/// `visiting` contains the structs that are currently being checked. These are
+/// assumed to be derivable when they are reached again through a recursive
+/// struct.
+fn rec_can_derive_default<'a>(
+    cx: &'a ExpCtxt<'_>,
+    ty: &'a Type,
+    visiting: &mut Vec<&'a SolIdent>,
+) -> bool {
     match ty {
         Type::Array(a) => {
             a.size().map_or(true, |sz| sz <= MAX_SUPPORTED_ARRAY_LEN)
-                && can_derive_default(cx, &a.ty)
+                && rec_can_derive_default(cx, &a.ty, visiting)
         }
         Type::Tuple(tuple) => {
             if tuple.types.len() > MAX_SUPPORTED_TUPLE_LEN {
                 false
             } else {
-                tuple.types.iter().all(|ty| can_derive_default(cx, ty))
+                tuple
+                    .types
+                    .iter()
+                    .all(|ty| rec_can_derive_default(cx, ty, visiting))
             }
         }
 
@@ -298,15 +351,24 @@ pub(super) fn can_derive_default(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
             Some(Item::Error(error)) => error
                 .parameters
                 .types()
-                .all(|ty| can_derive_default(cx, ty)),
+                .all(|ty| rec_can_derive_default(cx, ty, visiting)),
             Some(Item::Event(event)) => event
                 .parameters
                 .iter()
-                .all(|p| can_derive_default(cx, &p.ty)),
+                .all(|p| rec_can_derive_default(cx, &p.ty, visiting)),
             Some(Item::Struct(strukt)) => {
-                strukt.fields.types().all(|ty| can_derive_default(cx, ty))
+                if visiting.contains(&&strukt.name) {
+                    return true
+                }
+                visiting.push(&strukt.name);
+                let derive = strukt
+                    .fields
+                    .types()
+                    .all(|ty| rec_can_derive_default(cx, ty, visiting));
+                visiting.pop();
+                derive
             }
-            Some(Item::Udt(udt)) => can_derive_default(cx, &udt.ty),
+            Some(Item::Udt(udt)) => rec_can_derive_default(cx, &udt.ty, visiting),
             Some(item) => panic!("Invalid item in param list: {item:?}"),
             _ => false,
         },
@@ -315,11 +377,51 @@ pub(super) fn can_derive_default(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
     }
 }
 
+/// Returns whether the Rust type of the given type implements [`Default`].
+///
+/// Unlike [`can_derive_default`], this requires the structs that it contains
+/// to actually derive `Default`, either themselves or through
+/// `#[sol(all_derives)]`.
+pub(super) fn implements_default(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
+    match ty {
+        // `Vec<T>` is always `Default`
+        Type::Array(a) => match a.size() {
+            Some(size) => size <= MAX_SUPPORTED_ARRAY_LEN && implements_default(cx, &a.ty),
+            None => true,
+        },
+        Type::Tuple(tuple) => {
+            tuple.types.len() <= MAX_SUPPORTED_TUPLE_LEN
+                && tuple.types.iter().all(|ty| implements_default(cx, ty))
+        }
+
+        Type::Custom(name) => match cx.try_get_item(name) {
+            Some(Item::Struct(strukt)) => {
+                attr::derives_trait(&strukt.attrs, "Default")
+                    || (cx.attrs.all_derives == Some(true) && can_derive_default(cx, ty))
+            }
+            Some(Item::Udt(udt)) => implements_default(cx, &udt.ty),
+            _ => false,
+        },
+
+        _ => true,
+    }
+}
+
 /// Returns whether the given type can derive the builtin traits listed in
 /// `ExprCtxt::derives`, minus `Default`.
 pub(super) fn can_derive_builtin_traits(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
+    rec_can_derive_builtin_traits(cx, ty, &mut Vec::new())
+}
+
+/// The [`can_derive_builtin_traits`] recursive implementation. See
+/// [`rec_can_derive_default`] for `visiting`.
+fn rec_can_derive_builtin_traits<'a>(
+    cx: &'a ExpCtxt<'_>,
+    ty: &'a Type,
+    visiting: &mut Vec<&'a SolIdent>,
+) -> bool {
     match ty {
-        Type::Array(a) => can_derive_builtin_traits(cx, &a.ty),
+        Type::Array(a) => rec_can_derive_builtin_traits(cx, &a.ty, visiting),
         Type::Tuple(tuple) => {
             if tuple.types.len() > MAX_SUPPORTED_TUPLE_LEN {
                 false
@@ -327,7 +429,7 @@ pub(super) fn can_derive_builtin_traits(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
                 tuple
                     .types
                     .iter()
-                    .all(|ty| can_derive_builtin_traits(cx, ty))
+                    .all(|ty| rec_can_derive_builtin_traits(cx, ty, visiting))
             }
         }
 
@@ -336,16 +438,24 @@ pub(super) fn can_derive_builtin_traits(cx: &ExpCtxt<'_>, ty: &Type) -> bool {
             Some(Item::Error(error)) => error
                 .parameters
                 .types()
-                .all(|ty| can_derive_builtin_traits(cx, ty)),
+                .all(|ty| rec_can_derive_builtin_traits(cx, ty, visiting)),
             Some(Item::Event(event)) => event
                 .parameters
                 .iter()
-                .all(|p| can_derive_builtin_traits(cx, &p.ty)),
-            Some(Item::Struct(strukt)) => strukt
-                .fields
-                .types()
-                .all(|ty| can_derive_builtin_traits(cx, ty)),
-            Some(Item::Udt(udt)) => can_derive_builtin_traits(cx, &udt.ty),
+                .all(|p| rec_can_derive_builtin_traits(cx, &p.ty, visiting)),
+            Some(Item::Struct(strukt)) => {
+                if visiting.contains(&&strukt.name) {
+                    return true
+                }
+                visiting.push(&strukt.name);
+                let derive = strukt
+                    .fields
+                    .types()
+                    .all(|ty| rec_can_derive_builtin_traits(cx, ty, visiting));
+                visiting.pop();
+                derive
+            }
+            Some(Item::Udt(udt)) => rec_can_derive_builtin_traits(cx, &udt.ty, visiting),
             Some(item) => panic!("Invalid item in param list: {item:?}"),
             _ => false,
         },
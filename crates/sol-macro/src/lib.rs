@@ -28,6 +28,7 @@ use proc_macro::TokenStream;
 use syn::parse_macro_input;
 
 mod attr;
+mod derive;
 mod expand;
 mod input;
 #[cfg(feature = "json")]
@@ -91,6 +92,15 @@ mod utils;
 /// structs, enums, etc. These can be any Rust attribute, and they will be added
 /// to every Rust item generated from the Solidity item.
 ///
+/// Doc comments are outer attributes too. Additionally, the [NatSpec] `@param`
+/// and `@return` tags in the doc comments of functions, events and errors are
+/// added as docs to the corresponding fields of the generated structs.
+/// `@param` tags are matched by name. `@return` tags are matched by name
+/// if they start with the name of a return value, and by position
+/// otherwise.
+///
+/// [NatSpec]: https://docs.soliditylang.org/en/latest/natspec-format.html
+///
 /// This macro provides the `sol` attribute, which can be used to customize the
 /// generated code. Note that unused attributes are currently silently ignored,
 /// but this may change in the future.
@@ -102,11 +112,42 @@ mod utils;
 /// - `extra_methods`: adds extra implementations and methods to all applicable
 ///   generated types, such as `From` impls and `as_<variant>` methods. May
 ///   significantly increase compile times due to all the extra generated code
+/// - `phf`: only valid on contracts and interfaces. Decodes the `Calls` and
+///   `Errors` enums by looking up the selector in a perfect hash table
+///   generated at compile time, instead of matching on it. This is enabled by
+///   default for enums with at least 32 variants, and can be disabled with `phf
+///   = false`
 /// - `bytecode = <hex string literal>`: specifies the creation/init bytecode of
 ///   a contract. This will emit a `static` item with the specified bytes.
 /// - `deployed_bytecode = <hex string literal>`: specifies the deployed
 ///   bytecode of a contract. This will emit a `static` item with the specified
 ///   bytes.
+/// - `zero_is_none`: only valid on struct fields and function parameters of
+///   single-word value types, like `address` or `bytes32`. Makes the generated
+///   Rust field an `Option`, where `None` is encoded as the zero value and the
+///   zero value is decoded as `None`, using
+///   [`sol_data::ZeroAsNone`][ZeroAsNone]. This is a convention, not a feature
+///   of the ABI: the Solidity type and signature are unchanged
+/// - `return_struct = <identifier string literal>`: only valid on functions.
+///   Renames the generated `<name>Return` struct, for example to `Reserves` for
+///   `getReserves`. Like `<name>Return`, it converts to and from the tuple of
+///   its fields' Rust types, e.g. `(u128, u128, u32)`
+/// - `builder`: only valid on structs and events, or on the whole input.
+///   Generates a `<name>Builder` type with a `with_<field>` method per field,
+///   and a `<name>::builder()` constructor. Unset fields default to
+///   `Default::default()`, while `build()` returns an error if an indexed event
+///   parameter, or a field whose type has no default, was not set. The builder
+///   derives `Debug` if the type it builds does
+/// - `serde`: only valid on structs and enums, or on the whole input. Requires
+///   the `serde` feature of `alloy-sol-types`. Implements [`SolSerde`] for the
+///   type and, for structs, `Serialize` and `Deserialize` in the JSON ABI
+///   format, unless the struct already derives them: integers are decimal
+///   strings, addresses are checksummed, and bytes are `0x`-prefixed hex
+///   strings. The fields' types must implement [`SolSerde`] too, so nested
+///   structs and enums need the attribute as well
+///
+/// [ZeroAsNone]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/sol_data/struct.ZeroAsNone.html
+/// [`SolSerde`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/trait.SolSerde.html
 ///
 /// ### Structs and enums
 ///
@@ -138,6 +179,10 @@ mod utils;
 /// E.g. if there are two functions named `foo`, the generated types will be
 /// `foo_0Call` and `foo_1Call`, each of which will implement `SolCall`
 /// with their respective signatures.
+///
+/// Constructors generate a `constructorCall` struct that implements
+/// `SolConstructor`, whose `abi_encode` method encodes the arguments to be
+/// appended to the contract's creation code.
 /// ```ignore
 #[doc = include_str!("../doctests/function_like.rs")]
 /// ```
@@ -157,11 +202,13 @@ mod utils;
 /// ### Contracts/interfaces
 ///
 /// Contracts generate a module with the same name, which contains all the items.
-/// This module will also contain 3 container enums which implement
-/// `SolInterface`, one for each:
-/// - functions: `<contract_name>Calls`
-/// - errors: `<contract_name>Errors`
-/// - events: `<contract_name>Events`
+/// This module will also contain these container enums, one for each kind of
+/// item:
+/// - functions: `<contract_name>Calls`, which implements `SolInterface` and
+///   `SolCallInterface`
+/// - function return values: `<contract_name>Returns`
+/// - errors: `<contract_name>Errors`, which implements `SolInterface`
+/// - events: `<contract_name>Events`, which implements `SolEventInterface`
 /// ```ignore
 #[doc = include_str!("../doctests/contracts.rs")]
 /// ```
@@ -193,3 +240,36 @@ pub fn sol(input: TokenStream) -> TokenStream {
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
+
+/// Derives ABI encoding and decoding for a Rust struct, as a tuple of its
+/// fields.
+///
+/// The struct implements [`SolType`] with itself as the Rust type, so its
+/// values can be encoded and decoded with the [`SolType`] methods, such as
+/// `abi_encode` and `abi_decode`.
+///
+/// The Solidity type of each field is inferred from its Rust type, or given
+/// with a `#[sol(<type>)]` attribute. Inference is based on type names only:
+/// - `bool`, `Address`, `Function`, `String`, `Bytes`, `U256`, `I256`, `B256`
+///   and `FixedBytes<N>` are mapped to the corresponding Solidity types;
+/// - `Vec<T>`, `[T; N]` and tuples are mapped to arrays, fixed arrays and
+///   tuples of their inferred element types;
+/// - primitive integers and `Vec<u8>` are ambiguous, and must be given a
+///   Solidity type;
+/// - any other type must itself implement [`SolType`], e.g. with this derive.
+///
+/// The Rust type of the Solidity type must be the field's type.
+///
+/// [`SolType`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/trait.SolType.html
+///
+/// # Examples
+///
+/// ```ignore
+#[doc = include_str!("../doctests/derive.rs")]
+/// ```
+#[proc_macro_derive(SolValue, attributes(sol))]
+pub fn derive_sol_value(input: TokenStream) -> TokenStream {
+    derive::expand(parse_macro_input!(input))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -226,6 +226,13 @@ impl<'de> Decoder<'de> {
         self.offset
     }
 
+    /// Returns the number of bytes remaining in the buffer after the current
+    /// offset.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        self.buf.len().saturating_sub(self.offset)
+    }
+
     /// Decodes a single token from the underlying buffer.
     #[inline]
     pub fn decode<T: TokenType<'de>>(&mut self) -> Result<T> {
@@ -246,7 +253,20 @@ impl<'de> Decoder<'de> {
 /// to use raw tokens.
 #[inline]
 pub fn decode<'de, T: TokenType<'de>>(data: &'de [u8], validate: bool) -> Result<T> {
-    decode_sequence::<(T,)>(data, validate).map(|(t,)| t)
+    decode_sequence::<(T,)>(data, validate)
+        .map(|(t,)| t)
+        .map_err(|e| match e {
+            // the wrapping tuple is not part of the path
+            Error::Element { mut path, error } => {
+                path.remove(0);
+                if path.is_empty() {
+                    *error
+                } else {
+                    Error::Element { path, error }
+                }
+            }
+            e => e,
+        })
 }
 
 /// ABI-decodes top-level function args.
@@ -281,6 +301,71 @@ pub fn decode_sequence<'de, T: TokenSeq<'de>>(data: &'de [u8], validate: bool) -
     Ok(res)
 }
 
+/// Options for [`SolType::abi_decode_with_options`].
+///
+/// Create them with [`DecodeOptions::new`] or [`Default`], and set them with
+/// the `with_*` methods.
+///
+/// [`SolType::abi_decode_with_options`]: crate::SolType::abi_decode_with_options
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DecodeOptions {
+    /// Whether to type check the data and check that it re-encodes to the
+    /// input. This is the `validate` argument of the other decoding methods.
+    pub validate: bool,
+    /// Whether to reject data that is not byte-for-byte equal to the encoding
+    /// of the decoded value, with [`Error::NonCanonical`].
+    ///
+    /// This covers more than padding: besides `address`, `bool` or `uintN`
+    /// words with non-zero padding bytes, it rejects trailing data, offsets
+    /// that do not point right after the previous element, and non-zero
+    /// padding after `bytes` and `string` contents.
+    ///
+    /// Unlike `validate`, which rejects most of these as a type check or
+    /// reserialization failure, this reports the offset of the first
+    /// non-canonical byte.
+    pub canonical: bool,
+}
+
+impl DecodeOptions {
+    /// Creates new options with every check disabled.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            validate: false,
+            canonical: false,
+        }
+    }
+
+    /// Sets [`validate`](Self::validate).
+    #[inline]
+    pub const fn with_validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Sets [`canonical`](Self::canonical).
+    #[inline]
+    pub const fn with_canonical(mut self, canonical: bool) -> Self {
+        self.canonical = canonical;
+        self
+    }
+}
+
+/// Checks that `data` is equal to its canonical encoding, returning the offset
+/// of the first differing byte otherwise.
+pub(crate) fn check_canonical(data: &[u8], canonical: &[u8]) -> Result<()> {
+    let offset = data
+        .iter()
+        .zip(canonical)
+        .position(|(a, b)| a != b)
+        .or_else(|| (data.len() != canonical.len()).then(|| data.len().min(canonical.len())));
+    match offset {
+        Some(offset) => Err(Error::NonCanonical { offset }),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{sol_data, utils::pad_u32, SolType};
@@ -333,6 +418,22 @@ mod tests {
         assert_eq!(decoded, expected);
     }
 
+    #[test]
+    fn decode_after_static_fixed_array() {
+        type MyTy = (
+            sol_data::FixedArray<sol_data::Uint<16>, 2>,
+            sol_data::Uint<16>,
+        );
+        let value = ([1, 2], 3);
+        let encoded = MyTy::abi_encode(&value);
+        assert_eq!(MyTy::abi_decode(&encoded, true).unwrap(), value);
+
+        type MyArray = sol_data::Array<sol_data::FixedArray<sol_data::Uint<16>, 2>>;
+        let value = vec![[1, 2], [3, 4]];
+        let encoded = MyArray::abi_encode(&value);
+        assert_eq!(MyArray::abi_decode(&encoded, true).unwrap(), value);
+    }
+
     #[test]
     fn decode_dynamic_tuple() {
         type MyTy = (sol_data::String, sol_data::String);
@@ -675,4 +776,183 @@ mod tests {
             "did not match error"
         );
     }
+
+    #[test]
+    fn fixed_bytes_dirty_padding() {
+        type MyTy = sol_data::FixedBytes<4>;
+
+        let dirty = hex!("deadbeef00000000000000000000000000000000000000000000000000000001");
+
+        assert_eq!(MyTy::abi_decode(&dirty, false).unwrap(), hex!("deadbeef"));
+
+        assert!(
+            matches!(
+                MyTy::abi_decode(&dirty, true),
+                Err(crate::Error::TypeCheckFail { .. }),
+            ),
+            "did not match error"
+        );
+
+        let clean = hex!("deadbeef00000000000000000000000000000000000000000000000000000000");
+        assert_eq!(MyTy::abi_decode(&clean, true).unwrap(), hex!("deadbeef"));
+
+        // a full word has no padding to check
+        let full = hex!("deadbeef00000000000000000000000000000000000000000000000000000001");
+        assert_eq!(
+            sol_data::FixedBytes::<32>::abi_decode(&full, true).unwrap(),
+            full
+        );
+    }
+
+    #[test]
+    fn non_canonical_padding() {
+        use crate::{abi::DecodeOptions, Error};
+
+        let lenient = DecodeOptions::new();
+        let canonical = lenient.with_canonical(true);
+        let strict = canonical.with_validate(true);
+
+        let address = address!("1111111111111111111111111111111111111111");
+        let mut dirty = address.into_word();
+        dirty[3] = 0xff;
+        let decode = |options| sol_data::Address::abi_decode_with_options(&dirty[..], options);
+        assert_eq!(decode(lenient), Ok(address));
+        assert_eq!(decode(canonical), Err(Error::NonCanonical { offset: 3 }));
+        assert_eq!(decode(strict), Err(Error::NonCanonical { offset: 3 }));
+        assert!(matches!(
+            sol_data::Address::abi_decode_with_options(&dirty[..], lenient.with_validate(true)),
+            Err(Error::TypeCheckFail { .. })
+        ));
+
+        let clean = address.into_word();
+        assert_eq!(
+            sol_data::Address::abi_decode_with_options(&clean[..], strict),
+            Ok(address)
+        );
+
+        let two = hex!("0000000000000000000000000000000000000000000000000000000000000002");
+        assert_eq!(
+            sol_data::Bool::abi_decode_with_options(&two, lenient),
+            Ok(true)
+        );
+        assert_eq!(
+            sol_data::Bool::abi_decode_with_options(&two, canonical),
+            Err(Error::NonCanonical { offset: 31 })
+        );
+
+        let dirty_uint = hex!("0000000000000000000000000000000000000000000000000000000000000102");
+        assert_eq!(
+            sol_data::Uint::<8>::abi_decode_with_options(&dirty_uint, lenient),
+            Ok(2)
+        );
+        assert_eq!(
+            sol_data::Uint::<8>::abi_decode_with_options(&dirty_uint, canonical),
+            Err(Error::NonCanonical { offset: 30 })
+        );
+
+        // the offset is relative to the whole data
+        type MyTy = (sol_data::Bool, sol_data::Array<sol_data::Address>);
+        let value = (true, vec![address; 2]);
+        let mut data = MyTy::abi_encode(&value);
+        assert_eq!(MyTy::abi_decode_with_options(&data, strict), Ok(value));
+        // tuple offset, bool, array offset, array length, first address
+        let second = 5 * 32;
+        data[second + 11] = 1;
+        assert_eq!(
+            MyTy::abi_decode_with_options(&data, canonical),
+            Err(Error::NonCanonical {
+                offset: second + 11
+            })
+        );
+
+        // trailing data
+        let mut data = clean.to_vec();
+        data.push(0);
+        assert_eq!(
+            sol_data::Address::abi_decode_with_options(&data, lenient),
+            Ok(address)
+        );
+        assert_eq!(
+            sol_data::Address::abi_decode_with_options(&data, canonical),
+            Err(Error::NonCanonical { offset: 32 })
+        );
+    }
+
+    #[test]
+    fn decode_wrong_method_hint() {
+        type MyTy = (sol_data::Bytes,);
+        let value = (hex!("deadbeef").to_vec(),);
+
+        let params = MyTy::abi_encode_params(&value);
+        let err = MyTy::abi_decode(&params, true).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::WrongDecodeMethod {
+                encoded_with: "abi_encode_params",
+                decode_with: "abi_decode_params",
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "tuple.0: Type check failed for \"Solidity pointer (uint32)\" with data: \
+             00000000000000000000000000000000000000000000000000000004deadbeef; the data appears \
+             to be encoded with `abi_encode_params`, use `abi_decode_params` instead"
+        );
+        assert!(
+            matches!(err.root_cause(), crate::Error::TypeCheckFail { .. }),
+            "{err:?}"
+        );
+
+        let err = MyTy::abi_decode(&params, false).unwrap_err();
+        assert!(
+            matches!(err, crate::Error::WrongDecodeMethod { .. }),
+            "{err:?}"
+        );
+
+        let single = MyTy::abi_encode(&value);
+        let err = MyTy::abi_decode_params(&single, true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Reserialization did not match original; the data appears to be encoded with \
+             `abi_encode`, use `abi_decode` instead"
+        );
+        let err = MyTy::abi_decode_sequence(&single, true).unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::WrongDecodeMethod {
+                decode_with: "abi_decode",
+                ..
+            }
+        ));
+
+        // unrelated failures are not annotated
+        for err in [
+            MyTy::abi_decode(&params[..64], true).unwrap_err(),
+            MyTy::abi_decode_params(&params[..64], true).unwrap_err(),
+            sol_data::Bytes::abi_decode(&single, true).unwrap_err(),
+        ] {
+            assert!(
+                !matches!(err, crate::Error::WrongDecodeMethod { .. }),
+                "{err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn peek_does_not_advance() {
+        // a leading discriminator followed by a payload
+        let data = [B256::with_last_byte(2), B256::with_last_byte(0x2a)].concat();
+        let mut decoder = super::Decoder::new(&data, true);
+
+        assert_eq!(decoder.peek_word(), Ok(B256::with_last_byte(2)));
+        assert_eq!(decoder.peek_u32(), Ok(2));
+        assert_eq!(decoder.offset(), 0);
+
+        assert_eq!(decoder.take_u32(), Ok(2));
+        assert_eq!(decoder.peek_u32(), Ok(0x2a));
+        assert_eq!(decoder.take_word(), Ok(B256::with_last_byte(0x2a)));
+        assert_eq!(decoder.peek_word(), Err(crate::Error::Overrun));
+        assert_eq!(decoder.remaining(), 0);
+    }
 }
@@ -12,16 +12,66 @@ use crate::{
     utils, Word,
 };
 use alloc::vec::Vec;
-use core::{mem, ptr};
+use alloy_primitives::bytes::{BufMut, BytesMut};
+
+/// A byte buffer that the ABI-encoded output can be written to.
+///
+/// This is implemented for [`Vec<u8>`] and [`BytesMut`].
+pub trait OutBuf {
+    /// Reserves capacity for at least `additional` more bytes.
+    fn reserve(&mut self, additional: usize);
+
+    /// Appends the given bytes to the buffer.
+    fn extend_from_slice(&mut self, bytes: &[u8]);
+}
+
+impl OutBuf for Vec<u8> {
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional)
+    }
+
+    #[inline]
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        Vec::extend_from_slice(self, bytes)
+    }
+}
+
+impl OutBuf for BytesMut {
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        BytesMut::reserve(self, additional)
+    }
+
+    #[inline]
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.put_slice(bytes)
+    }
+}
+
+impl<B: OutBuf + ?Sized> OutBuf for &mut B {
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        (**self).reserve(additional)
+    }
+
+    #[inline]
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        (**self).extend_from_slice(bytes)
+    }
+}
 
 /// An ABI encoder.
 ///
+/// The encoded words are appended to the underlying [`OutBuf`] as they are
+/// written, which is a new [`Vec<u8>`] by default.
+///
 /// This is not intended for public consumption. It should be used only by the
 /// token types. If you have found yourself here, you probably want to use the
 /// high-level [`crate::SolType`] interface (or its dynamic equivalent) instead.
 #[derive(Default, Clone, Debug)]
-pub struct Encoder {
-    buf: Vec<Word>,
+pub struct Encoder<B = Vec<u8>> {
+    buf: B,
     suffix_offset: Vec<u32>,
 }
 
@@ -38,30 +88,45 @@ impl Encoder {
     /// Instantiate a new encoder with a given capacity in words.
     #[inline]
     pub fn with_capacity(size: usize) -> Self {
-        Self {
-            buf: Vec::with_capacity(size),
-            suffix_offset: Vec::with_capacity(8),
-        }
+        Self::with_buf(Vec::with_capacity(size * 32))
     }
 
     /// Finish the encoding process, returning the encoded words.
     ///
-    /// Use `into_bytes` instead to flatten the words into bytes.
-    // https://github.com/rust-lang/rust-clippy/issues/4979
-    #[allow(clippy::missing_const_for_fn)]
+    /// This copies the encoded bytes into words. Use `into_bytes` instead to
+    /// get the bytes without copying them.
     #[inline]
     pub fn finish(self) -> Vec<Word> {
-        self.buf
+        self.buf.chunks_exact(32).map(Word::from_slice).collect()
     }
 
     /// Finish the encoding process, returning the encoded bytes.
+    // https://github.com/rust-lang/rust-clippy/issues/4979
+    #[allow(clippy::missing_const_for_fn)]
     #[inline]
     pub fn into_bytes(self) -> Vec<u8> {
-        // TODO: remove once `Vec::into_flattened` is stabilized.
-        // unsafe { mem::transmute::<Vec<_>, Vec<[u8; 32]>>(self.buf).into_flattened() }
+        self.buf
+    }
+}
 
-        // SAFETY: `#[repr(transparent)] FixedBytes<N>([u8; N])`
-        unsafe { crate::impl_core::into_flattened::<u8, 32>(mem::transmute(self.buf)) }
+impl<B: OutBuf> Encoder<B> {
+    /// Instantiate a new encoder that appends to the given buffer.
+    ///
+    /// Pass a `&mut` reference to keep using the buffer after encoding.
+    #[inline]
+    pub const fn with_buf(buf: B) -> Self {
+        Self {
+            buf,
+            suffix_offset: Vec::new(),
+        }
+    }
+
+    /// Finish the encoding process, returning the underlying buffer.
+    // https://github.com/rust-lang/rust-clippy/issues/4979
+    #[allow(clippy::missing_const_for_fn)]
+    #[inline]
+    pub fn into_buf(self) -> B {
+        self.buf
     }
 
     /// Determine the current suffix offset.
@@ -99,7 +164,7 @@ impl Encoder {
     /// Append a word to the encoder.
     #[inline]
     pub fn append_word(&mut self, word: Word) {
-        self.buf.push(word);
+        self.buf.extend_from_slice(word.as_slice());
     }
 
     /// Append a pointer to the current suffix offset.
@@ -135,25 +200,10 @@ impl Encoder {
     /// Append a sequence of bytes, padding to the next word.
     #[inline(always)]
     fn append_bytes(&mut self, bytes: &[u8]) {
-        let n_words = utils::words_for(bytes);
-        self.buf.reserve(n_words);
-        unsafe {
-            // set length before copying
-            // this is fine because we reserved above and we don't panic below
-            let len = self.buf.len();
-            self.buf.set_len(len + n_words);
-
-            // copy
-            let cnt = bytes.len();
-            let dst = self.buf.as_mut_ptr().add(len).cast::<u8>();
-            ptr::copy_nonoverlapping(bytes.as_ptr(), dst, cnt);
-
-            // set remaining bytes to zero if necessary
-            let rem = cnt % 32;
-            if rem != 0 {
-                let pad = 32 - rem;
-                ptr::write_bytes(dst.add(cnt), 0, pad);
-            }
+        self.buf.extend_from_slice(bytes);
+        let rem = bytes.len() % 32;
+        if rem != 0 {
+            self.buf.extend_from_slice(&[0; 32][rem..]);
         }
     }
 }
@@ -165,6 +215,31 @@ pub fn encode_sequence<'a, T: TokenSeq<'a>>(tokens: &T) -> Vec<u8> {
     enc.into_bytes()
 }
 
+/// ABI-encode the first `n` tokens of a token sequence, as if they were a
+/// sequence of their own.
+///
+/// See [`TokenSeq::encode_sequence_prefix`] for more details.
+pub fn encode_sequence_prefix<'a, T: TokenSeq<'a>>(tokens: &T, n: usize) -> Vec<u8> {
+    let mut enc = Encoder::with_capacity(tokens.total_words());
+    tokens.encode_sequence_prefix(n, &mut enc);
+    enc.into_bytes()
+}
+
+/// ABI-encode a token sequence, appending it to the given buffer.
+pub fn encode_sequence_to<'a, T: TokenSeq<'a>, B: OutBuf + ?Sized>(tokens: &T, out: &mut B) {
+    out.reserve(tokens.total_words() * 32);
+    Encoder::with_buf(out).append_head_tail(tokens);
+}
+
+/// ABI-encode a token sequence, appending it to the given [`BytesMut`].
+///
+/// Use [`BytesMut::split`] and [`BytesMut::freeze`] to turn the output into
+/// an immutable [`Bytes`](alloy_primitives::Bytes) without copying it.
+#[inline]
+pub fn encode_into_bytes_mut<'a, T: TokenSeq<'a>>(tokens: &T, out: &mut BytesMut) {
+    encode_sequence_to(tokens, out)
+}
+
 /// ABI-encode a single token.
 #[inline]
 pub fn encode<'a, T: TokenType<'a>>(token: &T) -> Vec<u8> {
@@ -184,11 +259,29 @@ pub fn encode_params<'a, T: TokenSeq<'a>>(token: &T) -> Vec<u8> {
     }
 }
 
+/// ABI-encode a single token, appending it to the given buffer.
+#[inline]
+pub fn encode_to<'a, T: TokenType<'a>, B: OutBuf + ?Sized>(token: &T, out: &mut B) {
+    // SAFETY: Converting `&T` to `&(T,)` is sound. See `encode`.
+    encode_sequence_to::<(T,), B>(unsafe { &*(token as *const T).cast::<(T,)>() }, out)
+}
+
+/// ABI-encode a tuple as ABI function params, appending it to the given
+/// buffer.
+#[inline]
+pub fn encode_params_to<'a, T: TokenSeq<'a>, B: OutBuf + ?Sized>(token: &T, out: &mut B) {
+    if T::IS_TUPLE {
+        encode_sequence_to(token, out)
+    } else {
+        encode_to(token, out)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{sol_data, SolType};
     use alloc::{borrow::ToOwned, string::ToString};
-    use alloy_primitives::{hex, Address, U256};
+    use alloy_primitives::{hex, Address, FixedBytes, B256, U256};
 
     #[test]
     fn encode_address() {
@@ -653,6 +746,28 @@ mod tests {
         assert_eq!(encoded.len(), sol_data::Uint::<8>::abi_encoded_size(&uint));
     }
 
+    #[test]
+    fn encode_reinterpreted_uint() {
+        // `bytesN` is left-aligned in its word, while `uintN` is right-aligned
+        let bytes = FixedBytes::<4>(hex!("a9059cbb"));
+        let uint = u32::from(bytes);
+        assert_eq!(
+            sol_data::FixedBytes::<4>::abi_encode(&bytes),
+            hex!("a9059cbb00000000000000000000000000000000000000000000000000000000")
+        );
+        assert_eq!(
+            sol_data::Uint::<32>::abi_encode(&uint),
+            hex!("00000000000000000000000000000000000000000000000000000000a9059cbb")
+        );
+
+        // full words are encoded identically
+        let word = B256::repeat_byte(0x42);
+        assert_eq!(
+            sol_data::FixedBytes::<32>::abi_encode(&word),
+            sol_data::Uint::<256>::abi_encode(&word.to_uint())
+        );
+    }
+
     #[test]
     fn encode_int() {
         let int = 4;
@@ -1123,4 +1238,98 @@ mod tests {
         assert_eq!(encoded_params.len() + 32, encoded.len());
         assert_eq!(encoded_params.len(), MyTy::abi_encoded_size(&data));
     }
+
+    #[test]
+    fn encode_to_bytes_mut() {
+        use crate::abi::{encode_into_bytes_mut, encode_sequence_to};
+        use alloy_primitives::bytes::BytesMut;
+
+        type MyTy = (
+            sol_data::Address,
+            sol_data::Array<sol_data::String>,
+            sol_data::Uint<256>,
+        );
+        let data = (
+            Address::from([0x11u8; 20]),
+            vec!["a".to_owned(), "b".repeat(40)],
+            U256::from(1234),
+        );
+        let tokens = MyTy::tokenize(&data);
+        let expected = MyTy::abi_encode_sequence(&data);
+
+        let mut vec = Vec::new();
+        encode_sequence_to(&tokens, &mut vec);
+        assert_eq!(vec, expected);
+
+        let mut out = BytesMut::from(&b"prefix"[..]);
+        encode_into_bytes_mut(&tokens, &mut out);
+        assert_eq!(&out[..6], b"prefix");
+        assert_eq!(out[6..], expected[..]);
+
+        // appends after what is already in the buffer
+        let first = out.split().freeze();
+        encode_into_bytes_mut(&tokens, &mut out);
+        assert_eq!(out.freeze(), first[6..]);
+    }
+
+    #[test]
+    fn encode_to_appends() {
+        use crate::abi::{encode_params_to, encode_to};
+
+        type MyTy = (
+            sol_data::Address,
+            sol_data::Array<sol_data::String>,
+            sol_data::Uint<256>,
+        );
+        let data = (
+            Address::from([0x11u8; 20]),
+            vec!["a".to_owned(), "b".repeat(40)],
+            U256::from(1234),
+        );
+        let tokens = MyTy::tokenize(&data);
+
+        let mut out = b"prefix".to_vec();
+        encode_to(&tokens, &mut out);
+        encode_params_to(&tokens, &mut out);
+        let single = MyTy::abi_encode(&data);
+        let params = MyTy::abi_encode_params(&data);
+        assert_eq!(out, [&b"prefix"[..], &single, &params].concat());
+
+        // the buffer's capacity is reused
+        out.clear();
+        let capacity = out.capacity();
+        MyTy::abi_encode_to(&data, &mut out);
+        MyTy::abi_encode_params_to(&data, &mut out);
+        assert_eq!(out.capacity(), capacity);
+        assert_eq!(out, [single, params].concat());
+
+        let mut out = Vec::new();
+        sol_data::String::abi_encode_to(&"abc", &mut out);
+        sol_data::String::abi_encode_to(&"def", &mut out);
+        assert_eq!(
+            out,
+            [
+                sol_data::String::abi_encode(&"abc"),
+                sol_data::String::abi_encode(&"def")
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn encode_prefix() {
+        use crate::abi::encode_sequence_prefix;
+
+        type MyTy = sol_data::Array<sol_data::String>;
+        let data = vec!["a".to_owned(), "b".repeat(40), "c".to_owned()];
+        let tokens = MyTy::tokenize(&data);
+        for n in 0..=3 {
+            let expected = MyTy::abi_encode_sequence(&data[..n].to_vec());
+            assert_eq!(encode_sequence_prefix(&tokens, n), expected, "{n}");
+        }
+        assert_eq!(
+            encode_sequence_prefix(&tokens, 4),
+            MyTy::abi_encode_sequence(&data)
+        );
+    }
 }
@@ -27,10 +27,16 @@
 //! This is the least useful one. Most users will not need it.
 
 mod encoder;
-pub use encoder::{encode, encode_params, encode_sequence, Encoder};
+pub use encoder::{
+    encode, encode_into_bytes_mut, encode_params, encode_params_to, encode_sequence,
+    encode_sequence_prefix, encode_sequence_to, encode_to, Encoder, OutBuf,
+};
 
 mod decoder;
-pub use decoder::{decode, decode_params, decode_sequence, Decoder};
+pub(crate) use decoder::check_canonical;
+pub use decoder::{decode, decode_params, decode_sequence, DecodeOptions, Decoder};
+
+pub mod round_trip;
 
 pub mod token;
 pub use token::{TokenSeq, TokenType};
@@ -0,0 +1,113 @@
+//! Type-erased static coders, looked up by Solidity type name.
+//!
+//! The static coders are only available for types that are known at compile
+//! time. This module provides a fixed set of them behind function pointers, so
+//! that they can be compared with other implementations on the same inputs,
+//! such as the dynamic coder of `alloy-dyn-abi` when fuzzing.
+//!
+//! ```
+//! use alloy_primitives::U256;
+//! use alloy_sol_types::{abi::round_trip, sol_data, SolType};
+//!
+//! type MyTuple = (sol_data::Uint<256>, sol_data::Bool);
+//! let data = MyTuple::abi_encode(&(U256::from(1), true));
+//! let round_trip = round_trip::get("(uint256,bool)").unwrap();
+//! assert_eq!(round_trip(&data).unwrap(), data);
+//! ```
+
+use crate::{sol_data::*, Result, SolType};
+use alloc::vec::Vec;
+
+/// Decodes the data as a single value of a static type with validation, and
+/// ABI-encodes the result again.
+///
+/// Validation rejects data that is not canonically encoded, so the result is
+/// always equal to the input when decoding succeeds.
+pub type RoundTripFn = fn(&[u8]) -> Result<Vec<u8>>;
+
+macro_rules! round_trips {
+    ($($name:literal => $ty:ty),+ $(,)?) => {
+        /// The types with a [`RoundTripFn`], by their Solidity type name.
+        ///
+        /// All of these types use their full word, so any value of the
+        /// corresponding Rust type is valid.
+        pub static ROUND_TRIPS: &[(&str, RoundTripFn)] = &[$(($name, round_trip::<$ty>),)+];
+
+        #[cfg(test)]
+        #[test]
+        fn names() {
+            $(assert_eq!(<$ty as SolType>::sol_type_name(), $name);)+
+        }
+    };
+}
+
+round_trips! {
+    "bool" => Bool,
+    "address" => Address,
+    "function" => Function,
+    "uint256" => Uint<256>,
+    "int256" => Int<256>,
+    "bytes32" => FixedBytes<32>,
+    "bytes" => Bytes,
+    "string" => String,
+    "uint256[]" => Array<Uint<256>>,
+    "address[3]" => FixedArray<Address, 3>,
+    "string[2]" => FixedArray<String, 2>,
+    "bytes[][]" => Array<Array<Bytes>>,
+    "(uint256,bool)" => (Uint<256>, Bool),
+    "(address,bytes)[]" => Array<(Address, Bytes)>,
+    "(bytes32,(string,int256[]))" => (FixedBytes<32>, (String, Array<Int<256>>)),
+    "(string,uint256[2])[2]" => FixedArray<(String, FixedArray<Uint<256>, 2>), 2>,
+}
+
+/// Returns the [`RoundTripFn`] of the type with the given Solidity type name,
+/// if it is in [`ROUND_TRIPS`].
+pub fn get(name: &str) -> Option<RoundTripFn> {
+    ROUND_TRIPS
+        .iter()
+        .find(|&&(n, _)| n == name)
+        .map(|&(_, f)| f)
+}
+
+fn round_trip<T: SolType>(data: &[u8]) -> Result<Vec<u8>> {
+    T::abi_decode(data, true).map(|value| T::abi_encode(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use alloy_primitives::{hex, Bytes as RustBytes, U256};
+
+    #[test]
+    fn canonical() {
+        let data = <(Uint<256>, Bool)>::abi_encode(&(U256::from(1), true));
+        assert_eq!(get("(uint256,bool)").unwrap()(&data).unwrap(), data);
+
+        let address = alloy_primitives::Address::repeat_byte(0x11);
+        let value: Vec<(_, RustBytes)> =
+            vec![(address, hex!("01").into()), (address, Default::default())];
+        let data = Array::<(Address, Bytes)>::abi_encode(&value);
+        assert_eq!(get("(address,bytes)[]").unwrap()(&data).unwrap(), data);
+
+        assert!(get("uint8").is_none());
+    }
+
+    #[test]
+    fn non_canonical() {
+        let round_trip = get("bytes").unwrap();
+        let canonical = Bytes::abi_encode(&RustBytes::from(hex!("11")));
+        assert_eq!(round_trip(&canonical).unwrap(), canonical);
+        assert!(round_trip(&canonical[..64]).is_err());
+
+        // the same `bytes`, with an offset that skips an unused word
+        let mut data = canonical.clone();
+        data[31] = 0x40;
+        data.splice(32..32, [0; 32]);
+        assert_eq!(Bytes::abi_decode(&data, false).unwrap(), hex!("11"));
+        assert!(matches!(round_trip(&data), Err(Error::ReserMismatch)));
+
+        assert!(get("bool").unwrap()(&[2; 32]).is_err());
+        assert!(get("bool").unwrap()(&U256::from(2).to_be_bytes::<32>()).is_err());
+    }
+}
@@ -17,8 +17,8 @@
 //! - Dynamic-length byte arrays `u8[]`
 
 use crate::{
-    abi::{Decoder, Encoder},
-    Result, Word,
+    abi::{Decoder, Encoder, OutBuf},
+    Error, PathElement, Result, SolType, Word,
 };
 use alloc::vec::Vec;
 use alloy_primitives::{FixedBytes, I256, U256};
@@ -33,6 +33,7 @@ mod sealed {
     impl<T, const N: usize> Sealed for FixedSeqToken<T, N> {}
     impl<T> Sealed for DynSeqToken<T> {}
     impl<'a> Sealed for PackedSeqToken<'a> {}
+    impl<'de, T: RecursiveStruct> Sealed for RecursiveToken<'de, T> {}
 }
 
 use sealed::Sealed;
@@ -69,10 +70,10 @@ pub trait TokenType<'de>: Sealed + Sized {
     }
 
     /// Append head words to the encoder.
-    fn head_append(&self, enc: &mut Encoder);
+    fn head_append<B: OutBuf>(&self, enc: &mut Encoder<B>);
 
     /// Append tail words to the encoder.
-    fn tail_append(&self, enc: &mut Encoder);
+    fn tail_append<B: OutBuf>(&self, enc: &mut Encoder<B>);
 }
 
 /// A token composed of a sequence of other tokens
@@ -84,7 +85,14 @@ pub trait TokenSeq<'a>: TokenType<'a> {
     const IS_TUPLE: bool = false;
 
     /// ABI-encode the token sequence into the encoder.
-    fn encode_sequence(&self, enc: &mut Encoder);
+    fn encode_sequence<B: OutBuf>(&self, enc: &mut Encoder<B>);
+
+    /// ABI-encode the first `n` tokens of the sequence into the encoder, as if
+    /// they were a sequence of their own.
+    ///
+    /// If `n` is greater than the length of the sequence, all of the tokens
+    /// are encoded.
+    fn encode_sequence_prefix<B: OutBuf>(&self, n: usize, enc: &mut Encoder<B>);
 
     /// ABI-decode the token sequence from the encoder.
     fn decode_sequence(dec: &mut Decoder<'a>) -> Result<Self>;
@@ -135,6 +143,20 @@ impl From<[u8; 32]> for WordToken {
     }
 }
 
+impl TryFrom<&[u8]> for WordToken {
+    type Error = Error;
+
+    /// Converts a byte slice into a word token.
+    ///
+    /// Fails if the slice is not exactly 32 bytes long.
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self> {
+        Word::try_from(value)
+            .map(Self)
+            .map_err(|_| Error::type_check_fail(value, "bytes32"))
+    }
+}
+
 impl AsRef<Word> for WordToken {
     #[inline]
     fn as_ref(&self) -> &Word {
@@ -168,12 +190,12 @@ impl<'a> TokenType<'a> for WordToken {
     }
 
     #[inline]
-    fn head_append(&self, enc: &mut Encoder) {
+    fn head_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
         enc.append_word(self.0);
     }
 
     #[inline]
-    fn tail_append(&self, _enc: &mut Encoder) {}
+    fn tail_append<B: OutBuf>(&self, _enc: &mut Encoder<B>) {}
 }
 
 impl WordToken {
@@ -194,6 +216,13 @@ impl WordToken {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FixedSeqToken<T, const N: usize>(pub [T; N]);
 
+impl<T: Default, const N: usize> Default for FixedSeqToken<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self(core::array::from_fn(|_| T::default()))
+    }
+}
+
 impl<T, const N: usize> TryFrom<Vec<T>> for FixedSeqToken<T, N> {
     type Error = <[T; N] as TryFrom<Vec<T>>>::Error;
 
@@ -228,7 +257,13 @@ impl<'de, T: TokenType<'de>, const N: usize> TokenType<'de> for FixedSeqToken<T,
             dec.raw_child()
         };
 
-        Self::decode_sequence(&mut child)
+        let res = Self::decode_sequence(&mut child)?;
+
+        if !Self::DYNAMIC {
+            dec.take_offset(child);
+        }
+
+        Ok(res)
     }
 
     #[inline]
@@ -250,7 +285,7 @@ impl<'de, T: TokenType<'de>, const N: usize> TokenType<'de> for FixedSeqToken<T,
     }
 
     #[inline]
-    fn head_append(&self, enc: &mut Encoder) {
+    fn head_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
         if Self::DYNAMIC {
             enc.append_indirection();
         } else {
@@ -261,31 +296,63 @@ impl<'de, T: TokenType<'de>, const N: usize> TokenType<'de> for FixedSeqToken<T,
     }
 
     #[inline]
-    fn tail_append(&self, enc: &mut Encoder) {
+    fn tail_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
         if Self::DYNAMIC {
             self.encode_sequence(enc);
         }
     }
 }
 
-impl<'de, T: TokenType<'de>, const N: usize> TokenSeq<'de> for FixedSeqToken<T, N> {
-    fn encode_sequence(&self, enc: &mut Encoder) {
-        let head_words = self.0.iter().map(TokenType::head_words).sum::<usize>();
-        enc.push_offset(head_words as u32);
+/// ABI-encodes a slice of tokens as a sequence.
+fn encode_slice<'de, T: TokenType<'de>, B: OutBuf>(tokens: &[T], enc: &mut Encoder<B>) {
+    let head_words = tokens.iter().map(TokenType::head_words).sum::<usize>();
+    enc.push_offset(head_words as u32);
 
-        for inner in &self.0 {
-            inner.head_append(enc);
-            enc.bump_offset(inner.tail_words() as u32);
-        }
-        for inner in &self.0 {
-            inner.tail_append(enc);
-        }
+    for inner in tokens {
+        inner.head_append(enc);
+        enc.bump_offset(inner.tail_words() as u32);
+    }
+    for inner in tokens {
+        inner.tail_append(enc);
+    }
 
-        enc.pop_offset();
+    enc.pop_offset();
+}
+
+impl<'de, T: TokenType<'de>, const N: usize> TokenSeq<'de> for FixedSeqToken<T, N> {
+    #[inline]
+    fn encode_sequence<B: OutBuf>(&self, enc: &mut Encoder<B>) {
+        encode_slice(&self.0, enc)
+    }
+
+    #[inline]
+    fn encode_sequence_prefix<B: OutBuf>(&self, n: usize, enc: &mut Encoder<B>) {
+        encode_slice(&self.0[..n.min(N)], enc)
     }
 
     fn decode_sequence(dec: &mut Decoder<'de>) -> Result<Self> {
-        crate::impl_core::try_from_fn(|_| T::decode_from(dec)).map(Self)
+        let mismatch = |got| Error::FixedArrayLengthMismatch { expected: N, got };
+
+        // the head of each dynamic element is a single offset word, so the
+        // element count can be checked before following any of them
+        if T::DYNAMIC {
+            let got = dec.remaining() / 32;
+            if got < N {
+                return Err(mismatch(got))
+            }
+            return crate::impl_core::try_from_fn(|i| {
+                T::decode_from(dec).map_err(|e| e.in_element(PathElement::Index(i)))
+            })
+            .map(Self)
+        }
+
+        crate::impl_core::try_from_fn(|i| {
+            T::decode_from(dec).map_err(|e| match e.root_cause() {
+                Error::Overrun => mismatch(i),
+                _ => e.in_element(PathElement::Index(i)),
+            })
+        })
+        .map(Self)
     }
 }
 
@@ -315,6 +382,13 @@ impl<T, const N: usize> FixedSeqToken<T, N> {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DynSeqToken<T>(pub Vec<T>);
 
+impl<T> Default for DynSeqToken<T> {
+    #[inline]
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
 impl<T> From<Vec<T>> for DynSeqToken<T> {
     #[inline]
     fn from(value: Vec<T>) -> Self {
@@ -341,7 +415,7 @@ impl<'de, T: TokenType<'de>> TokenType<'de> for DynSeqToken<T> {
         // word AFTER the array size
         let mut child = child.raw_child();
         (0..len)
-            .map(|_| T::decode_from(&mut child))
+            .map(|i| T::decode_from(&mut child).map_err(|e| e.in_element(PathElement::Index(i))))
             .collect::<Result<Vec<T>>>()
             .map(DynSeqToken)
     }
@@ -357,31 +431,26 @@ impl<'de, T: TokenType<'de>> TokenType<'de> for DynSeqToken<T> {
     }
 
     #[inline]
-    fn head_append(&self, enc: &mut Encoder) {
+    fn head_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
         enc.append_indirection();
     }
 
     #[inline]
-    fn tail_append(&self, enc: &mut Encoder) {
+    fn tail_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
         enc.append_seq_len(self.0.len());
         self.encode_sequence(enc);
     }
 }
 
 impl<'de, T: TokenType<'de>> TokenSeq<'de> for DynSeqToken<T> {
-    fn encode_sequence(&self, enc: &mut Encoder) {
-        let head_words = self.0.iter().map(TokenType::head_words).sum::<usize>();
-        enc.push_offset(head_words as u32);
-
-        for inner in &self.0 {
-            inner.head_append(enc);
-            enc.bump_offset(inner.tail_words() as u32);
-        }
-        for inner in &self.0 {
-            inner.tail_append(enc);
-        }
+    #[inline]
+    fn encode_sequence<B: OutBuf>(&self, enc: &mut Encoder<B>) {
+        encode_slice(&self.0, enc)
+    }
 
-        enc.pop_offset();
+    #[inline]
+    fn encode_sequence_prefix<B: OutBuf>(&self, n: usize, enc: &mut Encoder<B>) {
+        encode_slice(&self.0[..n.min(self.0.len())], enc)
     }
 
     #[inline]
@@ -399,7 +468,7 @@ impl<T> DynSeqToken<T> {
 }
 
 /// A Packed Sequence - `bytes` or `string`
-#[derive(Clone, PartialEq, Eq, Copy)]
+#[derive(Clone, Default, PartialEq, Eq, Copy)]
 pub struct PackedSeqToken<'a>(pub &'a [u8]);
 
 impl<'a> fmt::Debug for PackedSeqToken<'a> {
@@ -451,12 +520,12 @@ impl<'de: 'a, 'a> TokenType<'de> for PackedSeqToken<'a> {
     }
 
     #[inline]
-    fn head_append(&self, enc: &mut Encoder) {
+    fn head_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
         enc.append_indirection();
     }
 
     #[inline]
-    fn tail_append(&self, enc: &mut Encoder) {
+    fn tail_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
         enc.append_packed_seq(self.0);
     }
 }
@@ -529,7 +598,7 @@ macro_rules! tuple_impls {
                 0 $( + $ty.total_words() )+
             }
 
-            fn head_append(&self, enc: &mut Encoder) {
+            fn head_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
                 if Self::DYNAMIC {
                     enc.append_indirection();
                 } else {
@@ -540,7 +609,7 @@ macro_rules! tuple_impls {
                 }
             }
 
-            fn tail_append(&self, enc: &mut Encoder) {
+            fn tail_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
                 if Self::DYNAMIC {
                     let ($($ty,)+) = self;
                     let head_words = 0 $( + $ty.head_words() )+;
@@ -562,7 +631,7 @@ macro_rules! tuple_impls {
         impl<'de, $($ty: TokenType<'de>,)+> TokenSeq<'de> for ($($ty,)+) {
             const IS_TUPLE: bool = true;
 
-            fn encode_sequence(&self, enc: &mut Encoder) {
+            fn encode_sequence<B: OutBuf>(&self, enc: &mut Encoder<B>) {
                 let ($($ty,)+) = self;
                 let head_words = 0 $( + $ty.head_words() )+;
                 enc.push_offset(head_words as u32);
@@ -576,10 +645,45 @@ macro_rules! tuple_impls {
                 enc.pop_offset();
             }
 
+            #[allow(unused_assignments)]
+            fn encode_sequence_prefix<B: OutBuf>(&self, n: usize, enc: &mut Encoder<B>) {
+                let ($($ty,)+) = self;
+                let mut i = 0;
+                let mut head_words = 0;
+                $(
+                    if i < n {
+                        head_words += $ty.head_words();
+                    }
+                    i += 1;
+                )+
+                enc.push_offset(head_words as u32);
+                i = 0;
+                $(
+                    if i < n {
+                        $ty.head_append(enc);
+                        enc.bump_offset($ty.tail_words() as u32);
+                    }
+                    i += 1;
+                )+
+                i = 0;
+                $(
+                    if i < n {
+                        $ty.tail_append(enc);
+                    }
+                    i += 1;
+                )+
+                enc.pop_offset();
+            }
+
+            #[allow(unused_assignments)]
             fn decode_sequence(dec: &mut Decoder<'de>) -> Result<Self> {
-                Ok(($(
-                    <$ty as TokenType>::decode_from(dec)?,
-                )+))
+                let mut i = 0;
+                Ok(($({
+                    let token = <$ty as TokenType>::decode_from(dec)
+                        .map_err(|e| e.in_element(PathElement::Field(i)))?;
+                    i += 1;
+                    token
+                },)+))
             }
         }
     };
@@ -604,17 +708,20 @@ impl<'de> TokenType<'de> for () {
     }
 
     #[inline]
-    fn head_append(&self, _enc: &mut Encoder) {}
+    fn head_append<B: OutBuf>(&self, _enc: &mut Encoder<B>) {}
 
     #[inline]
-    fn tail_append(&self, _enc: &mut Encoder) {}
+    fn tail_append<B: OutBuf>(&self, _enc: &mut Encoder<B>) {}
 }
 
 impl<'de> TokenSeq<'de> for () {
     const IS_TUPLE: bool = true;
 
     #[inline]
-    fn encode_sequence(&self, _enc: &mut Encoder) {}
+    fn encode_sequence<B: OutBuf>(&self, _enc: &mut Encoder<B>) {}
+
+    #[inline]
+    fn encode_sequence_prefix<B: OutBuf>(&self, _n: usize, _enc: &mut Encoder<B>) {}
 
     #[inline]
     fn decode_sequence(_dec: &mut Decoder<'de>) -> Result<Self> {
@@ -624,6 +731,86 @@ impl<'de> TokenSeq<'de> for () {
 
 all_the_tuples!(tuple_impls);
 
+/// A struct whose token type is a [`RecursiveToken`].
+///
+/// This is implemented by the [`sol!`](crate::sol) macro for structs that
+/// contain themselves through a dynamic array. It is not public API.
+#[doc(hidden)]
+pub trait RecursiveStruct {
+    /// The tuple of the struct's field types.
+    type Fields: SolType;
+}
+
+/// The token of a recursive struct, e.g. `struct Node { Node[] children; }`.
+///
+/// The token of a struct is usually the token of the tuple of its fields. A
+/// recursive struct's tuple token would contain itself, so this type names the
+/// struct instead, and is encoded exactly like the tuple it wraps.
+pub struct RecursiveToken<'de, T: RecursiveStruct>(
+    /// The token of the struct's fields.
+    pub <T::Fields as SolType>::TokenType<'de>,
+);
+
+impl<'de, T: RecursiveStruct> fmt::Debug for RecursiveToken<'de, T>
+where
+    <T::Fields as SolType>::TokenType<'de>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RecursiveToken").field(&self.0).finish()
+    }
+}
+
+impl<'de, T: RecursiveStruct> TokenType<'de> for RecursiveToken<'de, T> {
+    const DYNAMIC: bool = <<T::Fields as SolType>::TokenType<'de> as TokenType<'de>>::DYNAMIC;
+
+    #[inline]
+    fn decode_from(dec: &mut Decoder<'de>) -> Result<Self> {
+        TokenType::decode_from(dec).map(Self)
+    }
+
+    #[inline]
+    fn head_words(&self) -> usize {
+        self.0.head_words()
+    }
+
+    #[inline]
+    fn tail_words(&self) -> usize {
+        self.0.tail_words()
+    }
+
+    #[inline]
+    fn head_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
+        self.0.head_append(enc)
+    }
+
+    #[inline]
+    fn tail_append<B: OutBuf>(&self, enc: &mut Encoder<B>) {
+        self.0.tail_append(enc)
+    }
+}
+
+impl<'de, T: RecursiveStruct> TokenSeq<'de> for RecursiveToken<'de, T>
+where
+    <T::Fields as SolType>::TokenType<'de>: TokenSeq<'de>,
+{
+    const IS_TUPLE: bool = <<T::Fields as SolType>::TokenType<'de> as TokenSeq<'de>>::IS_TUPLE;
+
+    #[inline]
+    fn encode_sequence<B: OutBuf>(&self, enc: &mut Encoder<B>) {
+        self.0.encode_sequence(enc)
+    }
+
+    #[inline]
+    fn encode_sequence_prefix<B: OutBuf>(&self, n: usize, enc: &mut Encoder<B>) {
+        self.0.encode_sequence_prefix(n, enc)
+    }
+
+    #[inline]
+    fn decode_sequence(dec: &mut Decoder<'de>) -> Result<Self> {
+        TokenSeq::decode_sequence(dec).map(Self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
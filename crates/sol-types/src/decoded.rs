@@ -0,0 +1,197 @@
+//! Decoding of whole transactions: the calldata, the return or revert data,
+//! and the logs.
+//!
+//! [`decode_transaction`] ties together the container enums generated by the
+//! [`sol!`](crate::sol) macro for a contract: the `<contract_name>Calls` enum
+//! decodes the calldata and the return data, the `<contract_name>Errors` enum
+//! decodes the revert data, and the `<contract_name>Events` enum decodes the
+//! logs.
+//!
+//! ```
+//! use alloy_sol_types::{
+//!     decoded::{self, DecodedResult, LogData},
+//!     sol, SolCall, SolEvent,
+//! };
+//! use alloy_primitives::{Address, U256};
+//! use core::convert::Infallible;
+//!
+//! sol! {
+//!     #[derive(Debug)]
+//!     interface IERC20 {
+//!         function transfer(address to, uint256 amount) returns (bool);
+//!         event Transfer(address indexed from, address indexed to, uint256 amount);
+//!     }
+//! }
+//! use IERC20::*;
+//!
+//! let call = transferCall { to: Address::repeat_byte(0x11), amount: U256::from(100) };
+//! let event = Transfer { from: Address::ZERO, to: call.to, amount: call.amount };
+//! let topics = event.encode_topics().into_iter().map(|t| t.0).collect();
+//! let log = LogData::new(topics, event.encode_data());
+//! let output = transferCall::abi_encode_returns(&(true,));
+//!
+//! let tx = decoded::decode_transaction::<IERC20Calls, Infallible, IERC20Events>(
+//!     &call.abi_encode(),
+//!     &[log],
+//!     Ok(&output),
+//! )
+//! .unwrap();
+//! assert!(matches!(tx.result, DecodedResult::Return(IERC20Returns::transfer(ref r)) if r._0));
+//! println!("{tx}");
+//! ```
+
+use crate::{ContractError, Result, SolCallInterface, SolEventInterface, SolInterface, Word};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The topics and data of a log.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LogData {
+    /// The topics of the log. For non-anonymous events, the first topic is
+    /// the event's signature hash.
+    pub topics: Vec<Word>,
+    /// The ABI-encoded non-indexed parameters of the log.
+    pub data: Vec<u8>,
+}
+
+impl LogData {
+    /// Creates a new log.
+    #[inline]
+    pub const fn new(topics: Vec<Word>, data: Vec<u8>) -> Self {
+        Self { topics, data }
+    }
+}
+
+/// The decoded outcome of a call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodedResult<R, E> {
+    /// The call succeeded with the given return values.
+    Return(R),
+    /// The call reverted with a known error.
+    Revert(ContractError<E>),
+    /// The call reverted with data that could not be decoded, which includes
+    /// empty revert data.
+    UnknownRevert(Vec<u8>),
+}
+
+/// A decoded transaction. Created with [`decode_transaction`].
+///
+/// The [`Display`](fmt::Display) implementation prints the call, its
+/// outcome and the logs, one per line. The alternate flag (`{:#}`)
+/// pretty-prints the decoded values.
+pub struct DecodedTransaction<C: SolCallInterface, E, L> {
+    /// The decoded call.
+    pub call: C,
+    /// The decoded return values or revert error.
+    pub result: DecodedResult<C::Returns, E>,
+    /// The decoded logs, in the same order as the input logs.
+    ///
+    /// Logs that were not emitted by the contract, like the `Transfer`
+    /// events of the tokens in a swap, fail to decode with
+    /// [`Error::UnknownEvent`](crate::Error::UnknownEvent).
+    pub logs: Vec<Result<L>>,
+}
+
+impl<C, E, L> fmt::Debug for DecodedTransaction<C, E, L>
+where
+    C: SolCallInterface + fmt::Debug,
+    C::Returns: fmt::Debug,
+    E: fmt::Debug,
+    L: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecodedTransaction")
+            .field("call", &self.call)
+            .field("result", &self.result)
+            .field("logs", &self.logs)
+            .finish()
+    }
+}
+
+impl<C, E, L> fmt::Display for DecodedTransaction<C, E, L>
+where
+    C: SolCallInterface + fmt::Debug,
+    C::Returns: fmt::Debug,
+    E: fmt::Debug,
+    L: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("call: ")?;
+        debug(f, &self.call)?;
+
+        f.write_str("\n")?;
+        match &self.result {
+            DecodedResult::Return(returns) => {
+                f.write_str("return: ")?;
+                debug(f, returns)?;
+            }
+            DecodedResult::Revert(ContractError::CustomError(error)) => {
+                f.write_str("error: ")?;
+                debug(f, error)?;
+            }
+            DecodedResult::Revert(ContractError::Revert(revert)) => revert.fmt(f)?,
+            DecodedResult::Revert(ContractError::Panic(panic)) => panic.fmt(f)?,
+            DecodedResult::UnknownRevert(data) => {
+                write!(f, "revert: unknown data {}", hex::encode_prefixed(data))?;
+            }
+        }
+
+        for (i, log) in self.logs.iter().enumerate() {
+            write!(f, "\nlog {i}: ")?;
+            match log {
+                Ok(log) => debug(f, log)?,
+                Err(e) => e.fmt(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn debug<T: fmt::Debug>(f: &mut fmt::Formatter<'_>, value: &T) -> fmt::Result {
+    if f.alternate() {
+        write!(f, "{value:#?}")
+    } else {
+        write!(f, "{value:?}")
+    }
+}
+
+/// Decodes a transaction to a contract, given its calldata, its logs, and its
+/// return data if it succeeded or its revert data if it reverted.
+///
+/// The type parameters are the `<contract_name>Calls`,
+/// `<contract_name>Errors` and `<contract_name>Events` enums generated by the
+/// [`sol!`](crate::sol) macro. Use [`Infallible`](core::convert::Infallible)
+/// for the errors or the events of contracts that do not declare any.
+///
+/// The data is decoded without validation, as transactions that were already
+/// executed are not necessarily ABI-compliant.
+///
+/// # Errors
+///
+/// Returns an error if the calldata or the return data can not be decoded.
+/// Revert data and logs that can not be decoded are instead reported in the
+/// returned [`DecodedTransaction`].
+pub fn decode_transaction<C, E, L>(
+    input: &[u8],
+    logs: &[LogData],
+    result: Result<&[u8], &[u8]>,
+) -> Result<DecodedTransaction<C, E, L>>
+where
+    C: SolCallInterface,
+    E: SolInterface,
+    L: SolEventInterface,
+{
+    let call = C::abi_decode(input, false)?;
+    let result = match result {
+        Ok(output) => DecodedResult::Return(call.abi_decode_returns(output, false)?),
+        Err(revert) => match ContractError::<E>::abi_decode(revert, false) {
+            Ok(error) => DecodedResult::Revert(error),
+            Err(_) => DecodedResult::UnknownRevert(revert.to_vec()),
+        },
+    };
+    let logs = logs
+        .iter()
+        .map(|log| L::decode_log(&log.topics, &log.data, false))
+        .collect();
+    Ok(DecodedTransaction { call, result, logs })
+}
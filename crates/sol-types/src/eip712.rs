@@ -1,6 +1,168 @@
-use crate::{abi::token::WordToken, sol_data, Encodable, SolType};
+use crate::{abi::token::WordToken, sol_data, Encodable, SolStruct, SolType};
 use alloc::{borrow::Cow, string::String, vec::Vec};
-use alloy_primitives::{keccak256, Address, FixedBytes, B256, U256};
+use alloy_primitives::{
+    keccak256, keccak256_const, keccak256_slices, Address, FixedBytes, B256, U256,
+};
+use core::{fmt, ops};
+
+/// The set of fields present in an [`Eip712Domain`].
+///
+/// The bit layout matches the `fields` bitmap returned by the [EIP-5267]
+/// `eip712Domain()` function, so the value returned by a contract can be
+/// compared directly against [`Eip712Domain::fields_present`].
+///
+/// [EIP-5267]: https://eips.ethereum.org/EIPS/eip-5267
+///
+/// ```
+/// use alloy_sol_types::{eip712_domain, DomainFields};
+///
+/// let domain = eip712_domain! {
+///     name: "MyCoolProtocol",
+///     chain_id: 1,
+/// };
+/// assert!(domain.matches_fields(DomainFields::NAME | DomainFields::CHAIN_ID));
+/// assert!(!domain.matches_fields(DomainFields::NAME));
+///
+/// // `bytes1 fields` as returned by `eip712Domain()`
+/// assert_eq!(DomainFields::from_bits(0x05), domain.fields_present());
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DomainFields(u8);
+
+impl fmt::Debug for DomainFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_set();
+        for (flag, name) in Self::NAMES {
+            if self.contains(flag) {
+                list.entry(&format_args!("{name}"));
+            }
+        }
+        list.finish()
+    }
+}
+
+impl DomainFields {
+    /// No fields.
+    pub const EMPTY: Self = Self(0);
+    /// The `name` field.
+    pub const NAME: Self = Self(1 << 0);
+    /// The `version` field.
+    pub const VERSION: Self = Self(1 << 1);
+    /// The `chainId` field.
+    pub const CHAIN_ID: Self = Self(1 << 2);
+    /// The `verifyingContract` field.
+    pub const VERIFYING_CONTRACT: Self = Self(1 << 3);
+    /// The `salt` field.
+    pub const SALT: Self = Self(1 << 4);
+    /// All fields.
+    pub const ALL: Self = Self(0x1f);
+
+    const NAMES: [(Self, &'static str); 5] = [
+        (Self::NAME, "name"),
+        (Self::VERSION, "version"),
+        (Self::CHAIN_ID, "chainId"),
+        (Self::VERIFYING_CONTRACT, "verifyingContract"),
+        (Self::SALT, "salt"),
+    ];
+
+    /// Creates a new set of fields from its raw bits, truncating any unknown
+    /// bits.
+    #[inline]
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits & Self::ALL.0)
+    }
+
+    /// Returns the raw bits of this set.
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if no fields are set.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all of the fields in `other` are also set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the union of the two sets.
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the intersection of the two sets.
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the fields set in `self` but not in `other`.
+    #[inline]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl From<FixedBytes<1>> for DomainFields {
+    #[inline]
+    fn from(value: FixedBytes<1>) -> Self {
+        Self::from_bits(value.0[0])
+    }
+}
+
+impl From<DomainFields> for FixedBytes<1> {
+    #[inline]
+    fn from(value: DomainFields) -> Self {
+        Self([value.0])
+    }
+}
+
+impl ops::BitOr for DomainFields {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl ops::BitOrAssign for DomainFields {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl ops::BitAnd for DomainFields {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
+impl ops::BitAndAssign for DomainFields {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl ops::Sub for DomainFields {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(rhs)
+    }
+}
 
 /// Eip712 Domain attributes used in determining the domain separator;
 /// Unused fields are left out of the struct type.
@@ -73,12 +235,128 @@ impl Eip712Domain {
         }
     }
 
+    /// Instantiate a new domain from `'static` strings, in a `const` context.
+    ///
+    /// The [`separator_const`](Self::separator_const) of the returned domain
+    /// can also be computed at compile time. Note that the domain must be
+    /// declared as a reference, as it can not be dropped in a `const` context.
+    ///
+    /// ```
+    /// use alloy_primitives::{address, B256, U256};
+    /// use alloy_sol_types::Eip712Domain;
+    ///
+    /// const DOMAIN: &Eip712Domain = &Eip712Domain::new_const(
+    ///     Some("MyCoolProtocol"),
+    ///     Some("1"),
+    ///     Some(U256::from_limbs([1, 0, 0, 0])),
+    ///     Some(address!("CcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC")),
+    ///     None,
+    /// );
+    /// const DOMAIN_SEPARATOR: B256 = DOMAIN.separator_const();
+    /// assert_eq!(DOMAIN_SEPARATOR, DOMAIN.separator());
+    /// ```
+    #[inline]
+    pub const fn new_const(
+        name: Option<&'static str>,
+        version: Option<&'static str>,
+        chain_id: Option<U256>,
+        verifying_contract: Option<Address>,
+        salt: Option<B256>,
+    ) -> Self {
+        const fn cow(s: Option<&'static str>) -> Option<Cow<'static, str>> {
+            match s {
+                Some(s) => Some(Cow::Borrowed(s)),
+                None => None,
+            }
+        }
+
+        Self::new(cow(name), cow(version), chain_id, verifying_contract, salt)
+    }
+
     /// Calculate the domain separator for the domain object.
     #[inline]
     pub fn separator(&self) -> B256 {
         self.hash_struct()
     }
 
+    /// Calculate the domain separator for the domain object, in a `const`
+    /// context.
+    ///
+    /// This is much slower than [`separator`](Self::separator), and should
+    /// only be used to compute the separator of a domain that is known at
+    /// compile time, such as one created with
+    /// [`new_const`](Self::new_const).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name or the version is an owned string.
+    pub const fn separator_const(&self) -> B256 {
+        // large enough for both the longest type (95 bytes) and data (192 bytes)
+        const fn push(mut buf: [u8; 224], mut len: usize, bytes: &[u8]) -> ([u8; 224], usize) {
+            let mut i = 0;
+            while i < bytes.len() {
+                buf[len] = bytes[i];
+                len += 1;
+                i += 1;
+            }
+            (buf, len)
+        }
+
+        const fn str_bytes(s: &Option<Cow<'static, str>>) -> Option<&'static [u8]> {
+            match s {
+                Some(Cow::Borrowed(s)) => Some(s.as_bytes()),
+                Some(Cow::Owned(_)) => panic!("`separator_const` requires borrowed strings"),
+                None => None,
+            }
+        }
+
+        let name = str_bytes(&self.name);
+        let version = str_bytes(&self.version);
+
+        // `encodeType`, with the trailing comma replaced by the closing parenthesis
+        let (mut ty, mut ty_len) = push([0; 224], 0, b"EIP712Domain(");
+        if name.is_some() {
+            (ty, ty_len) = push(ty, ty_len, b"string name,");
+        }
+        if version.is_some() {
+            (ty, ty_len) = push(ty, ty_len, b"string version,");
+        }
+        if self.chain_id.is_some() {
+            (ty, ty_len) = push(ty, ty_len, b"uint256 chainId,");
+        }
+        if self.verifying_contract.is_some() {
+            (ty, ty_len) = push(ty, ty_len, b"address verifyingContract,");
+        }
+        if self.salt.is_some() {
+            (ty, ty_len) = push(ty, ty_len, b"bytes32 salt,");
+        }
+        if ty[ty_len - 1] == b',' {
+            ty_len -= 1;
+        }
+        (ty, ty_len) = push(ty, ty_len, b")");
+
+        // `typeHash ++ encodeData`
+        let type_hash = keccak256_const(prefix(&ty, ty_len));
+        let (mut data, mut data_len) = push([0; 224], 0, &type_hash.0);
+        if let Some(name) = name {
+            (data, data_len) = push(data, data_len, &keccak256_const(name).0);
+        }
+        if let Some(version) = version {
+            (data, data_len) = push(data, data_len, &keccak256_const(version).0);
+        }
+        if let Some(chain_id) = &self.chain_id {
+            (data, data_len) = push(data, data_len, &chain_id.to_be_bytes::<32>());
+        }
+        if let Some(verifying_contract) = &self.verifying_contract {
+            (data, data_len) = push(data, data_len, &[0; 12]);
+            (data, data_len) = push(data, data_len, &verifying_contract.0 .0);
+        }
+        if let Some(salt) = &self.salt {
+            (data, data_len) = push(data, data_len, &salt.0);
+        }
+        keccak256_const(prefix(&data, data_len))
+    }
+
     /// EIP-712 `encodeType`:
     /// <https://eips.ethereum.org/EIPS/eip-712#definition-of-encodetype>
     pub fn encode_type(&self) -> String {
@@ -129,6 +407,31 @@ impl Eip712Domain {
             + self.salt.is_some() as usize
     }
 
+    /// Returns the set of fields that are present in this domain.
+    #[inline]
+    pub const fn fields_present(&self) -> DomainFields {
+        DomainFields(
+            self.name.is_some() as u8
+                | (self.version.is_some() as u8) << 1
+                | (self.chain_id.is_some() as u8) << 2
+                | (self.verifying_contract.is_some() as u8) << 3
+                | (self.salt.is_some() as u8) << 4,
+        )
+    }
+
+    /// Returns `true` if this domain contains exactly the `expected` fields.
+    ///
+    /// Some contracts reject signatures whose domain separator includes
+    /// fields that the contract itself does not use, so this can be used to
+    /// check a domain against the `fields` returned by the contract's
+    /// [EIP-5267] `eip712Domain()` function.
+    ///
+    /// [EIP-5267]: https://eips.ethereum.org/EIPS/eip-5267
+    #[inline]
+    pub const fn matches_fields(&self, expected: DomainFields) -> bool {
+        self.fields_present().0 == expected.0
+    }
+
     /// Returns the number of bytes that will be used to encode the domain.
     #[inline]
     pub const fn abi_encoded_size(&self) -> usize {
@@ -182,6 +485,29 @@ impl Eip712Domain {
         type_hash.extend(self.encode_data());
         keccak256(type_hash)
     }
+
+    /// Computes the EIP-712 signing hashes of multiple structs in this domain.
+    ///
+    /// This is equivalent to calling [`SolStruct::eip712_signing_hash`] on
+    /// each item, but only computes the domain separator once, which is
+    /// faster when signing many structs.
+    pub fn signing_hashes<T: SolStruct>(&self, items: &[T]) -> Vec<B256> {
+        let domain_separator = self.separator();
+        items
+            .iter()
+            .map(|item| {
+                let struct_hash = item.eip712_hash_struct();
+                keccak256_slices(&[&[0x19, 0x01], &domain_separator[..], &struct_hash[..]])
+            })
+            .collect()
+    }
+}
+
+/// `&bytes[..len]`, in a `const` context.
+const fn prefix(bytes: &[u8], len: usize) -> &[u8] {
+    assert!(len <= bytes.len());
+    // SAFETY: `len` is in bounds.
+    unsafe { core::slice::from_raw_parts(bytes.as_ptr(), len) }
 }
 
 /// Convenience macro to instantiate an EIP-712 domain.
@@ -340,4 +666,74 @@ mod tests {
             version: my_cow.into_owned(),
         };
     }
+
+    #[test]
+    fn fields_present() {
+        let domain = eip712_domain! {
+            name: "abcd",
+            chain_id: 1,
+            verifying_contract: Address::ZERO,
+        };
+        let expected =
+            DomainFields::NAME | DomainFields::CHAIN_ID | DomainFields::VERIFYING_CONTRACT;
+        assert_eq!(domain.fields_present(), expected);
+        assert_eq!(domain.fields_present().bits(), 0b01101);
+        assert!(domain.matches_fields(expected));
+        assert!(!domain.matches_fields(expected | DomainFields::VERSION));
+        assert!(!domain.matches_fields(expected - DomainFields::CHAIN_ID));
+        assert_eq!(DomainFields::from(FixedBytes([0x0d])), expected);
+        assert_eq!(
+            format!("{expected:?}"),
+            r#"{name, chainId, verifyingContract}"#
+        );
+
+        assert_eq!(
+            Eip712Domain::default().fields_present(),
+            DomainFields::EMPTY
+        );
+        for fields in 0..32 {
+            let domain = Eip712Domain::new_const(
+                (fields & 1 != 0).then_some("abcd"),
+                (fields & 2 != 0).then_some(""),
+                (fields & 4 != 0).then_some(U256::MAX),
+                (fields & 8 != 0).then_some(Address::repeat_byte(0x11)),
+                (fields & 16 != 0).then_some(B256::repeat_byte(0x22)),
+            );
+            assert_eq!(domain.fields_present().bits(), fields);
+            assert_eq!(domain.num_words(), fields.count_ones() as usize);
+        }
+    }
+
+    #[test]
+    fn const_separator() {
+        // the domain of the `Mail` example in EIP-712
+        const DOMAIN: &Eip712Domain = &Eip712Domain::new_const(
+            Some("Ether Mail"),
+            Some("1"),
+            Some(U256::from_limbs([1, 0, 0, 0])),
+            Some(alloy_primitives::address!(
+                "CcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+            )),
+            None,
+        );
+        const SEPARATOR: B256 = DOMAIN.separator_const();
+        assert_eq!(
+            SEPARATOR,
+            alloy_primitives::b256!(
+                "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+            )
+        );
+        assert_eq!(SEPARATOR, DOMAIN.separator());
+
+        for fields in 0..32 {
+            let domain = Eip712Domain::new_const(
+                (fields & 1 != 0).then_some("abcd"),
+                (fields & 2 != 0).then_some(""),
+                (fields & 4 != 0).then_some(U256::MAX),
+                (fields & 8 != 0).then_some(Address::repeat_byte(0x11)),
+                (fields & 16 != 0).then_some(B256::repeat_byte(0x22)),
+            );
+            assert_eq!(domain.separator_const(), domain.separator(), "{fields:05b}");
+        }
+    }
 }
@@ -8,7 +8,7 @@
 // except according to those terms.
 
 use crate::abi;
-use alloc::{borrow::Cow, string::String};
+use alloc::{borrow::Cow, boxed::Box, string::String, vec, vec::Vec};
 use core::fmt;
 
 /// ABI result type.
@@ -16,6 +16,7 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 
 /// ABI Encoding and Decoding errors.
 #[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
     /// A typecheck detected a word that does not match the data type.
     TypeCheckFail {
@@ -31,6 +32,23 @@ pub enum Error {
     /// Validation reserialization did not match input.
     ReserMismatch,
 
+    /// The data is not encoded canonically, such as a word with non-zero
+    /// padding bytes.
+    ///
+    /// See [`DecodeOptions::canonical`](crate::abi::DecodeOptions::canonical).
+    NonCanonical {
+        /// The offset of the first non-canonical byte in the data.
+        offset: usize,
+    },
+
+    /// The data contains fewer elements than the fixed-size array type.
+    FixedArrayLengthMismatch {
+        /// The length of the array type.
+        expected: usize,
+        /// The number of elements available in the data.
+        got: usize,
+    },
+
     /// Invalid enum value.
     InvalidEnumValue {
         /// The name of the enum.
@@ -49,6 +67,55 @@ pub enum Error {
         selector: alloy_primitives::FixedBytes<4>,
     },
 
+    /// Unknown event signature.
+    UnknownEvent {
+        /// The type name.
+        name: &'static str,
+        /// The first topic of the log, if any.
+        signature: Option<alloy_primitives::B256>,
+    },
+
+    /// A required field was not set when building a value.
+    ///
+    /// See the `builder` attribute of [`sol!`](crate::sol!).
+    MissingField {
+        /// The type name.
+        name: &'static str,
+        /// The name of the field that was not set.
+        field: &'static str,
+    },
+
+    /// An error in an element of a tuple or array.
+    ///
+    /// Decode errors in nested values are wrapped in this variant, so matching
+    /// on the variant that caused them, like [`Error::Overrun`], needs to go
+    /// through [`Error::root_cause`].
+    ///
+    /// Only [`SolType`](crate::SolType) decoding records element paths.
+    /// `alloy-dyn-abi` decodes into type-erased tokens, which do not tell
+    /// tuples and fixed-size arrays apart, so its errors are never wrapped.
+    Element {
+        /// The path from the outermost value to the element.
+        path: Vec<PathElement>,
+        /// The error in the element.
+        error: Box<Error>,
+    },
+
+    /// Decoding failed, but the data decodes with a different method.
+    ///
+    /// This is most often a single dynamic value, like `(bytes,)`, that was
+    /// encoded with [`abi_encode_params`](crate::SolType::abi_encode_params)
+    /// and decoded with [`abi_decode`](crate::SolType::abi_decode), or vice
+    /// versa. See the [`SolType`](crate::SolType#encoding-and-decoding) docs.
+    WrongDecodeMethod {
+        /// The method the data appears to have been encoded with.
+        encoded_with: &'static str,
+        /// The method that decodes the data.
+        decode_with: &'static str,
+        /// The original error.
+        error: Box<Error>,
+    },
+
     /// Hex error.
     FromHexError(hex::FromHexError),
 
@@ -60,6 +127,7 @@ pub enum Error {
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
+            Self::Element { error, .. } | Self::WrongDecodeMethod { error, .. } => Some(&**error),
             Self::FromHexError(e) => Some(e),
             _ => None,
         }
@@ -78,6 +146,13 @@ impl fmt::Display for Error {
             ),
             Self::Overrun => f.write_str("Buffer overrun while deserializing"),
             Self::ReserMismatch => f.write_str("Reserialization did not match original"),
+            Self::NonCanonical { offset } => {
+                write!(f, "Non-canonical encoding at byte offset {offset}")
+            }
+            Self::FixedArrayLengthMismatch { expected, got } => write!(
+                f,
+                "Fixed array length mismatch: expected {expected} elements, got {got}"
+            ),
             Self::InvalidEnumValue { name, value, max } => write!(
                 f,
                 "`{value}` is not a valid {name} enum value (max: `{max}`)"
@@ -85,12 +160,62 @@ impl fmt::Display for Error {
             Self::UnknownSelector { name, selector } => {
                 write!(f, "Unknown selector `{selector}` for {name}")
             }
+            Self::UnknownEvent {
+                name,
+                signature: Some(signature),
+            } => write!(f, "Unknown event signature `{signature}` for {name}"),
+            Self::UnknownEvent {
+                name,
+                signature: None,
+            } => write!(f, "Missing event signature for {name}"),
+            Self::MissingField { name, field } => {
+                write!(f, "Missing required field `{field}` for {name}")
+            }
+            Self::Element { path, error } => {
+                let root = match path.first() {
+                    Some(PathElement::Index(_)) => "array",
+                    _ => "tuple",
+                };
+                f.write_str(root)?;
+                for element in path {
+                    element.fmt(f)?;
+                }
+                write!(f, ": {error}")
+            }
+            Self::WrongDecodeMethod {
+                encoded_with,
+                decode_with,
+                error,
+            } => write!(
+                f,
+                "{error}; the data appears to be encoded with `{encoded_with}`, \
+                 use `{decode_with}` instead"
+            ),
             Self::FromHexError(e) => e.fmt(f),
             Self::Other(e) => f.write_str(e),
         }
     }
 }
 
+/// A step in the path to an element of a tuple or array. See
+/// [`Error::Element`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathElement {
+    /// A tuple or struct field, by position.
+    Field(usize),
+    /// An array element.
+    Index(usize),
+}
+
+impl fmt::Display for PathElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Field(i) => write!(f, ".{i}"),
+            Self::Index(i) => write!(f, "[{i}]"),
+        }
+    }
+}
+
 impl Error {
     /// Instantiates a new error with a static str.
     #[cold]
@@ -126,6 +251,38 @@ impl Error {
         }
     }
 
+    /// Prepends `element` to the path of this error, wrapping it in an
+    /// [`Error::Element`] if it is not one already.
+    #[cold]
+    pub fn in_element(self, element: PathElement) -> Self {
+        match self {
+            Self::Element { mut path, error } => {
+                path.insert(0, element);
+                Self::Element { path, error }
+            }
+            error => Self::Element {
+                path: vec![element],
+                error: Box::new(error),
+            },
+        }
+    }
+
+    /// Returns the error that caused this one, without its
+    /// [element path](Error::Element) or
+    /// [decode method hint](Error::WrongDecodeMethod).
+    ///
+    /// Match on this instead of on `self` to handle a specific kind of
+    /// decode error, wherever in the value it occurred.
+    #[inline]
+    pub fn root_cause(&self) -> &Self {
+        match self {
+            Self::Element { error, .. } | Self::WrongDecodeMethod { error, .. } => {
+                error.root_cause()
+            }
+            error => error,
+        }
+    }
+
     /// Instantiates a new [`Error::UnknownSelector`] with the provided data.
     #[cold]
     pub fn unknown_selector(name: &'static str, selector: [u8; 4]) -> Self {
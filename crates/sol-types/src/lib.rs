@@ -139,7 +139,18 @@
 //! recommend users use them wherever possible. We do not recommend that users
 //! interact with Tokens, except when implementing their own [`SolType`].
 //!
+//! ## Runtime types
+//!
+//! [`SolType`]s describe types known at compile time. To encode or decode
+//! types that are only known at runtime, such as those parsed from a JSON ABI
+//! or from a string like `"uint256[2][]"`, use `DynSolType` and `DynSolValue`
+//! from the [`alloy-dyn-abi`] crate, which implement the same ABI coding on
+//! top of this crate: `DynSolType` parses the Solidity type grammar with
+//! `FromStr`, and `DynSolType::abi_decode` decodes into a `DynSolValue`. This
+//! crate has no runtime type model of its own.
+//!
 //! [`TokenType`]: abi::TokenType
+//! [`alloy-dyn-abi`]: https://docs.rs/alloy-dyn-abi
 
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/alloy-rs/core/main/assets/alloy.jpg",
@@ -169,33 +180,44 @@ mod macros;
 
 pub mod abi;
 
+pub mod decoded;
+
 mod errors;
-pub use errors::{Error, Result};
+pub use errors::{Error, PathElement, Result};
 
 mod impl_core;
 
 mod types;
+#[cfg(feature = "serde")]
+pub use types::SolSerde;
 pub use types::{
-    data_type as sol_data, decode_revert_reason, ContractError, Encodable, EventTopic,
-    GenericContractError, Panic, PanicKind, Revert, Selectors, SolCall, SolEnum, SolError,
-    SolEvent, SolInterface, SolStruct, SolType, TopicList,
+    data_type as sol_data, decode_revert_reason, CallError, ContractError, Encodable, EventTopic,
+    GenericContractError, Panic, PanicKind, Pretty, Revert, Selectors, SolCall, SolCallInterface,
+    SolConstructor, SolEnum, SolError, SolEvent, SolEventInterface, SolInterface, SolStruct,
+    SolType, TopicList,
 };
 
 pub mod utils;
 
 mod eip712;
-pub use eip712::Eip712Domain;
+pub use eip712::{DomainFields, Eip712Domain};
 
 /// The ABI word type.
 pub type Word = alloy_primitives::B256;
 
 #[doc(no_inline)]
-pub use alloy_sol_macro::sol;
+pub use alloy_sol_macro::{sol, SolValue};
 
 // Not public API.
 #[doc(hidden)]
 pub mod private {
-    pub use super::utils::{just_ok, next_multiple_of_32, words_for, words_for_len};
+    pub use super::{
+        abi::token::RecursiveStruct,
+        utils::{
+            just_ok, next_multiple_of_32, selector_index, selector_index_sorted, words_for,
+            words_for_len,
+        },
+    };
     pub use alloc::{
         borrow::{Borrow, Cow, ToOwned},
         string::{String, ToString},
@@ -209,6 +231,17 @@ pub mod private {
     pub use Option::{None, Some};
     pub use Result::{Err, Ok};
 
+    #[cfg(feature = "tracing")]
+    pub use tracing;
+
+    #[cfg(feature = "sol-literal")]
+    pub use crate::types::literal::struct_literal;
+
+    #[cfg(feature = "serde")]
+    pub use crate::types::serde::{SerdeRef, SerdeValue};
+    #[cfg(feature = "serde")]
+    pub use serde;
+
     #[inline(always)]
     pub const fn u256(n: u64) -> U256 {
         U256::from_limbs([n, 0, 0, 0])
@@ -55,3 +55,52 @@ macro_rules! all_the_tuples {
         $name!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15, T16, T17, T18, T19, T20, T21, T22, T23, T24);
     };
 }
+
+/// Enters a [`tracing`] span at an ABI encoding or decoding entry point.
+///
+/// The span is exited when the enclosing scope ends. This expands to nothing
+/// when the `tracing` feature is disabled, so the fields are never evaluated.
+///
+/// [`tracing`]: https://docs.rs/tracing
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "tracing")]
+macro_rules! abi_trace_span {
+    ($name:literal $(, $($fields:tt)*)?) => {
+        let _span = $crate::private::tracing::debug_span!($name $(, $($fields)*)?).entered();
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "tracing"))]
+macro_rules! abi_trace_span {
+    ($($t:tt)*) => {};
+}
+
+/// Emits a debug event if the given [`Result`](crate::Result) is an error,
+/// and returns it unchanged.
+///
+/// This expands to the expression itself when the `tracing` feature is
+/// disabled.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "tracing")]
+macro_rules! abi_trace_result {
+    ($res:expr) => {{
+        let res = $res;
+        if let $crate::private::Err(error) = &res {
+            $crate::private::tracing::debug!(?error, "ABI coding failed");
+        }
+        res
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "tracing"))]
+macro_rules! abi_trace_result {
+    ($res:expr) => {
+        $res
+    };
+}
@@ -6,14 +6,23 @@
 
 #![allow(missing_copy_implementations, missing_debug_implementations)]
 
-use crate::{abi::token::*, utils, Encodable, SolType, Word};
+use crate::{
+    abi::{token::*, Decoder, Encoder},
+    utils, Encodable, SolType, Word,
+};
 use alloc::{borrow::Cow, string::String as RustString, vec::Vec};
 use alloy_primitives::{
-    keccak256, Address as RustAddress, FixedBytes as RustFixedBytes, Function as RustFunction,
-    I256, U256,
+    keccak256, keccak256_slices, Address as RustAddress, FixedBytes as RustFixedBytes,
+    Function as RustFunction, I256, U256,
 };
 use core::{borrow::Borrow, fmt::*, hash::Hash, marker::PhantomData, ops::*};
 
+#[cfg(feature = "sol-literal")]
+use super::literal;
+use super::pretty::{self, Pretty};
+#[cfg(feature = "sol-literal")]
+use alloc::string::ToString;
+
 // IMPORTANT: Keep in sync with `rec_expand_rust_type` in
 // `sol-macro/src/expand/ty.rs`
 
@@ -38,7 +47,7 @@ impl SolType for Bool {
 
     #[inline]
     fn valid_token(token: &Self::TokenType<'_>) -> bool {
-        utils::check_zeroes(&token.0[..31])
+        utils::check_zeroes(&token.0[..31]) && token.0[31] <= 1
     }
 
     #[inline]
@@ -46,11 +55,27 @@ impl SolType for Bool {
         token.0 != Word::ZERO
     }
 
+    #[cfg(feature = "sol-literal")]
+    #[inline]
+    fn to_sol_literal(rust: &Self::RustType) -> RustString {
+        RustString::from(if *rust { "true" } else { "false" })
+    }
+
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{rust}")
+    }
+
     #[inline]
     fn eip712_data_word(rust: &Self::RustType) -> Word {
         Encodable::<Self>::to_tokens(rust).0
     }
 
+    #[inline]
+    fn packed_encoded_size(_rust: &Self::RustType) -> usize {
+        1
+    }
+
     #[inline]
     fn abi_encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         out.push(*rust as u8);
@@ -103,11 +128,27 @@ where
         IntBitCount::<BITS>::detokenize_int(token)
     }
 
+    #[cfg(feature = "sol-literal")]
+    #[inline]
+    fn to_sol_literal(rust: &Self::RustType) -> RustString {
+        rust.to_string()
+    }
+
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{rust}")
+    }
+
     #[inline]
     fn eip712_data_word(rust: &Self::RustType) -> Word {
         Encodable::<Self>::to_tokens(rust).0
     }
 
+    #[inline]
+    fn packed_encoded_size(_rust: &Self::RustType) -> usize {
+        IntBitCount::<BITS>::BYTES
+    }
+
     #[inline]
     fn abi_encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         IntBitCount::<BITS>::encode_packed_to_int(*rust, out);
@@ -150,17 +191,78 @@ where
         IntBitCount::<BITS>::detokenize_uint(token)
     }
 
+    #[cfg(feature = "sol-literal")]
+    #[inline]
+    fn to_sol_literal(rust: &Self::RustType) -> RustString {
+        rust.to_string()
+    }
+
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{rust}")
+    }
+
     #[inline]
     fn eip712_data_word(rust: &Self::RustType) -> Word {
         Encodable::<Self>::to_tokens(rust).0
     }
 
+    #[inline]
+    fn packed_encoded_size(_rust: &Self::RustType) -> usize {
+        IntBitCount::<BITS>::BYTES
+    }
+
     #[inline]
     fn abi_encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         IntBitCount::<BITS>::encode_packed_to_uint(*rust, out);
     }
 }
 
+impl<const BITS: usize> Uint<BITS>
+where
+    IntBitCount<BITS>: SupportedInt,
+{
+    /// ABI-decodes a single `uintN` value, accepting fewer than 32 bytes of
+    /// data by zero-extending it on the left.
+    ///
+    /// **This is not standard ABI decoding**, and should only be used to
+    /// recover values from non-compliant contracts, such as ones that emit a
+    /// truncated final word in their event data. To decode such a field, slice
+    /// the data at the field's offset and pass the rest to this function.
+    ///
+    /// If `data` is at least 32 bytes long, this is the same as the strict
+    /// [`abi_decode`](SolType::abi_decode). Empty data is still rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::U256;
+    /// use alloy_sol_types::{sol_data, SolType};
+    ///
+    /// let data = [0x01, 0x00];
+    /// assert!(sol_data::Uint::<256>::abi_decode(&data, true).is_err());
+    /// assert_eq!(
+    ///     sol_data::Uint::<256>::abi_decode_lenient(&data, true).unwrap(),
+    ///     U256::from(0x0100)
+    /// );
+    /// ```
+    #[inline]
+    pub fn abi_decode_lenient(
+        data: &[u8],
+        validate: bool,
+    ) -> crate::Result<<Self as SolType>::RustType> {
+        if data.is_empty() {
+            return Err(crate::Error::Overrun)
+        }
+        if data.len() >= 32 {
+            return Self::abi_decode(data, validate)
+        }
+        let mut word = Word::ZERO;
+        word[32 - data.len()..].copy_from_slice(data);
+        Self::abi_decode(word.as_slice(), validate)
+    }
+}
+
 /// Address - `address`
 pub struct Address;
 
@@ -185,9 +287,36 @@ impl SolType for Address {
         RustAddress::from_word(token.0)
     }
 
+    #[cfg(feature = "sol-literal")]
+    #[inline]
+    fn to_sol_literal(rust: &Self::RustType) -> RustString {
+        rust.to_checksum(None)
+    }
+
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        // not `Display`, which abbreviates with the alternate flag
+        f.write_str(&rust.to_checksum(None))
+    }
+
+    /// Copies the low 20 bytes of each word directly into the output,
+    /// without going through [`detokenize`](SolType::detokenize) element by
+    /// element. Validation of the upper 12 bytes happens separately, in
+    /// [`valid_token`](SolType::valid_token).
+    #[inline]
+    fn detokenize_array(tokens: Vec<Self::TokenType<'_>>) -> Vec<Self::RustType> {
+        let mut out = Vec::with_capacity(tokens.len());
+        out.extend(
+            tokens
+                .iter()
+                .map(|token| RustAddress::from_slice(&token.0[12..])),
+        );
+        out
+    }
+
     #[inline]
     fn valid_token(token: &Self::TokenType<'_>) -> bool {
-        utils::check_zeroes(&token.0[..12])
+        RustAddress::checked_from_word(token.0).is_some()
     }
 
     #[inline]
@@ -195,6 +324,11 @@ impl SolType for Address {
         rust.into_word()
     }
 
+    #[inline]
+    fn packed_encoded_size(_rust: &Self::RustType) -> usize {
+        20
+    }
+
     #[inline]
     fn abi_encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         out.extend_from_slice(rust.as_slice());
@@ -202,6 +336,9 @@ impl SolType for Address {
 }
 
 /// Function - `function`
+///
+/// An external function pointer: the 20-byte address followed by the 4-byte
+/// selector, left-aligned in a word like `bytes24`.
 pub struct Function;
 
 impl<T: Borrow<[u8; 24]>> Encodable<Function> for T {
@@ -225,6 +362,17 @@ impl SolType for Function {
         RustFunction::from_word(token.0)
     }
 
+    #[cfg(feature = "sol-literal")]
+    #[inline]
+    fn to_sol_literal(rust: &Self::RustType) -> RustString {
+        literal::hex_string(rust.as_slice())
+    }
+
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        pretty::hex(rust.as_slice(), f)
+    }
+
     #[inline]
     fn valid_token(token: &Self::TokenType<'_>) -> bool {
         utils::check_zeroes(&token.0[24..])
@@ -235,6 +383,11 @@ impl SolType for Function {
         rust.into_word()
     }
 
+    #[inline]
+    fn packed_encoded_size(_rust: &Self::RustType) -> usize {
+        24
+    }
+
     #[inline]
     fn abi_encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         out.extend_from_slice(rust.as_slice());
@@ -277,9 +430,25 @@ impl SolType for Bytes {
         token.into_vec()
     }
 
+    #[cfg(feature = "sol-literal")]
+    #[inline]
+    fn to_sol_literal(rust: &Self::RustType) -> RustString {
+        literal::hex_string(rust)
+    }
+
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        pretty::hex(rust, f)
+    }
+
     #[inline]
     fn eip712_data_word(rust: &Self::RustType) -> Word {
-        keccak256(Self::abi_encode_packed(rust))
+        keccak256(rust)
+    }
+
+    #[inline]
+    fn packed_encoded_size(rust: &Self::RustType) -> usize {
+        rust.len()
     }
 
     #[inline]
@@ -288,6 +457,134 @@ impl SolType for Bytes {
     }
 }
 
+impl Bytes {
+    /// ABI-decodes a single `bytes` value, borrowing it from `data` instead of
+    /// copying it into a [`Vec`].
+    ///
+    /// To decode a sequence of values, such as event data, without copying,
+    /// decode into [`PackedSeqToken`]s with
+    /// [`abi::decode_sequence`](crate::abi::decode_sequence) instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::U256;
+    /// use alloy_sol_types::{
+    ///     abi::{
+    ///         self,
+    ///         token::{PackedSeqToken, WordToken},
+    ///     },
+    ///     sol_data, SolType,
+    /// };
+    ///
+    /// let encoded = sol_data::Bytes::abi_encode(b"hello");
+    /// let decoded: &[u8] = sol_data::Bytes::abi_decode_borrowed(&encoded, true).unwrap();
+    /// assert_eq!(decoded, b"hello");
+    /// assert_eq!(decoded.as_ptr(), encoded[64..].as_ptr());
+    ///
+    /// type Data = (sol_data::Bytes, sol_data::Uint<256>);
+    /// let encoded = Data::abi_encode_sequence(&(b"hello".to_vec(), U256::from(42)));
+    /// let (data, value): (PackedSeqToken<'_>, WordToken) =
+    ///     abi::decode_sequence(&encoded, true).unwrap();
+    /// assert_eq!(data.0, b"hello");
+    /// assert_eq!(value.0[31], 42);
+    /// ```
+    #[inline]
+    pub fn abi_decode_borrowed(data: &[u8], validate: bool) -> crate::Result<&[u8]> {
+        crate::abi::decode::<PackedSeqToken<'_>>(data, validate).map(|token| token.0)
+    }
+}
+
+/// `bytes`, with [`alloy_primitives::Bytes`] as its Rust type instead of
+/// [`Vec<u8>`].
+///
+/// Used by `#[derive(SolValue)]` for `Bytes` fields.
+#[doc(hidden)]
+pub struct PrimitiveBytes;
+
+impl<T: ?Sized + AsRef<[u8]>> Encodable<PrimitiveBytes> for T {
+    #[inline]
+    fn to_tokens(&self) -> PackedSeqToken<'_> {
+        PackedSeqToken(self.as_ref())
+    }
+}
+
+impl SolType for PrimitiveBytes {
+    type RustType = alloy_primitives::Bytes;
+    type TokenType<'a> = PackedSeqToken<'a>;
+
+    const ENCODED_SIZE: Option<usize> = None;
+
+    #[inline]
+    fn sol_type_name() -> Cow<'static, str> {
+        <Bytes as SolType>::sol_type_name()
+    }
+
+    #[inline]
+    fn abi_encoded_size(rust: &Self::RustType) -> usize {
+        32 + utils::padded_len(rust)
+    }
+
+    #[inline]
+    fn valid_token(_token: &Self::TokenType<'_>) -> bool {
+        true
+    }
+
+    #[inline]
+    fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
+        token.into_vec().into()
+    }
+
+    #[cfg(feature = "sol-literal")]
+    #[inline]
+    fn to_sol_literal(rust: &Self::RustType) -> RustString {
+        literal::hex_string(rust)
+    }
+
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        pretty::hex(rust, f)
+    }
+
+    #[inline]
+    fn eip712_data_word(rust: &Self::RustType) -> Word {
+        keccak256(rust)
+    }
+
+    #[inline]
+    fn packed_encoded_size(rust: &Self::RustType) -> usize {
+        rust.len()
+    }
+
+    #[inline]
+    fn abi_encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
+        out.extend_from_slice(rust);
+    }
+}
+
+impl String {
+    /// ABI-decodes a single `string` value, borrowing it from `data` instead
+    /// of copying it into a [`String`](RustString).
+    ///
+    /// Unlike [`abi_decode`](SolType::abi_decode), which replaces invalid
+    /// UTF-8 when not validating, this always fails on invalid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_sol_types::{sol_data, SolType};
+    ///
+    /// let encoded = sol_data::String::abi_encode(&"hello");
+    /// let decoded: &str = sol_data::String::abi_decode_borrowed(&encoded, true).unwrap();
+    /// assert_eq!(decoded, "hello");
+    /// ```
+    #[inline]
+    pub fn abi_decode_borrowed(data: &[u8], validate: bool) -> crate::Result<&str> {
+        let bytes = Bytes::abi_decode_borrowed(data, validate)?;
+        core::str::from_utf8(bytes).map_err(|_| crate::Error::type_check_fail(bytes, "string"))
+    }
+}
+
 /// Array - `T[]`
 pub struct Array<T: SolType>(PhantomData<T>);
 
@@ -338,7 +635,14 @@ impl<T: SolType> SolType for Array<T> {
 
     #[inline]
     fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
-        token.0.into_iter().map(T::detokenize).collect()
+        T::detokenize_array(token.0)
+    }
+
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        f.debug_list()
+            .entries(rust.iter().map(Pretty::<T>::new))
+            .finish()
     }
 
     #[inline]
@@ -350,11 +654,84 @@ impl<T: SolType> SolType for Array<T> {
         keccak256(encoded)
     }
 
+    #[inline]
+    fn packed_encoded_size(rust: &Self::RustType) -> usize {
+        packed_array_size::<T>(rust)
+    }
+
     #[inline]
     fn abi_encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
-        for item in rust {
-            T::abi_encode_packed_to(item, out);
+        encode_packed_array_to::<T>(rust, out);
+    }
+}
+
+impl<T: SolType> Array<T> {
+    /// ABI-decodes an array, decoding and validating each element
+    /// independently.
+    ///
+    /// Returns the successfully decoded elements, and the index and error of
+    /// each element that failed to decode, so that a batch with some malformed
+    /// elements can still be processed.
+    ///
+    /// This only works when the element boundaries can be recovered from the
+    /// array's head: the array offset and length must be valid, and the data
+    /// must be long enough for the heads of all elements. Static elements are
+    /// located by their fixed size, and dynamic elements by their offsets, so
+    /// a corrupt offset only affects its own element. Otherwise, an error is
+    /// returned for the whole array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::Address;
+    /// use alloy_sol_types::{sol_data, SolType};
+    ///
+    /// type Transfers = sol_data::Array<(sol_data::Address, sol_data::Bool)>;
+    ///
+    /// let transfers = vec![
+    ///     (Address::repeat_byte(1), true),
+    ///     (Address::repeat_byte(2), false),
+    /// ];
+    /// let mut encoded = Transfers::abi_encode(&transfers);
+    /// // dirty the upper bytes of the first address
+    /// encoded[64] = 0xff;
+    ///
+    /// let (decoded, errors) = Transfers::abi_decode_lenient(&encoded).unwrap();
+    /// assert_eq!(decoded, [transfers[1]]);
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].0, 0);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn abi_decode_lenient(
+        data: &[u8],
+    ) -> crate::Result<(Vec<T::RustType>, Vec<(usize, crate::Error)>)> {
+        let mut dec = Decoder::new(data, true);
+        let mut child = dec.take_indirection()?;
+        let len = child.take_u32()? as usize;
+
+        // static elements are stored in place, dynamic ones behind an offset
+        let head_size = T::ENCODED_SIZE.unwrap_or(32);
+        if len
+            .checked_mul(head_size)
+            .map_or(true, |size| size > child.remaining())
+        {
+            return Err(crate::Error::Overrun)
         }
+
+        let mut values = Vec::with_capacity(len);
+        let mut errors = Vec::new();
+        for i in 0..len {
+            let mut element = child.raw_child();
+            element.set_offset(i * head_size);
+            let result = element
+                .decode::<T::TokenType<'_>>()
+                .and_then(|token| T::type_check(&token).map(|()| T::detokenize(token)));
+            match result {
+                Ok(value) => values.push(value),
+                Err(error) => errors.push((i, error)),
+            }
+        }
+        Ok((values, errors))
     }
 }
 
@@ -398,9 +775,25 @@ impl SolType for String {
         RustString::from_utf8_lossy(&Bytes::detokenize(token)).into_owned()
     }
 
+    #[cfg(feature = "sol-literal")]
+    #[inline]
+    fn to_sol_literal(rust: &Self::RustType) -> RustString {
+        literal::string(rust)
+    }
+
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{rust:?}")
+    }
+
     #[inline]
     fn eip712_data_word(rust: &Self::RustType) -> Word {
-        keccak256(Self::abi_encode_packed(rust))
+        keccak256(rust.as_bytes())
+    }
+
+    #[inline]
+    fn packed_encoded_size(rust: &Self::RustType) -> usize {
+        rust.len()
     }
 
     #[inline]
@@ -448,11 +841,32 @@ where
         token.0[..N].try_into().unwrap()
     }
 
+    #[cfg(feature = "sol-literal")]
+    #[inline]
+    fn to_sol_literal(rust: &Self::RustType) -> RustString {
+        // 40-digit hex number literals must be valid checksummed addresses
+        if N == 20 {
+            alloc::format!("bytes20({})", literal::hex_string(rust.as_slice()))
+        } else {
+            alloc::format!("0x{}", hex::encode(rust))
+        }
+    }
+
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        pretty::hex(rust.as_slice(), f)
+    }
+
     #[inline]
     fn eip712_data_word(rust: &Self::RustType) -> Word {
         Encodable::<Self>::to_tokens(rust).0
     }
 
+    #[inline]
+    fn packed_encoded_size(_rust: &Self::RustType) -> usize {
+        N
+    }
+
     #[inline]
     fn abi_encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         // write only the first n bytes
@@ -511,6 +925,13 @@ impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
         token.0.map(T::detokenize)
     }
 
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        f.debug_list()
+            .entries(rust.iter().map(Pretty::<T>::new))
+            .finish()
+    }
+
     #[inline]
     fn eip712_data_word(rust: &Self::RustType) -> Word {
         // TODO: collect into an array of [u8; 32] and flatten it to a slice like in
@@ -522,11 +943,294 @@ impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
         keccak256(crate::impl_core::into_flattened(encoded))
     }
 
+    #[inline]
+    fn packed_encoded_size(rust: &Self::RustType) -> usize {
+        packed_array_size::<T>(rust)
+    }
+
     #[inline]
     fn abi_encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
-        for item in rust {
+        encode_packed_array_to::<T>(rust, out);
+    }
+}
+
+/// ZeroAsNone - `Option<T>` where the zero value of `T` means `None`.
+///
+/// This is a common convention for "not set" fields, e.g. the zero address or
+/// the zero hash, and not a feature of the ABI itself: the type is encoded
+/// exactly like `T`, with `None` encoded as the zero word, and a zero word is
+/// decoded as `None`. This means that `Some(zero)` does not round-trip.
+///
+/// Only single-word types, such as `address`, `bool`, `uintN` and `bytesN`,
+/// are supported. In the [`sol!`][crate::sol] macro, this type can be applied
+/// to struct and function parameters with the `#[sol(zero_is_none)]`
+/// attribute.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::Address;
+/// use alloy_sol_types::{sol_data, SolType};
+///
+/// type MaybeAddress = sol_data::ZeroAsNone<sol_data::Address>;
+///
+/// let encoded = MaybeAddress::abi_encode(&None::<Address>);
+/// assert_eq!(encoded, [0u8; 32]);
+/// assert_eq!(MaybeAddress::abi_decode(&encoded, true).unwrap(), None);
+///
+/// let address = Address::repeat_byte(0x11);
+/// let encoded = MaybeAddress::abi_encode(&Some(address));
+/// assert_eq!(encoded, sol_data::Address::abi_encode(&address));
+/// assert_eq!(
+///     MaybeAddress::abi_decode(&encoded, true).unwrap(),
+///     Some(address)
+/// );
+/// ```
+pub struct ZeroAsNone<T>(PhantomData<T>);
+
+impl<T, U> Encodable<ZeroAsNone<T>> for Option<U>
+where
+    T: for<'a> SolType<TokenType<'a> = WordToken>,
+    U: Encodable<T>,
+{
+    #[inline]
+    fn to_tokens(&self) -> WordToken {
+        match self {
+            Some(value) => Encodable::<T>::to_tokens(value),
+            None => WordToken(Word::ZERO),
+        }
+    }
+}
+
+impl<T> SolType for ZeroAsNone<T>
+where
+    T: for<'a> SolType<TokenType<'a> = WordToken>,
+{
+    type RustType = Option<T::RustType>;
+    type TokenType<'a> = WordToken;
+
+    #[inline]
+    fn sol_type_name() -> Cow<'static, str> {
+        T::sol_type_name()
+    }
+
+    #[inline]
+    fn valid_token(token: &Self::TokenType<'_>) -> bool {
+        T::valid_token(token)
+    }
+
+    #[inline]
+    fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
+        if token.0 == Word::ZERO {
+            None
+        } else {
+            Some(T::detokenize(token))
+        }
+    }
+
+    #[cfg(feature = "sol-literal")]
+    #[inline]
+    fn to_sol_literal(rust: &Self::RustType) -> RustString {
+        match rust {
+            Some(value) => T::to_sol_literal(value),
+            None => T::to_sol_literal(&T::detokenize(WordToken(Word::ZERO))),
+        }
+    }
+
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        match rust {
+            Some(value) => T::fmt_pretty(value, f),
+            None => f.write_str("None"),
+        }
+    }
+
+    #[inline]
+    fn eip712_data_word(rust: &Self::RustType) -> Word {
+        match rust {
+            Some(value) => T::eip712_data_word(value),
+            None => Word::ZERO,
+        }
+    }
+
+    #[inline]
+    fn packed_encoded_size(rust: &Self::RustType) -> usize {
+        match rust {
+            Some(value) => T::packed_encoded_size(value),
+            None => T::packed_encoded_size(&T::detokenize(WordToken(Word::ZERO))),
+        }
+    }
+
+    #[inline]
+    fn abi_encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
+        match rust {
+            Some(value) => T::abi_encode_packed_to(value, out),
+            None => T::abi_encode_packed_to(&T::detokenize(WordToken(Word::ZERO)), out),
+        }
+    }
+}
+
+/// Optional - `(bool,T)`, where the flag indicates whether the value is
+/// present.
+///
+/// This is a common Solidity idiom for optional values, as the language has no
+/// option type. `None` is encoded with a `false` flag and the default value of
+/// `T`: zero, or empty for dynamic types. When decoding, the value is ignored
+/// if the flag is `false`.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::U256;
+/// use alloy_sol_types::{sol_data, SolType};
+///
+/// type MaybeAmount = sol_data::Optional<sol_data::Uint<256>>;
+/// assert_eq!(<MaybeAmount as SolType>::sol_type_name(), "(bool,uint256)");
+///
+/// type Tuple = (sol_data::Bool, sol_data::Uint<256>);
+///
+/// let encoded = MaybeAmount::abi_encode(&Some(U256::from(42)));
+/// assert_eq!(encoded, Tuple::abi_encode(&(true, U256::from(42))));
+/// assert_eq!(
+///     MaybeAmount::abi_decode(&encoded, true).unwrap(),
+///     Some(U256::from(42))
+/// );
+///
+/// let encoded = MaybeAmount::abi_encode(&None::<U256>);
+/// assert_eq!(encoded, [0u8; 64]);
+/// assert_eq!(MaybeAmount::abi_decode(&encoded, true).unwrap(), None);
+/// ```
+pub struct Optional<T>(PhantomData<T>);
+
+impl<T, U> Encodable<Optional<T>> for Option<U>
+where
+    T: SolType,
+    U: Encodable<T>,
+    for<'a> T::TokenType<'a>: Default,
+{
+    #[inline]
+    fn to_tokens(&self) -> (WordToken, T::TokenType<'_>) {
+        match self {
+            Some(value) => (WordToken::from(true), Encodable::<T>::to_tokens(value)),
+            None => (WordToken::from(false), Default::default()),
+        }
+    }
+}
+
+impl<T> Optional<T>
+where
+    T: SolType,
+    for<'a> T::TokenType<'a>: Default,
+{
+    /// Applies `f` to the value, or to the default value of `T` if it is not
+    /// present.
+    #[inline]
+    fn with_value<R>(rust: &Option<T::RustType>, f: impl FnOnce(&T::RustType) -> R) -> R {
+        match rust {
+            Some(value) => f(value),
+            None => f(&T::detokenize(Default::default())),
+        }
+    }
+}
+
+impl<T> SolType for Optional<T>
+where
+    T: SolType,
+    for<'a> T::TokenType<'a>: Default,
+{
+    type RustType = Option<T::RustType>;
+    type TokenType<'a> = (WordToken, T::TokenType<'a>);
+
+    const ENCODED_SIZE: Option<usize> = <(Bool, T) as SolType>::ENCODED_SIZE;
+
+    #[inline]
+    fn sol_type_name() -> Cow<'static, str> {
+        <(Bool, T) as SolType>::sol_type_name()
+    }
+
+    #[inline]
+    fn abi_encoded_size(rust: &Self::RustType) -> usize {
+        if let Some(size) = Self::ENCODED_SIZE {
+            return size
+        }
+        Self::with_value(rust, |value| 64 + T::abi_encoded_size(value))
+    }
+
+    #[inline]
+    fn valid_token((flag, value): &Self::TokenType<'_>) -> bool {
+        Bool::valid_token(flag) && T::valid_token(value)
+    }
+
+    #[inline]
+    fn detokenize((flag, value): Self::TokenType<'_>) -> Self::RustType {
+        if Bool::detokenize(flag) {
+            Some(T::detokenize(value))
+        } else {
+            None
+        }
+    }
+
+    #[cfg(feature = "sol-literal")]
+    #[inline]
+    fn to_sol_literal(rust: &Self::RustType) -> RustString {
+        let flag = Bool::to_sol_literal(&rust.is_some());
+        Self::with_value(rust, |value| {
+            literal::list('(', [flag, T::to_sol_literal(value)], ')')
+        })
+    }
+
+    #[inline]
+    fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+        match rust {
+            Some(value) => T::fmt_pretty(value, f),
+            None => f.write_str("None"),
+        }
+    }
+
+    #[inline]
+    fn eip712_data_word(rust: &Self::RustType) -> Word {
+        let flag = Bool::eip712_data_word(&rust.is_some());
+        let value = Self::with_value(rust, T::eip712_data_word);
+        keccak256_slices(&[flag.as_slice(), value.as_slice()])
+    }
+
+    #[inline]
+    fn packed_encoded_size(rust: &Self::RustType) -> usize {
+        1 + Self::with_value(rust, T::packed_encoded_size)
+    }
+
+    #[inline]
+    fn abi_encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
+        Bool::abi_encode_packed_to(&rust.is_some(), out);
+        Self::with_value(rust, |value| T::abi_encode_packed_to(value, out));
+    }
+}
+
+// In packed mode, array elements are padded to their standard ABI encoding.
+// Dynamic elements, which Solidity rejects here, are packed in place.
+#[inline]
+fn packed_array_size<T: SolType>(items: &[T::RustType]) -> usize {
+    if <T::TokenType<'_> as TokenType<'_>>::DYNAMIC {
+        items.iter().map(T::packed_encoded_size).sum()
+    } else {
+        items
+            .iter()
+            .map(|item| T::tokenize(item).total_words() * 32)
+            .sum()
+    }
+}
+
+#[inline]
+fn encode_packed_array_to<T: SolType>(items: &[T::RustType], out: &mut Vec<u8>) {
+    if <T::TokenType<'_> as TokenType<'_>>::DYNAMIC {
+        for item in items {
             T::abi_encode_packed_to(item, out);
         }
+    } else {
+        let mut enc = Encoder::with_buf(out);
+        for item in items {
+            T::tokenize(item).head_append(&mut enc);
+        }
     }
 }
 
@@ -616,6 +1320,21 @@ macro_rules! tuple_impls {
                 )+)
             }
 
+            #[cfg(feature = "sol-literal")]
+            #[inline]
+            fn to_sol_literal(rust: &Self::RustType) -> RustString {
+                let ($($ty,)+) = rust;
+                literal::list('(', [$(<$ty as SolType>::to_sol_literal($ty),)+], ')')
+            }
+
+            #[inline]
+            fn fmt_pretty(rust: &Self::RustType, f: &mut Formatter<'_>) -> Result {
+                let ($($ty,)+) = rust;
+                f.debug_tuple("")
+                    $(.field(&Pretty::<$ty>::new($ty)))+
+                    .finish()
+            }
+
             fn eip712_data_word(rust: &Self::RustType) -> Word {
                 const COUNT: usize = 0usize $(+ tuple_impls!(@one $ty))+;
                 let ($($ty,)+) = rust;
@@ -627,9 +1346,15 @@ macro_rules! tuple_impls {
                 keccak256(encoding).into()
             }
 
+            fn packed_encoded_size(rust: &Self::RustType) -> usize {
+                let ($($ty,)+) = rust;
+                0 $(
+                    + <$ty as SolType>::packed_encoded_size($ty)
+                )+
+            }
+
             fn abi_encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
                 let ($($ty,)+) = rust;
-                // TODO: Reserve
                 $(
                     <$ty as SolType>::abi_encode_packed_to($ty, out);
                 )+
@@ -664,11 +1389,27 @@ impl SolType for () {
     #[inline]
     fn detokenize((): ()) -> Self::RustType {}
 
+    #[cfg(feature = "sol-literal")]
+    #[inline]
+    fn to_sol_literal((): &()) -> RustString {
+        "()".into()
+    }
+
+    #[inline]
+    fn fmt_pretty((): &(), f: &mut Formatter<'_>) -> Result {
+        f.write_str("()")
+    }
+
     #[inline]
     fn eip712_data_word((): &()) -> Word {
         Word::ZERO
     }
 
+    #[inline]
+    fn packed_encoded_size((): &()) -> usize {
+        0
+    }
+
     #[inline]
     fn abi_encode_packed_to((): &(), _out: &mut Vec<u8>) {}
 }
@@ -697,11 +1438,17 @@ pub trait SupportedFixedBytes: Sealed {
 }
 
 macro_rules! supported_fixed_bytes {
-    ($($n:literal),+) => {$(
-        impl SupportedFixedBytes for ByteCount<$n> {
-            const NAME: &'static str = concat!("bytes", $n);
-        }
-    )+};
+    ($($n:literal),+) => {
+        $(
+            impl SupportedFixedBytes for ByteCount<$n> {
+                const NAME: &'static str = concat!("bytes", $n);
+            }
+        )+
+
+        const FIXED_BYTES_TYPES: &[(&str, &str)] = &[$(
+            (concat!("bytes", $n), concat!("alloy_primitives::FixedBytes<", $n, ">")),
+        )+];
+    };
 }
 
 supported_fixed_bytes!(
@@ -786,21 +1533,41 @@ pub trait SupportedInt: Sealed {
 }
 
 macro_rules! supported_int {
-    ($($n:literal => $i:ident, $u:ident;)+) => {$(
-        impl SupportedInt for IntBitCount<$n> {
-            type Int = $i;
-            type Uint = $u;
+    ($($n:literal => $i:ident, $u:ident;)+) => {
+        $(
+            impl SupportedInt for IntBitCount<$n> {
+                type Int = $i;
+                type Uint = $u;
 
-            const UINT_NAME: &'static str = concat!("uint", $n);
-            const INT_NAME: &'static str = concat!("int", $n);
+                const UINT_NAME: &'static str = concat!("uint", $n);
+                const INT_NAME: &'static str = concat!("int", $n);
 
-            const BITS: usize = $n;
-            const SKIP_BYTES: usize = (<$i>::BITS as usize - <Self as SupportedInt>::BITS) / 8;
+                const BITS: usize = $n;
+                const SKIP_BYTES: usize =
+                    (<$i>::BITS as usize - <Self as SupportedInt>::BITS) / 8;
 
-            int_impls2!($i);
-            int_impls2!($u);
-        }
-    )+};
+                int_impls2!($i);
+                int_impls2!($u);
+            }
+        )+
+
+        const INT_TYPES: &[(&str, &str)] = &[$(
+            (concat!("int", $n), int_rust_path!($i)),
+            (concat!("uint", $n), int_rust_path!($u)),
+        )+];
+    };
+}
+
+macro_rules! int_rust_path {
+    (I256) => {
+        "alloy_primitives::I256"
+    };
+    (U256) => {
+        "alloy_primitives::U256"
+    };
+    ($ty:ident) => {
+        stringify!($ty)
+    };
 }
 
 macro_rules! int_impls {
@@ -951,6 +1718,84 @@ supported_int!(
     256 => I256, U256;
 );
 
+const ELEMENTARY_TYPES: &[(&str, &str)] = &[
+    ("address", "alloy_primitives::Address"),
+    ("bool", "bool"),
+    ("bytes", "alloc::vec::Vec<u8>"),
+    ("function", "alloy_primitives::Function"),
+    ("string", "alloc::string::String"),
+];
+
+const SOLIDITY_TYPE_MAP_LEN: usize =
+    ELEMENTARY_TYPES.len() + INT_TYPES.len() + FIXED_BYTES_TYPES.len();
+
+/// The Rust types that the Solidity elementary types map to.
+///
+/// Each entry is a pair of the canonical Solidity type name and the fully
+/// qualified path of the [`SolType::RustType`] that the [`sol!`] macro uses
+/// for it, e.g. `("uint48", "u64")` or
+/// `("bytes17", "alloy_primitives::FixedBytes<17>")`. All `int<N>`,
+/// `uint<N>` and `bytes<N>` sizes are listed.
+///
+/// This is generated from the same definitions as the types in this module,
+/// and is meant to be consumed by external code generators.
+///
+/// [`sol!`]: crate::sol
+pub const SOLIDITY_TYPE_MAP: &[(&str, &str)] = &{
+    let mut map = [("", ""); SOLIDITY_TYPE_MAP_LEN];
+    let mut i = 0;
+    let mut j = 0;
+    while j < ELEMENTARY_TYPES.len() {
+        map[i] = ELEMENTARY_TYPES[j];
+        i += 1;
+        j += 1;
+    }
+    j = 0;
+    while j < INT_TYPES.len() {
+        map[i] = INT_TYPES[j];
+        i += 1;
+        j += 1;
+    }
+    j = 0;
+    while j < FIXED_BYTES_TYPES.len() {
+        map[i] = FIXED_BYTES_TYPES[j];
+        i += 1;
+        j += 1;
+    }
+    map
+};
+
+/// Returns the fully qualified path of the Rust type that the given Solidity
+/// elementary type maps to, as listed in [`SOLIDITY_TYPE_MAP`].
+///
+/// The `int` and `uint` aliases are also accepted.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_sol_types::sol_data::rust_type_for;
+///
+/// assert_eq!(rust_type_for("uint48"), Some("u64"));
+/// assert_eq!(rust_type_for("uint"), Some("alloy_primitives::U256"));
+/// assert_eq!(
+///     rust_type_for("bytes17"),
+///     Some("alloy_primitives::FixedBytes<17>")
+/// );
+/// assert_eq!(rust_type_for("uint7"), None);
+/// assert_eq!(rust_type_for("uint8[]"), None);
+/// ```
+pub fn rust_type_for(solidity: &str) -> Option<&'static str> {
+    let solidity = match solidity {
+        "int" => "int256",
+        "uint" => "uint256",
+        s => s,
+    };
+    SOLIDITY_TYPE_MAP
+        .iter()
+        .find(|(sol, _)| *sol == solidity)
+        .map(|&(_, rust)| rust)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -966,6 +1811,30 @@ mod tests {
         MyTy::tokenize(&b);
     }
 
+    #[test]
+    fn encode_bytes() {
+        type MyTy = (Address, Array<Uint<256>>, String);
+        let value = (
+            RustAddress::repeat_byte(0x11),
+            vec![U256::from(1), U256::MAX],
+            "a".into(),
+        );
+
+        assert_eq!(MyTy::abi_encode_bytes(&value), MyTy::abi_encode(&value));
+        assert_eq!(
+            MyTy::abi_encode_sequence_bytes(&value),
+            MyTy::abi_encode_sequence(&value)
+        );
+        assert_eq!(
+            MyTy::abi_encode_params_bytes(&value),
+            MyTy::abi_encode_params(&value)
+        );
+        assert_eq!(
+            MyTy::abi_encode_packed_bytes(&value),
+            MyTy::abi_encode_packed(&value)
+        );
+    }
+
     macro_rules! roundtrip {
         ($($name:ident($st:ty : $t:ty);)+) => {
             proptest::proptest! {$(
@@ -998,6 +1867,204 @@ mod tests {
         roundtrip_i128(Int<128>: i128);
         roundtrip_u256(Uint<256>: U256);
         roundtrip_i256(Int<256>: I256);
+
+        roundtrip_optional_u64(Optional<Uint<64>>: Option<u64>);
+        roundtrip_optional_string(Optional<String>: Option<RustString>);
+    }
+
+    #[test]
+    fn decode_lenient() {
+        type Entry = (Address, Uint<8>, Bool);
+        let entries: Vec<_> = (1..=5u8)
+            .map(|i| (RustAddress::repeat_byte(i), i, i % 2 == 0))
+            .collect();
+        let encoded = Array::<Entry>::abi_encode(&entries);
+        let element = |i: usize| 64 + i * 96;
+
+        let (decoded, errors) = Array::<Entry>::abi_decode_lenient(&encoded).unwrap();
+        assert_eq!(decoded, entries);
+        assert!(errors.is_empty());
+
+        // dirty address, out of range `uint8` and dirty `bool`
+        let mut dirty = encoded.clone();
+        dirty[element(0)] = 1;
+        dirty[element(2) + 32 + 30] = 1;
+        dirty[element(3) + 64] = 1;
+        assert!(Array::<Entry>::abi_decode(&dirty, true).is_err());
+        let (decoded, errors) = Array::<Entry>::abi_decode_lenient(&dirty).unwrap();
+        assert_eq!(decoded, [entries[1], entries[4]]);
+        let indices: Vec<_> = errors.iter().map(|(i, _)| *i).collect();
+        assert_eq!(indices, [0, 2, 3]);
+        for (_, error) in &errors {
+            assert!(
+                matches!(error.root_cause(), crate::Error::TypeCheckFail { .. }),
+                "{error}"
+            );
+        }
+
+        // element boundaries are not recoverable
+        assert_eq!(
+            Array::<Entry>::abi_decode_lenient(&encoded[..element(4)]),
+            Err(crate::Error::Overrun)
+        );
+
+        // a bad offset only affects its own element
+        let strings = vec![RustString::from("a"), "b".into(), "c".into()];
+        let mut encoded = Array::<String>::abi_encode(&strings);
+        encoded[64 + 32 + 31] = 0xff;
+        let (decoded, errors) = Array::<String>::abi_decode_lenient(&encoded).unwrap();
+        assert_eq!(decoded, ["a", "c"]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 1);
+    }
+
+    #[test]
+    fn decode_lenient_uint() {
+        // a log with a full first word and a truncated 30-byte final word
+        let value = U256::from_be_bytes(alloy_primitives::hex!(
+            "0000aabbccddeeff00112233445566778899aabbccddeeff0011223344556677"
+        ));
+        let mut data = Uint::<256>::abi_encode(&U256::from(1));
+        data.extend_from_slice(&value.to_be_bytes::<32>()[2..]);
+        assert_eq!(data.len(), 62);
+
+        assert_eq!(
+            Uint::<256>::abi_decode(&data[32..], true),
+            Err(crate::Error::Overrun)
+        );
+        assert_eq!(
+            Uint::<256>::abi_decode_lenient(&data[32..], true),
+            Ok(value)
+        );
+        assert_eq!(
+            Uint::<256>::abi_decode_lenient(&data[..32], true),
+            Ok(U256::from(1))
+        );
+        assert_eq!(
+            Uint::<256>::abi_decode_lenient(&data[61..], true),
+            Ok(U256::from(0x77))
+        );
+        assert_eq!(
+            Uint::<256>::abi_decode_lenient(&[], true),
+            Err(crate::Error::Overrun)
+        );
+
+        // the zero-extended word is still validated
+        assert_eq!(
+            Uint::<64>::abi_decode_lenient(&[1; 8], true),
+            Ok(u64::from_be_bytes([1; 8]))
+        );
+        assert!(Uint::<64>::abi_decode_lenient(&[1; 9], true).is_err());
+        assert_eq!(
+            Uint::<64>::abi_decode_lenient(&[1; 9], false),
+            Ok(u64::from_be_bytes([1; 8]))
+        );
+    }
+
+    #[test]
+    fn decode_borrowed() {
+        let encoded = Bytes::abi_encode(&[0xde, 0xad, 0xbe, 0xef]);
+        let decoded = Bytes::abi_decode_borrowed(&encoded, true).unwrap();
+        assert_eq!(decoded, [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(decoded.as_ptr(), encoded[64..].as_ptr());
+        assert_eq!(
+            Bytes::abi_decode_borrowed(&encoded[..64], true),
+            Err(crate::Error::Overrun)
+        );
+
+        let encoded = String::abi_encode(&"héllo");
+        let decoded = String::abi_decode_borrowed(&encoded, true).unwrap();
+        assert_eq!(decoded, "héllo");
+        assert_eq!(decoded.as_ptr(), encoded[64..].as_ptr());
+
+        // dirty padding is only rejected when validating
+        let mut dirty = encoded.clone();
+        dirty[95] = 1;
+        assert_eq!(String::abi_decode_borrowed(&dirty, false), Ok("héllo"));
+        assert!(String::abi_decode_borrowed(&dirty, true).is_err());
+
+        // invalid UTF-8 is always rejected
+        let mut invalid = encoded;
+        invalid[65] = 0xff;
+        assert!(String::abi_decode_borrowed(&invalid, false).is_err());
+        assert!(String::abi_decode_borrowed(&invalid, true).is_err());
+        assert_eq!(
+            String::abi_decode(&invalid, false).unwrap(),
+            "h\u{fffd}\u{fffd}llo"
+        );
+    }
+
+    #[test]
+    fn optional() {
+        type MaybeString = Optional<String>;
+        assert_eq!(<MaybeString as SolType>::sol_type_name(), "(bool,string)");
+        assert_eq!(<Optional<Address>>::ENCODED_SIZE, Some(64));
+        assert_eq!(MaybeString::ENCODED_SIZE, None);
+
+        let some = Some(RustString::from("hello"));
+        let encoded = MaybeString::abi_encode(&some);
+        assert_eq!(encoded, <(Bool, String)>::abi_encode(&(true, "hello")));
+        assert_eq!(MaybeString::abi_encoded_size(&some), 32 * 4);
+        assert_eq!(MaybeString::abi_decode(&encoded, true).unwrap(), some);
+
+        // `None` is encoded with the default value
+        let encoded = MaybeString::abi_encode(&None::<RustString>);
+        assert_eq!(
+            encoded,
+            <(Bool, String)>::abi_encode(&(false, RustString::new()))
+        );
+        assert_eq!(MaybeString::abi_encoded_size(&None), 32 * 3);
+        assert_eq!(MaybeString::abi_decode(&encoded, true).unwrap(), None);
+        assert_eq!(
+            MaybeString::eip712_data_word(&None),
+            <(Bool, String)>::eip712_data_word(&(false, RustString::new()))
+        );
+
+        // the value is ignored if the flag is not set
+        let encoded = <(Bool, String)>::abi_encode(&(false, "ignored"));
+        assert_eq!(MaybeString::abi_decode(&encoded, true).unwrap(), None);
+
+        // the flag must be a valid `bool`
+        let mut encoded = <Optional<Uint<64>>>::abi_encode(&Some(1u64));
+        encoded[0] = 1;
+        assert!(<Optional<Uint<64>>>::abi_decode(&encoded, true).is_err());
+
+        type MaybeArray = Optional<FixedArray<Array<Bool>, 2>>;
+        assert_eq!(
+            MaybeArray::detokenize(MaybeArray::tokenize(&None::<[Vec<bool>; 2]>)),
+            None
+        );
+        let value = Some([vec![true], vec![]]);
+        assert_eq!(
+            MaybeArray::abi_decode(&MaybeArray::abi_encode(&value), true).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn address_array() {
+        let addresses: Vec<RustAddress> = (0..500u32)
+            .map(|i| RustAddress::from_word(keccak256(i.to_be_bytes())))
+            .collect();
+        let encoded = Array::<Address>::abi_encode(&addresses);
+
+        let token = crate::abi::decode::<DynSeqToken<WordToken>>(&encoded, true).unwrap();
+        let generic: Vec<_> = token.0.iter().copied().map(Address::detokenize).collect();
+        assert_eq!(Array::<Address>::detokenize(token), generic);
+        assert_eq!(
+            Array::<Address>::abi_decode(&encoded, true).unwrap(),
+            addresses
+        );
+        assert_eq!(generic, addresses);
+
+        // dirty upper bytes in a single element
+        let mut dirty = encoded.clone();
+        dirty[64 + 32 * 250] = 1;
+        assert!(Array::<Address>::abi_decode(&dirty, true).is_err());
+        assert_eq!(
+            Array::<Address>::abi_decode(&dirty, false).unwrap(),
+            addresses
+        );
     }
 
     #[test]
@@ -1054,8 +2121,10 @@ mod tests {
         let token = WordToken::new(word);
         macro_rules! test {
             ($($n:literal => $x:expr),+ $(,)?) => {$(
-                assert_eq!(<Uint<$n>>::detokenize(token), $x);
-                assert_eq!(<Int<$n>>::detokenize(token), $x);
+                let uint: <Uint<$n> as SolType>::RustType = $x;
+                let int: <Int<$n> as SolType>::RustType = $x;
+                assert_eq!(<Uint<$n>>::detokenize(token), uint);
+                assert_eq!(<Int<$n>>::detokenize(token), int);
             )+};
         }
         #[rustfmt::skip]
@@ -1180,4 +2249,52 @@ mod tests {
         assert_eq!(<Int<248>>::detokenize(token), "0xff82038405860788098a0b8c0d8e0f901192139415961798199a1b9c1d9e1fa0".as_u256_as_i256());
         assert_eq!(<Int<256>>::detokenize(token), "0x0182038405860788098a0b8c0d8e0f901192139415961798199a1b9c1d9e1fa0".as_u256_as_i256());
     }
+
+    #[test]
+    fn encode_packed() {
+        macro_rules! test {
+            ($($st:ty: $value:expr => $expected:literal),+ $(,)?) => {$({
+                let value = $value;
+                let encoded = <$st>::abi_encode_packed(&value);
+                assert_eq!(encoded, alloy_primitives::hex!($expected), stringify!($st));
+                assert_eq!(<$st>::packed_encoded_size(&value), encoded.len(), stringify!($st));
+            })+};
+        }
+
+        test! {
+            Bool: true => "01",
+            Int<8>: -1i8 => "ff",
+            Int<24>: -2i32 => "fffffe",
+            Uint<16>: 0x0102u16 => "0102",
+            Uint<256>: U256::from(1) => "0000000000000000000000000000000000000000000000000000000000000001",
+            Address: RustAddress::repeat_byte(0x11) => "1111111111111111111111111111111111111111",
+            Function: RustFunction::repeat_byte(0x22) => "222222222222222222222222222222222222222222222222",
+            FixedBytes<3>: RustFixedBytes([1, 2, 3]) => "010203",
+            Bytes: vec![0xaa; 3] => "aaaaaa",
+            String: RustString::from("hi") => "6869",
+            ZeroAsNone<Uint<16>>: None::<u16> => "0000",
+            Optional<Uint<16>>: Some(0x0102u16) => "010102",
+            Optional<String>: None::<RustString> => "00",
+            (): () => "",
+
+            // from the Solidity docs:
+            // abi.encodePacked(int16(-1), bytes1(0x42), uint16(0x03), string("Hello, world!"))
+            (Int<16>, FixedBytes<1>, Uint<16>, String):
+                (-1i16, RustFixedBytes([0x42]), 3u16, RustString::from("Hello, world!"))
+                => "ffff42000348656c6c6f2c20776f726c6421",
+
+            // array elements are padded to a full word
+            Array<Uint<16>>: vec![1u16, 2] => "0000000000000000000000000000000000000000000000000000000000000001\
+                                               0000000000000000000000000000000000000000000000000000000000000002",
+            Array<Int<8>>: vec![-1i8] => "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            FixedArray<FixedBytes<2>, 2>: [RustFixedBytes([1, 2]), RustFixedBytes([3, 4])]
+                => "0102000000000000000000000000000000000000000000000000000000000000\
+                    0304000000000000000000000000000000000000000000000000000000000000",
+            FixedArray<Bool, 2>: [true, false] => "0000000000000000000000000000000000000000000000000000000000000001\
+                                                   0000000000000000000000000000000000000000000000000000000000000000",
+            Array<Address>: Vec::<RustAddress>::new() => "",
+            (Bool, Array<Bool>): (true, vec![true])
+                => "010000000000000000000000000000000000000000000000000000000000000001",
+        }
+    }
 }
@@ -3,7 +3,7 @@ use crate::{
     Result, SolType, Word,
 };
 use alloc::vec::Vec;
-use alloy_primitives::{FixedBytes, B256};
+use alloy_primitives::{keccak256, FixedBytes, B256};
 
 mod topic;
 pub use topic::EventTopic;
@@ -78,6 +78,7 @@ pub trait SolEvent: Sized {
     /// ABI-encode the dynamic data of this event into the given buffer.
     #[inline]
     fn encode_data_to(&self, out: &mut Vec<u8>) {
+        abi_trace_span!("encode_data", signature = Self::SIGNATURE);
         out.reserve(self.abi_encoded_size());
         out.extend(crate::abi::encode_sequence(&self.tokenize_body()));
     }
@@ -122,6 +123,30 @@ pub trait SolEvent: Sized {
         out
     }
 
+    /// Hash the log of this event, as a key for deduplicating logs.
+    ///
+    /// This is the Keccak-256 hash of the event's topics, as returned by
+    /// [`encode_topics`](SolEvent::encode_topics), immediately followed by its
+    /// data, as returned by [`encode_data`](SolEvent::encode_data):
+    ///
+    /// ```text
+    /// keccak256(topics[0] ++ ... ++ topics[n - 1] ++ data)
+    /// ```
+    ///
+    /// For non-anonymous events, `topics[0]` is the event's
+    /// [`SIGNATURE_HASH`](SolEvent::SIGNATURE_HASH). Anonymous events start
+    /// directly with their first indexed parameter.
+    #[inline]
+    fn event_hash(&self) -> B256 {
+        let topics = self.encode_topics();
+        let mut preimage = Vec::with_capacity(topics.len() * 32 + self.abi_encoded_size());
+        for topic in &topics {
+            preimage.extend_from_slice(topic.as_slice());
+        }
+        self.encode_data_to(&mut preimage);
+        keccak256(preimage)
+    }
+
     /// Decode the topics of this event from the given data.
     #[inline]
     fn decode_topics<I, D>(topics: I) -> Result<<Self::TopicList as SolType>::RustType>
@@ -138,6 +163,12 @@ pub trait SolEvent: Sized {
         data: &'a [u8],
         validate: bool,
     ) -> Result<<Self::DataTuple<'a> as SolType>::RustType> {
+        abi_trace_span!(
+            "abi_decode_data",
+            signature = Self::SIGNATURE,
+            len = data.len(),
+            validate,
+        );
         <Self::DataTuple<'a> as SolType>::abi_decode_sequence(data, validate)
     }
 
@@ -147,8 +178,39 @@ pub trait SolEvent: Sized {
         I: IntoIterator<Item = D>,
         D: Into<WordToken>,
     {
-        let topics = Self::decode_topics(topics)?;
+        abi_trace_span!(
+            "decode_log",
+            signature = Self::SIGNATURE,
+            len = data.len(),
+            validate,
+        );
+        let topics = abi_trace_result!(Self::decode_topics(topics))?;
         let body = Self::abi_decode_data(data, validate)?;
         Ok(Self::new(topics, body))
     }
+
+    /// Decode the event from the given log info, with the topics given as raw
+    /// byte slices, such as `Vec<Vec<u8>>` returned by some RPC libraries.
+    ///
+    /// Fails if any of the topics is not exactly 32 bytes long.
+    fn decode_log_raw<I, D>(topics: I, data: &[u8], validate: bool) -> Result<Self>
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<[u8]>,
+    {
+        let topics = topics
+            .into_iter()
+            .enumerate()
+            .map(|(i, topic)| {
+                let topic = topic.as_ref();
+                WordToken::try_from(topic).map_err(|_| {
+                    crate::Error::custom(format!(
+                        "invalid length for topic {i}: expected 32 bytes, got {}",
+                        topic.len()
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::decode_log(topics, data, validate)
+    }
 }
@@ -71,6 +71,13 @@ impl EventTopic for Bool {
     word_impl!();
 }
 
+impl<T> EventTopic for ZeroAsNone<T>
+where
+    T: for<'a> SolType<TokenType<'a> = WordToken>,
+{
+    word_impl!();
+}
+
 impl<const BITS: usize> EventTopic for Int<BITS>
 where
     IntBitCount<BITS>: SupportedInt,
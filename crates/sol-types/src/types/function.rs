@@ -1,8 +1,11 @@
 use crate::{
     abi::{TokenSeq, TokenType},
-    Encodable, Result, SolType, Word,
+    ContractError, Encodable, GenericContractError, Panic, Result, Revert, SolInterface, SolType,
+    Word,
 };
 use alloc::vec::Vec;
+use alloy_primitives::{bytes::BytesMut, Bytes};
+use core::fmt;
 
 /// Solidity call (a tuple with a selector).
 ///
@@ -57,6 +60,12 @@ pub trait SolCall: Sized {
     /// selector.
     #[inline]
     fn abi_decode_raw(data: &[u8], validate: bool) -> Result<Self> {
+        abi_trace_span!(
+            "abi_decode_raw",
+            signature = Self::SIGNATURE,
+            len = data.len(),
+            validate,
+        );
         <Self::Arguments<'_> as SolType>::abi_decode_sequence(data, validate).map(Self::new)
     }
 
@@ -64,37 +73,256 @@ pub trait SolCall: Sized {
     /// selector.
     #[inline]
     fn abi_decode(data: &[u8], validate: bool) -> Result<Self> {
-        let data = data
+        abi_trace_span!(
+            "abi_decode",
+            signature = Self::SIGNATURE,
+            len = data.len(),
+            validate,
+        );
+        let data = abi_trace_result!(data
             .strip_prefix(&Self::SELECTOR)
-            .ok_or_else(|| crate::Error::type_check_fail_sig(data, Self::SIGNATURE))?;
+            .ok_or_else(|| crate::Error::type_check_fail_sig(data, Self::SIGNATURE)))?;
         Self::abi_decode_raw(data, validate)
     }
 
     /// ABI encode the call to the given buffer **without** its selector.
     #[inline]
     fn abi_encode_raw(&self, out: &mut Vec<u8>) {
-        out.reserve(self.abi_encoded_size());
-        out.extend(crate::abi::encode_sequence(&self.tokenize()));
+        abi_trace_span!("abi_encode_raw", signature = Self::SIGNATURE);
+        crate::abi::encode_sequence_to(&self.tokenize(), out);
+    }
+
+    /// ABI encode the first `n` arguments of the call, **without** its
+    /// selector, as if they were the only arguments of the call.
+    ///
+    /// This is useful for signed calldata, such as in meta-transactions, where
+    /// the signature is the last argument of the call and is computed over
+    /// the other arguments.
+    ///
+    /// This is the same as encoding the tuple of the first `n` arguments with
+    /// [`SolType::abi_encode_sequence`]. Note that it is **not** a prefix of
+    /// [`abi_encode_raw`](SolCall::abi_encode_raw) if any of the arguments is
+    /// dynamic, as the offsets of dynamic arguments depend on the total
+    /// number of arguments.
+    ///
+    /// ```
+    /// use alloy_primitives::{keccak256, Address, B256, U256};
+    /// use alloy_sol_types::{sol, sol_data, SolCall, SolType};
+    ///
+    /// sol! {
+    ///     function execute(address to, bytes data, uint256 nonce, bytes signature);
+    /// }
+    ///
+    /// let mut call = executeCall {
+    ///     to: Address::repeat_byte(0x11),
+    ///     data: vec![0xde, 0xad, 0xbe, 0xef],
+    ///     nonce: U256::from(1),
+    ///     signature: vec![],
+    /// };
+    /// let hash = keccak256(call.abi_encode_args_prefix(3));
+    ///
+    /// type Prefix = (sol_data::Address, sol_data::Bytes, sol_data::Uint<256>);
+    /// let prefix = Prefix::abi_encode_sequence(&(call.to, call.data.clone(), call.nonce));
+    /// assert_eq!(hash, keccak256(prefix));
+    ///
+    /// // sign `hash` ...
+    /// call.signature = vec![0x42; 65];
+    /// let calldata = call.abi_encode();
+    /// ```
+    #[inline]
+    fn abi_encode_args_prefix(&self, n: usize) -> Vec<u8> {
+        crate::abi::encode_sequence_prefix(&self.tokenize(), n)
     }
 
     /// ABI encode the call to the given buffer **with** its selector.
     #[inline]
     fn abi_encode(&self) -> Vec<u8> {
+        abi_trace_span!("abi_encode", signature = Self::SIGNATURE);
         let mut out = Vec::with_capacity(4 + self.abi_encoded_size());
         out.extend(&Self::SELECTOR);
         self.abi_encode_raw(&mut out);
         out
     }
 
+    /// ABI encode the call **with** its selector, into [`Bytes`].
+    #[inline]
+    fn abi_encode_bytes(&self) -> Bytes {
+        abi_trace_span!("abi_encode_bytes", signature = Self::SIGNATURE);
+        let mut out = BytesMut::with_capacity(4 + self.abi_encoded_size());
+        out.extend_from_slice(&Self::SELECTOR);
+        crate::abi::encode_into_bytes_mut(&self.tokenize(), &mut out);
+        out.freeze().into()
+    }
+
     /// ABI decode this call's return values from the given slice.
     fn abi_decode_returns(data: &[u8], validate: bool) -> Result<Self::Return>;
 
+    /// ABI decode the raw output of a call, such as an `eth_call` response,
+    /// into either this call's return values or the error it reverted with.
+    ///
+    /// The data is first decoded as a [`GenericContractError`], then as the
+    /// return values. Since the output does not record whether the call
+    /// succeeded, return values that happen to start with the [`Revert`] or
+    /// [`Panic`] selector and decode as such are reported as errors.
+    #[inline]
+    fn abi_decode_response(data: &[u8], validate: bool) -> Result<Self::Return, CallError> {
+        abi_trace_span!(
+            "abi_decode_response",
+            signature = Self::SIGNATURE,
+            len = data.len(),
+            validate,
+        );
+        if let Ok(error) = GenericContractError::abi_decode(data, validate) {
+            return Err(error.into())
+        }
+        Self::abi_decode_returns(data, validate).map_err(CallError::Unknown)
+    }
+
     /// ABI encode the call's return values.
     #[inline]
     fn abi_encode_returns<'a, E>(e: &'a E) -> Vec<u8>
     where
         E: Encodable<Self::ReturnTuple<'a>>,
     {
+        abi_trace_span!("abi_encode_returns", signature = Self::SIGNATURE);
         crate::abi::encode_sequence(&e.to_tokens())
     }
 }
+
+/// Solidity constructor.
+///
+/// The constructor arguments of a contract are ABI-encoded as a sequence,
+/// without a selector, and appended to the contract's creation code.
+///
+/// ### Implementer's Guide
+///
+/// We do not recommend implementing this trait directly. Instead, we recommend
+/// using the [`sol`][crate::sol] proc macro to parse a Solidity constructor
+/// definition.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{Address, U256};
+/// use alloy_sol_types::{sol, SolConstructor};
+///
+/// sol! {
+///     constructor(address owner, uint256 supply);
+/// }
+///
+/// let args = constructorCall {
+///     owner: Address::ZERO,
+///     supply: U256::from(1),
+/// };
+/// let encoded = args.abi_encode();
+/// assert_eq!(encoded.len(), 64);
+/// assert_eq!(
+///     constructorCall::abi_decode(&encoded, true).unwrap().supply,
+///     args.supply
+/// );
+///
+/// // the encoded arguments are appended to the creation code
+/// # let creation_code: &[u8] = &[];
+/// let deploy_data = [creation_code, &encoded].concat();
+/// ```
+pub trait SolConstructor: Sized {
+    /// The underlying tuple type which represents this type's arguments.
+    ///
+    /// If this type has no arguments, this will be the unit type `()`.
+    type Arguments<'a>: SolType<TokenType<'a> = Self::Token<'a>>;
+
+    /// The arguments' corresponding [TokenSeq] type.
+    type Token<'a>: TokenSeq<'a>;
+
+    /// Convert from the tuple type used for ABI encoding and decoding.
+    fn new(tuple: <Self::Arguments<'_> as SolType>::RustType) -> Self;
+
+    /// Tokenize the constructor's arguments.
+    fn tokenize(&self) -> Self::Token<'_>;
+
+    /// The size of the encoded arguments in bytes.
+    #[inline]
+    fn abi_encoded_size(&self) -> usize {
+        if let Some(size) = <Self::Arguments<'_> as SolType>::ENCODED_SIZE {
+            return size
+        }
+
+        self.tokenize().total_words() * Word::len_bytes()
+    }
+
+    /// ABI encode the constructor's arguments to the given buffer.
+    #[inline]
+    fn abi_encode_to(&self, out: &mut Vec<u8>) {
+        abi_trace_span!("abi_encode_to", signature = "constructor");
+        crate::abi::encode_sequence_to(&self.tokenize(), out);
+    }
+
+    /// ABI encode the constructor's arguments, to be appended to the
+    /// contract's creation code.
+    ///
+    /// A constructor without arguments encodes to an empty vector.
+    #[inline]
+    fn abi_encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.abi_encoded_size());
+        self.abi_encode_to(&mut out);
+        out
+    }
+
+    /// ABI decode the constructor's arguments from the given slice.
+    #[inline]
+    fn abi_decode(data: &[u8], validate: bool) -> Result<Self> {
+        abi_trace_span!(
+            "abi_decode",
+            signature = "constructor",
+            len = data.len(),
+            validate,
+        );
+        <Self::Arguments<'_> as SolType>::abi_decode_sequence(data, validate).map(Self::new)
+    }
+}
+
+/// The error returned by [`SolCall::abi_decode_response`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum CallError {
+    /// The call reverted with a reason string. See [`Revert`].
+    Revert(Revert),
+    /// The call panicked. See [`Panic`], and [`Panic::kind`] for the
+    /// [`PanicKind`](crate::PanicKind).
+    Panic(Panic),
+    /// The output is neither a known error nor valid return values. Contains
+    /// the error from decoding the return values.
+    Unknown(crate::Error),
+}
+
+impl From<GenericContractError> for CallError {
+    #[inline]
+    fn from(value: GenericContractError) -> Self {
+        match value {
+            ContractError::CustomError(never) => match never {},
+            ContractError::Revert(revert) => Self::Revert(revert),
+            ContractError::Panic(panic) => Self::Panic(panic),
+        }
+    }
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Revert(revert) => revert.fmt(f),
+            Self::Panic(panic) => panic.fmt(f),
+            Self::Unknown(error) => write!(f, "could not decode call output: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CallError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Revert(revert) => Some(revert),
+            Self::Panic(panic) => Some(panic),
+            Self::Unknown(error) => Some(error),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Error, Panic, Result, Revert, SolError};
+use crate::{Error, Panic, Result, Revert, SolError, Word};
 use alloc::vec::Vec;
 use core::{convert::Infallible, fmt, iter::FusedIterator, marker::PhantomData};
 
@@ -135,6 +135,63 @@ impl SolInterface for Infallible {
     }
 }
 
+/// A [`SolInterface`] of [`SolCall`]s, which can also decode the return
+/// values of its calls.
+///
+/// The [`sol`][crate::sol] proc macro implements this trait for the
+/// `<contract_name>Calls` enum of every contract, with the
+/// `<contract_name>Returns` enum as [`Returns`](Self::Returns).
+///
+/// [`SolCall`]: crate::SolCall
+pub trait SolCallInterface: SolInterface {
+    /// The return values of the calls.
+    type Returns;
+
+    /// ABI-decodes the return data of this call.
+    fn abi_decode_returns(&self, data: &[u8], validate: bool) -> Result<Self::Returns>;
+}
+
+/// A collection of [`SolEvent`]s, decoded by their signature hash, which is
+/// the first topic of a log.
+///
+/// Anonymous events do not have a signature hash, and are never decoded.
+///
+/// This trait is implemented for [`Infallible`] to represent an empty
+/// collection.
+///
+/// [`SolEvent`]: crate::SolEvent
+///
+/// ### Implementer's Guide
+///
+/// We do not recommend implementing this trait directly. Instead, we recommend
+/// using the [`sol`][crate::sol] proc macro to parse a Solidity contract
+/// definition.
+pub trait SolEventInterface: Sized {
+    /// The name of this type.
+    const NAME: &'static str;
+
+    /// The number of variants.
+    const COUNT: usize;
+
+    /// Decodes the log with the given topics and data into one of the
+    /// variants of `self`.
+    fn decode_log(topics: &[Word], data: &[u8], validate: bool) -> Result<Self>;
+}
+
+/// An empty [`SolEventInterface`] implementation.
+impl SolEventInterface for Infallible {
+    const NAME: &'static str = "Infallible";
+    const COUNT: usize = 0;
+
+    #[inline]
+    fn decode_log(topics: &[Word], _data: &[u8], _validate: bool) -> Result<Self> {
+        Err(Error::UnknownEvent {
+            name: <Self as SolEventInterface>::NAME,
+            signature: topics.first().copied(),
+        })
+    }
+}
+
 /// A generic contract error.
 ///
 /// Contains a [`Revert`] or [`Panic`] error.
@@ -270,7 +327,7 @@ impl<T: SolInterface> SolInterface for ContractError<T> {
         match selector {
             Revert::SELECTOR => Revert::abi_decode_raw(data, validate).map(Self::Revert),
             Panic::SELECTOR => Panic::abi_decode_raw(data, validate).map(Self::Panic),
-            _ => T::abi_decode(data, validate).map(Self::CustomError),
+            _ => T::abi_decode_raw(selector, data, validate).map(Self::CustomError),
         }
     }
 
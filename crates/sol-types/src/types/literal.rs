@@ -0,0 +1,72 @@
+//! Helpers for rendering values as Solidity literals.
+//!
+//! See [`SolType::to_sol_literal`](crate::SolType::to_sol_literal).
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+/// Renders the given bytes as a hex string literal: `hex"0102"`.
+pub(crate) fn hex_string(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(5 + bytes.len() * 2);
+    s.push_str("hex\"");
+    s.push_str(&hex::encode(bytes));
+    s.push('"');
+    s
+}
+
+/// Renders the given string as a quoted string literal, escaping it as
+/// necessary. Non-ASCII strings are rendered as `unicode"..."` literals.
+pub(crate) fn string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 9);
+    if !s.is_ascii() {
+        out.push_str("unicode");
+    }
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_ascii_control() => {
+                let _ = write!(out, "\\x{:02x}", c as u8);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Joins the given literals with `, ` and wraps them in `open` and `close`.
+pub(crate) fn list(open: char, items: impl IntoIterator<Item = String>, close: char) -> String {
+    let mut s = String::new();
+    s.push(open);
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            s.push_str(", ");
+        }
+        s.push_str(&item);
+    }
+    s.push(close);
+    s
+}
+
+/// Renders a struct literal with named fields: `Name({a: 1, b: 2})`.
+///
+/// Used by the [`sol!`](crate::sol) macro.
+#[doc(hidden)]
+pub fn struct_literal(name: &str, fields: Vec<(&str, String)>) -> String {
+    let fields = fields.into_iter().map(|(name, value)| {
+        let mut field = String::from(name);
+        field.push_str(": ");
+        field.push_str(&value);
+        field
+    });
+    let mut s = String::from(name);
+    s.push('(');
+    s.push_str(&list('{', fields, '}'));
+    s.push(')');
+    s
+}
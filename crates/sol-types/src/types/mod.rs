@@ -10,10 +10,13 @@ mod event;
 pub use event::{EventTopic, SolEvent, TopicList};
 
 mod function;
-pub use function::SolCall;
+pub use function::{CallError, SolCall, SolConstructor};
 
 mod interface;
-pub use interface::{ContractError, GenericContractError, Selectors, SolInterface};
+pub use interface::{
+    ContractError, GenericContractError, Selectors, SolCallInterface, SolEventInterface,
+    SolInterface,
+};
 
 mod r#struct;
 pub use r#struct::SolStruct;
@@ -21,6 +24,38 @@ pub use r#struct::SolStruct;
 mod ty;
 pub use ty::{Encodable, SolType};
 
+#[cfg(feature = "sol-literal")]
+pub(crate) mod literal;
+
+/// Expands to the given [`SolType::to_sol_literal`] implementation only if the
+/// `sol-literal` feature of this crate is enabled.
+///
+/// Used by the [`sol!`](crate::sol) macro, which can't see this crate's
+/// features.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "sol-literal")]
+macro_rules! __sol_literal_impl {
+    ($($item:tt)*) => {
+        $($item)*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "sol-literal"))]
+macro_rules! __sol_literal_impl {
+    ($($item:tt)*) => {};
+}
+
+mod pretty;
+pub use pretty::Pretty;
+
+#[cfg(feature = "serde")]
+pub(crate) mod serde;
+#[cfg(feature = "serde")]
+pub use self::serde::SolSerde;
+
 // Solidity user-defined value types.
 // No exports are needed as the only item is a macro.
 mod udt;
@@ -0,0 +1,88 @@
+//! Human-readable formatting of Solidity values.
+//!
+//! See [`SolType::fmt_pretty`].
+
+use crate::SolType;
+use core::{fmt, marker::PhantomData};
+
+/// Formats a value of a [`SolType`] for humans, with
+/// [`SolType::fmt_pretty`].
+///
+/// Both `Display` and `Debug` are implemented the same way, so that the
+/// wrapper can be used as a `Debug` field. The alternate flag (`{:#}`) formats
+/// arrays, tuples and structs over multiple lines.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{address, U256};
+/// use alloy_sol_types::{sol, SolStruct};
+///
+/// sol! {
+///     struct Transfer {
+///         address to;
+///         uint256 amount;
+///     }
+/// }
+///
+/// let transfer = Transfer {
+///     to: address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045"),
+///     amount: U256::from(100),
+/// };
+/// assert_eq!(
+///     transfer.pretty().to_string(),
+///     "Transfer { to: 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045, amount: 100 }"
+/// );
+/// assert_eq!(
+///     format!("{:#}", transfer.pretty()),
+///     "\
+/// Transfer {
+///     to: 0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045,
+///     amount: 100,
+/// }"
+/// );
+/// ```
+pub struct Pretty<'a, T: SolType + ?Sized> {
+    value: &'a T::RustType,
+    _ty: PhantomData<fn() -> T>,
+}
+
+impl<T: SolType + ?Sized> Clone for Pretty<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: SolType + ?Sized> Copy for Pretty<'_, T> {}
+
+impl<'a, T: SolType + ?Sized> Pretty<'a, T> {
+    /// Wraps the given value.
+    #[inline]
+    pub const fn new(value: &'a T::RustType) -> Self {
+        Self {
+            value,
+            _ty: PhantomData,
+        }
+    }
+}
+
+impl<T: SolType + ?Sized> fmt::Display for Pretty<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt_pretty(self.value, f)
+    }
+}
+
+impl<T: SolType + ?Sized> fmt::Debug for Pretty<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt_pretty(self.value, f)
+    }
+}
+
+/// Writes the given bytes as `0x`-prefixed lowercase hex.
+pub(crate) fn hex(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("0x")?;
+    bytes.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+}
@@ -0,0 +1,338 @@
+use super::data_type::{
+    self as sol_data, ByteCount, IntBitCount, SupportedFixedBytes, SupportedInt,
+};
+use crate::{abi::token::WordToken, SolType};
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::{Address, FixedBytes, Function};
+use core::{fmt, marker::PhantomData, str::FromStr};
+use serde::{
+    de::{self, SeqAccess, Unexpected, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// [`serde`] support for [`SolType`]s, following the JSON ABI conventions for
+/// values.
+///
+/// The Rust types of some Solidity types, like `uint8` and `uint64`, are
+/// shared, so the serialization format is defined on the Solidity type
+/// instead of on the Rust type:
+/// - `intN` and `uintN` are decimal strings;
+/// - `address` is a checksummed hex string. Any case is accepted when
+///   deserializing;
+/// - `bytes`, `bytesN` and `function` are `0x`-prefixed hex strings;
+/// - `bool` and `string` are their native values;
+/// - arrays and tuples are sequences of their elements.
+///
+/// The [`sol!`](crate::sol) macro implements this trait for user-defined value
+/// types, and for the structs and enums with the `#[sol(serde)]` attribute.
+/// For these structs, it also implements [`Serialize`] and [`Deserialize`]
+/// using it for every field:
+///
+/// ```
+/// use alloy_primitives::{address, U256};
+/// use alloy_sol_types::sol;
+///
+/// sol! {
+///     #[sol(serde)]
+///     struct Transfer {
+///         address to;
+///         uint256 amount;
+///         bytes data;
+///     }
+/// }
+///
+/// let transfer = Transfer {
+///     to: address!("d8da6bf26964af9d7eed9e03e53415d37aa96045"),
+///     amount: U256::from(1000),
+///     data: vec![0xab, 0xcd],
+/// };
+/// let json = serde_json::to_string(&transfer).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"to":"0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045","amount":"1000","data":"0xabcd"}"#
+/// );
+/// let decoded: Transfer = serde_json::from_str(&json).unwrap();
+/// assert_eq!(decoded.to, transfer.to);
+/// ```
+///
+/// Structs that already derive `Serialize` or `Deserialize` keep their
+/// derived implementations.
+pub trait SolSerde: SolType {
+    /// Serializes a Rust value of this type.
+    fn serialize<S: Serializer>(rust: &Self::RustType, serializer: S) -> Result<S::Ok, S::Error>;
+
+    /// Deserializes a Rust value of this type.
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self::RustType, D::Error>;
+}
+
+/// Serializes a borrowed Rust value with [`SolSerde`].
+#[doc(hidden)]
+#[allow(missing_debug_implementations)]
+pub struct SerdeRef<'a, T: SolSerde>(pub &'a T::RustType);
+
+impl<T: SolSerde> Serialize for SerdeRef<'_, T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        T::serialize(self.0, serializer)
+    }
+}
+
+/// Deserializes a Rust value with [`SolSerde`].
+#[doc(hidden)]
+#[allow(missing_debug_implementations)]
+pub struct SerdeValue<T: SolSerde>(pub T::RustType);
+
+impl<'de, T: SolSerde> Deserialize<'de> for SerdeValue<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self)
+    }
+}
+
+fn deserialize_from_str<'de, D, T>(deserializer: D, expected: &str) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse()
+        .map_err(|_| de::Error::invalid_value(Unexpected::Str(&s), &expected))
+}
+
+impl SolSerde for sol_data::Bool {
+    #[inline]
+    fn serialize<S: Serializer>(rust: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(*rust)
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+        bool::deserialize(deserializer)
+    }
+}
+
+impl<const BITS: usize> SolSerde for sol_data::Int<BITS>
+where
+    IntBitCount<BITS>: SupportedInt,
+    <IntBitCount<BITS> as SupportedInt>::Int: FromStr,
+{
+    #[inline]
+    fn serialize<S: Serializer>(rust: &Self::RustType, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(rust)
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self::RustType, D::Error> {
+        deserialize_from_str(deserializer, IntBitCount::<BITS>::INT_NAME)
+    }
+}
+
+impl<const BITS: usize> SolSerde for sol_data::Uint<BITS>
+where
+    IntBitCount<BITS>: SupportedInt,
+    <IntBitCount<BITS> as SupportedInt>::Uint: FromStr,
+{
+    #[inline]
+    fn serialize<S: Serializer>(rust: &Self::RustType, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(rust)
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self::RustType, D::Error> {
+        deserialize_from_str(deserializer, IntBitCount::<BITS>::UINT_NAME)
+    }
+}
+
+impl SolSerde for sol_data::Address {
+    #[inline]
+    fn serialize<S: Serializer>(rust: &Address, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut buf = [0; 42];
+        serializer.serialize_str(rust.to_checksum_raw(&mut buf, None))
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+        deserialize_from_str(deserializer, "a hex-encoded address")
+    }
+}
+
+impl SolSerde for sol_data::Function {
+    #[inline]
+    fn serialize<S: Serializer>(rust: &Function, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode_prefixed(rust))
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Function, D::Error> {
+        deserialize_from_str(deserializer, "a hex-encoded function pointer")
+    }
+}
+
+impl<const N: usize> SolSerde for sol_data::FixedBytes<N>
+where
+    ByteCount<N>: SupportedFixedBytes,
+{
+    #[inline]
+    fn serialize<S: Serializer>(rust: &FixedBytes<N>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode_prefixed(rust))
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<FixedBytes<N>, D::Error> {
+        deserialize_from_str(deserializer, ByteCount::<N>::NAME)
+    }
+}
+
+impl SolSerde for sol_data::Bytes {
+    #[inline]
+    fn serialize<S: Serializer>(rust: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode_prefixed(rust))
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        hex::decode(&s).map_err(|_| de::Error::invalid_value(Unexpected::Str(&s), &"a hex string"))
+    }
+}
+
+impl SolSerde for sol_data::String {
+    #[inline]
+    fn serialize<S: Serializer>(rust: &String, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(rust)
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        String::deserialize(deserializer)
+    }
+}
+
+impl<T: SolSerde> SolSerde for sol_data::Array<T> {
+    #[inline]
+    fn serialize<S: Serializer>(rust: &Self::RustType, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(rust.iter().map(SerdeRef::<T>))
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self::RustType, D::Error> {
+        let values = Vec::<SerdeValue<T>>::deserialize(deserializer)?;
+        Ok(values.into_iter().map(|value| value.0).collect())
+    }
+}
+
+impl<T: SolSerde, const N: usize> SolSerde for sol_data::FixedArray<T, N> {
+    #[inline]
+    fn serialize<S: Serializer>(rust: &Self::RustType, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(rust.iter().map(SerdeRef::<T>))
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self::RustType, D::Error> {
+        let values = sol_data::Array::<T>::deserialize(deserializer)?;
+        let len = values.len();
+        values.try_into().map_err(|_| {
+            de::Error::invalid_length(len, &format!("an array of length {N}").as_str())
+        })
+    }
+}
+
+impl<T> SolSerde for sol_data::ZeroAsNone<T>
+where
+    T: for<'a> SolType<TokenType<'a> = WordToken> + SolSerde,
+{
+    #[inline]
+    fn serialize<S: Serializer>(rust: &Self::RustType, serializer: S) -> Result<S::Ok, S::Error> {
+        match rust {
+            Some(value) => serializer.serialize_some(&SerdeRef::<T>(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self::RustType, D::Error> {
+        Option::<SerdeValue<T>>::deserialize(deserializer).map(|value| value.map(|value| value.0))
+    }
+}
+
+impl<T> SolSerde for sol_data::Optional<T>
+where
+    T: SolType + SolSerde,
+    for<'a> T::TokenType<'a>: Default,
+{
+    #[inline]
+    fn serialize<S: Serializer>(rust: &Self::RustType, serializer: S) -> Result<S::Ok, S::Error> {
+        match rust {
+            Some(value) => serializer.serialize_some(&SerdeRef::<T>(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self::RustType, D::Error> {
+        Option::<SerdeValue<T>>::deserialize(deserializer).map(|value| value.map(|value| value.0))
+    }
+}
+
+impl SolSerde for () {
+    #[inline]
+    fn serialize<S: Serializer>((): &(), serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit()
+    }
+
+    #[inline]
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(), D::Error> {
+        <() as Deserialize<'de>>::deserialize(deserializer)
+    }
+}
+
+macro_rules! tuple_impls {
+    ($($ty:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($ty: SolSerde,)+> SolSerde for ($($ty,)+) {
+            fn serialize<S: Serializer>(rust: &Self::RustType, serializer: S) -> Result<S::Ok, S::Error> {
+                const COUNT: usize = 0usize $(+ tuple_impls!(@one $ty))+;
+                let ($($ty,)+) = rust;
+                let mut tuple = serializer.serialize_tuple(COUNT)?;
+                $(
+                    tuple.serialize_element(&SerdeRef::<$ty>($ty))?;
+                )+
+                tuple.end()
+            }
+
+            fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self::RustType, D::Error> {
+                const COUNT: usize = 0usize $(+ tuple_impls!(@one $ty))+;
+
+                struct TupleVisitor<$($ty,)+>(PhantomData<($($ty,)+)>);
+
+                impl<'de, $($ty: SolSerde,)+> Visitor<'de> for TupleVisitor<$($ty,)+> {
+                    type Value = ($($ty::RustType,)+);
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        write!(f, "a tuple of size {COUNT}")
+                    }
+
+                    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                        let mut i = 0;
+                        $(
+                            let $ty = seq
+                                .next_element::<SerdeValue<$ty>>()?
+                                .ok_or_else(|| de::Error::invalid_length(i, &self))?
+                                .0;
+                            i += 1;
+                        )+
+                        let _ = i;
+                        Ok(($($ty,)+))
+                    }
+                }
+
+                deserializer.deserialize_tuple(COUNT, TupleVisitor::<$($ty,)+>(PhantomData))
+            }
+        }
+    };
+
+    (@one $ty:ident) => { 1usize };
+}
+
+all_the_tuples!(tuple_impls);
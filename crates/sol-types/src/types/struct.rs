@@ -2,9 +2,9 @@
 //! Solidity structs logic, particularly for EIP-712 encoding/decoding.
 
 use super::SolType;
-use crate::Eip712Domain;
+use crate::{Eip712Domain, Pretty, Word};
 use alloc::{borrow::Cow, vec::Vec};
-use alloy_primitives::{keccak256, B256};
+use alloy_primitives::{keccak256, keccak256_slices, utils::Keccak256, B256};
 
 /// A Solidity Struct.
 ///
@@ -69,6 +69,20 @@ pub trait SolStruct: SolType<RustType = Self> {
     /// <https://eips.ethereum.org/EIPS/eip-712#definition-of-encodedata>
     fn eip712_encode_data(&self) -> Vec<u8>;
 
+    /// EIP-712 `encodeData`, passing each encoded member word to `out` in
+    /// order instead of concatenating them.
+    ///
+    /// The default implementation chunks [`eip712_encode_data`]; the
+    /// [`sol`][crate::sol] macro overrides it to not allocate.
+    ///
+    /// [`eip712_encode_data`]: SolStruct::eip712_encode_data
+    #[inline]
+    fn eip712_encode_data_to(&self, out: &mut impl FnMut(&Word)) {
+        for chunk in self.eip712_encode_data().chunks_exact(32) {
+            out(&Word::from_slice(chunk));
+        }
+    }
+
     /// EIP-712 `hashStruct`
     /// <https://eips.ethereum.org/EIPS/eip-712#definition-of-hashstruct>
     #[inline]
@@ -78,6 +92,39 @@ pub trait SolStruct: SolType<RustType = Self> {
         keccak256(type_hash)
     }
 
+    /// EIP-712 `hashStruct`, computed by streaming the type hash and the
+    /// [encoded member words](SolStruct::eip712_encode_data_to) into a
+    /// [`Keccak256`] hasher.
+    ///
+    /// The result is the same as [`eip712_hash_struct`], but the encoded data
+    /// is not collected into a buffer first. For [`sol!`](crate::sol) structs
+    /// whose members are all value types, like `address` and `uint256`, this
+    /// does not allocate at all.
+    ///
+    /// Other members are hashed with [`SolType::eip712_data_word`], which
+    /// allocates for arrays and nested structs, and a struct with nested
+    /// struct members builds its `encodeType` string to compute its type
+    /// hash.
+    ///
+    /// [`eip712_hash_struct`]: SolStruct::eip712_hash_struct
+    #[inline]
+    fn eip712_hash_struct_no_alloc(&self) -> B256 {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.eip712_type_hash());
+        self.eip712_encode_data_to(&mut |word| hasher.update(word));
+        hasher.finalize()
+    }
+
+    /// Returns a wrapper that formats this struct for humans, as
+    /// `Name { a: 1, b: 0x0102 }`, with [`SolType::fmt_pretty`].
+    ///
+    /// Use the alternate flag (`{:#}`) to format it over multiple lines, with
+    /// nested structs indented.
+    #[inline]
+    fn pretty(&self) -> Pretty<'_, Self> {
+        Pretty::new(self)
+    }
+
     /// EIP-712 `signTypedData`
     /// <https://eips.ethereum.org/EIPS/eip-712#specification-of-the-eth_signtypeddata-json-rpc>
     #[inline]
@@ -85,11 +132,6 @@ pub trait SolStruct: SolType<RustType = Self> {
         let domain_separator = domain.hash_struct();
         let struct_hash = self.eip712_hash_struct();
 
-        let mut digest_input = [0u8; 2 + 32 + 32];
-        digest_input[0] = 0x19;
-        digest_input[1] = 0x01;
-        digest_input[2..34].copy_from_slice(&domain_separator[..]);
-        digest_input[34..66].copy_from_slice(&struct_hash[..]);
-        keccak256(digest_input)
+        keccak256_slices(&[&[0x19, 0x01], &domain_separator[..], &struct_hash[..]])
     }
 }
@@ -1,8 +1,9 @@
 use crate::{
-    abi::{self, TokenSeq, TokenType},
-    Result, Word,
+    abi::{self, DecodeOptions, TokenSeq, TokenType},
+    Error, Result, Word,
 };
-use alloc::{borrow::Cow, vec::Vec};
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+use alloy_primitives::{Bytes, Selector};
 
 /// An encodable is any type that may be encoded via a given [`SolType`].
 ///
@@ -94,6 +95,62 @@ pub trait Encodable<T: ?Sized + SolType> {
 /// exist at runtime. They ONLY contain information about the type, they do not
 /// carry any data.
 ///
+/// ### Encoding and decoding
+///
+/// Each encoding method has exactly one matching decoding method:
+///
+/// | Encode with             | Decode with             |
+/// |-------------------------|-------------------------|
+/// | [`abi_encode`]          | [`abi_decode`]          |
+/// | [`abi_encode_params`]   | [`abi_decode_params`]   |
+/// | [`abi_encode_sequence`] | [`abi_decode_sequence`] |
+///
+/// `abi_encode` encodes a value as if it were wrapped in a single-element
+/// tuple, like a lone return value or event field. `abi_encode_params` and
+/// `abi_encode_sequence` encode the fields of a tuple one after another, like
+/// function arguments, without the wrapping tuple. For a tuple containing a
+/// dynamic type, this means that `abi_encode` emits an extra leading offset
+/// word:
+///
+/// ```
+/// use alloy_primitives::hex;
+/// use alloy_sol_types::{sol_data, SolType};
+///
+/// type Params = (sol_data::Bytes,);
+/// let value = (hex!("deadbeef").to_vec(),);
+///
+/// let params = Params::abi_encode_params(&value);
+/// assert_eq!(
+///     params,
+///     hex!(
+///         "0000000000000000000000000000000000000000000000000000000000000020"
+///         "0000000000000000000000000000000000000000000000000000000000000004"
+///         "deadbeef00000000000000000000000000000000000000000000000000000000"
+///     )
+/// );
+/// assert_eq!(Params::abi_decode_params(&params, true).unwrap(), value);
+///
+/// let single = Params::abi_encode(&value);
+/// assert_eq!(single[32..], params);
+/// assert_eq!(Params::abi_decode(&single, true).unwrap(), value);
+///
+/// // Mixing the methods fails, and the error says which method to use instead.
+/// let err = Params::abi_decode(&params, true).unwrap_err();
+/// assert!(err.to_string().ends_with("use `abi_decode_params` instead"), "{err}");
+/// let err = Params::abi_decode_params(&single, true).unwrap_err();
+/// assert!(err.to_string().ends_with("use `abi_decode` instead"), "{err}");
+/// ```
+///
+/// Without `validate`, decoding `abi_encode` output with `abi_decode_params`
+/// may succeed with garbage data, so always validate untrusted input.
+///
+/// [`abi_encode`]: SolType::abi_encode
+/// [`abi_decode`]: SolType::abi_decode
+/// [`abi_encode_params`]: SolType::abi_encode_params
+/// [`abi_decode_params`]: SolType::abi_decode_params
+/// [`abi_encode_sequence`]: SolType::abi_encode_sequence
+/// [`abi_decode_sequence`]: SolType::abi_decode_sequence
+///
 /// ### Implementer's Guide
 ///
 /// We do not recommend implementing this trait directly. Instead, we recommend
@@ -161,6 +218,17 @@ pub trait SolType {
     /// Detokenize a value from the given token.
     fn detokenize(token: Self::TokenType<'_>) -> Self::RustType;
 
+    /// Detokenize the elements of a dynamic array of this type.
+    ///
+    /// Used by [`sol_data::Array`](crate::sol_data::Array). The default
+    /// implementation maps [`detokenize`][SolType::detokenize] over the
+    /// tokens; types can override it with a specialized loop.
+    #[doc(hidden)]
+    #[inline]
+    fn detokenize_array(tokens: Vec<Self::TokenType<'_>>) -> Vec<Self::RustType> {
+        tokens.into_iter().map(Self::detokenize).collect()
+    }
+
     /// Tokenizes the given value into this type's token.
     fn tokenize<E: Encodable<Self>>(rust: &E) -> Self::TokenType<'_> {
         rust.to_tokens()
@@ -176,6 +244,125 @@ pub trait SolType {
     /// <https://eips.ethereum.org/EIPS/eip-712#definition-of-encodedata>
     fn eip712_data_word(rust: &Self::RustType) -> Word;
 
+    /// Renders the given value as a Solidity literal.
+    ///
+    /// This is useful for generating test fixtures, e.g. for Foundry tests
+    /// that need a decoded value as Solidity source:
+    /// - `bool`, `intN` and `uintN` are rendered as-is, in decimal;
+    /// - `address` is rendered as a checksummed address literal;
+    /// - `bytes` and `function` are rendered as hex string literals:
+    ///   `hex"0102"`;
+    /// - `bytesN` is rendered as a hex number literal: `0x0102`, except for
+    ///   `bytes20` which is rendered as `bytes20(hex"...")` to not be confused
+    ///   with an address;
+    /// - `string` is rendered as an escaped string literal, prefixed with
+    ///   `unicode` if it contains non-ASCII characters;
+    /// - tuples are rendered as `(a, b, c)`;
+    /// - structs generated by the [`sol!`](crate::sol) macro are rendered as
+    ///   `Name({a: 1, b: 2})`, enums as `Name.Variant`, and user-defined value
+    ///   types as `Name.wrap(value)`.
+    ///
+    /// The default implementation renders the value as an `abi.decode` call
+    /// of its ABI encoding, which works for any type. Arrays use it too:
+    /// Solidity has no literal for dynamic arrays, and an inline array
+    /// `[a, b]` is a fixed-size array typed after its first element, so it
+    /// isn't assignable to most array types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{Address, U256};
+    /// use alloy_sol_types::{sol_data, SolType};
+    ///
+    /// type MyTy = (sol_data::Uint<256>, sol_data::Bytes, sol_data::Address);
+    /// let value = (U256::from(1), vec![1, 2], Address::ZERO);
+    /// assert_eq!(
+    ///     MyTy::to_sol_literal(&value),
+    ///     r#"(1, hex"0102", 0x0000000000000000000000000000000000000000)"#
+    /// );
+    ///
+    /// type MyArray = sol_data::Array<sol_data::Uint<8>>;
+    /// assert_eq!(
+    ///     MyArray::to_sol_literal(&vec![]),
+    ///     concat!(
+    ///         r#"abi.decode(hex""#,
+    ///         "0000000000000000000000000000000000000000000000000000000000000020",
+    ///         "0000000000000000000000000000000000000000000000000000000000000000",
+    ///         r#"", (uint8[]))"#,
+    ///     )
+    /// );
+    /// ```
+    #[cfg(feature = "sol-literal")]
+    fn to_sol_literal(rust: &Self::RustType) -> alloc::string::String {
+        alloc::format!(
+            "abi.decode(hex\"{}\", ({}))",
+            hex::encode(Self::abi_encode(rust)),
+            Self::sol_type_name()
+        )
+    }
+
+    /// Formats the given value for humans, in a Rust-like syntax.
+    ///
+    /// This is used by [`Pretty`](crate::Pretty), which implements `Display`
+    /// and `Debug` with it, and by
+    /// [`SolStruct::pretty`](crate::SolStruct::pretty):
+    /// - `bool`, `intN` and `uintN` are formatted as-is, in decimal;
+    /// - `address` is formatted as a checksummed address;
+    /// - `bytes`, `bytesN` and `function` are hex-encoded: `0x0102`;
+    /// - `string` is formatted as a quoted, escaped string;
+    /// - arrays are formatted as `[a, b]`, and tuples as `(a, b)`;
+    /// - structs generated by the [`sol!`](crate::sol) macro are formatted as
+    ///   `Name { a: 1, b: 2 }`, enums as `Name.Variant`, and user-defined value
+    ///   types as `Name(value)`.
+    ///
+    /// Like `Debug`, the alternate flag (`{:#}`) formats arrays, tuples and
+    /// structs over multiple lines, with nested values indented.
+    ///
+    /// The default implementation formats the type name and the hex-encoded
+    /// ABI encoding of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{Address, U256};
+    /// use alloy_sol_types::{sol_data, Pretty};
+    ///
+    /// type MyTy = (
+    ///     sol_data::Uint<256>,
+    ///     sol_data::Bytes,
+    ///     sol_data::Array<sol_data::Address>,
+    /// );
+    /// let value = (U256::from(1), vec![1, 2], vec![Address::ZERO]);
+    /// assert_eq!(
+    ///     Pretty::<MyTy>::new(&value).to_string(),
+    ///     "(1, 0x0102, [0x0000000000000000000000000000000000000000])"
+    /// );
+    /// ```
+    fn fmt_pretty(rust: &Self::RustType, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}(0x{})",
+            Self::sol_type_name(),
+            hex::encode(Self::abi_encode(rust))
+        )
+    }
+
+    /// Calculate the length of the [packed
+    /// encoding][SolType::abi_encode_packed] of the data.
+    ///
+    /// Unlike [`abi_encoded_size`][SolType::abi_encoded_size], this is the
+    /// unpadded size: e.g. 1 for `bool` and `uint8`, N for `bytesN`, and the
+    /// content length for `bytes` and `string`.
+    ///
+    /// The default implementation packs the data and returns its length, so
+    /// it should be overridden when the size can be computed directly.
+    #[inline]
+    fn packed_encoded_size(rust: &Self::RustType) -> usize {
+        let mut out = Vec::new();
+        Self::abi_encode_packed_to(rust, &mut out);
+        out.len()
+    }
+
     /// Non-standard Packed Mode ABI encoding.
     ///
     /// See [`abi_encode_packed`][SolType::abi_encode_packed] for more details.
@@ -192,14 +379,23 @@ pub trait SolType {
     /// More information can be found in the [Solidity docs](https://docs.soliditylang.org/en/latest/abi-spec.html#non-standard-packed-mode).
     #[inline]
     fn abi_encode_packed(rust: &Self::RustType) -> Vec<u8> {
-        let mut out = Vec::new();
+        let mut out = Vec::with_capacity(Self::packed_encoded_size(rust));
         Self::abi_encode_packed_to(rust, &mut out);
         out
     }
 
+    /// Non-standard Packed Mode ABI encoding, into [`Bytes`].
+    ///
+    /// See [`abi_encode_packed`][SolType::abi_encode_packed] for more details.
+    #[inline]
+    fn abi_encode_packed_bytes(rust: &Self::RustType) -> Bytes {
+        Self::abi_encode_packed(rust).into()
+    }
+
     /// Encode a single ABI token by wrapping it in a 1-length sequence.
     #[inline]
     fn abi_encode<E: Encodable<Self>>(rust: &E) -> Vec<u8> {
+        abi_trace_span!("abi_encode", sol_type = %Self::sol_type_name());
         abi::encode(&rust.to_tokens())
     }
 
@@ -209,6 +405,7 @@ pub trait SolType {
     where
         for<'a> Self::TokenType<'a>: TokenSeq<'a>,
     {
+        abi_trace_span!("abi_encode_sequence", sol_type = %Self::sol_type_name());
         abi::encode_sequence(&rust.to_tokens())
     }
 
@@ -218,14 +415,131 @@ pub trait SolType {
     where
         for<'a> Self::TokenType<'a>: TokenSeq<'a>,
     {
+        abi_trace_span!("abi_encode_params", sol_type = %Self::sol_type_name());
         abi::encode_params(&rust.to_tokens())
     }
 
+    /// Encode a single ABI token by wrapping it in a 1-length sequence,
+    /// appending it to the given buffer.
+    ///
+    /// See [`abi_encode`][SolType::abi_encode] for more details.
+    ///
+    /// This can be used to concatenate many encoded values into the same
+    /// buffer, reusing its capacity instead of returning a new [`Vec`] for
+    /// each one.
+    #[inline]
+    fn abi_encode_to<E: Encodable<Self>>(rust: &E, out: &mut Vec<u8>) {
+        abi_trace_span!("abi_encode_to", sol_type = %Self::sol_type_name());
+        abi::encode_to(&rust.to_tokens(), out)
+    }
+
+    /// Encode an ABI sequence, appending it to the given buffer.
+    ///
+    /// See [`abi_encode_sequence`][SolType::abi_encode_sequence] for more
+    /// details.
+    #[inline]
+    fn abi_encode_sequence_to<E: Encodable<Self>>(rust: &E, out: &mut Vec<u8>)
+    where
+        for<'a> Self::TokenType<'a>: TokenSeq<'a>,
+    {
+        abi_trace_span!("abi_encode_sequence_to", sol_type = %Self::sol_type_name());
+        abi::encode_sequence_to(&rust.to_tokens(), out)
+    }
+
+    /// Encode an ABI sequence suitable for function parameters, appending it
+    /// to the given buffer.
+    ///
+    /// See [`abi_encode_params`][SolType::abi_encode_params] for more details.
+    #[inline]
+    fn abi_encode_params_to<E: Encodable<Self>>(rust: &E, out: &mut Vec<u8>)
+    where
+        for<'a> Self::TokenType<'a>: TokenSeq<'a>,
+    {
+        abi_trace_span!("abi_encode_params_to", sol_type = %Self::sol_type_name());
+        abi::encode_params_to(&rust.to_tokens(), out)
+    }
+
+    /// Encode a single ABI token by wrapping it in a 1-length sequence, into
+    /// [`Bytes`].
+    ///
+    /// See [`abi_encode`][SolType::abi_encode] for more details.
+    #[inline]
+    fn abi_encode_bytes<E: Encodable<Self>>(rust: &E) -> Bytes {
+        Self::abi_encode(rust).into()
+    }
+
+    /// Encode an ABI sequence, into [`Bytes`].
+    ///
+    /// See [`abi_encode_sequence`][SolType::abi_encode_sequence] for more
+    /// details.
+    #[inline]
+    fn abi_encode_sequence_bytes<E: Encodable<Self>>(rust: &E) -> Bytes
+    where
+        for<'a> Self::TokenType<'a>: TokenSeq<'a>,
+    {
+        Self::abi_encode_sequence(rust).into()
+    }
+
+    /// Encode an ABI sequence suitable for function parameters, into
+    /// [`Bytes`].
+    ///
+    /// See [`abi_encode_params`][SolType::abi_encode_params] for more details.
+    #[inline]
+    fn abi_encode_params_bytes<E: Encodable<Self>>(rust: &E) -> Bytes
+    where
+        for<'a> Self::TokenType<'a>: TokenSeq<'a>,
+    {
+        Self::abi_encode_params(rust).into()
+    }
+
     /// Decode a Rust type from an ABI blob.
     #[inline]
     fn abi_decode(data: &[u8], validate: bool) -> Result<Self::RustType> {
-        abi::decode::<Self::TokenType<'_>>(data, validate)
+        abi_trace_span!(
+            "abi_decode",
+            sol_type = %Self::sol_type_name(),
+            len = data.len(),
+            validate,
+        );
+        abi_trace_result!(abi::decode::<Self::TokenType<'_>>(data, validate)
             .and_then(|t| check_decode::<Self>(t, validate))
+            .map_err(|e| params_hint::<Self>(e, data)))
+    }
+
+    /// ABI-decode the given data with the given [`DecodeOptions`].
+    ///
+    /// With [`canonical`](DecodeOptions::canonical) set, the data is decoded
+    /// leniently and must then be byte-for-byte equal to the encoding of the
+    /// decoded value, which also rejects trailing data and unusual offsets.
+    /// Otherwise, this is the same as [`abi_decode`](SolType::abi_decode).
+    ///
+    /// ```
+    /// use alloy_sol_types::{abi::DecodeOptions, sol_data, Error, SolType};
+    ///
+    /// // an address with non-zero padding
+    /// let mut data = [0u8; 32];
+    /// data[0] = 0x01;
+    /// data[31] = 0x02;
+    /// let options = DecodeOptions::new().with_canonical(true);
+    /// assert_eq!(
+    ///     sol_data::Address::abi_decode_with_options(&data, options),
+    ///     Err(Error::NonCanonical { offset: 0 })
+    /// );
+    /// assert!(sol_data::Address::abi_decode(&data, false).is_ok());
+    /// ```
+    #[inline]
+    fn abi_decode_with_options(data: &[u8], options: DecodeOptions) -> Result<Self::RustType> {
+        if !options.canonical {
+            return Self::abi_decode(data, options.validate)
+        }
+        let value = Self::abi_decode(data, false)?;
+        abi::check_canonical(data, &Self::abi_encode(&value))?;
+        if options.validate {
+            // type checks that do not affect the encoding, such as UTF-8
+            Self::abi_decode(data, true)
+        } else {
+            Ok(value)
+        }
     }
 
     /// ABI-decode the given data
@@ -234,8 +548,41 @@ pub trait SolType {
     where
         Self::TokenType<'de>: TokenSeq<'de>,
     {
-        abi::decode_params::<Self::TokenType<'_>>(data, validate)
+        abi_trace_span!(
+            "abi_decode_params",
+            sol_type = %Self::sol_type_name(),
+            len = data.len(),
+            validate,
+        );
+        abi_trace_result!(abi::decode_params::<Self::TokenType<'_>>(data, validate)
             .and_then(|t| check_decode::<Self>(t, validate))
+            .map_err(|e| single_hint::<Self>(e, data)))
+    }
+
+    /// ABI-decode function calldata, **with** its 4-byte selector, as
+    /// parameters.
+    ///
+    /// The selector is not checked, but it is returned alongside the decoded
+    /// parameters so that it can be compared against an expected value.
+    /// Returns [`Error::Overrun`](crate::Error::Overrun) if the data is
+    /// shorter than 4 bytes.
+    ///
+    /// If the function is known at compile time, prefer
+    /// [`SolCall::abi_decode`](crate::SolCall::abi_decode), which also checks
+    /// the selector.
+    #[inline]
+    fn abi_decode_calldata<'de>(
+        data: &'de [u8],
+        validate: bool,
+    ) -> Result<(Selector, Self::RustType)>
+    where
+        Self::TokenType<'de>: TokenSeq<'de>,
+    {
+        if data.len() < 4 {
+            return Err(crate::Error::Overrun)
+        }
+        let (selector, params) = data.split_at(4);
+        Self::abi_decode_params(params, validate).map(|r| (Selector::from_slice(selector), r))
     }
 
     /// ABI-decode a Rust type from an ABI blob.
@@ -244,9 +591,69 @@ pub trait SolType {
     where
         Self::TokenType<'de>: TokenSeq<'de>,
     {
-        abi::decode_sequence::<Self::TokenType<'_>>(data, validate)
+        abi_trace_span!(
+            "abi_decode_sequence",
+            sol_type = %Self::sol_type_name(),
+            len = data.len(),
+            validate,
+        );
+        abi_trace_result!(abi::decode_sequence::<Self::TokenType<'_>>(data, validate)
             .and_then(|t| check_decode::<Self>(t, validate))
+            .map_err(|e| single_hint::<Self>(e, data)))
+    }
+}
+
+/// The leading offset word that [`SolType::abi_encode`] emits for dynamic
+/// values.
+const SINGLE_OFFSET: Word = Word::with_last_byte(32);
+
+/// Called when [`SolType::abi_decode`] fails: if the data decodes once the
+/// leading offset word is restored, it was most likely encoded with
+/// [`SolType::abi_encode_params`], so point the caller to the right method.
+///
+/// The retry always validates, so that only an exact match is reported.
+#[cold]
+fn params_hint<T: ?Sized + SolType>(error: Error, data: &[u8]) -> Error {
+    if T::DYNAMIC {
+        let mut prefixed = Vec::with_capacity(32 + data.len());
+        prefixed.extend_from_slice(SINGLE_OFFSET.as_slice());
+        prefixed.extend_from_slice(data);
+        let decoded = abi::decode::<T::TokenType<'_>>(&prefixed, true)
+            .and_then(|t| check_decode::<T>(t, true));
+        if decoded.is_ok() {
+            return Error::WrongDecodeMethod {
+                encoded_with: "abi_encode_params",
+                decode_with: "abi_decode_params",
+                error: Box::new(error),
+            }
+        }
+    }
+    error
+}
+
+/// Called when [`SolType::abi_decode_params`] or
+/// [`SolType::abi_decode_sequence`] fail: if the data starts with an offset
+/// word and decodes with [`SolType::abi_decode`], it was most likely encoded
+/// with [`SolType::abi_encode`], so point the caller to the right method.
+#[cold]
+fn single_hint<'de, T: ?Sized + SolType>(error: Error, data: &'de [u8]) -> Error
+where
+    T::TokenType<'de>: TokenSeq<'de>,
+{
+    if <T::TokenType<'de> as TokenSeq<'de>>::IS_TUPLE
+        && T::DYNAMIC
+        && data.starts_with(SINGLE_OFFSET.as_slice())
+        && abi::decode::<T::TokenType<'_>>(data, true)
+            .and_then(|t| check_decode::<T>(t, true))
+            .is_ok()
+    {
+        return Error::WrongDecodeMethod {
+            encoded_with: "abi_encode",
+            decode_with: "abi_decode",
+            error: Box::new(error),
+        }
     }
+    error
 }
 
 fn check_decode<T: ?Sized + SolType>(
@@ -61,7 +61,9 @@ macro_rules! define_udt {
             type RustType = <$underlying as $crate::SolType>::RustType;
             type TokenType<'a> = <$underlying as $crate::SolType>::TokenType<'a>;
 
-            const DYNAMIC: bool = false;
+            const ENCODED_SIZE: $crate::private::Option<usize> =
+                <$underlying as $crate::SolType>::ENCODED_SIZE;
+            const DYNAMIC: bool = <$underlying as $crate::SolType>::DYNAMIC;
 
             #[inline]
             fn sol_type_name() -> $crate::private::Cow<'static, str> {
@@ -89,10 +91,27 @@ macro_rules! define_udt {
                 <Self as $crate::SolType>::tokenize(rust).0
             }
 
+            #[inline]
+            fn packed_encoded_size(rust: &Self::RustType) -> usize {
+                <$underlying as $crate::SolType>::packed_encoded_size(rust)
+            }
+
             #[inline]
             fn abi_encode_packed_to(rust: &Self::RustType, out: &mut $crate::private::Vec<u8>) {
                 <$underlying as $crate::SolType>::abi_encode_packed_to(rust, out)
             }
+
+            #[inline]
+            fn fmt_pretty(
+                rust: &Self::RustType,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                f.debug_tuple(Self::NAME)
+                    .field(&$crate::Pretty::<$underlying>::new(rust))
+                    .finish()
+            }
+
+            $crate::__udt_sol_literal_impl!($underlying);
         }
 
         impl $crate::EventTopic for $name {
@@ -111,6 +130,8 @@ macro_rules! define_udt {
                 <$underlying as $crate::EventTopic>::encode_topic(rust)
             }
         }
+
+        $crate::__udt_serde_impl!($name, $underlying);
     };
 
     (
@@ -171,3 +192,57 @@ macro_rules! define_udt {
         );
     };
 }
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "serde")]
+macro_rules! __udt_serde_impl {
+    ($name:ident, $underlying:ty) => {
+        impl $crate::SolSerde for $name {
+            #[inline]
+            fn serialize<S: $crate::private::serde::Serializer>(
+                rust: &Self::RustType,
+                serializer: S,
+            ) -> $crate::private::Result<S::Ok, S::Error> {
+                <$underlying as $crate::SolSerde>::serialize(rust, serializer)
+            }
+
+            #[inline]
+            fn deserialize<'de, D: $crate::private::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> $crate::private::Result<Self::RustType, D::Error> {
+                <$underlying as $crate::SolSerde>::deserialize(deserializer)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "serde"))]
+macro_rules! __udt_serde_impl {
+    ($name:ident, $underlying:ty) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "sol-literal")]
+macro_rules! __udt_sol_literal_impl {
+    ($underlying:ty) => {
+        #[inline]
+        fn to_sol_literal(rust: &Self::RustType) -> $crate::private::String {
+            let mut s = $crate::private::String::from(Self::NAME);
+            s.push_str(".wrap(");
+            s.push_str(&<$underlying as $crate::SolType>::to_sol_literal(rust));
+            s.push(')');
+            s
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "sol-literal"))]
+macro_rules! __udt_sol_literal_impl {
+    ($underlying:ty) => {};
+}
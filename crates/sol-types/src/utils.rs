@@ -55,6 +55,68 @@ pub const fn just_ok<T>(_: &T) -> crate::Result<()> {
     Ok(())
 }
 
+/// Returns the index of `selector` in `selectors`, using the perfect hash
+/// table generated by the [`sol!`](crate::sol) macro for the selectors of an
+/// interface.
+///
+/// The selector is first assigned a bucket by its high bits, and then a slot
+/// by hashing it with the bucket's displacement. `displacements` and `slots`
+/// must have power of two lengths.
+#[doc(hidden)]
+#[inline]
+pub const fn selector_index(
+    selectors: &[[u8; 4]],
+    displacements: &[u32],
+    slots: &[u16],
+    selector: [u8; 4],
+) -> Option<usize> {
+    let key = u32::from_be_bytes(selector);
+    let bucket = high_bits(key, displacements.len());
+    let slot = high_bits(
+        (key ^ displacements[bucket]).wrapping_mul(0x9e37_79b9),
+        slots.len(),
+    );
+    let index = slots[slot] as usize;
+    if index < selectors.len() && u32::from_be_bytes(selectors[index]) == key {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+/// Returns the index of `selector` in the sorted `selectors` with a binary
+/// search.
+///
+/// Used by the [`sol!`](crate::sol) macro instead of [`selector_index`] when it
+/// can't generate a perfect hash table for the selectors of an interface.
+#[doc(hidden)]
+#[inline]
+pub const fn selector_index_sorted(selectors: &[[u8; 4]], selector: [u8; 4]) -> Option<usize> {
+    let key = u32::from_be_bytes(selector);
+    let (mut lo, mut hi) = (0, selectors.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let x = u32::from_be_bytes(selectors[mid]);
+        if x == key {
+            return Some(mid)
+        } else if x < key {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    None
+}
+
+/// Returns the `log2(len)` high bits of `x`.
+#[inline(always)]
+const fn high_bits(x: u32, len: usize) -> usize {
+    match x.checked_shr(32 - len.trailing_zeros()) {
+        Some(bits) => bits as usize,
+        None => 0,
+    }
+}
+
 #[inline]
 pub(crate) fn check_zeroes(data: &[u8]) -> bool {
     data.iter().all(|b| *b == 0)
@@ -110,4 +172,20 @@ mod tests {
             b256!("00000000000000000000000000000000000000000000000000000000ffffffff")
         );
     }
+    #[test]
+    fn test_selector_index_sorted() {
+        let selectors = [
+            [0, 0, 0, 1],
+            [0x12, 0, 0, 0],
+            [0x42, 0x96, 0x6c, 0x68],
+            [0xff; 4],
+        ];
+        for (i, &selector) in selectors.iter().enumerate() {
+            assert_eq!(selector_index_sorted(&selectors, selector), Some(i));
+            assert_eq!(selector_index_sorted(&selectors[..i], selector), None);
+        }
+        assert_eq!(selector_index_sorted(&selectors, [0; 4]), None);
+        assert_eq!(selector_index_sorted(&selectors, [0x42; 4]), None);
+        assert_eq!(selector_index_sorted(&[], [0; 4]), None);
+    }
 }
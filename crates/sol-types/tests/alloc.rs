@@ -0,0 +1,95 @@
+//! Checks that encoding into an existing buffer does not allocate the output,
+//! and that EIP-712 struct hashing does not allocate for value-type members.
+
+use alloy_primitives::{bytes::BytesMut, Address, U256};
+use alloy_sol_types::{abi, sol, sol_data, SolStruct, SolType};
+
+#[path = "../../primitives/tests/common/counting_alloc.rs"]
+mod counting_alloc;
+
+/// Returns the number of bytes allocated by `f`.
+fn count_allocated(f: impl FnOnce()) -> usize {
+    counting_alloc::count_allocations(f).1.bytes
+}
+
+// The tokens of these types borrow the value, so only the encoder allocates.
+type MyTuple = (
+    sol_data::Bytes,
+    sol_data::FixedArray<sol_data::Uint<256>, 4>,
+);
+
+#[test]
+fn encode_to_does_not_allocate_output() {
+    let value = (vec![0x11; 4096], [U256::from(1); 4]);
+    let expected = MyTuple::abi_encode_sequence(&value);
+    let len = expected.len();
+
+    // sanity check for the allocator
+    assert!(count_allocated(|| drop(MyTuple::abi_encode_sequence(&value))) >= len);
+
+    // only the stack of suffix offsets
+    let max = 64;
+
+    let mut out = Vec::with_capacity(len);
+    let n = count_allocated(|| MyTuple::abi_encode_sequence_to(&value, &mut out));
+    assert!(n <= max, "{n}");
+    assert_eq!(out, expected);
+
+    out.clear();
+    let n = count_allocated(|| MyTuple::abi_encode_params_to(&value, &mut out));
+    assert!(n <= max, "{n}");
+    assert_eq!(out, expected);
+
+    let expected = MyTuple::abi_encode(&value);
+    let mut out = Vec::with_capacity(expected.len());
+    let n = count_allocated(|| MyTuple::abi_encode_to(&value, &mut out));
+    assert!(n <= max, "{n}");
+    assert_eq!(out, expected);
+
+    let mut out = BytesMut::with_capacity(len);
+    let tokens = MyTuple::tokenize(&value);
+    let n = count_allocated(|| abi::encode_into_bytes_mut(&tokens, &mut out));
+    assert!(n <= max, "{n}");
+    assert_eq!(out, MyTuple::abi_encode_sequence(&value));
+}
+
+sol! {
+    struct Permit {
+        address owner;
+        address spender;
+        uint256 value;
+        uint256 nonce;
+        uint256 deadline;
+    }
+
+    struct Batch {
+        Permit permit;
+        uint256[] amounts;
+    }
+}
+
+#[test]
+fn eip712_hash_struct_no_alloc() {
+    let permit = Permit {
+        owner: Address::repeat_byte(0x11),
+        spender: Address::repeat_byte(0x22),
+        value: U256::from(1_000_000u64),
+        nonce: U256::from(7),
+        deadline: U256::MAX,
+    };
+    let expected = permit.eip712_hash_struct();
+    let mut hash = None;
+    let n = count_allocated(|| hash = Some(permit.eip712_hash_struct_no_alloc()));
+    assert_eq!(n, 0);
+    assert_eq!(hash, Some(expected));
+
+    // nested structs and arrays are hashed with `eip712_data_word`
+    let batch = Batch {
+        permit,
+        amounts: vec![U256::from(1), U256::from(2)],
+    };
+    let expected = batch.eip712_hash_struct();
+    let n = count_allocated(|| hash = Some(batch.eip712_hash_struct_no_alloc()));
+    assert!(n > 0);
+    assert_eq!(hash, Some(expected));
+}
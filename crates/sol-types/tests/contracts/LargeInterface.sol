@@ -0,0 +1,176 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+/// An interface with many functions and errors, used to test and benchmark
+/// the selector dispatch of the generated `Calls` and `Errors` enums.
+interface LargeInterface {
+    function getToken() external;
+    function getPool(uint256 id) external;
+    function getPosition(address account, uint256 amount) external;
+    function getReward(bytes32 key, bool enabled) external returns (uint256);
+    function getFee(address[] calldata accounts) external returns (uint256);
+    function getOwner(string calldata name, uint8 decimals) external returns (uint256);
+    function getOracle(uint256 id, bytes calldata data) external returns (bool);
+    function getVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (bool);
+    function setToken() external returns (bool);
+    function setPool(uint256 id) external returns (address);
+    function setPosition(address account, uint256 amount) external returns (address);
+    function setReward(bytes32 key, bool enabled) external returns (address);
+    function setFee(address[] calldata accounts) external;
+    function setOwner(string calldata name, uint8 decimals) external;
+    function setOracle(uint256 id, bytes calldata data) external;
+    function setVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (uint256);
+    function addToken() external returns (uint256);
+    function addPool(uint256 id) external returns (uint256);
+    function addPosition(address account, uint256 amount) external returns (bool);
+    function addReward(bytes32 key, bool enabled) external returns (bool);
+    function addFee(address[] calldata accounts) external returns (bool);
+    function addOwner(string calldata name, uint8 decimals) external returns (address);
+    function addOracle(uint256 id, bytes calldata data) external returns (address);
+    function addVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (address);
+    function removeToken() external;
+    function removePool(uint256 id) external;
+    function removePosition(address account, uint256 amount) external;
+    function removeReward(bytes32 key, bool enabled) external returns (uint256);
+    function removeFee(address[] calldata accounts) external returns (uint256);
+    function removeOwner(string calldata name, uint8 decimals) external returns (uint256);
+    function removeOracle(uint256 id, bytes calldata data) external returns (bool);
+    function removeVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (bool);
+    function updateToken() external returns (bool);
+    function updatePool(uint256 id) external returns (address);
+    function updatePosition(address account, uint256 amount) external returns (address);
+    function updateReward(bytes32 key, bool enabled) external returns (address);
+    function updateFee(address[] calldata accounts) external;
+    function updateOwner(string calldata name, uint8 decimals) external;
+    function updateOracle(uint256 id, bytes calldata data) external;
+    function updateVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (uint256);
+    function claimToken() external returns (uint256);
+    function claimPool(uint256 id) external returns (uint256);
+    function claimPosition(address account, uint256 amount) external returns (bool);
+    function claimReward(bytes32 key, bool enabled) external returns (bool);
+    function claimFee(address[] calldata accounts) external returns (bool);
+    function claimOwner(string calldata name, uint8 decimals) external returns (address);
+    function claimOracle(uint256 id, bytes calldata data) external returns (address);
+    function claimVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (address);
+    function depositToken() external;
+    function depositPool(uint256 id) external;
+    function depositPosition(address account, uint256 amount) external;
+    function depositReward(bytes32 key, bool enabled) external returns (uint256);
+    function depositFee(address[] calldata accounts) external returns (uint256);
+    function depositOwner(string calldata name, uint8 decimals) external returns (uint256);
+    function depositOracle(uint256 id, bytes calldata data) external returns (bool);
+    function depositVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (bool);
+    function withdrawToken() external returns (bool);
+    function withdrawPool(uint256 id) external returns (address);
+    function withdrawPosition(address account, uint256 amount) external returns (address);
+    function withdrawReward(bytes32 key, bool enabled) external returns (address);
+    function withdrawFee(address[] calldata accounts) external;
+    function withdrawOwner(string calldata name, uint8 decimals) external;
+    function withdrawOracle(uint256 id, bytes calldata data) external;
+    function withdrawVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (uint256);
+    function swapToken() external returns (uint256);
+    function swapPool(uint256 id) external returns (uint256);
+    function swapPosition(address account, uint256 amount) external returns (bool);
+    function swapReward(bytes32 key, bool enabled) external returns (bool);
+    function swapFee(address[] calldata accounts) external returns (bool);
+    function swapOwner(string calldata name, uint8 decimals) external returns (address);
+    function swapOracle(uint256 id, bytes calldata data) external returns (address);
+    function swapVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (address);
+    function transferToken() external;
+    function transferPool(uint256 id) external;
+    function transferPosition(address account, uint256 amount) external;
+    function transferReward(bytes32 key, bool enabled) external returns (uint256);
+    function transferFee(address[] calldata accounts) external returns (uint256);
+    function transferOwner(string calldata name, uint8 decimals) external returns (uint256);
+    function transferOracle(uint256 id, bytes calldata data) external returns (bool);
+    function transferVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (bool);
+    function approveToken() external returns (bool);
+    function approvePool(uint256 id) external returns (address);
+    function approvePosition(address account, uint256 amount) external returns (address);
+    function approveReward(bytes32 key, bool enabled) external returns (address);
+    function approveFee(address[] calldata accounts) external;
+    function approveOwner(string calldata name, uint8 decimals) external;
+    function approveOracle(uint256 id, bytes calldata data) external;
+    function approveVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (uint256);
+    function mintToken() external returns (uint256);
+    function mintPool(uint256 id) external returns (uint256);
+    function mintPosition(address account, uint256 amount) external returns (bool);
+    function mintReward(bytes32 key, bool enabled) external returns (bool);
+    function mintFee(address[] calldata accounts) external returns (bool);
+    function mintOwner(string calldata name, uint8 decimals) external returns (address);
+    function mintOracle(uint256 id, bytes calldata data) external returns (address);
+    function mintVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (address);
+    function burnToken() external;
+    function burnPool(uint256 id) external;
+    function burnPosition(address account, uint256 amount) external;
+    function burnReward(bytes32 key, bool enabled) external returns (uint256);
+    function burnFee(address[] calldata accounts) external returns (uint256);
+    function burnOwner(string calldata name, uint8 decimals) external returns (uint256);
+    function burnOracle(uint256 id, bytes calldata data) external returns (bool);
+    function burnVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (bool);
+    function stakeToken() external returns (bool);
+    function stakePool(uint256 id) external returns (address);
+    function stakePosition(address account, uint256 amount) external returns (address);
+    function stakeReward(bytes32 key, bool enabled) external returns (address);
+    function stakeFee(address[] calldata accounts) external;
+    function stakeOwner(string calldata name, uint8 decimals) external;
+    function stakeOracle(uint256 id, bytes calldata data) external;
+    function stakeVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (uint256);
+    function unstakeToken() external returns (uint256);
+    function unstakePool(uint256 id) external returns (uint256);
+    function unstakePosition(address account, uint256 amount) external returns (bool);
+    function unstakeReward(bytes32 key, bool enabled) external returns (bool);
+    function unstakeFee(address[] calldata accounts) external returns (bool);
+    function unstakeOwner(string calldata name, uint8 decimals) external returns (address);
+    function unstakeOracle(uint256 id, bytes calldata data) external returns (address);
+    function unstakeVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (address);
+    function lockToken() external;
+    function lockPool(uint256 id) external;
+    function lockPosition(address account, uint256 amount) external;
+    function lockReward(bytes32 key, bool enabled) external returns (uint256);
+    function lockFee(address[] calldata accounts) external returns (uint256);
+    function lockOwner(string calldata name, uint8 decimals) external returns (uint256);
+    function lockOracle(uint256 id, bytes calldata data) external returns (bool);
+    function lockVault(address from, address to, uint256[] calldata amounts, bytes calldata data) external returns (bool);
+
+    error InvalidToken();
+    error InvalidPool(uint256 id);
+    error InvalidPosition(address account);
+    error InvalidReward(uint256 expected, uint256 actual);
+    error InvalidFee();
+    error InvalidOwner(uint256 id);
+    error InvalidOracle(address account);
+    error InvalidVault(uint256 expected, uint256 actual);
+    error InsufficientToken();
+    error InsufficientPool(uint256 id);
+    error InsufficientPosition(address account);
+    error InsufficientReward(uint256 expected, uint256 actual);
+    error InsufficientFee();
+    error InsufficientOwner(uint256 id);
+    error InsufficientOracle(address account);
+    error InsufficientVault(uint256 expected, uint256 actual);
+    error UnauthorizedToken();
+    error UnauthorizedPool(uint256 id);
+    error UnauthorizedPosition(address account);
+    error UnauthorizedReward(uint256 expected, uint256 actual);
+    error UnauthorizedFee();
+    error UnauthorizedOwner(uint256 id);
+    error UnauthorizedOracle(address account);
+    error UnauthorizedVault(uint256 expected, uint256 actual);
+    error ExpiredToken();
+    error ExpiredPool(uint256 id);
+    error ExpiredPosition(address account);
+    error ExpiredReward(uint256 expected, uint256 actual);
+    error ExpiredFee();
+    error ExpiredOwner(uint256 id);
+    error ExpiredOracle(address account);
+    error ExpiredVault(uint256 expected, uint256 actual);
+    error PausedToken();
+    error PausedPool(uint256 id);
+    error PausedPosition(address account);
+    error PausedReward(uint256 expected, uint256 actual);
+    error PausedFee();
+    error PausedOwner(uint256 id);
+    error PausedOracle(address account);
+    error PausedVault(uint256 expected, uint256 actual);
+}
@@ -0,0 +1,263 @@
+//! Decodes a synthetic Uniswap V2 `swapExactTokensForTokens` transaction,
+//! swapping 2,500 USDC for WETH through the USDC/WETH pair.
+//!
+//! The contract addresses are the real mainnet ones, but the calldata, return
+//! data and logs were encoded by hand, and do not belong to an actual
+//! transaction.
+
+use alloy_primitives::{address, b256, hex, Address, B256, U256};
+use alloy_sol_types::{
+    decoded::{decode_transaction, DecodedResult, DecodedTransaction, LogData},
+    sol, ContractError, Error, SolError, SolInterface,
+};
+
+sol! {
+    #[derive(Debug, PartialEq)]
+    interface IUniswapV2Router02 {
+        function swapExactTokensForTokens(
+            uint256 amountIn,
+            uint256 amountOutMin,
+            address[] calldata path,
+            address to,
+            uint256 deadline
+        ) external returns (uint256[] memory amounts);
+
+        function WETH() external pure returns (address);
+
+        error InsufficientOutputAmount(uint256 amountOut, uint256 amountOutMin);
+
+        // token and pair events, emitted during the swap
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Sync(uint112 reserve0, uint112 reserve1);
+        event Swap(
+            address indexed sender,
+            uint256 amount0In,
+            uint256 amount1In,
+            uint256 amount0Out,
+            uint256 amount1Out,
+            address indexed to
+        );
+    }
+}
+
+use IUniswapV2Router02::*;
+
+type Decoded =
+    DecodedTransaction<IUniswapV2Router02Calls, IUniswapV2Router02Errors, IUniswapV2Router02Events>;
+
+const USDC: Address = address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+const WETH: Address = address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+const PAIR: Address = address!("B4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc");
+const ROUTER: Address = address!("7a250d5630B4cF539739dF2C5dAcb4c659F2488D");
+const USER: Address = address!("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+
+const AMOUNT_IN: u64 = 2_500_000_000;
+const AMOUNT_OUT: u64 = 1_318_421_052_631_578_947;
+
+const INPUT: &[u8] = &hex!(
+    "38ed1739"
+    "000000000000000000000000000000000000000000000000000000009502f900"
+    "000000000000000000000000000000000000000000000000120a871cc0020000"
+    "00000000000000000000000000000000000000000000000000000000000000a0"
+    "000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045"
+    "000000000000000000000000000000000000000000000000000000006553f100"
+    "0000000000000000000000000000000000000000000000000000000000000002"
+    "000000000000000000000000a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+    "000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"
+);
+
+const OUTPUT: &[u8] = &hex!(
+    "0000000000000000000000000000000000000000000000000000000000000020"
+    "0000000000000000000000000000000000000000000000000000000000000002"
+    "000000000000000000000000000000000000000000000000000000009502f900"
+    "000000000000000000000000000000000000000000000000124bf8f64a807943"
+);
+
+const REVERT: &[u8] = &hex!(
+    "08c379a0"
+    "0000000000000000000000000000000000000000000000000000000000000020"
+    "000000000000000000000000000000000000000000000000000000000000002b"
+    "556e69737761705632526f757465723a20494e53554646494349454e545f4f55"
+    "545055545f414d4f554e54000000000000000000000000000000000000000000"
+);
+
+const TRANSFER: B256 = b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+const SYNC: B256 = b256!("1c411e9a96e071241c2f21f7726b17ae89e3cab4c78be50e062b03a9fffbbad1");
+const SWAP: B256 = b256!("d78ad95fa46c994b6551d0da85fc275fe613ce37657fb8d5e3d130840159d822");
+const APPROVAL: B256 = b256!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925");
+
+fn logs() -> Vec<LogData> {
+    vec![
+        // USDC: user -> pair
+        LogData::new(
+            vec![TRANSFER, USER.into_word(), PAIR.into_word()],
+            hex!("000000000000000000000000000000000000000000000000000000009502f900").to_vec(),
+        ),
+        // WETH: pair -> user
+        LogData::new(
+            vec![TRANSFER, PAIR.into_word(), USER.into_word()],
+            hex!("000000000000000000000000000000000000000000000000124bf8f64a807943").to_vec(),
+        ),
+        LogData::new(
+            vec![SYNC],
+            hex!(
+                "0000000000000000000000000000000000000000000000000000290a1f9cea14"
+                "00000000000000000000000000000000000000000000050e5999ab0bafc993ca"
+            )
+            .to_vec(),
+        ),
+        LogData::new(
+            vec![SWAP, ROUTER.into_word(), USER.into_word()],
+            hex!(
+                "000000000000000000000000000000000000000000000000000000009502f900"
+                "0000000000000000000000000000000000000000000000000000000000000000"
+                "0000000000000000000000000000000000000000000000000000000000000000"
+                "000000000000000000000000000000000000000000000000124bf8f64a807943"
+            )
+            .to_vec(),
+        ),
+    ]
+}
+
+#[test]
+fn synthetic_swap() {
+    let tx: Decoded = decode_transaction(INPUT, &logs(), Ok(OUTPUT)).unwrap();
+
+    let IUniswapV2Router02Calls::swapExactTokensForTokens(call) = &tx.call else {
+        panic!("unexpected call: {:?}", tx.call)
+    };
+    assert_eq!(call.amountIn, U256::from(AMOUNT_IN));
+    assert_eq!(call.amountOutMin, U256::from(1_300_000_000_000_000_000u64));
+    assert_eq!(call.path, [USDC, WETH]);
+    assert_eq!(call.to, USER);
+    assert_eq!(call.deadline, U256::from(1_700_000_000));
+
+    let DecodedResult::Return(IUniswapV2Router02Returns::swapExactTokensForTokens(returns)) =
+        &tx.result
+    else {
+        panic!("unexpected result: {:?}", tx.result)
+    };
+    assert_eq!(
+        returns.amounts,
+        [U256::from(AMOUNT_IN), U256::from(AMOUNT_OUT)]
+    );
+
+    let logs: Vec<_> = tx.logs.iter().map(|log| log.as_ref().unwrap()).collect();
+    assert_eq!(
+        logs,
+        [
+            &IUniswapV2Router02Events::Transfer(Transfer {
+                from: USER,
+                to: PAIR,
+                value: U256::from(AMOUNT_IN),
+            }),
+            &IUniswapV2Router02Events::Transfer(Transfer {
+                from: PAIR,
+                to: USER,
+                value: U256::from(AMOUNT_OUT),
+            }),
+            &IUniswapV2Router02Events::Sync(Sync {
+                reserve0: 45_123_456_789_012,
+                reserve1: 23_876_543_210_987_654_321_098,
+            }),
+            &IUniswapV2Router02Events::Swap(Swap {
+                sender: ROUTER,
+                amount0In: U256::from(AMOUNT_IN),
+                amount1In: U256::ZERO,
+                amount0Out: U256::ZERO,
+                amount1Out: U256::from(AMOUNT_OUT),
+                to: USER,
+            }),
+        ]
+    );
+
+    let display = tx.to_string();
+    let lines: Vec<_> = display.lines().collect();
+    assert_eq!(lines.len(), 6);
+    assert!(lines[0].starts_with("call: swapExactTokensForTokens(swapExactTokensForTokensCall {"));
+    assert!(lines[1].starts_with("return: swapExactTokensForTokens("));
+    assert!(lines[5].starts_with("log 3: Swap(Swap { sender: 0x7a250d5630b4cf539739df2c5"));
+    assert!(format!("{tx:#}").lines().count() > 6);
+}
+
+#[test]
+fn reverts() {
+    let tx: Decoded = decode_transaction(INPUT, &[], Err(REVERT)).unwrap();
+    let DecodedResult::Revert(ContractError::Revert(revert)) = &tx.result else {
+        panic!("unexpected result: {:?}", tx.result)
+    };
+    assert_eq!(
+        revert.reason(),
+        "UniswapV2Router: INSUFFICIENT_OUTPUT_AMOUNT"
+    );
+    assert_eq!(
+        tx.to_string().lines().nth(1),
+        Some("revert: UniswapV2Router: INSUFFICIENT_OUTPUT_AMOUNT")
+    );
+    assert!(tx.logs.is_empty());
+
+    let error = InsufficientOutputAmount {
+        amountOut: U256::from(AMOUNT_OUT),
+        amountOutMin: U256::from(AMOUNT_OUT + 1),
+    };
+    let tx: Decoded = decode_transaction(INPUT, &[], Err(&error.abi_encode())).unwrap();
+    assert_eq!(
+        tx.result,
+        DecodedResult::Revert(ContractError::CustomError(
+            IUniswapV2Router02Errors::InsufficientOutputAmount(error)
+        ))
+    );
+
+    // out of gas, or `revert()` without a reason
+    let tx: Decoded = decode_transaction(INPUT, &[], Err(&[])).unwrap();
+    assert_eq!(tx.result, DecodedResult::UnknownRevert(vec![]));
+    assert_eq!(
+        tx.to_string().lines().nth(1),
+        Some("revert: unknown data 0x")
+    );
+}
+
+#[test]
+fn unknown_data() {
+    // logs of other contracts are reported, but do not fail the decoding
+    let mut logs = logs();
+    logs[0].topics[0] = APPROVAL;
+    logs[2].topics.clear();
+    let tx: Decoded = decode_transaction(INPUT, &logs, Ok(OUTPUT)).unwrap();
+    assert_eq!(
+        tx.logs[0],
+        Err(Error::UnknownEvent {
+            name: "IUniswapV2Router02Events",
+            signature: Some(APPROVAL),
+        })
+    );
+    assert!(tx.logs[1].is_ok());
+    assert_eq!(
+        tx.logs[2],
+        Err(Error::UnknownEvent {
+            name: "IUniswapV2Router02Events",
+            signature: None,
+        })
+    );
+
+    // calldata and return data are required
+    let weth_call = IUniswapV2Router02Calls::WETH(WETHCall {}).abi_encode();
+    assert!(decode_transaction::<
+        IUniswapV2Router02Calls,
+        IUniswapV2Router02Errors,
+        IUniswapV2Router02Events,
+    >(&weth_call, &[], Ok(OUTPUT))
+    .is_ok());
+    assert!(decode_transaction::<
+        IUniswapV2Router02Calls,
+        IUniswapV2Router02Errors,
+        IUniswapV2Router02Events,
+    >(&weth_call, &[], Ok(&[]))
+    .is_err());
+    assert!(decode_transaction::<
+        IUniswapV2Router02Calls,
+        IUniswapV2Router02Errors,
+        IUniswapV2Router02Events,
+    >(&INPUT[..4], &[], Ok(OUTPUT))
+    .is_err());
+}
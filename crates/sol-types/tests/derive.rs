@@ -0,0 +1,173 @@
+use alloy_primitives::{bytes, hex, Address, Bytes, FixedBytes, B256, I256, U256};
+use alloy_sol_types::{sol, sol_data, Pretty, SolType, SolValue};
+
+sol! {
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        int64 x;
+        int64 y;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, SolValue)]
+struct Static {
+    a: bool,
+    b: B256,
+    c: FixedBytes<4>,
+    d: I256,
+    e: [(Address, U256); 2],
+}
+
+#[derive(Clone, Debug, PartialEq, SolValue)]
+struct Dynamic {
+    #[sol(int64)]
+    a: i64,
+    b: Vec<Static>,
+    #[sol(bytes)]
+    c: Vec<u8>,
+    d: Vec<Vec<String>>,
+    e: Point,
+    #[sol(uint16[2][])]
+    f: Vec<[u16; 2]>,
+    g: Bytes,
+}
+
+#[derive(Clone, Debug, PartialEq, SolValue)]
+struct Unit;
+
+fn dynamic() -> Dynamic {
+    let s = Static {
+        a: true,
+        b: B256::repeat_byte(0x11),
+        c: FixedBytes(hex!("deadbeef")),
+        d: I256::MINUS_ONE,
+        e: [
+            (Address::repeat_byte(0x22), U256::from(1)),
+            (Address::ZERO, U256::MAX),
+        ],
+    };
+    Dynamic {
+        a: -1,
+        b: vec![
+            s.clone(),
+            Static {
+                a: false,
+                c: FixedBytes([0; 4]),
+                ..s
+            },
+        ],
+        c: b"hello".to_vec(),
+        d: vec![vec![], vec!["a".into(), "bc".into()]],
+        e: Point { x: 1, y: -2 },
+        f: vec![[1, 2], [3, 4]],
+        g: bytes!("c0ffee"),
+    }
+}
+
+#[test]
+fn derive_static() {
+    type Tuple = sol! { (bool, bytes32, bytes4, int256, (address, uint256)[2]) };
+
+    let value = dynamic().b.remove(1);
+    assert_eq!(Static::sol_type_name(), Tuple::sol_type_name());
+    assert_eq!(Static::ENCODED_SIZE, Some(32 * 8));
+    assert_eq!(Static::abi_encoded_size(&value), 32 * 8);
+
+    let tuple = (value.a, value.b, value.c, value.d, value.e);
+    let encoded = Static::abi_encode(&value);
+    assert_eq!(encoded, Tuple::abi_encode(&tuple));
+    assert_eq!(
+        Static::abi_encode_packed(&value),
+        Tuple::abi_encode_packed(&tuple)
+    );
+    assert_eq!(
+        Static::eip712_data_word(&value),
+        Tuple::eip712_data_word(&tuple)
+    );
+    assert_eq!(Static::abi_decode(&encoded, true).unwrap(), value);
+}
+
+#[test]
+fn derive_dynamic() {
+    type Tuple = sol! {
+        (
+            int64,
+            (bool, bytes32, bytes4, int256, (address, uint256)[2])[],
+            bytes,
+            string[][],
+            Point,
+            uint16[2][],
+            bytes,
+        )
+    };
+
+    let value = dynamic();
+    assert_eq!(Dynamic::sol_type_name(), Tuple::sol_type_name());
+    assert_eq!(Dynamic::ENCODED_SIZE, None);
+
+    let tuple = (
+        value.a,
+        value
+            .b
+            .iter()
+            .map(|s| (s.a, s.b, s.c, s.d, s.e))
+            .collect::<Vec<_>>(),
+        value.c.clone(),
+        value.d.clone(),
+        value.e.clone(),
+        value.f.clone(),
+        value.g.to_vec(),
+    );
+    let encoded = Dynamic::abi_encode(&value);
+    assert_eq!(encoded, Tuple::abi_encode(&tuple));
+    assert_eq!(
+        Dynamic::abi_encoded_size(&value),
+        Tuple::abi_encoded_size(&tuple)
+    );
+    assert_eq!(
+        Dynamic::abi_encode_packed(&value),
+        Tuple::abi_encode_packed(&tuple)
+    );
+    assert_eq!(Dynamic::abi_decode(&encoded, true).unwrap(), value);
+
+    let mut invalid = encoded.clone();
+    invalid[31] = 0x41;
+    assert!(Dynamic::abi_decode(&invalid, true).is_err());
+
+    let values = vec![value.clone(), value];
+    let encoded = sol_data::Array::<Dynamic>::abi_encode(&values);
+    assert_eq!(
+        sol_data::Array::<Dynamic>::abi_decode(&encoded, true).unwrap(),
+        values
+    );
+}
+
+#[test]
+fn derive_unit() {
+    assert_eq!(Unit::sol_type_name(), "()");
+    assert_eq!(Unit::abi_encode(&Unit), []);
+    assert_eq!(Unit::abi_decode(&[], true).unwrap(), Unit);
+}
+
+#[test]
+fn derive_pretty() {
+    let value = dynamic();
+    assert_eq!(
+        Pretty::<Dynamic>::new(&value).to_string(),
+        concat!(
+            "Dynamic { a: -1, b: [Static { a: true, ",
+            "b: 0x1111111111111111111111111111111111111111111111111111111111111111, ",
+            "c: 0xdeadbeef, d: -1, e: [(0x2222222222222222222222222222222222222222, 1), ",
+            "(0x0000000000000000000000000000000000000000, ",
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935)] }, ",
+            "Static { a: false, ",
+            "b: 0x1111111111111111111111111111111111111111111111111111111111111111, ",
+            "c: 0x00000000, d: -1, e: [(0x2222222222222222222222222222222222222222, 1), ",
+            "(0x0000000000000000000000000000000000000000, ",
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935)] }], ",
+            r#"c: 0x68656c6c6f, d: [[], ["a", "bc"]], e: Point { x: 1, y: -2 }, "#,
+            "f: [[1, 2], [3, 4]], g: 0xc0ffee }",
+        )
+    );
+    assert_eq!(Pretty::<Unit>::new(&Unit).to_string(), "Unit");
+}
@@ -1,5 +1,6 @@
 mod doctests {
     mod contracts;
+    mod derive;
     mod events;
     mod function_like;
     #[cfg(feature = "json")]
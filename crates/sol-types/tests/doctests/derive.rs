@@ -0,0 +1,47 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, SolType, SolValue};
+
+#[derive(Debug, PartialEq, SolValue)]
+struct Order {
+    maker: Address,
+    amount: U256,
+    // primitive integers must be given a Solidity type
+    #[sol(uint40)]
+    deadline: u64,
+    #[sol(uint8[])]
+    flags: Vec<u8>,
+    // other types must implement `SolType`
+    asset: Asset,
+}
+
+#[derive(Debug, PartialEq, SolValue)]
+struct Asset(Address, String);
+
+// encoded like the tuple of its fields
+type OrderTuple = sol! { (address, uint256, uint40, uint8[], (address, string)) };
+
+#[test]
+fn derive() {
+    let order = Order {
+        maker: Address::repeat_byte(0x11),
+        amount: U256::from(42),
+        deadline: 1_700_000_000,
+        flags: vec![1, 2],
+        asset: Asset(Address::repeat_byte(0x22), "WETH".into()),
+    };
+    assert_eq!(
+        Order::sol_type_name(),
+        "(address,uint256,uint40,uint8[],(address,string))"
+    );
+
+    let encoded = Order::abi_encode(&order);
+    let tuple = (
+        order.maker,
+        order.amount,
+        order.deadline,
+        order.flags.clone(),
+        (order.asset.0, order.asset.1.clone()),
+    );
+    assert_eq!(encoded, OrderTuple::abi_encode(&tuple));
+    assert_eq!(Order::abi_decode(&encoded, true).unwrap(), order);
+}
@@ -0,0 +1,111 @@
+//! Checks the `ENCODED_SIZE` and `DYNAMIC` constants of representative types,
+//! and that `abi_encoded_size` and `packed_encoded_size` match the actual
+//! encoded lengths.
+
+use alloy_primitives::{Address as A, FixedBytes as B, Function as F, I256, U256};
+use alloy_sol_types::{sol, sol_data::*, SolType};
+
+sol! {
+    struct Static {
+        uint256 a;
+        address b;
+        bool c;
+    }
+
+    struct Dynamic {
+        string a;
+    }
+
+    type Udt is uint64;
+
+    enum Kind {
+        A,
+        B,
+    }
+}
+
+const fn eq(a: Option<usize>, b: Option<usize>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a == b,
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+macro_rules! encoded_size {
+    ($($ty:ty => $size:expr, $value:expr;)+) => {
+        $(
+            const _: () = assert!(eq(<$ty as SolType>::ENCODED_SIZE, $size));
+            const _: () = assert!(<$ty as SolType>::DYNAMIC == eq($size, None));
+        )+
+
+        #[test]
+        fn encoded_size() {
+            $({
+                let value: <$ty as SolType>::RustType = $value;
+                let size = <$ty as SolType>::abi_encoded_size(&value);
+                let expected: Option<usize> = $size;
+                if let Some(expected) = expected {
+                    assert_eq!(size, expected, "{}", <$ty as SolType>::sol_type_name());
+                }
+                // dynamic types are encoded behind an offset
+                let offset = 32 * <$ty as SolType>::DYNAMIC as usize;
+                let encoded = <$ty as SolType>::abi_encode(&value);
+                assert_eq!(size + offset, encoded.len(), "{}", <$ty as SolType>::sol_type_name());
+
+                let packed = <$ty as SolType>::abi_encode_packed(&value);
+                let packed_size = <$ty as SolType>::packed_encoded_size(&value);
+                assert_eq!(packed_size, packed.len(), "{}", <$ty as SolType>::sol_type_name());
+            })+
+        }
+    };
+}
+
+fn st(s: &str) -> std::string::String {
+    s.into()
+}
+
+fn s() -> Static {
+    Static {
+        a: U256::from(1),
+        b: A::ZERO,
+        c: true,
+    }
+}
+
+fn d() -> Dynamic {
+    Dynamic {
+        a: "a string longer than thirty-two bytes".into(),
+    }
+}
+
+encoded_size! {
+    Bool => Some(32), true;
+    Uint<8> => Some(32), 1u8;
+    Int<256> => Some(32), I256::MINUS_ONE;
+    Address => Some(32), A::ZERO;
+    Function => Some(32), F::ZERO;
+    FixedBytes<32> => Some(32), B::<32>::ZERO;
+    Bytes => None, vec![1u8; 33];
+    String => None, st("abc");
+    Array<Uint<256>> => None, vec![U256::from(1); 3];
+    Array<String> => None, vec![st("a"); 2];
+    FixedArray<Uint<256>, 3> => Some(96), [U256::ZERO; 3];
+    FixedArray<String, 2> => None, [st("a"), st("b")];
+    FixedArray<FixedArray<Bool, 2>, 3> => Some(192), [[true; 2]; 3];
+    (Uint<256>, Address, Bool) => Some(96), (U256::ZERO, A::ZERO, false);
+    (Uint<256>, String) => None, (U256::ZERO, st("abc"));
+    (Bool, (Address, FixedBytes<4>)) => Some(96), (true, (A::ZERO, B([0; 4])));
+    (Bool, (Address, Bytes)) => None, (true, (A::ZERO, vec![0u8; 40]));
+    () => Some(0), ();
+    Static => Some(96), s();
+    Dynamic => None, d();
+    FixedArray<Static, 2> => Some(192), [s(), s()];
+    FixedArray<Dynamic, 2> => None, [d(), d()];
+    (Dynamic, Uint<8>) => None, (d(), 1u8);
+    (Static, Uint<8>) => Some(128), (s(), 1u8);
+    Udt => Some(32), 1u64;
+    Kind => Some(32), Kind::B;
+    Optional<Address> => Some(64), Some(A::ZERO);
+    ZeroAsNone<Address> => Some(32), None::<A>;
+}
@@ -0,0 +1,86 @@
+//! Checks that all public types can be shared and sent between threads.
+
+use alloy_sol_types::{
+    abi::{
+        token::{DynSeqToken, FixedSeqToken, PackedSeqToken, RecursiveToken, WordToken},
+        DecodeOptions, Decoder, Encoder,
+    },
+    decoded::{DecodedResult, DecodedTransaction, LogData},
+    sol, sol_data, CallError, ContractError, DomainFields, Eip712Domain, Error,
+    GenericContractError, Panic, PanicKind, PathElement, Pretty, Revert, Selectors,
+};
+use static_assertions::assert_impl_all;
+
+macro_rules! assert_send_sync {
+    ($($t:ty),+ $(,)?) => {$(
+        assert_impl_all!($t: Send, Sync);
+    )+};
+}
+
+sol! {
+    interface IToken {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        error Unauthorized(address caller);
+        function transfer(address to, uint256 value) external returns (bool);
+    }
+
+    contract Token {
+        type Amount is uint256;
+        enum Kind { Fungible, NonFungible }
+        struct Node { Amount value; Node[] children; }
+        constructor(string name, Kind kind);
+    }
+}
+
+assert_send_sync!(
+    // errors
+    Error,
+    PathElement,
+    CallError,
+    ContractError<IToken::ITokenErrors>,
+    GenericContractError,
+    Revert,
+    Panic,
+    PanicKind,
+    // encoding
+    DecodeOptions,
+    Decoder<'static>,
+    Encoder,
+    WordToken,
+    FixedSeqToken<WordToken, 2>,
+    DynSeqToken<PackedSeqToken<'static>>,
+    PackedSeqToken<'static>,
+    RecursiveToken<'static, Token::Node>,
+    DomainFields,
+    Eip712Domain,
+    Pretty<'static, sol_data::Uint<256>>,
+    Selectors<IToken::ITokenCalls>,
+    // decoded transactions
+    LogData,
+    DecodedResult<IToken::transferReturn, IToken::ITokenErrors>,
+    DecodedTransaction<IToken::ITokenCalls, IToken::ITokenErrors, IToken::ITokenEvents>,
+    // generated types
+    IToken::Transfer,
+    IToken::Unauthorized,
+    IToken::transferCall,
+    IToken::ITokenCalls,
+    IToken::ITokenEvents,
+    Token::Amount,
+    Token::Kind,
+    Token::Node,
+    Token::constructorCall,
+    // Solidity types
+    sol_data::Address,
+    sol_data::Array<sol_data::String>,
+    sol_data::Bool,
+    sol_data::ByteCount<32>,
+    sol_data::Bytes,
+    sol_data::FixedArray<sol_data::Function, 2>,
+    sol_data::FixedBytes<32>,
+    sol_data::Int<256>,
+    sol_data::IntBitCount<256>,
+    sol_data::Optional<sol_data::Uint<8>>,
+    sol_data::String,
+    sol_data::Uint<256>,
+    sol_data::ZeroAsNone<sol_data::Address>,
+);
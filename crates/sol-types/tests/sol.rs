@@ -1,5 +1,8 @@
-use alloy_primitives::{keccak256, Address, B256, I256, U256};
-use alloy_sol_types::{eip712_domain, sol, SolCall, SolError, SolStruct, SolType};
+use alloy_primitives::{hex, keccak256, Address, FixedBytes, B256, I256, U256};
+use alloy_sol_types::{
+    eip712_domain, sol, sol_data, CallError, Error, Panic, PanicKind, PathElement, Pretty, Revert,
+    SolCall, SolError, SolEvent, SolStruct, SolType,
+};
 use serde::Serialize;
 use serde_json::Value;
 
@@ -128,6 +131,7 @@ fn function() {
         call.abi_encoded_size(),
         32 + (64 + 32) + (64 + 32 + 32) + (64 + 3 * 32) + 2 * 32 + (32 + 32) + (64 + 4 * (32 + 32))
     );
+    assert_eq!(call.abi_encode_bytes(), encoded);
 }
 
 #[test]
@@ -147,6 +151,130 @@ fn error() {
     assert_eq!(e.abi_encoded_size(), 64);
 }
 
+#[test]
+fn selector_consts() {
+    sol! {
+        function transfer(address to, uint256 amount);
+        function approve(address spender, uint256 amount);
+    }
+
+    assert_eq!(transferCall::SIGNATURE, "transfer(address,uint256)");
+    assert_eq!(transferCall::SELECTOR, hex!("a9059cbb"));
+    assert_eq!(approveCall::SIGNATURE, "approve(address,uint256)");
+    assert_eq!(approveCall::SELECTOR, hex!("095ea7b3"));
+
+    // usable in patterns, without hashing at runtime
+    fn dispatch(selector: [u8; 4]) -> Option<&'static str> {
+        match selector {
+            transferCall::SELECTOR => Some(transferCall::SIGNATURE),
+            approveCall::SELECTOR => Some(approveCall::SIGNATURE),
+            _ => None,
+        }
+    }
+    assert_eq!(
+        dispatch(hex!("a9059cbb")),
+        Some("transfer(address,uint256)")
+    );
+    assert_eq!(dispatch(hex!("095ea7b3")), Some("approve(address,uint256)"));
+    assert_eq!(dispatch([0; 4]), None);
+}
+
+#[test]
+fn decode_calldata() {
+    sol! {
+        function transfer(address to, uint256 amount, string memo);
+    }
+
+    let call = transferCall {
+        to: Address::repeat_byte(0x11),
+        amount: U256::from(1234),
+        memo: "hi".to_owned(),
+    };
+    let calldata = call.abi_encode();
+
+    type Params = (sol_data::Address, sol_data::Uint<256>, sol_data::String);
+    let (selector, (to, amount, memo)) = Params::abi_decode_calldata(&calldata, true).unwrap();
+    assert_eq!(selector, transferCall::SELECTOR);
+    assert_eq!(to, call.to);
+    assert_eq!(amount, call.amount);
+    assert_eq!(memo, call.memo);
+
+    assert_eq!(
+        Params::abi_decode_calldata(&calldata[..3], true),
+        Err(alloy_sol_types::Error::Overrun)
+    );
+    assert_eq!(
+        <()>::abi_decode_calldata(&transferCall::SELECTOR, true),
+        Ok((transferCall::SELECTOR.into(), ()))
+    );
+}
+
+#[test]
+fn decode_response() {
+    sol! {
+        function balanceOf(address owner) returns (uint256 balance);
+    }
+
+    let data = balanceOfCall::abi_encode_returns(&(U256::from(42),));
+    let ret = balanceOfCall::abi_decode_response(&data, true).unwrap();
+    assert_eq!(ret.balance, U256::from(42));
+
+    let data = Revert::from("insufficient balance").abi_encode();
+    let err = balanceOfCall::abi_decode_response(&data, true)
+        .err()
+        .unwrap();
+    assert_eq!(err, CallError::Revert(Revert::from("insufficient balance")));
+    assert_eq!(err.to_string(), "revert: insufficient balance");
+
+    let data = Panic::from(PanicKind::DivisionByZero).abi_encode();
+    let err = balanceOfCall::abi_decode_response(&data, true)
+        .err()
+        .unwrap();
+    match err {
+        CallError::Panic(panic) => assert_eq!(panic.kind(), Some(PanicKind::DivisionByZero)),
+        _ => panic!("expected panic: {err:?}"),
+    }
+
+    assert!(matches!(
+        balanceOfCall::abi_decode_response(&[0x12; 3], true),
+        Err(CallError::Unknown(_))
+    ));
+}
+
+#[test]
+fn function_type() {
+    sol! {
+        struct Callback {
+            function(uint256) external returns (bool) f;
+            uint256 gas;
+        }
+    }
+
+    let address = Address::repeat_byte(0x11);
+    let selector = FixedBytes::<4>::new([0x12, 0x34, 0x56, 0x78]);
+    let callback = Callback {
+        f: (address, selector).into(),
+        gas: U256::from(100_000),
+    };
+    assert_eq!(sol_data::Function::sol_type_name(), "function");
+
+    let encoded = Callback::abi_encode(&callback);
+    assert_eq!(encoded.len(), 64);
+    // address and selector are left-aligned in the word
+    assert_eq!(encoded[..20], address[..]);
+    assert_eq!(encoded[20..24], selector[..]);
+    assert_eq!(encoded[24..32], [0; 8]);
+
+    let decoded = Callback::abi_decode(&encoded, true).unwrap();
+    assert_eq!(decoded.f.to_address_and_selector(), (address, selector));
+    assert_eq!(decoded.gas, callback.gas);
+
+    // non-zero padding is rejected when validating
+    let mut dirty = encoded;
+    dirty[31] = 1;
+    assert!(Callback::abi_decode(&dirty, true).is_err());
+}
+
 // https://github.com/alloy-rs/core/issues/158
 #[test]
 fn empty_call() {
@@ -374,6 +502,51 @@ fn enum_variant_attrs() {
     assert!(serde_json::to_string(&MyEnum::C).is_err());
 }
 
+#[test]
+fn rust_keywords() {
+    // This has to be in a module (not a function) because of Rust import rules
+    mod keywords {
+        alloy_sol_types::sol! {
+            #[derive(Debug, PartialEq)]
+            struct Order {
+                uint8 type;
+                address ref;
+                bool move;
+            };
+
+            library Lib {
+                function apply(Order memory self, uint256 override) external returns (bool);;
+            }
+        }
+    }
+    use keywords::*;
+
+    let order = Order {
+        r#type: 1,
+        r#ref: Address::repeat_byte(0x11),
+        r#move: true,
+    };
+    let encoded = Order::abi_encode(&order);
+    assert_eq!(Order::abi_decode(&encoded, true).unwrap(), order);
+    assert_eq!(
+        Order::eip712_encode_type(),
+        "Order(uint8 type,address ref,bool move)"
+    );
+
+    // `self` cannot be a raw identifier
+    let call = Lib::applyCall {
+        self_: order,
+        r#override: U256::from(2),
+    };
+    assert_eq!(
+        Lib::applyCall::SIGNATURE,
+        "apply((uint8,address,bool),uint256)"
+    );
+    let decoded = Lib::applyCall::abi_decode(&call.abi_encode(), true).unwrap();
+    assert_eq!(decoded.self_, call.self_);
+    assert_eq!(decoded.r#override, call.r#override);
+}
+
 #[test]
 fn nested_items() {
     // This has to be in a module (not a function) because of Rust import rules
@@ -519,5 +692,1104 @@ fn eip712_encode_data_nesting() {
         "25c3d40a39e639a4d0b6e4d2ace5e1281e039c88494d97d8d08f99a6ea75d775"
             .parse::<B256>()
             .unwrap()
-    )
+    );
+}
+
+#[test]
+fn eip712_signing_hashes() {
+    sol! {
+        struct Person {
+            string name;
+            address wallet;
+        }
+
+        struct Mail {
+            Person from;
+            Person to;
+            string contents;
+        }
+    }
+
+    let mail = Mail {
+        from: Person {
+            name: "Cow".to_owned(),
+            wallet: "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"
+                .parse()
+                .unwrap(),
+        },
+        to: Person {
+            name: "Bob".to_owned(),
+            wallet: "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"
+                .parse()
+                .unwrap(),
+        },
+        contents: String::new(),
+    };
+
+    let domain = eip712_domain! {
+        name: "Ether Mail",
+        version: "1",
+        chain_id: 1,
+    };
+    let mails: Vec<_> = ["Hello, Bob!", "Hello again!", ""]
+        .iter()
+        .map(|contents| Mail {
+            contents: contents.to_string(),
+            ..mail.clone()
+        })
+        .collect();
+    let hashes = domain.signing_hashes(&mails);
+    assert_eq!(hashes.len(), mails.len());
+    for (mail, hash) in mails.iter().zip(&hashes) {
+        assert_eq!(
+            *hash,
+            alloy_sol_types::SolStruct::eip712_signing_hash(mail, &domain)
+        );
+    }
+    assert!(domain.signing_hashes::<Mail>(&[]).is_empty());
+}
+
+#[test]
+fn eip712_hash_struct_no_alloc() {
+    sol! {
+        struct Permit {
+            address owner;
+            address spender;
+            uint256 value;
+            uint256 nonce;
+            uint256 deadline;
+        }
+
+        struct Message {
+            address from;
+            bytes payload;
+            string note;
+        }
+    }
+
+    fn assert_same<T: SolStruct>(value: &T) {
+        let mut words = Vec::new();
+        value.eip712_encode_data_to(&mut |word| words.extend_from_slice(word.as_slice()));
+        assert_eq!(words, value.eip712_encode_data());
+        assert_eq!(
+            value.eip712_type_hash(),
+            keccak256(T::eip712_encode_type().as_bytes())
+        );
+        assert_eq!(
+            value.eip712_hash_struct_no_alloc(),
+            value.eip712_hash_struct()
+        );
+    }
+
+    let permit = Permit {
+        owner: Address::repeat_byte(0x11),
+        spender: Address::repeat_byte(0x22),
+        value: U256::from(1_000_000u64),
+        nonce: U256::from(7),
+        deadline: U256::MAX,
+    };
+    assert_same(&permit);
+    assert_eq!(
+        permit.eip712_type_hash(),
+        keccak256(
+            "Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)"
+        )
+    );
+
+    let message = Message {
+        from: Address::repeat_byte(0x33),
+        payload: hex!("deadbeef").to_vec(),
+        note: "hello".to_owned(),
+    };
+    assert_same(&message);
+    let mut words = Vec::new();
+    message.eip712_encode_data_to(&mut |word| words.push(*word));
+    assert_eq!(words[1], keccak256(hex!("deadbeef")));
+    assert_eq!(words[2], keccak256("hello"));
+}
+
+#[test]
+fn recursive_structs() {
+    sol! {
+        #[derive(Debug, PartialEq)]
+        struct Node {
+            uint256 value;
+            Node[] children;
+        }
+
+        struct Tree {
+            Node root;
+            address owner;
+        }
+
+        struct A {
+            uint256 x;
+            B[] bs;
+        }
+
+        struct B {
+            A a;
+            bool flag;
+        }
+    }
+
+    assert_eq!(
+        Node::eip712_encode_type(),
+        "Node(uint256 value,Node[] children)"
+    );
+    assert_eq!(
+        Tree::eip712_encode_type(),
+        "Tree(Node root,address owner)Node(uint256 value,Node[] children)"
+    );
+    assert_eq!(
+        A::eip712_encode_type(),
+        "A(uint256 x,B[] bs)B(A a,bool flag)"
+    );
+    assert_eq!(
+        B::eip712_encode_type(),
+        "B(A a,bool flag)A(uint256 x,B[] bs)"
+    );
+
+    let leaf = |value| Node {
+        value: U256::from(value),
+        children: vec![],
+    };
+    let node = Node {
+        value: U256::from(1),
+        children: vec![leaf(2), leaf(3)],
+    };
+
+    // the leaves' empty arrays encode the same regardless of their element type
+    type Leaf = (sol_data::Uint<256>, sol_data::Array<sol_data::Uint<256>>);
+    type Flat = (sol_data::Uint<256>, sol_data::Array<Leaf>);
+    let flat: <Flat as SolType>::RustType = (
+        U256::from(1),
+        vec![(U256::from(2), vec![]), (U256::from(3), vec![])],
+    );
+    let encoded = Node::abi_encode(&node);
+    assert_eq!(encoded, Flat::abi_encode(&flat));
+    assert_eq!(Node::abi_encoded_size(&node), Flat::abi_encoded_size(&flat));
+    assert_eq!(Node::abi_decode(&encoded, true).unwrap(), node);
+    assert_eq!(
+        node.eip712_type_hash(),
+        keccak256("Node(uint256 value,Node[] children)")
+    );
+}
+
+#[test]
+fn eip712_hash_struct_recursive() {
+    sol! {
+        struct Node {
+            uint256 value;
+            Node[] children;
+        }
+    }
+
+    let leaf = |value| Node {
+        value: U256::from(value),
+        children: vec![],
+    };
+    let node = Node {
+        value: U256::from(1),
+        children: vec![leaf(2), leaf(3)],
+    };
+
+    // hashStruct(s) = keccak256(typeHash ‖ encodeData(s)), where an array of
+    // structs is encoded as the hash of its concatenated `hashStruct`s
+    let type_hash = keccak256("Node(uint256 value,Node[] children)");
+    let hash_struct = |value: u64, children: &[B256]| {
+        let children = keccak256(children.concat());
+        keccak256([type_hash, B256::from(U256::from(value)), children].concat())
+    };
+    let expected = hash_struct(1, &[hash_struct(2, &[]), hash_struct(3, &[])]);
+
+    assert_eq!(leaf(2).eip712_hash_struct(), hash_struct(2, &[]));
+    assert_eq!(node.eip712_hash_struct(), expected);
+    assert_eq!(node.eip712_hash_struct_no_alloc(), expected);
+}
+
+#[test]
+fn event_decode_log_raw() {
+    sol! {
+        #[derive(Debug)]
+        event Transfer(address indexed from, address indexed to, uint256 value);
+    }
+
+    let event = Transfer {
+        from: Address::repeat_byte(0x11),
+        to: Address::repeat_byte(0x22),
+        value: U256::from(42),
+    };
+    let data = event.encode_data();
+    let topics: Vec<Vec<u8>> = event
+        .encode_topics()
+        .into_iter()
+        .map(|t| t.0.to_vec())
+        .collect();
+
+    let decoded = Transfer::decode_log_raw(&topics, &data, true).unwrap();
+    assert_eq!(decoded.from, event.from);
+    assert_eq!(decoded.to, event.to);
+    assert_eq!(decoded.value, event.value);
+
+    let mut malformed = topics.clone();
+    malformed[1].pop();
+    let err = Transfer::decode_log_raw(&malformed, &data, true).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid length for topic 1: expected 32 bytes, got 31"
+    );
+}
+
+#[test]
+fn event_hash() {
+    sol! {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Memo(address indexed from, string memo) anonymous;
+    }
+
+    let (from, to) = (Address::repeat_byte(0x11), Address::repeat_byte(0x22));
+    let transfer = |value: u64| Transfer {
+        from,
+        to,
+        value: U256::from(value),
+    };
+    let event = transfer(42);
+    assert_eq!(event.event_hash(), transfer(42).event_hash());
+    assert_ne!(event.event_hash(), transfer(43).event_hash());
+
+    let preimage = [
+        Transfer::SIGNATURE_HASH.as_slice(),
+        from.into_word().as_slice(),
+        to.into_word().as_slice(),
+        &event.encode_data(),
+    ]
+    .concat();
+    assert_eq!(event.event_hash(), keccak256(preimage));
+
+    let memo = Memo {
+        from,
+        memo: "hello".into(),
+    };
+    let preimage = [from.into_word().as_slice(), &memo.encode_data()].concat();
+    assert_eq!(memo.event_hash(), keccak256(preimage));
+    let other = Memo {
+        memo: "world".into(),
+        ..memo
+    };
+    assert_ne!(memo.event_hash(), other.event_hash());
+}
+
+#[test]
+fn builder() {
+    sol! {
+        #[derive(Debug, PartialEq)]
+        #[sol(builder)]
+        event Transfer(
+            address indexed from,
+            address indexed to,
+            string indexed tag,
+            uint256 value,
+            string memo
+        );
+
+        #[derive(Debug, PartialEq)]
+        #[sol(builder)]
+        struct Batch {
+            uint256 nonce;
+            address[] targets;
+            uint8[33] flags;
+        }
+
+        #[sol(builder)]
+        struct NoDebug {
+            uint256 a;
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Inner {
+            uint256 a;
+        }
+
+        #[derive(Debug, Default, PartialEq)]
+        struct InnerDefault {
+            uint256 a;
+        }
+
+        #[derive(Debug, PartialEq)]
+        #[sol(builder)]
+        struct Outer {
+            Inner inner;
+            Inner[] inners;
+            InnerDefault inner_default;
+        }
+    }
+
+    let (from, to) = (Address::repeat_byte(0x11), Address::repeat_byte(0x22));
+    let tag = keccak256("tag");
+    let event = Transfer::builder()
+        .with_from(from)
+        .with_to(to)
+        .with_tag(tag)
+        .with_value(U256::from(42))
+        .build()
+        .unwrap();
+    let manual = Transfer {
+        from,
+        to,
+        tag,
+        value: U256::from(42),
+        memo: String::new(),
+    };
+    assert_eq!(event, manual);
+    assert_eq!(event.encode_topics(), manual.encode_topics());
+    assert_eq!(event.encode_data(), manual.encode_data());
+
+    // indexed parameters must be set, the others default
+    let partial = Transfer::builder().with_from(from).with_to(to);
+    assert_eq!(
+        partial.clone().build(),
+        Err(Error::MissingField {
+            name: "Transfer",
+            field: "tag"
+        })
+    );
+    let event = partial
+        .with_tag(tag)
+        .with_memo("hi".into())
+        .build()
+        .unwrap();
+    assert_eq!(event.value, U256::ZERO);
+    assert_eq!(event.memo, "hi");
+
+    // `[u8; 33]` has no `Default` implementation
+    let err = Batch::builder()
+        .with_nonce(U256::from(1))
+        .build()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Missing required field `flags` for Batch");
+    let batch = Batch::builder().with_flags([1; 33]).build().unwrap();
+    assert_eq!(
+        batch,
+        Batch {
+            nonce: U256::ZERO,
+            targets: vec![],
+            flags: [1; 33]
+        }
+    );
+
+    // the builder derives `Debug` only along with the type it builds
+    let _ = NoDebug::builder().with_a(U256::from(1)).build().unwrap();
+    assert_eq!(
+        format!("{:?}", Batch::builder().with_nonce(U256::from(1))),
+        "BatchBuilder { nonce: Some(1), targets: None, flags: None }"
+    );
+
+    // only structs that derive `Default` are defaulted
+    let err = Outer::builder().build().unwrap_err();
+    assert_eq!(err.to_string(), "Missing required field `inner` for Outer");
+    let inner = Inner { a: U256::from(1) };
+    let outer = Outer::builder().with_inner(inner.clone()).build().unwrap();
+    assert_eq!(
+        outer,
+        Outer {
+            inner,
+            inners: vec![],
+            inner_default: InnerDefault::default(),
+        }
+    );
+}
+
+#[test]
+fn zero_is_none() {
+    sol! {
+        struct Order {
+            #[sol(zero_is_none)]
+            address referrer;
+            address maker;
+            #[sol(zero_is_none)]
+            bytes32 salt;
+            uint256 amount;
+        }
+
+        function place(Order order, #[sol(zero_is_none)] address recipient);
+    }
+
+    let order = Order {
+        referrer: None,
+        maker: Address::repeat_byte(0x11),
+        salt: Some(B256::repeat_byte(0x22)),
+        amount: U256::from(100),
+    };
+    let encoded = Order::abi_encode(&order);
+    assert_eq!(encoded[..32], [0u8; 32]);
+    assert_eq!(encoded[64..96], B256::repeat_byte(0x22)[..]);
+
+    let decoded = Order::abi_decode(&encoded, true).unwrap();
+    assert_eq!(decoded.referrer, None);
+    assert_eq!(decoded.maker, order.maker);
+    assert_eq!(decoded.salt, order.salt);
+    assert_eq!(decoded.amount, order.amount);
+
+    // the ABI and EIP-712 type are unchanged
+    assert_eq!(
+        placeCall::SIGNATURE,
+        "place((address,address,bytes32,uint256),address)"
+    );
+    assert_eq!(
+        Order::eip712_encode_type(),
+        "Order(address referrer,address maker,bytes32 salt,uint256 amount)"
+    );
+
+    for recipient in [None, Some(Address::repeat_byte(0x33))] {
+        let call = placeCall {
+            order: order.clone(),
+            recipient,
+        };
+        let decoded = placeCall::abi_decode(&call.abi_encode(), true).unwrap();
+        assert_eq!(decoded.recipient, recipient);
+        assert_eq!(decoded.order.salt, order.salt);
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_roundtrip() {
+    use alloy_sol_types::SolSerde;
+
+    sol! {
+        #![sol(serde)]
+
+        #[derive(Debug, PartialEq)]
+        enum Side {
+            Buy,
+            Sell,
+        }
+
+        type Price is uint128;
+
+        #[derive(Debug, PartialEq)]
+        struct Leg {
+            address token;
+            uint256 amount;
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Order {
+            Leg[] legs;
+            bytes32 salt;
+            bytes data;
+            int64 offset;
+            Price limit;
+            bool partial;
+            string memo;
+            uint8[2] flags;
+        }
+    }
+
+    let order = Order {
+        legs: vec![
+            Leg {
+                token: "0xd8da6bf26964af9d7eed9e03e53415d37aa96045"
+                    .parse()
+                    .unwrap(),
+                amount: U256::MAX,
+            },
+            Leg {
+                token: Address::ZERO,
+                amount: U256::from(1),
+            },
+        ],
+        salt: B256::repeat_byte(0x11),
+        data: vec![0xde, 0xad],
+        offset: -5,
+        limit: 1_000,
+        partial: true,
+        memo: "hi".into(),
+        flags: [1, 2],
+    };
+
+    let json = serde_json::to_value(&order).unwrap();
+    let leg = &json["legs"][0];
+    assert_eq!(leg["token"], "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045");
+    assert_eq!(leg["amount"], U256::MAX.to_string());
+    assert_eq!(json["salt"], format!("0x{}", "11".repeat(32)));
+    assert_eq!(json["data"], "0xdead");
+    assert_eq!(json["offset"], "-5");
+    assert_eq!(json["limit"], "1000");
+    assert_eq!(json["partial"], true);
+    assert_eq!(json["memo"], "hi");
+    assert_eq!(json["flags"], serde_json::json!(["1", "2"]));
+
+    let decoded: Order = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(decoded, order);
+
+    // addresses are accepted in any case
+    let mut lenient = json.clone();
+    lenient["legs"][0]["token"] = "0xD8DA6BF26964AF9D7EED9E03E53415D37AA96045".into();
+    assert_eq!(serde_json::from_value::<Order>(lenient).unwrap(), order);
+
+    let mut invalid = json;
+    invalid["flags"] = serde_json::json!(["1"]);
+    assert!(serde_json::from_value::<Order>(invalid).is_err());
+
+    // enums are `uint8`s
+    let value = Side::serialize(&Side::Sell, serde_json::value::Serializer).unwrap();
+    assert_eq!(value, "1");
+    assert_eq!(Side::deserialize(value).unwrap(), Side::Sell);
+    assert!(Side::deserialize(Value::from("2")).is_err());
+
+    // without the attribute, the impls are left to the user
+    sol! {
+        struct Manual {
+            uint256 a;
+        }
+    }
+
+    impl Serialize for Manual {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.a.serialize(serializer)
+        }
+    }
+
+    let value = serde_json::to_value(Manual { a: U256::from(1) }).unwrap();
+    assert_eq!(value, "0x1");
+}
+
+#[test]
+fn encode_args_prefix() {
+    sol! {
+        function permit(
+            address owner,
+            address spender,
+            uint256 value,
+            uint256 deadline,
+            uint8 v,
+            bytes32 r,
+            bytes32 s
+        );
+
+        function executeMetaTransaction(
+            address user,
+            bytes functionSignature,
+            uint256 nonce,
+            bytes signature
+        );
+    }
+
+    // EIP-2612: the signed values are a prefix of the static arguments
+    let permit = permitCall {
+        owner: Address::repeat_byte(0x11),
+        spender: Address::repeat_byte(0x22),
+        value: U256::MAX,
+        deadline: U256::from(1_700_000_000),
+        v: 27,
+        r: B256::repeat_byte(0x33),
+        s: B256::repeat_byte(0x44),
+    };
+    let raw = &permit.abi_encode()[4..];
+    for n in 0..=7 {
+        assert_eq!(permit.abi_encode_args_prefix(n), raw[..n * 32], "{n}");
+    }
+    assert_eq!(permit.abi_encode_args_prefix(8), raw);
+
+    // dynamic arguments have their offsets relative to the prefix
+    let call = executeMetaTransactionCall {
+        user: Address::repeat_byte(0x11),
+        functionSignature: hex!("095ea7b3").to_vec(),
+        nonce: U256::from(3),
+        signature: vec![0x55; 65],
+    };
+    type Prefix = (sol_data::Address, sol_data::Bytes, sol_data::Uint<256>);
+    assert_eq!(
+        call.abi_encode_args_prefix(3),
+        Prefix::abi_encode_sequence(&(call.user, call.functionSignature.clone(), call.nonce))
+    );
+    assert_eq!(call.abi_encode_args_prefix(4), &call.abi_encode()[4..]);
+    assert!(call.abi_encode_args_prefix(0).is_empty());
+}
+
+#[test]
+fn fixed_array_length_mismatch() {
+    sol! {
+        #[derive(Debug, PartialEq)]
+        struct Prices {
+            uint64 timestamp;
+            uint256[4] prices;
+        }
+    }
+
+    let words = |n: u64| -> Vec<u8> {
+        (1..=n)
+            .flat_map(|i| U256::from(i).to_be_bytes::<32>())
+            .collect()
+    };
+    let mismatch = |expected, got| Error::FixedArrayLengthMismatch { expected, got };
+
+    // top level, static elements
+    type Words = sol_data::FixedArray<sol_data::Uint<256>, 4>;
+    for n in 0..4 {
+        assert_eq!(
+            Words::abi_decode(&words(n), false),
+            Err(mismatch(4, n as usize))
+        );
+        assert_eq!(
+            Words::abi_decode(&words(n), true),
+            Err(mismatch(4, n as usize))
+        );
+    }
+    // extra elements can not be told apart from trailing data
+    let expected = [1, 2, 3, 4].map(U256::from);
+    assert_eq!(Words::abi_decode(&words(5), false), Ok(expected));
+    assert_eq!(
+        Words::abi_decode(&words(5), true),
+        Err(Error::ReserMismatch)
+    );
+
+    // top level, dynamic elements
+    type Strings = sol_data::FixedArray<sol_data::String, 3>;
+    let strings = ["a".to_string(), "b".to_string(), "c".to_string()];
+    let encoded = Strings::abi_encode(&strings);
+    assert_eq!(Strings::abi_decode(&encoded, true).unwrap(), strings);
+    assert_eq!(
+        Strings::abi_decode(&encoded[..96], true),
+        Err(mismatch(3, 2))
+    );
+
+    // nested inside a struct
+    let prices = Prices {
+        timestamp: 1_700_000_000,
+        prices: [1, 2, 3, 4].map(U256::from),
+    };
+    let encoded = Prices::abi_encode(&prices);
+    assert_eq!(Prices::abi_decode(&encoded, true).unwrap(), prices);
+    let field = |error| Error::Element {
+        path: vec![PathElement::Field(1)],
+        error: Box::new(error),
+    };
+    assert_eq!(
+        Prices::abi_decode(&encoded[..128], true),
+        Err(field(mismatch(4, 3)))
+    );
+    assert_eq!(
+        Prices::abi_decode(&encoded[..40], false),
+        Err(field(mismatch(4, 0)))
+    );
+
+    let mut over = encoded.clone();
+    over.extend_from_slice(&[0; 32]);
+    assert_eq!(Prices::abi_decode(&over, false).unwrap(), prices);
+    assert_eq!(Prices::abi_decode(&over, true), Err(Error::ReserMismatch));
+}
+
+#[test]
+fn decode_error_path() {
+    sol! {
+        #[derive(Debug)]
+        struct Transfer {
+            address to;
+            uint256 amount;
+        }
+
+        #[derive(Debug)]
+        function batch(address token, bool strict, uint256[] amounts, Transfer[] transfers);
+    }
+
+    let call = batchCall {
+        token: Address::repeat_byte(0x11),
+        strict: true,
+        amounts: (0..100).map(U256::from).collect(),
+        transfers: vec![],
+    };
+    let encoded = call.abi_encode();
+
+    // cut off the amounts after the 57th element
+    let truncated = &encoded[4..4 + 32 * (4 + 1 + 57) + 16];
+    let err = batchCall::abi_decode_raw(truncated, false).unwrap_err();
+    assert_eq!(
+        err,
+        Error::Element {
+            path: vec![PathElement::Field(2), PathElement::Index(57)],
+            error: Box::new(Error::Overrun),
+        }
+    );
+    assert_eq!(err.root_cause(), &Error::Overrun);
+    assert_eq!(
+        err.to_string(),
+        "tuple.2[57]: Buffer overrun while deserializing"
+    );
+
+    // a single value is not wrapped in a tuple
+    type Amounts = sol_data::Array<sol_data::Uint<256>>;
+    let encoded = Amounts::abi_encode(&call.amounts);
+    let err = Amounts::abi_decode(&encoded[..32 * (2 + 57) + 16], false).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "array[57]: Buffer overrun while deserializing"
+    );
+    assert_eq!(
+        Amounts::abi_decode(&encoded[..32], false),
+        Err(Error::Overrun)
+    );
+
+    // nested struct fields
+    let call = batchCall {
+        transfers: vec![
+            Transfer {
+                to: Address::repeat_byte(0x22),
+                amount: U256::from(1),
+            };
+            3
+        ],
+        ..call
+    };
+    let encoded = call.abi_encode();
+    let err = batchCall::abi_decode(&encoded[..encoded.len() - 16], false).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "tuple.3[2].1: Buffer overrun while deserializing"
+    );
+}
+
+#[test]
+fn large_interface_dispatch() {
+    mod table {
+        alloy_sol_types::sol!("tests/contracts/LargeInterface.sol");
+    }
+    mod linear {
+        alloy_sol_types::sol!(
+            #![sol(phf = false)]
+            "tests/contracts/LargeInterface.sol"
+        );
+    }
+    use alloy_sol_types::SolInterface;
+    use linear::LargeInterface::{
+        LargeInterfaceCalls as Linear, LargeInterfaceErrors as LinearErrors,
+    };
+    use table::LargeInterface::{
+        LargeInterfaceCalls as Table, LargeInterfaceErrors as TableErrors,
+    };
+
+    assert_eq!(Table::COUNT, 128);
+    assert_eq!(Table::SELECTORS, Linear::SELECTORS);
+    assert!(Table::SELECTORS.windows(2).all(|w| w[0] < w[1]));
+
+    // zeroes are valid arguments for every function
+    let mut data = [0u8; 4 + 32 * 8];
+    for (i, &selector) in Table::SELECTORS.iter().enumerate() {
+        assert_eq!(Table::selector_index(selector), Some(i));
+        assert!(Table::valid_selector(selector));
+        assert!(Linear::valid_selector(selector));
+
+        data[..4].copy_from_slice(&selector);
+        let table = Table::abi_decode(&data, false).unwrap();
+        let linear = Linear::abi_decode(&data, false).unwrap();
+        assert_eq!(table.selector(), selector);
+        assert_eq!(table.abi_encode(), linear.abi_encode());
+    }
+
+    assert_eq!(TableErrors::COUNT, 40);
+    for (i, &selector) in TableErrors::SELECTORS.iter().enumerate() {
+        assert_eq!(TableErrors::selector_index(selector), Some(i));
+        data[..4].copy_from_slice(&selector);
+        let table = TableErrors::abi_decode(&data, false).unwrap();
+        let linear = LinearErrors::abi_decode(&data, false).unwrap();
+        assert_eq!(table.selector(), selector);
+        assert_eq!(table.abi_encode(), linear.abi_encode());
+    }
+
+    for selector in [[0; 4], [0xff; 4], [0x12, 0x34, 0x56, 0x78]] {
+        assert_eq!(Table::selector_index(selector), None);
+        assert!(!Table::valid_selector(selector));
+        data[..4].copy_from_slice(&selector);
+        let error = Error::unknown_selector("LargeInterfaceCalls", selector);
+        assert_eq!(Table::abi_decode(&data, false).err(), Some(error.clone()));
+        assert_eq!(Linear::abi_decode(&data, false).err(), Some(error));
+    }
+}
+
+#[test]
+fn return_struct() {
+    sol! {
+        #[derive(Debug, PartialEq)]
+        interface IUniswapV2Pair {
+            #[sol(return_struct = "Reserves")]
+            function getReserves()
+                external
+                view
+                returns (uint112 reserve0, uint112 reserve1, uint32 blockTimestampLast);
+            function token0() external view returns (address);
+        }
+    }
+    use IUniswapV2Pair::{getReservesCall, IUniswapV2PairCalls, Reserves};
+
+    // `getReserves()` of the USDC/WETH pair
+    let data = hex!(
+        "000000000000000000000000000000000000000000000000000025c42f673dd5"
+        "0000000000000000000000000000000000000000000004a4a47c477a270490c0"
+        "000000000000000000000000000000000000000000000000000000006525b1ef"
+    );
+    let reserves: Reserves = getReservesCall::abi_decode_returns(&data, true).unwrap();
+    assert_eq!(
+        reserves,
+        Reserves {
+            reserve0: 41_524_539_112_917,
+            reserve1: 21_926_584_386_476_245_815_488,
+            blockTimestampLast: 1_696_969_199,
+        }
+    );
+
+    // `uint112` and `uint32` are the smallest fitting Rust integers
+    let (reserve0, reserve1, timestamp): (u128, u128, u32) = reserves.clone().into();
+    assert_eq!(timestamp, 1_696_969_199);
+    assert_eq!(Reserves::from((reserve0, reserve1, timestamp)), reserves);
+    assert_eq!(
+        getReservesCall::abi_encode_returns(&(reserve0, reserve1, timestamp)),
+        data
+    );
+
+    // values that do not fit in `uint112` are rejected
+    let mut overflow = data;
+    overflow[32 + 17] = 1;
+    assert!(getReservesCall::abi_decode_returns(&overflow, true).is_err());
+
+    // other functions keep the default name
+    let token0 = IUniswapV2Pair::token0Return { _0: Address::ZERO };
+    let _ = IUniswapV2Pair::IUniswapV2PairReturns::token0(token0);
+    let _ = IUniswapV2Pair::IUniswapV2PairReturns::getReserves(reserves);
+    let _ = IUniswapV2PairCalls::getReserves(getReservesCall {});
+}
+
+#[test]
+#[cfg(feature = "sol-literal")]
+fn sol_literal() {
+    sol! {
+        type Amount is uint128;
+
+        enum Kind {
+            Deposit,
+            Withdrawal,
+        }
+
+        struct Inner {
+            Amount amount;
+            bytes data;
+        }
+
+        struct Outer {
+            address owner;
+            Inner[] inners;
+            string note;
+            bytes32 hash;
+            int8 delta;
+        }
+    }
+
+    let outer = Outer {
+        owner: Address::repeat_byte(0xab),
+        inners: vec![
+            Inner {
+                amount: 1,
+                data: vec![1, 2],
+            },
+            Inner {
+                amount: 2,
+                data: vec![],
+            },
+        ],
+        note: "say \"hi\"\n".into(),
+        hash: B256::with_last_byte(1),
+        delta: -3,
+    };
+    // arrays have no literal form that compiles for every array type
+    let inners = format!(
+        r#"abi.decode(hex"{}", (Inner[]))"#,
+        hex::encode(sol_data::Array::<Inner>::abi_encode(&outer.inners))
+    );
+    let value = (outer, Kind::Withdrawal, true);
+
+    type Nested = (Outer, Kind, sol_data::Bool);
+    assert_eq!(
+        Nested::to_sol_literal(&value),
+        format!(
+            "(Outer({{owner: 0xABaBaBaBABabABabAbAbABAbABabababaBaBABaB, inners: {inners}, \
+             note: {note}, \
+             hash: 0x0000000000000000000000000000000000000000000000000000000000000001, \
+             delta: -3}}), Kind.Withdrawal, true)",
+            note = r#""say \"hi\"\n""#,
+        )
+    );
+
+    assert_eq!(
+        sol_data::FixedArray::<sol_data::Uint<256>, 2>::to_sol_literal(&[U256::from(7); 2]),
+        concat!(
+            r#"abi.decode(hex""#,
+            "0000000000000000000000000000000000000000000000000000000000000007",
+            "0000000000000000000000000000000000000000000000000000000000000007",
+            r#"", (uint256[2]))"#,
+        )
+    );
+
+    assert_eq!(
+        sol_data::String::to_sol_literal(&"é".into()),
+        r#"unicode"é""#
+    );
+    assert_eq!(
+        sol_data::FixedBytes::<20>::to_sol_literal(&FixedBytes::ZERO),
+        r#"bytes20(hex"0000000000000000000000000000000000000000")"#
+    );
+}
+
+#[test]
+fn pretty() {
+    sol! {
+        type Amount is uint128;
+
+        enum Kind {
+            Deposit,
+            Withdrawal,
+        }
+
+        struct Inner {
+            Amount amount;
+            bytes data;
+        }
+
+        struct Outer {
+            address owner;
+            Inner[] inners;
+            string note;
+            bytes4 tag;
+            int8 delta;
+        }
+    }
+
+    let outer = Outer {
+        owner: Address::repeat_byte(0xab),
+        inners: vec![
+            Inner {
+                amount: 1,
+                data: vec![1, 2],
+            },
+            Inner {
+                amount: 2,
+                data: vec![],
+            },
+        ],
+        note: "say \"hi\"".into(),
+        tag: FixedBytes(hex!("deadbeef")),
+        delta: -3,
+    };
+
+    assert_eq!(
+        outer.pretty().to_string(),
+        concat!(
+            "Outer { owner: 0xABaBaBaBABabABabAbAbABAbABabababaBaBABaB, inners: [",
+            "Inner { amount: Amount(1), data: 0x0102 }, Inner { amount: Amount(2), data: 0x }], ",
+            r#"note: "say \"hi\"", tag: 0xdeadbeef, delta: -3 }"#,
+        )
+    );
+    assert_eq!(format!("{:?}", outer.pretty()), outer.pretty().to_string());
+
+    // nested values are indented, and addresses are not abbreviated
+    assert_eq!(
+        format!("{:#}", outer.pretty()),
+        r#"Outer {
+    owner: 0xABaBaBaBABabABabAbAbABAbABabababaBaBABaB,
+    inners: [
+        Inner {
+            amount: Amount(
+                1,
+            ),
+            data: 0x0102,
+        },
+        Inner {
+            amount: Amount(
+                2,
+            ),
+            data: 0x,
+        },
+    ],
+    note: "say \"hi\"",
+    tag: 0xdeadbeef,
+    delta: -3,
+}"#
+    );
+
+    type Tuple = (
+        Kind,
+        sol_data::Bool,
+        sol_data::Array<sol_data::Uint<256>>,
+        Outer,
+    );
+    let value = (Kind::Withdrawal, true, vec![U256::from(7)], outer.clone());
+    assert!(Pretty::<Tuple>::new(&value)
+        .to_string()
+        .starts_with("(Kind.Withdrawal, true, [7], Outer { owner: "));
+    assert_eq!(Pretty::<()>::new(&()).to_string(), "()");
+}
+
+#[test]
+fn constructor() {
+    use alloy_sol_types::SolConstructor;
+
+    sol! {
+        contract Token {
+            constructor(string name, address owner, uint256 supply);
+            function name() external view returns (string);
+        }
+
+        contract Empty {
+            constructor();
+        }
+    }
+
+    let args = Token::constructorCall {
+        name: "Token".into(),
+        owner: Address::repeat_byte(0x11),
+        supply: U256::from(1000),
+    };
+    let encoded = args.abi_encode();
+    type Args = (sol_data::String, sol_data::Address, sol_data::Uint<256>);
+    assert_eq!(
+        encoded,
+        Args::abi_encode_sequence(&(args.name.clone(), args.owner, args.supply))
+    );
+    assert_eq!(encoded.len(), args.abi_encoded_size());
+
+    let decoded = Token::constructorCall::abi_decode(&encoded, true).unwrap();
+    assert_eq!(
+        (decoded.name, decoded.owner, decoded.supply),
+        (args.name, args.owner, args.supply)
+    );
+
+    // no selector, and no arguments encode to nothing
+    assert_eq!(Empty::constructorCall {}.abi_encode(), Vec::<u8>::new());
+    assert_eq!(Empty::constructorCall {}.abi_encoded_size(), 0);
+}
+
+// Constructors that compiled before they were expanded still compile.
+#[test]
+fn constructor_previously_valid() {
+    use alloy_sol_types::SolConstructor;
+
+    sol! {
+        contract Unnamed {
+            constructor(address, uint256) payable;
+        }
+
+        contract Unresolved {
+            constructor(Other.Config config, address owner);
+            function owner() external view returns (address);
+        }
+    }
+
+    let args = Unnamed::constructorCall {
+        _0: Address::repeat_byte(0x11),
+        _1: U256::from(1),
+    };
+    assert_eq!(args.abi_encoded_size(), 64);
+
+    // no `constructorCall` is generated for unresolved types, as before
+    let _ = Unresolved::ownerCall {};
 }
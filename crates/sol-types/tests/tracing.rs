@@ -0,0 +1,127 @@
+#![cfg(feature = "tracing")]
+
+use alloy_primitives::U256;
+use alloy_sol_types::{sol, sol_data, SolCall, SolType};
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, registry::Registry, Layer};
+
+#[derive(Debug, Default)]
+struct Record {
+    name: &'static str,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Record {
+    fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+impl Visit for Record {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.fields.push((field.name(), format!("{value:?}")));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.fields.push((field.name(), value.to_string()));
+    }
+}
+
+#[derive(Clone, Default)]
+struct Capture {
+    spans: Arc<Mutex<Vec<Record>>>,
+    events: Arc<Mutex<Vec<Record>>>,
+}
+
+impl<S: Subscriber> Layer<S> for Capture {
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, _id: &span::Id, _ctx: Context<'_, S>) {
+        let mut record = Record {
+            name: attrs.metadata().name(),
+            ..Default::default()
+        };
+        attrs.record(&mut record);
+        self.spans.lock().unwrap().push(record);
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut record = Record {
+            name: event.metadata().name(),
+            ..Default::default()
+        };
+        event.record(&mut record);
+        self.events.lock().unwrap().push(record);
+    }
+}
+
+fn with_capture<R>(f: impl FnOnce() -> R) -> (R, Capture) {
+    let capture = Capture::default();
+    let subscriber = Registry::default().with(capture.clone());
+    let r = tracing::subscriber::with_default(subscriber, f);
+    (r, capture)
+}
+
+#[test]
+fn sol_type_spans() {
+    type MyTy = (sol_data::Uint<256>, sol_data::Bool);
+
+    let (encoded, capture) = with_capture(|| MyTy::abi_encode(&(U256::from(1), true)));
+    {
+        let spans = capture.spans.lock().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].name, "abi_encode");
+        assert_eq!(spans[0].field("sol_type"), Some("(uint256,bool)"));
+    }
+
+    let (decoded, capture) = with_capture(|| MyTy::abi_decode(&encoded, true));
+    assert_eq!(decoded.unwrap(), (U256::from(1), true));
+    let spans = capture.spans.lock().unwrap();
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].name, "abi_decode");
+    assert_eq!(spans[0].field("sol_type"), Some("(uint256,bool)"));
+    assert_eq!(spans[0].field("len"), Some("64"));
+    assert_eq!(spans[0].field("validate"), Some("true"));
+    assert!(capture.events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn sol_call_spans_and_errors() {
+    sol! {
+        function foo(uint256 a, bool b);
+    }
+
+    let call = fooCall {
+        a: U256::from(1),
+        b: true,
+    };
+    let encoded = call.abi_encode();
+
+    let (decoded, capture) = with_capture(|| fooCall::abi_decode(&encoded, true));
+    assert_eq!(decoded.unwrap().a, call.a);
+    {
+        let spans = capture.spans.lock().unwrap();
+        let names = spans.iter().map(|s| s.name).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["abi_decode", "abi_decode_raw", "abi_decode_sequence"]
+        );
+        assert_eq!(spans[0].field("signature"), Some(fooCall::SIGNATURE));
+        assert_eq!(spans[0].field("len"), Some("68"));
+    }
+
+    // wrong selector
+    let (decoded, capture) = with_capture(|| fooCall::abi_decode(&[0; 68], true));
+    assert!(decoded.is_err());
+    let events = capture.events.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    assert!(events[0].field("error").unwrap().contains("TypeCheckFail"));
+}
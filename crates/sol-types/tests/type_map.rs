@@ -0,0 +1,171 @@
+//! Checks that [`SOLIDITY_TYPE_MAP`] matches the types generated by [`sol!`].
+
+extern crate alloc;
+
+use alloy_sol_types::{
+    sol,
+    sol_data::{rust_type_for, SOLIDITY_TYPE_MAP},
+};
+
+fn normalize(ty: &str) -> String {
+    ty.split_whitespace().collect()
+}
+
+macro_rules! type_map {
+    ($($sol:ident => $rust:ty;)+) => {
+        // Each function only compiles if the field type generated by `sol!` is
+        // exactly the listed Rust type.
+        $(
+            #[allow(non_snake_case, dead_code)]
+            fn $sol() {
+                sol! {
+                    struct S {
+                        $sol x;
+                    }
+                }
+
+                fn field(s: S) -> $rust {
+                    s.x
+                }
+            }
+        )+
+
+        const TYPE_MAP: &[(&str, &str)] = &[$((stringify!($sol), stringify!($rust)),)+];
+    };
+}
+
+type_map! {
+    address => alloy_primitives::Address;
+    bool => bool;
+    bytes => alloc::vec::Vec<u8>;
+    string => alloc::string::String;
+    int8 => i8;
+    uint8 => u8;
+    int16 => i16;
+    uint16 => u16;
+    int24 => i32;
+    uint24 => u32;
+    int32 => i32;
+    uint32 => u32;
+    int40 => i64;
+    uint40 => u64;
+    int48 => i64;
+    uint48 => u64;
+    int56 => i64;
+    uint56 => u64;
+    int64 => i64;
+    uint64 => u64;
+    int72 => i128;
+    uint72 => u128;
+    int80 => i128;
+    uint80 => u128;
+    int88 => i128;
+    uint88 => u128;
+    int96 => i128;
+    uint96 => u128;
+    int104 => i128;
+    uint104 => u128;
+    int112 => i128;
+    uint112 => u128;
+    int120 => i128;
+    uint120 => u128;
+    int128 => i128;
+    uint128 => u128;
+    int136 => alloy_primitives::I256;
+    uint136 => alloy_primitives::U256;
+    int144 => alloy_primitives::I256;
+    uint144 => alloy_primitives::U256;
+    int152 => alloy_primitives::I256;
+    uint152 => alloy_primitives::U256;
+    int160 => alloy_primitives::I256;
+    uint160 => alloy_primitives::U256;
+    int168 => alloy_primitives::I256;
+    uint168 => alloy_primitives::U256;
+    int176 => alloy_primitives::I256;
+    uint176 => alloy_primitives::U256;
+    int184 => alloy_primitives::I256;
+    uint184 => alloy_primitives::U256;
+    int192 => alloy_primitives::I256;
+    uint192 => alloy_primitives::U256;
+    int200 => alloy_primitives::I256;
+    uint200 => alloy_primitives::U256;
+    int208 => alloy_primitives::I256;
+    uint208 => alloy_primitives::U256;
+    int216 => alloy_primitives::I256;
+    uint216 => alloy_primitives::U256;
+    int224 => alloy_primitives::I256;
+    uint224 => alloy_primitives::U256;
+    int232 => alloy_primitives::I256;
+    uint232 => alloy_primitives::U256;
+    int240 => alloy_primitives::I256;
+    uint240 => alloy_primitives::U256;
+    int248 => alloy_primitives::I256;
+    uint248 => alloy_primitives::U256;
+    int256 => alloy_primitives::I256;
+    uint256 => alloy_primitives::U256;
+    bytes1 => alloy_primitives::FixedBytes<1>;
+    bytes2 => alloy_primitives::FixedBytes<2>;
+    bytes3 => alloy_primitives::FixedBytes<3>;
+    bytes4 => alloy_primitives::FixedBytes<4>;
+    bytes5 => alloy_primitives::FixedBytes<5>;
+    bytes6 => alloy_primitives::FixedBytes<6>;
+    bytes7 => alloy_primitives::FixedBytes<7>;
+    bytes8 => alloy_primitives::FixedBytes<8>;
+    bytes9 => alloy_primitives::FixedBytes<9>;
+    bytes10 => alloy_primitives::FixedBytes<10>;
+    bytes11 => alloy_primitives::FixedBytes<11>;
+    bytes12 => alloy_primitives::FixedBytes<12>;
+    bytes13 => alloy_primitives::FixedBytes<13>;
+    bytes14 => alloy_primitives::FixedBytes<14>;
+    bytes15 => alloy_primitives::FixedBytes<15>;
+    bytes16 => alloy_primitives::FixedBytes<16>;
+    bytes17 => alloy_primitives::FixedBytes<17>;
+    bytes18 => alloy_primitives::FixedBytes<18>;
+    bytes19 => alloy_primitives::FixedBytes<19>;
+    bytes20 => alloy_primitives::FixedBytes<20>;
+    bytes21 => alloy_primitives::FixedBytes<21>;
+    bytes22 => alloy_primitives::FixedBytes<22>;
+    bytes23 => alloy_primitives::FixedBytes<23>;
+    bytes24 => alloy_primitives::FixedBytes<24>;
+    bytes25 => alloy_primitives::FixedBytes<25>;
+    bytes26 => alloy_primitives::FixedBytes<26>;
+    bytes27 => alloy_primitives::FixedBytes<27>;
+    bytes28 => alloy_primitives::FixedBytes<28>;
+    bytes29 => alloy_primitives::FixedBytes<29>;
+    bytes30 => alloy_primitives::FixedBytes<30>;
+    bytes31 => alloy_primitives::FixedBytes<31>;
+    bytes32 => alloy_primitives::FixedBytes<32>;
+}
+
+// `function` types can't be written as a single identifier
+sol! {
+    struct FunctionStruct {
+        function(uint256) external returns (bool) x;
+    }
+}
+
+#[allow(dead_code)]
+fn function_field(s: FunctionStruct) -> alloy_primitives::Function {
+    s.x
+}
+
+#[test]
+fn solidity_type_map() {
+    let mut expected: Vec<_> = TYPE_MAP
+        .iter()
+        .map(|&(sol, rust)| (sol.to_string(), normalize(rust)))
+        .collect();
+    expected.push(("function".into(), "alloy_primitives::Function".into()));
+    expected.sort();
+
+    let mut actual: Vec<_> = SOLIDITY_TYPE_MAP
+        .iter()
+        .map(|&(sol, rust)| (sol.to_string(), rust.to_string()))
+        .collect();
+    actual.sort();
+
+    assert_eq!(actual, expected);
+    for (sol, rust) in &actual {
+        assert_eq!(rust_type_for(sol), Some(rust.as_str()));
+    }
+}
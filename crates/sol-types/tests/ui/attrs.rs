@@ -0,0 +1,89 @@
+use alloy_sol_types::sol;
+
+sol! {
+    #![sol(return_struct = "Ret")]
+    function f() returns (uint256);
+}
+
+sol! {
+    #[sol(return_struct = "Ret")]
+    struct Struct {
+        uint256 a;
+    }
+}
+
+sol! {
+    #[sol(return_struct = "Ret")]
+    event Event(uint256 a);
+}
+
+sol! {
+    #[sol(return_struct = "Ret")]
+    error Error(uint256 a);
+}
+
+sol! {
+    #[sol(return_struct = "Ret")]
+    enum Enum {
+        A,
+    }
+}
+
+sol! {
+    #[sol(return_struct = "Ret")]
+    contract Contract {}
+}
+
+sol! {
+    contract Constructor {
+        #[sol(return_struct = "Ret")]
+        constructor(uint256 a);
+    }
+}
+
+sol! {
+    #[sol(builder)]
+    function f(uint256 a);
+}
+
+sol! {
+    contract BuilderConstructor {
+        #[sol(builder)]
+        constructor(uint256 a);
+    }
+}
+
+sol! {
+    #[sol(builder)]
+    contract BuilderContract {}
+}
+
+sol! {
+    #[sol(builder)]
+    error BuilderError(uint256 a);
+}
+
+sol! {
+    #[sol(builder = false)]
+    enum BuilderEnum {
+        A,
+    }
+}
+
+
+sol! {
+    #[sol(serde)]
+    function serdeFunction(uint256 a);
+}
+
+sol! {
+    #[sol(serde)]
+    event SerdeEvent(uint256 a);
+}
+
+sol! {
+    #[sol(serde)]
+    error SerdeError(uint256 a);
+}
+
+fn main() {}
@@ -34,4 +34,18 @@ sol! {
     }
 }
 
+sol! {
+    interface SelectorCollision {
+        function burn(uint256) external;
+        function collate_propagate_storage(bytes16) external;
+    }
+}
+
+sol! {
+    interface ErrorSelectorCollision {
+        error burn(uint256);
+        error collate_propagate_storage(bytes16);
+    }
+}
+
 fn main() {}
@@ -0,0 +1,49 @@
+use alloy_sol_types::SolValue;
+
+#[derive(SolValue)]
+struct Ambiguous {
+    a: u64,
+    b: Vec<u8>,
+    c: (bool, [usize; 2]),
+}
+
+#[derive(SolValue)]
+struct Mismatched {
+    #[sol(uint256)]
+    a: u64,
+}
+
+#[derive(SolValue)]
+struct NotSolType {
+    a: std::collections::HashMap<u8, u8>,
+}
+
+#[derive(SolValue)]
+struct Duplicate {
+    #[sol(uint64)]
+    #[sol(uint32)]
+    a: u64,
+}
+
+#[derive(SolValue)]
+struct Generic<T> {
+    a: T,
+}
+
+#[derive(SolValue)]
+enum Enum {
+    A,
+}
+
+// OK
+#[derive(SolValue)]
+struct Annotated {
+    #[sol(uint64)]
+    a: u64,
+    #[sol(bytes)]
+    b: Vec<u8>,
+    #[sol((bool, int128[2]))]
+    c: (bool, [i128; 2]),
+}
+
+fn main() {}
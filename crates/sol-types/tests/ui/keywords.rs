@@ -0,0 +1,20 @@
+use alloy_sol_types::sol;
+
+// OK
+sol! {
+    struct RawKeywords {
+        uint type;
+        bool move;
+    }
+
+    function lib(RawKeywords memory self, uint super) returns (uint crate);
+}
+
+sol! {
+    struct NotRawKeywords {
+        uint self;
+        uint crate;
+    }
+}
+
+fn main() {}
@@ -1,4 +1,4 @@
-use crate::{Item, Spanned};
+use crate::{utils::skip_semis, Item, Spanned};
 use proc_macro2::Span;
 use syn::{
     parse::{Parse, ParseStream},
@@ -18,10 +18,12 @@ impl Parse for File {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let attrs = input.call(Attribute::parse_inner)?;
         let mut items = Vec::new();
+        skip_semis(input)?;
         let mut first = true;
         while first || !input.is_empty() {
             first = false;
             items.push(input.parse()?);
+            skip_semis(input)?;
         }
         Ok(Self { attrs, items })
     }
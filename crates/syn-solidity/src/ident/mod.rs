@@ -27,8 +27,10 @@ impl quote::IdentFragment for SolIdent {
 }
 
 impl fmt::Display for SolIdent {
+    /// Formats the identifier as it is written in Solidity, without the `r#`
+    /// prefix of raw identifiers.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
+        f.write_str(&self.as_string())
     }
 }
 
@@ -104,9 +106,16 @@ impl SolIdent {
     }
 
     /// Parses any identifier including keywords.
+    ///
+    /// Rust keywords, which are valid Solidity identifiers, such as `type` or
+    /// `move`, are converted to raw identifiers (`r#type`), so that they can be
+    /// used in Rust code. [`as_string`](Self::as_string) still returns the
+    /// original name.
     pub fn parse_any(input: ParseStream<'_>) -> Result<Self> {
         check_dollar(input)?;
-        input.call(Ident::parse_any).map(Self)
+        input
+            .call(Ident::parse_any)
+            .map(|ident| Self(to_raw_if_keyword(ident)))
     }
 
     /// Peeks any identifier including keywords.
@@ -123,6 +132,26 @@ impl SolIdent {
     }
 }
 
+/// Rust keywords that can be used as raw identifiers. `self`, `Self`, `super`
+/// and `crate` cannot be raw identifiers, and `true` and `false` are literals
+/// in Solidity too.
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "final", "fn", "for", "if", "impl", "in", "let", "loop", "macro",
+    "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static", "struct",
+    "trait", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while",
+    "yield",
+];
+
+fn to_raw_if_keyword(ident: Ident) -> Ident {
+    let s = ident.to_string();
+    if RUST_KEYWORDS.contains(&s.as_str()) {
+        Ident::new_raw(&s, ident.span())
+    } else {
+        ident
+    }
+}
+
 fn check_dollar(input: ParseStream<'_>) -> Result<()> {
     if input.peek(Token![$]) {
         Err(input.error("Solidity identifiers starting with `$` are unsupported. This is a known limitation of syn-solidity."))
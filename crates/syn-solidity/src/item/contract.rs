@@ -1,4 +1,8 @@
-use crate::{kw, utils::DebugPunctuated, Item, Modifier, SolIdent, Spanned};
+use crate::{
+    kw,
+    utils::{skip_semis, DebugPunctuated},
+    Item, Modifier, SolIdent, Spanned,
+};
 use proc_macro2::Span;
 use std::{cmp::Ordering, fmt};
 use syn::{
@@ -60,12 +64,14 @@ impl Parse for ItemContract {
             brace_token: braced!(content in input),
             body: {
                 let mut body = Vec::new();
+                skip_semis(&content)?;
                 while !content.is_empty() {
                     let item: Item = content.parse()?;
                     if matches!(item, Item::Contract(_)) {
                         return Err(Error::new(item.span(), "cannot declare nested contracts"))
                     }
                     body.push(item);
+                    skip_semis(&content)?;
                 }
                 body
             },
@@ -36,6 +36,13 @@ pub(crate) fn peek_tt(input: ParseStream<'_>) -> Option<TokenTree> {
     })
 }
 
+/// Skips any number of stray semicolons, which are not valid Solidity but are
+/// harmless, such as the one in `struct A { ... };`.
+pub(crate) fn skip_semis(input: ParseStream<'_>) -> Result<()> {
+    while input.parse::<Option<Token![;]>>()?.is_some() {}
+    Ok(())
+}
+
 pub(crate) fn tts_until_semi(input: ParseStream<'_>) -> TokenStream {
     let mut tts = TokenStream::new();
     while !input.is_empty() && !input.peek(Token![;]) {
@@ -1,4 +1,4 @@
-use crate::{SolIdent, Spanned, Type, VariableDeclaration};
+use crate::{utils::skip_semis, SolIdent, Spanned, Type, VariableDeclaration};
 use proc_macro2::Span;
 use std::{
     fmt,
@@ -75,11 +75,18 @@ impl Parse for ParameterList {
 /// Struct: enforce semicolon after each field and field name.
 impl Parse for FieldList {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
-        let this = input.parse_terminated(VariableDeclaration::parse_with_name, Token![;])?;
+        let mut this = Punctuated::new();
+        skip_semis(input)?;
+        while !input.is_empty() {
+            this.push_value(VariableDeclaration::parse_with_name(input)?);
+            if input.is_empty() {
+                return Err(input.error("expected trailing semicolon"))
+            }
+            this.push_punct(input.parse()?);
+            skip_semis(input)?;
+        }
         if this.is_empty() {
             Err(input.error("defining empty structs is disallowed"))
-        } else if !this.trailing_punct() {
-            Err(input.error("expected trailing semicolon"))
         } else {
             Ok(Self(this))
         }
@@ -26,3 +26,15 @@ fn ident_dollar() {
         .to_string()
         .contains("Solidity identifiers starting with `$` are unsupported."));
 }
+
+#[test]
+fn ident_rust_keyword() {
+    let id: SolIdent = syn::parse_str("type").unwrap();
+    assert_eq!(id.0.to_string(), "r#type");
+    assert_eq!(id.as_string(), "type");
+    assert_eq!(id.to_string(), "type");
+
+    // cannot be raw identifiers
+    let id: SolIdent = syn::parse_str("self").unwrap();
+    assert_eq!(id.0.to_string(), "self");
+}
@@ -1,6 +1,6 @@
 use proc_macro2::Span;
 use syn::parse_quote;
-use syn_solidity::{FunctionKind, ItemFunction};
+use syn_solidity::{File, FunctionKind, Item, ItemFunction};
 
 #[test]
 fn modifiers() {
@@ -19,3 +19,32 @@ fn modifiers() {
     assert_eq!(none.paren_token, None);
     assert_eq!(some.paren_token, Some(Default::default()));
 }
+
+#[test]
+fn stray_semicolons() {
+    let file: File = parse_quote! {
+        ;
+        struct A { uint a;; uint b; };;
+        contract C { ; function f() external;; };
+    };
+    assert_eq!(file.items.len(), 2);
+    let Item::Struct(a) = &file.items[0] else {
+        panic!("{:?}", file.items[0])
+    };
+    assert_eq!(a.fields.len(), 2);
+    let Item::Contract(c) = &file.items[1] else {
+        panic!("{:?}", file.items[1])
+    };
+    assert_eq!(c.body.len(), 1);
+
+    let e = syn::parse_str::<File>("struct A { uint a }").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "unexpected end of input, expected trailing semicolon"
+    );
+    let e = syn::parse_str::<File>("struct A { ; }").unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "unexpected end of input, defining empty structs is disallowed"
+    );
+}
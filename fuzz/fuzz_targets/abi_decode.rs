@@ -0,0 +1,31 @@
+//! Decodes arbitrary bytes with the static and dynamic coders of the types in
+//! `ROUND_TRIPS`, and with `ethabi` as a reference. None may panic, and any data
+//! that the static coder accepts must be re-encoded unchanged by all of them.
+//!
+//! The first byte selects the type, and the rest is the data.
+
+#![no_main]
+
+use alloy_dyn_abi::DynSolType;
+use alloy_sol_types::abi::round_trip::ROUND_TRIPS;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&i, data)) = data.split_first() else { return };
+    let (name, round_trip) = ROUND_TRIPS[i as usize % ROUND_TRIPS.len()];
+    let ty = DynSolType::parse(name).unwrap();
+
+    let decoded = ty.abi_decode(data);
+    // `ethabi` does not support `function`, and does not validate encodings
+    let reference = ethabi::param_type::Reader::read(name)
+        .ok()
+        .map(|param| ethabi::decode(&[param], data));
+
+    if let Ok(encoded) = round_trip(data) {
+        assert_eq!(encoded, data, "{name}");
+        assert_eq!(decoded.unwrap().abi_encode(), data, "{name}");
+        if let Some(tokens) = reference {
+            assert_eq!(ethabi::encode(&tokens.unwrap()), data, "{name}");
+        }
+    }
+});
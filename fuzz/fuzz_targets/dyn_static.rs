@@ -0,0 +1,26 @@
+//! Encodes arbitrary values with `alloy-dyn-abi`, and checks that the static
+//! coder of the same type, and `ethabi` as a reference, decode them and encode
+//! them back to the same bytes.
+
+#![no_main]
+
+use alloy_dyn_abi::{DynSolType, DynSolValue};
+use alloy_sol_types::abi::round_trip::ROUND_TRIPS;
+use libfuzzer_sys::{arbitrary::Unstructured, fuzz_target};
+
+fuzz_target!(|data: &[u8]| {
+    let mut u = Unstructured::new(data);
+    let Ok(&(name, round_trip)) = u.choose(ROUND_TRIPS) else { return };
+    let ty = DynSolType::parse(name).unwrap();
+    let Ok(value) = DynSolValue::arbitrary_from_type(&ty, &mut u) else { return };
+
+    let encoded = value.abi_encode();
+    assert_eq!(round_trip(&encoded).unwrap(), encoded, "{name}: {value:?}");
+    assert_eq!(ty.abi_decode(&encoded).unwrap(), value, "{name}");
+
+    // `ethabi` does not support `function`
+    if let Ok(param) = ethabi::param_type::Reader::read(name) {
+        let tokens = ethabi::decode(&[param], &encoded).unwrap();
+        assert_eq!(ethabi::encode(&tokens), encoded, "{name}");
+    }
+});
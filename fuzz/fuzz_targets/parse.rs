@@ -0,0 +1,30 @@
+//! Parses arbitrary strings as types and function signatures. The parsers may
+//! not panic, and the canonical name of any parsed type must parse to the same
+//! type.
+
+#![no_main]
+
+use alloy_dyn_abi::DynSolType;
+use alloy_sol_type_parser::{is_valid_identifier, RootType, TupleSpecifier, TypeSpecifier};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|s: &str| {
+    let _ = RootType::parse(s);
+    let _ = TypeSpecifier::parse(s);
+    let _ = TupleSpecifier::parse(s);
+
+    if let Ok(ty) = DynSolType::parse(s) {
+        let name = ty.sol_type_name();
+        assert_eq!(DynSolType::parse(&name).as_ref(), Ok(&ty), "{s:?} -> {name:?}");
+    }
+
+    // `name(type,...)`
+    if let Some(i) = s.find('(') {
+        let (name, params) = s.split_at(i);
+        if is_valid_identifier(name) {
+            if let Ok(tuple) = TupleSpecifier::parse(params) {
+                assert_eq!(tuple.span, params.trim());
+            }
+        }
+    }
+});